@@ -328,10 +328,17 @@ export declare class JsCompilation {
 export declare class JsCompiler {
   constructor(compilerPath: string, options: RawOptions, builtinPlugins: Array<BuiltinPlugin>, registerJsTaps: RegisterJsTaps, outputFilesystem: ThreadsafeNodeFS, intermediateFilesystem: ThreadsafeNodeFS | undefined | null, inputFilesystem: ThreadsafeNodeFS | undefined | null, resolverFactoryReference: JsResolverFactory, unsafeFastDrop: boolean, platform: RawCompilerPlatform)
   setNonSkippableRegisters(kinds: Array<RegisterJsTapKind>): void
+  /**
+   * Set the start time of the watcher which is watching the next build,
+   * persistent cache saves it to match the watcher state resumed by the next process
+   */
+  setWatcherStartTime(startTime?: bigint | undefined | null): void
   /** Build with the given option passed to the constructor */
   build(callback: (err: null | Error) => void): void
   /** Rebuild with the given option passed to the constructor */
   rebuild(changed_files: string[], removed_files: string[], callback: (err: null | Error) => void): void
+  /** Build with the changed and removed files which are resumed from the watcher of previous process */
+  resume(changed_files: string[], removed_files: string[], since: bigint, callback: (err: null | Error) => void): void
   close(): Promise<void>
   getVirtualFileStore(): VirtualFileStore | null
 }
@@ -422,6 +429,7 @@ export declare class JsResolverFactory {
 export declare class JsStats {
   toJson(jsOptions: JsStatsOptions): JsStatsCompilation
   getLogging(acceptedTypes: number): Array<JsStatsLogging>
+  getIncremental(): JsStatsIncremental | null
 }
 
 export declare class KnownBuildInfo {
@@ -449,6 +457,12 @@ export declare class ModuleGraphConnection {
 export declare class NativeWatcher {
   constructor(options: NativeWatcherOptions)
   watch(files: [Array<string>, Array<string>], directories: [Array<string>, Array<string>], missing: [Array<string>, Array<string>], startTime: bigint, callback: (err: Error | null, result: NativeWatchResult) => void, callbackUndelayed: (path: string) => void): void
+  /**
+   * Resume the changes since the last seen state which is saved in `stateFile` by the previous process.
+   *
+   * Returns `null` if there is no saved state or the changes can not be told exactly.
+   */
+  resume(): Promise<NativeResumeResult | null>
   triggerEvent(kind: 'change' | 'remove' | 'create', path: string): void
   /**
    * # Safety
@@ -502,6 +516,8 @@ export type JsVirtualFileStore = VirtualFileStore
 
 export interface AssetInfoRelated {
   sourceMap?: string | null
+  /** the compressed copies of the asset, keyed by the compression algorithm */
+  compressed?: Record<string, string>
 }
 
 export declare function async(path: string, request: string): Promise<ResolveResult>
@@ -564,6 +580,7 @@ export declare enum BuiltinPluginName {
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
+  WebAssemblyModulesPlugin = 'WebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
@@ -597,7 +614,10 @@ export declare enum BuiltinPluginName {
   RstestPlugin = 'RstestPlugin',
   RslibPlugin = 'RslibPlugin',
   CircularDependencyRspackPlugin = 'CircularDependencyRspackPlugin',
+  ImportCostRspackPlugin = 'ImportCostRspackPlugin',
+  CompressionRspackPlugin = 'CompressionRspackPlugin',
   URLPlugin = 'URLPlugin',
+  IsolatedDtsPlugin = 'IsolatedDtsPlugin',
   JsLoaderRspackPlugin = 'JsLoaderRspackPlugin',
   LazyCompilationPlugin = 'LazyCompilationPlugin',
   ModuleInfoHeaderPlugin = 'ModuleInfoHeaderPlugin',
@@ -1253,6 +1273,13 @@ export interface JsStatsAsset {
   auxiliaryChunkNames: Array<string>
   auxiliaryChunkIdHints: Array<string>
   auxiliaryChunks: Array<string | undefined | null>
+  compressedSizes: Array<JsStatsAssetCompressedSize>
+}
+
+export interface JsStatsAssetCompressedSize {
+  algorithm: string
+  name: string
+  size: number
 }
 
 export interface JsStatsAssetInfo {
@@ -1363,6 +1390,34 @@ export interface JsStatsGetAssets {
   assetsByChunkName: Array<JsStatsAssetsByChunkName>
 }
 
+export interface JsStatsIncremental {
+  changedFiles: Array<string>
+  removedFiles: Array<string>
+  invalidatedModules: Array<JsStatsIncrementalInvalidatedModule>
+  affectedModules: Array<JsStatsIncrementalAffectedModule>
+  affectedChunks: Array<string>
+  passes: Array<JsStatsIncrementalPass>
+}
+
+export interface JsStatsIncrementalAffectedModule {
+  identifier: string
+  affectedBy?: string
+}
+
+export interface JsStatsIncrementalInvalidatedModule {
+  identifier: string
+  kind: 'add' | 'update' | 'remove'
+  files: Array<string>
+}
+
+export interface JsStatsIncrementalPass {
+  name: string
+  incremental: boolean
+  affected?: number
+  total?: number
+  reason?: string
+}
+
 export interface JsStatsLogging {
   name: string
   type: string
@@ -1520,6 +1575,8 @@ export interface KnownAssetInfo {
   cssUnusedIdents?: Array<string>
   /** whether this asset is over the size limit */
   isOverSizeLimit?: boolean
+  /** whether this asset is a compressed copy of another asset */
+  compressed?: boolean
   /** the asset type */
   assetType?: string
 }
@@ -1702,10 +1759,21 @@ export interface NapiResolveOptions {
   enablePnp?: boolean
 }
 
+export interface NativeResumeResult {
+  changedFiles: Array<string>
+  removedFiles: Array<string>
+  /** Unix timestamp in milliseconds of the saved state, the changes are made after it. */
+  since: bigint
+}
+
 export interface NativeWatcherOptions {
   followSymlinks?: boolean
   pollInterval?: number
   aggregateTimeout?: number
+  /** The socket of a watchman-like daemon, the changes are queried from it instead of watching the disk. */
+  querySocket?: string
+  /** The file to persist the last seen state of the watcher, the changes since it can be resumed after restarts. */
+  stateFile?: string
   /**
    * The ignored paths for the watcher.
    * It can be a single path, an array of paths, or a regular expression.
@@ -1723,6 +1791,8 @@ export interface NodeFsStats {
   birthtimeMs: number
   size: number
   mode: number
+  /** The inode number, the file system may not support it. */
+  ino?: number
 }
 
 export interface PathWithInfo {
@@ -1848,6 +1918,15 @@ export interface RawCompilerPlatform {
   electron?: boolean | null
 }
 
+export interface RawCompressionRspackPluginOptions {
+  test?: string | RegExp | (string | RegExp)[]
+  include?: string | RegExp | (string | RegExp)[]
+  exclude?: string | RegExp | (string | RegExp)[]
+  algorithms?: Array<'gzip' | 'brotli' | 'zstd'>
+  threshold?: number
+  minRatio?: number
+}
+
 export interface RawConsumeOptions {
   key: string
   import?: string
@@ -2004,6 +2083,7 @@ export interface RawCssAutoGeneratorOptions {
   exportsOnly?: boolean
   localIdentName?: string
   esModule?: boolean
+  exportsDeclaration?: boolean | RawCssExportsDeclarationOptions
 }
 
 export interface RawCssAutoParserOptions {
@@ -2018,6 +2098,10 @@ export interface RawCssChunkingPluginOptions {
   exclude?: RegExp
 }
 
+export interface RawCssExportsDeclarationOptions {
+  outputDir?: string
+}
+
 export interface RawCssExtractPluginOption {
   filename: JsFilename
   chunkFilename: JsFilename
@@ -2040,6 +2124,7 @@ export interface RawCssModuleGeneratorOptions {
   exportsOnly?: boolean
   localIdentName?: string
   esModule?: boolean
+  exportsDeclaration?: boolean | RawCssExportsDeclarationOptions
 }
 
 export interface RawCssModuleParserOptions {
@@ -2146,6 +2231,7 @@ export interface RawExperimentSnapshotOptions {
   immutablePaths: Array<string|RegExp>
   unmanagedPaths: Array<string|RegExp>
   managedPaths: Array<string|RegExp>
+  contentHash: boolean
 }
 
 export interface RawExposeOptions {
@@ -2262,7 +2348,12 @@ export interface RawHttpUriPluginOptions {
   lockfileLocation?: string
   cacheLocation?: string
   upgrade: boolean
-  httpClient: (url: string, headers: Record<string, string>) => Promise<JsHttpResponseRaw>
+  proxy?: string
+  noProxy?: Array<string>
+  /** The request headers keyed by origin */
+  headers?: Record<string, Record<string, string>>
+  frozen: boolean
+  httpClient: (url: string, headers: Record<string, string>, proxy?: string) => Promise<JsHttpResponseRaw>
 }
 
 export interface RawIgnorePluginOptions {
@@ -2271,8 +2362,13 @@ export interface RawIgnorePluginOptions {
   checkResource?: (resource: string, context: string) => boolean
 }
 
+export interface RawImportCostRspackPluginOptions {
+  filename: string
+}
+
 export interface RawIncremental {
   silent: boolean
+  explain: boolean
   make: boolean
   inferAsyncModules: boolean
   providedExports: boolean
@@ -2314,6 +2410,10 @@ export interface RawIntegrityItem {
   integrity: string
 }
 
+export interface RawIsolatedDtsPluginOptions {
+  preserveModules?: string
+}
+
 export declare enum RawJavascriptParserCommonjsExports {
   SkipInEsm = 'skipInEsm'
 }
@@ -2377,6 +2477,11 @@ typeReexportsPresence?: string
  */
 jsx?: boolean
 deferImport?: boolean
+/**
+ * This option is experimental in Rspack only and subject to change or be removed anytime.
+ * @experimental
+ */
+exportedEnum?: boolean
 }
 
 export interface RawJsonGeneratorOptions {
@@ -2386,6 +2491,7 @@ export interface RawJsonGeneratorOptions {
 export interface RawJsonParserOptions {
   exportsDepth?: number
   parse?: (source: string) => string
+  syntax?: "json" | "jsonc" | "json5"
 }
 
 export interface RawLazyCompilationOption {
@@ -2716,6 +2822,19 @@ export interface RawRemoteOptions {
   shareScope: string
 }
 
+export interface RawRemoteHttpResponse {
+  status: number
+  body: Buffer
+}
+
+export interface RawRemoteStorageOptions {
+  type: "http" | "directory"
+  url?: string
+  headers?: Record<string, string>
+  httpClient?: (method: string, url: string, headers: Record<string, string>, body?: Buffer) => Promise<RawRemoteHttpResponse>
+  directory?: string
+}
+
 export interface RawResolveOptions {
   preferRelative?: boolean
   preferAbsolute?: boolean
@@ -2836,11 +2955,28 @@ export interface RawRuntimeChunkOptions {
   name: string | ((entrypoint: { name: string }) => string)
 }
 
+export interface RawSizeBudget {
+  entrypoint?: string
+  chunk?: string
+  asset?: string
+  maxSize?: number
+  maxGzipSize?: number
+  maxBrotliSize?: number
+}
+
+export interface RawSizeLimitsBaseline {
+  path: string
+  threshold: number
+  update: boolean
+}
+
 export interface RawSizeLimitsPluginOptions {
   assetFilter?: (assetFilename: string) => boolean
   hints?: "error" | "warning"
   maxAssetSize?: number
   maxEntrypointSize?: number
+  budgets?: Array<RawSizeBudget>
+  baseline?: RawSizeLimitsBaseline
 }
 
 export interface RawSplitChunkSizes {
@@ -2882,6 +3018,9 @@ export interface RawStatsOptions {
 export interface RawStorageOptions {
   type: "filesystem"
   directory: string
+  remote?: RawRemoteStorageOptions
+  maxSize?: number
+  maxGenerations?: number
 }
 
 export interface RawSubresourceIntegrityPluginOptions {
@@ -39,6 +39,7 @@ pub(super) enum BuiltinPluginOptions {
   JsonModulesPlugin,
   AssetModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
+  WebAssemblyModulesPlugin,
   CssModulesPlugin,
 
   // Entry and runtime plugins
@@ -152,9 +153,7 @@ impl BuilderContext {
         rspack_plugin_runtime::enable_chunk_loading_plugin(chunk_loading_type, &mut plugins);
       }
       BuiltinPluginOptions::EnableWasmLoadingPlugin(wasm_loading_type) => {
-        plugins.push(rspack_plugin_wasm::enable_wasm_loading_plugin(
-          wasm_loading_type,
-        ));
+        rspack_plugin_wasm::enable_wasm_loading_plugin(wasm_loading_type, &mut plugins);
       }
 
       // Runtime and error handling plugins
@@ -207,6 +206,9 @@ impl BuilderContext {
       BuiltinPluginOptions::AsyncWebAssemblyModulesPlugin => {
         plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::default().boxed());
       }
+      BuiltinPluginOptions::WebAssemblyModulesPlugin => {
+        plugins.push(rspack_plugin_wasm::SyncWasmPlugin::default().boxed());
+      }
       BuiltinPluginOptions::CssModulesPlugin => {
         plugins.push(rspack_plugin_css::CssPlugin::default().boxed());
       }
@@ -0,0 +1,115 @@
+use std::sync::{Arc, RwLock};
+
+use derive_more::Debug;
+use rspack_core::{
+  ApplyContext, BoxLoader, Context, ModuleRuleUseLoader, NormalModuleFactoryResolveLoader, Plugin,
+  Resolver,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::FxHashMap as HashMap;
+
+type LoaderFactory = Arc<dyn Fn(Option<&str>) -> Result<BoxLoader> + Send + Sync>;
+
+/// Registry of custom loaders, which maps loader names to [`Loader`] implementations.
+///
+/// The loaders are resolved in module rules the same way as the built-in loaders,
+/// a module rule uses a custom loader by its name, such as `builtin:my-loader`.
+///
+/// [`Loader`]: rspack_core::Loader
+#[derive(Debug, Default, Clone)]
+pub struct LoaderRegistry {
+  #[debug(skip)]
+  factories: HashMap<String, LoaderFactory>,
+}
+
+impl LoaderRegistry {
+  /// Register a loader under `name`, the options of module rules are ignored.
+  pub fn register(&mut self, name: impl Into<String>, loader: BoxLoader) -> &mut Self {
+    self.register_factory(name, move |_| Ok(loader.clone()))
+  }
+
+  /// Register a loader factory under `name`, the loader is created with the options of module rules.
+  ///
+  /// The created loaders are cached by the options, so the factory is only called once for the same options.
+  pub fn register_factory<F>(&mut self, name: impl Into<String>, factory: F) -> &mut Self
+  where
+    F: Fn(Option<&str>) -> Result<BoxLoader> + Send + Sync + 'static,
+  {
+    self.factories.insert(name.into(), Arc::new(factory));
+    self
+  }
+
+  /// Returns `true` if no loader is registered.
+  pub fn is_empty(&self) -> bool {
+    self.factories.is_empty()
+  }
+
+  fn get(&self, loader_request: &str) -> Option<&LoaderFactory> {
+    // the loader request may contain a query, e.g. `builtin:my-loader?foo=bar`
+    let name = loader_request
+      .split_once('?')
+      .map_or(loader_request, |(name, _)| name);
+    self.factories.get(name)
+  }
+}
+
+type LoaderCache = RwLock<HashMap<(String, Option<String>), BoxLoader>>;
+
+#[plugin]
+#[derive(Debug)]
+pub(crate) struct LoaderRegistryPlugin {
+  registry: LoaderRegistry,
+  #[debug(skip)]
+  cache: LoaderCache,
+}
+
+impl LoaderRegistryPlugin {
+  pub(crate) fn new(registry: LoaderRegistry) -> Self {
+    Self::new_inner(registry, Default::default())
+  }
+}
+
+impl Plugin for LoaderRegistryPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.LoaderRegistryPlugin"
+  }
+
+  fn apply(&self, ctx: &mut ApplyContext<'_>) -> Result<()> {
+    ctx
+      .normal_module_factory_hooks
+      .resolve_loader
+      .tap(resolve_loader::new(self));
+    Ok(())
+  }
+}
+
+#[plugin_hook(NormalModuleFactoryResolveLoader for LoaderRegistryPlugin)]
+async fn resolve_loader(
+  &self,
+  _context: &Context,
+  _resolver: &Resolver,
+  l: &ModuleRuleUseLoader,
+) -> Result<Option<BoxLoader>> {
+  let Some(factory) = self.registry.get(&l.loader) else {
+    return Ok(None);
+  };
+
+  let key = (l.loader.clone(), l.options.clone());
+  if let Some(loader) = self
+    .cache
+    .read()
+    .expect("should lock loader cache")
+    .get(&key)
+  {
+    return Ok(Some(loader.clone()));
+  }
+
+  let loader = factory(l.options.as_deref())?;
+  self
+    .cache
+    .write()
+    .expect("should lock loader cache")
+    .insert(key, loader.clone());
+  Ok(Some(loader))
+}
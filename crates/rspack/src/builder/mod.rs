@@ -4,10 +4,13 @@ mod browserslist_target;
 mod builder_context;
 mod devtool;
 mod externals;
+mod loader_registry;
 mod target;
 
 pub use builder_context::BuilderContext;
 pub use devtool::Devtool;
+pub use loader_registry::LoaderRegistry;
+use loader_registry::LoaderRegistryPlugin;
 use rspack_tasks::CURRENT_COMPILER_CONTEXT;
 pub use target::Targets;
 
@@ -37,20 +40,21 @@ use devtool::DevtoolFlags;
 use externals::ExternalsPresets;
 use indexmap::IndexMap;
 use rspack_core::{
-  AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions, BoxPlugin, ByDependency,
-  CacheOptions, ChunkLoading, ChunkLoadingType, CleanOptions, Compiler, CompilerOptions,
-  CompilerPlatform, Context, CrossOriginLoading, CssAutoGeneratorOptions, CssAutoParserOptions,
-  CssExportsConvention, CssGeneratorOptions, CssModuleGeneratorOptions, CssModuleParserOptions,
-  CssParserOptions, DynamicImportMode, EntryDescription, EntryOptions, EntryRuntime, Environment,
-  ExperimentCacheOptions, Experiments, ExternalItem, ExternalType, Filename, GeneratorOptions,
-  GeneratorOptionsMap, JavascriptParserCommonjsExportsOption, JavascriptParserCommonjsOptions,
-  JavascriptParserOptions, JavascriptParserOrder, JavascriptParserUrl, JsonGeneratorOptions,
-  JsonParserOptions, LibraryName, LibraryNonUmdObject, LibraryOptions, LibraryType,
-  MangleExportsOption, Mode, ModuleNoParseRules, ModuleOptions, ModuleRule, ModuleRuleEffect,
-  ModuleType, NodeDirnameOption, NodeFilenameOption, NodeGlobalOption, NodeOption, Optimization,
-  OutputOptions, ParseOption, ParserOptions, ParserOptionsMap, PathInfo, PublicPath, Resolve,
-  RspackFuture, RuleSetCondition, RuleSetLogicalConditions, SideEffectOption, StatsOptions,
-  TrustedTypes, UnsafeCachePredicate, UsedExportsOption, WasmLoading, WasmLoadingType,
+  AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions, BoxLoader, BoxPlugin,
+  ByDependency, CacheOptions, ChunkLoading, ChunkLoadingType, CleanOptions, Compiler,
+  CompilerOptions, CompilerPlatform, Context, CrossOriginLoading, CssAutoGeneratorOptions,
+  CssAutoParserOptions, CssExportsConvention, CssExportsDeclaration, CssGeneratorOptions,
+  CssModuleGeneratorOptions, CssModuleParserOptions, CssParserOptions, DynamicImportMode,
+  EntryDescription, EntryOptions, EntryRuntime, Environment, ExperimentCacheOptions, Experiments,
+  ExternalItem, ExternalType, Filename, GeneratorOptions, GeneratorOptionsMap,
+  JavascriptParserCommonjsExportsOption, JavascriptParserCommonjsOptions, JavascriptParserOptions,
+  JavascriptParserOrder, JavascriptParserUrl, JsonGeneratorOptions, JsonParserOptions, LibraryName,
+  LibraryNonUmdObject, LibraryOptions, LibraryType, MangleExportsOption, Mode, ModuleNoParseRules,
+  ModuleOptions, ModuleRule, ModuleRuleEffect, ModuleType, NodeDirnameOption, NodeFilenameOption,
+  NodeGlobalOption, NodeOption, Optimization, OutputOptions, ParseOption, ParserOptions,
+  ParserOptionsMap, PathInfo, PublicPath, Resolve, RspackFuture, RuleSetCondition,
+  RuleSetLogicalConditions, SideEffectOption, StatsOptions, TrustedTypes, UnsafeCachePredicate,
+  UsedExportsOption, WasmLoading, WasmLoadingType,
   incremental::{IncrementalOptions, IncrementalPasses},
 };
 use rspack_error::{Error, Result};
@@ -106,6 +110,7 @@ pub struct CompilerBuilder {
   input_filesystem: Option<Arc<dyn ReadableFileSystem>>,
   intermediate_filesystem: Option<Arc<dyn IntermediateFileSystem>>,
   output_filesystem: Option<Arc<dyn WritableFileSystem>>,
+  loader_registry: LoaderRegistry,
 }
 
 impl CompilerBuilder {
@@ -120,6 +125,7 @@ impl CompilerBuilder {
       input_filesystem: None,
       intermediate_filesystem: None,
       output_filesystem: None,
+      loader_registry: LoaderRegistry::default(),
     }
   }
 }
@@ -443,6 +449,30 @@ impl CompilerBuilder {
     self
   }
 
+  /// Register a custom loader under `name`, such as `builtin:my-loader`.
+  ///
+  /// See [`LoaderRegistry::register`] for more details.
+  pub fn loader(&mut self, name: impl Into<String>, loader: BoxLoader) -> &mut Self {
+    self.loader_registry.register(name, loader);
+    self
+  }
+
+  /// Register a custom loader factory under `name`, the loader is created with the options of module rules.
+  ///
+  /// See [`LoaderRegistry::register_factory`] for more details.
+  pub fn loader_factory<F>(&mut self, name: impl Into<String>, factory: F) -> &mut Self
+  where
+    F: Fn(Option<&str>) -> Result<BoxLoader> + Send + Sync + 'static,
+  {
+    self.loader_registry.register_factory(name, factory);
+    self
+  }
+
+  /// Get the registry of custom loaders.
+  pub fn loader_registry(&mut self) -> &mut LoaderRegistry {
+    &mut self.loader_registry
+  }
+
   /// Build [`Compiler`] from options and plugins.
   pub fn build(&mut self) -> Result<Compiler> {
     let mut builder_context = BuilderContext::default();
@@ -450,6 +480,10 @@ impl CompilerBuilder {
     let mut plugins = builder_context.take_plugins(&compiler_options);
     let platform = builder_context.take_platform();
     plugins.append(&mut self.plugins);
+    let loader_registry = std::mem::take(&mut self.loader_registry);
+    if !loader_registry.is_empty() {
+      plugins.push(Box::new(LoaderRegistryPlugin::new(loader_registry)));
+    }
 
     let input_filesystem = self.input_filesystem.take();
     let intermediate_filesystem = self.intermediate_filesystem.take();
@@ -964,6 +998,12 @@ impl CompilerOptionsBuilder {
         .plugins
         .push(BuiltinPluginOptions::AsyncWebAssemblyModulesPlugin);
     }
+    let sync_web_assembly = expect!(experiments_builder.sync_web_assembly);
+    if sync_web_assembly {
+      builder_context
+        .plugins
+        .push(BuiltinPluginOptions::WebAssemblyModulesPlugin);
+    }
     let css = expect!(experiments_builder.css);
     if css {
       builder_context
@@ -977,6 +1017,7 @@ impl CompilerOptionsBuilder {
     let module = f!(self.module.take(), ModuleOptions::builder).build(
       builder_context,
       async_web_assembly,
+      sync_web_assembly,
       css,
       &target_properties,
       &mode,
@@ -1688,6 +1729,7 @@ impl ModuleOptionsBuilder {
     &mut self,
     _builder_context: &mut BuilderContext,
     async_web_assembly: bool,
+    sync_web_assembly: bool,
     css: bool,
     target_properties: &TargetProperties,
     mode: &Mode,
@@ -1731,6 +1773,7 @@ impl ModuleOptionsBuilder {
           import_dynamic: Some(true),
           commonjs_magic_comments: Some(false),
           jsx: Some(false),
+          exported_enum: Some(false),
           ..Default::default()
         }),
       );
@@ -1746,6 +1789,7 @@ impl ModuleOptionsBuilder {
             Some(u32::MAX)
           },
           parse: ParseOption::None,
+          syntax: None,
         }),
       );
     }
@@ -1798,6 +1842,7 @@ impl ModuleOptionsBuilder {
           local_ident_name: Some("[uniqueName]-[id]-[local]".into()),
 
           es_module: Some(true),
+          exports_declaration: Some(CssExportsDeclaration::Disable),
         }),
       );
 
@@ -1808,11 +1853,12 @@ impl ModuleOptionsBuilder {
           exports_convention: Some(CssExportsConvention::default()),
           local_ident_name: Some("[uniqueName]-[id]-[local]".into()),
           es_module: Some(true),
+          exports_declaration: Some(CssExportsDeclaration::Disable),
         }),
       );
     }
 
-    let default_rules = default_rules(async_web_assembly, css);
+    let default_rules = default_rules(async_web_assembly, sync_web_assembly, css);
 
     Ok(ModuleOptions {
       rules: vec![
@@ -1833,7 +1879,7 @@ impl ModuleOptionsBuilder {
   }
 }
 
-fn default_rules(async_web_assembly: bool, css: bool) -> Vec<ModuleRule> {
+fn default_rules(async_web_assembly: bool, sync_web_assembly: bool, css: bool) -> Vec<ModuleRule> {
   let mut rules = vec![
     // application/node
     ModuleRule {
@@ -1992,7 +2038,14 @@ fn default_rules(async_web_assembly: bool, css: bool) -> Vec<ModuleRule> {
   ];
 
   // Add WebAssembly rules if enabled
-  if async_web_assembly {
+  let wasm_module_type = if async_web_assembly {
+    Some(ModuleType::WasmAsync)
+  } else if sync_web_assembly {
+    Some(ModuleType::WasmSync)
+  } else {
+    None
+  };
+  if let Some(wasm_module_type) = wasm_module_type {
     rules.extend(vec![
       ModuleRule {
         test: Some(RuleSetCondition::Func(Box::new(|ctx| {
@@ -2004,7 +2057,7 @@ fn default_rules(async_web_assembly: bool, css: bool) -> Vec<ModuleRule> {
           )))
         }))),
         effect: ModuleRuleEffect {
-          r#type: Some(ModuleType::WasmAsync),
+          r#type: Some(wasm_module_type),
           ..Default::default()
         },
         rules: Some(vec![ModuleRule {
@@ -2026,7 +2079,7 @@ fn default_rules(async_web_assembly: bool, css: bool) -> Vec<ModuleRule> {
       ModuleRule {
         mimetype: Some(RuleSetCondition::String("application/wasm".into()).into()),
         effect: ModuleRuleEffect {
-          r#type: Some(ModuleType::WasmAsync),
+          r#type: Some(wasm_module_type),
           ..Default::default()
         },
         rules: Some(vec![ModuleRule {
@@ -3699,6 +3752,8 @@ pub struct ExperimentsBuilder {
   css: Option<bool>,
   /// Whether to enable async web assembly.
   async_web_assembly: Option<bool>,
+  /// Whether to enable sync web assembly.
+  sync_web_assembly: Option<bool>,
   // TODO: lazy compilation
 }
 
@@ -3713,6 +3768,7 @@ impl From<Experiments> for ExperimentsBuilder {
       future_defaults: None,
       css: Some(value.css),
       async_web_assembly: None,
+      sync_web_assembly: None,
     }
   }
 }
@@ -3728,6 +3784,7 @@ impl From<&mut ExperimentsBuilder> for ExperimentsBuilder {
       future_defaults: value.future_defaults.take(),
       css: value.css.take(),
       async_web_assembly: value.async_web_assembly.take(),
+      sync_web_assembly: value.sync_web_assembly.take(),
     }
   }
 }
@@ -3769,6 +3826,12 @@ impl ExperimentsBuilder {
     self
   }
 
+  /// Set whether to enable sync web assembly.
+  pub fn sync_web_assembly(&mut self, sync_web_assembly: bool) -> &mut Self {
+    self.sync_web_assembly = Some(sync_web_assembly);
+    self
+  }
+
   /// Build [`Experiments`] from options.
   ///
   /// [`Experiments`]: rspack_core::options::Experiments
@@ -3786,6 +3849,7 @@ impl ExperimentsBuilder {
       };
       IncrementalOptions {
         silent: true,
+        explain: false,
         passes,
       }
     });
@@ -3803,6 +3867,7 @@ impl ExperimentsBuilder {
     let future_defaults = w!(self.future_defaults, false);
     w!(self.css, *future_defaults);
     w!(self.async_web_assembly, *future_defaults);
+    w!(self.sync_web_assembly, false);
     w!(self.output_module, false);
 
     Ok(Experiments {
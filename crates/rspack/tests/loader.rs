@@ -210,3 +210,39 @@ async fn preact_refresh() {
   })
   .await;
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn custom_loader() {
+  use std::sync::Arc;
+
+  use rspack_loader_testing::{SIMPLE_LOADER_IDENTIFIER, SimpleLoader};
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  within_compiler_context_for_testing(async {
+    let mut compiler = Compiler::builder()
+      .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/custom-loader"))
+      .entry("main", "./src/index.js")
+      .module(ModuleOptions::builder().rule(ModuleRule {
+        test: Some(RuleSetCondition::Regexp(
+          RspackRegex::new("\\.txt$").unwrap(),
+        )),
+        effect: ModuleRuleEffect {
+          r#use: ModuleRuleUse::Array(vec![ModuleRuleUseLoader {
+            loader: SIMPLE_LOADER_IDENTIFIER.to_string(),
+            options: None,
+          }]),
+          ..Default::default()
+        },
+        ..Default::default()
+      }))
+      .loader(SIMPLE_LOADER_IDENTIFIER, Arc::new(SimpleLoader))
+      .build()
+      .unwrap();
+
+    compiler.build().await.unwrap();
+
+    let errors: Vec<_> = compiler.compilation.get_errors().collect();
+    assert!(errors.is_empty());
+  })
+  .await;
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use napi::{
   Env, JsValue,
   bindgen_prelude::{
@@ -16,6 +18,8 @@ use rustc_hash::FxHashSet;
 #[napi(object)]
 pub struct AssetInfoRelated {
   pub source_map: Option<Either<String, Null>>,
+  /// the compressed copies of the asset, keyed by the compression algorithm
+  pub compressed: Option<HashMap<String, String>>,
 }
 
 impl From<AssetInfoRelated> for rspack_core::AssetInfoRelated {
@@ -65,6 +69,8 @@ pub struct KnownAssetInfo {
   pub css_unused_idents: Option<Vec<String>>,
   /// whether this asset is over the size limit
   pub is_over_size_limit: Option<bool>,
+  /// whether this asset is a compressed copy of another asset
+  pub compressed: Option<bool>,
   /// the asset type
   pub asset_type: Option<String>,
 }
@@ -142,6 +148,7 @@ impl From<AssetInfo> for rspack_core::AssetInfo {
       related,
       css_unused_idents,
       is_over_size_limit,
+      compressed,
       asset_type,
     } = known;
 
@@ -166,6 +173,12 @@ impl From<AssetInfo> for rspack_core::AssetInfo {
       })
       .unwrap_or_default();
 
+    let compressed_assets = related
+      .as_ref()
+      .and_then(|related| related.compressed.clone())
+      .map(|compressed| compressed.into_iter().collect())
+      .unwrap_or_default();
+
     Self {
       immutable,
       minimized,
@@ -181,6 +194,8 @@ impl From<AssetInfo> for rspack_core::AssetInfo {
       version: String::default(),
       css_unused_idents: css_unused_idents.map(|i| i.into_iter().collect()),
       is_over_size_limit,
+      compressed,
+      compressed_assets,
       asset_type: asset_type.map(Into::into).unwrap_or_default(),
       extras,
     }
@@ -209,6 +224,7 @@ impl From<rspack_core::AssetInfoRelated> for AssetInfoRelated {
   fn from(related: rspack_core::AssetInfoRelated) -> Self {
     Self {
       source_map: related.source_map.map(Either::A),
+      compressed: None,
     }
   }
 }
@@ -229,18 +245,25 @@ impl From<rspack_core::AssetInfo> for AssetInfo {
       related,
       css_unused_idents,
       is_over_size_limit,
+      compressed,
+      compressed_assets,
       asset_type,
       extras,
       ..
     } = value;
 
+    let mut related = AssetInfoRelated::from(related);
+    // the compressed copies are exposed as `info.related.compressed` like the other related assets
+    related.compressed =
+      (!compressed_assets.is_empty()).then(|| compressed_assets.into_iter().collect());
+
     Self {
       known: KnownAssetInfo {
         immutable,
         minimized,
         development,
         hot_module_replacement,
-        related: Some(related.into()),
+        related: Some(related),
         chunkhash: Some(Either::B(chunk_hash.into_iter().collect())),
         fullhash: Some(Either::B(full_hash.into_iter().collect())),
         contenthash: Some(Either::B(content_hash.into_iter().collect())),
@@ -249,6 +272,7 @@ impl From<rspack_core::AssetInfo> for AssetInfo {
         javascript_module,
         css_unused_idents: css_unused_idents.map(|i| i.into_iter().collect()),
         is_over_size_limit,
+        compressed,
         asset_type: Some(asset_type.to_string()),
       },
       extras,
@@ -68,6 +68,8 @@ pub struct NodeFsStats {
   pub birthtime_ms: u32,
   pub size: u32,
   pub mode: u32,
+  /// The inode number, the file system may not support it.
+  pub ino: Option<i64>,
 }
 
 impl From<NodeFsStats> for FileMetadata {
@@ -80,6 +82,7 @@ impl From<NodeFsStats> for FileMetadata {
       mtime_ms: value.mtime_ms as u64,
       ctime_ms: value.ctime_ms as u64,
       size: value.size as u64,
+      ino: value.ino.map_or(0, |ino| ino as u64),
     }
   }
 }
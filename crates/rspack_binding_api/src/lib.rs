@@ -337,6 +337,13 @@ impl JsCompiler {
     self.js_hooks_plugin.set_non_skippable_registers(kinds)
   }
 
+  /// Set the start time of the watcher which is watching the next build,
+  /// persistent cache saves it to match the watcher state resumed by the next process
+  #[napi]
+  pub fn set_watcher_start_time(&mut self, start_time: Option<BigInt>) {
+    self.compiler.watcher_start_time = start_time.map(|time| time.get_u64().1);
+  }
+
   /// Build with the given option passed to the constructor
   #[napi(ts_args_type = "callback: (err: null | Error) => void")]
   pub fn build(
@@ -397,6 +404,45 @@ impl JsCompiler {
     }
   }
 
+  /// Build with the changed and removed files which are resumed from the watcher of previous process
+  #[napi(
+    ts_args_type = "changed_files: string[], removed_files: string[], since: bigint, callback: (err: null | Error) => void"
+  )]
+  pub fn resume(
+    &mut self,
+    reference: Reference<JsCompiler>,
+    changed_files: Vec<String>,
+    removed_files: Vec<String>,
+    since: BigInt,
+    f: Function<'static>,
+  ) -> Result<(), ErrorCode> {
+    use std::collections::HashSet;
+
+    let since = since.get_u64().1;
+    unsafe {
+      self.run(reference, |compiler, guard| {
+        callbackify(
+          f,
+          async move {
+            compiler
+              .resume(
+                HashSet::from_iter(changed_files.into_iter()),
+                HashSet::from_iter(removed_files.into_iter()),
+                since,
+              )
+              .await
+              .to_napi_result_with_message(|e| {
+                print_error_diagnostic(e, compiler.options.stats.colors)
+              })?;
+            tracing::debug!("resume ok");
+            Ok(())
+          },
+          Some(|| drop(guard)),
+        )
+      })
+    }
+  }
+
   #[napi]
   pub async fn close(&self) -> Result<()> {
     self
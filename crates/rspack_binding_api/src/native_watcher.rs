@@ -32,6 +32,12 @@ pub struct NativeWatcherOptions {
 
   pub aggregate_timeout: Option<u32>,
 
+  /// The socket of a watchman-like daemon, the changes are queried from it instead of watching the disk.
+  pub query_socket: Option<String>,
+
+  /// The file to persist the last seen state of the watcher, the changes since it can be resumed after restarts.
+  pub state_file: Option<String>,
+
   #[napi(ts_type = "string | string[] | RegExp")]
   /// The ignored paths for the watcher.
   /// It can be a single path, an array of paths, or a regular expression.
@@ -44,6 +50,14 @@ pub struct NativeWatchResult {
   pub removed_files: Vec<String>,
 }
 
+#[napi(object, object_from_js = false)]
+pub struct NativeResumeResult {
+  pub changed_files: Vec<String>,
+  pub removed_files: Vec<String>,
+  /// Unix timestamp in milliseconds of the saved state, the changes are made after it.
+  pub since: BigInt,
+}
+
 #[napi]
 pub struct NativeWatcher {
   watcher: FsWatcher,
@@ -63,6 +77,8 @@ impl NativeWatcher {
         follow_symlinks: options.follow_symlinks.unwrap_or(false),
         poll_interval: options.poll_interval,
         aggregate_timeout: options.aggregate_timeout,
+        query_socket: options.query_socket.map(PathBuf::from),
+        state_file: options.state_file.map(PathBuf::from),
       },
       to_fs_watcher_ignored(options.ignored.clone()),
     );
@@ -118,6 +134,23 @@ impl NativeWatcher {
     Ok(())
   }
 
+  /// Resume the changes since the last seen state which is saved in `stateFile` by the previous process.
+  ///
+  /// Returns `null` if there is no saved state or the changes can not be told exactly.
+  #[napi]
+  pub async fn resume(&self) -> napi::Result<Option<NativeResumeResult>> {
+    let changes = self
+      .watcher
+      .resume()
+      .await
+      .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    Ok(changes.map(|changes| NativeResumeResult {
+      changed_files: changes.changed_files.into_iter().collect(),
+      removed_files: changes.deleted_files.into_iter().collect(),
+      since: BigInt::from(changes.since),
+    }))
+  }
+
   #[napi(ts_type = "(kind: 'change' | 'remove' | 'create', path: string): void")]
   pub fn trigger_event(&self, kind: String, path: String) {
     if let Some(kind) = match kind.as_str() {
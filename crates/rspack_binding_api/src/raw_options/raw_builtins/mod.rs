@@ -1,6 +1,7 @@
 mod raw_banner;
 mod raw_bundle_info;
 mod raw_circular_dependency;
+mod raw_compression;
 mod raw_context_replacement;
 mod raw_copy;
 mod raw_css_chunking;
@@ -11,6 +12,8 @@ mod raw_html;
 mod raw_http_uri;
 mod raw_ids;
 mod raw_ignore;
+mod raw_import_cost;
+mod raw_isolated_dts;
 mod raw_lazy_compilation;
 mod raw_lightning_css_minimizer;
 mod raw_limit_chunk_count;
@@ -44,6 +47,7 @@ use rspack_plugin_asset::AssetPlugin;
 use rspack_plugin_banner::BannerPlugin;
 use rspack_plugin_case_sensitive::CaseSensitivePlugin;
 use rspack_plugin_circular_dependencies::CircularDependencyRspackPlugin;
+use rspack_plugin_compression::CompressionPlugin;
 use rspack_plugin_copy::{CopyRspackPlugin, CopyRspackPluginOptions};
 use rspack_plugin_css::CssPlugin;
 use rspack_plugin_css_chunking::CssChunkingPlugin;
@@ -64,6 +68,8 @@ use rspack_plugin_externals::{
 use rspack_plugin_hmr::HotModuleReplacementPlugin;
 use rspack_plugin_html::HtmlRspackPlugin;
 use rspack_plugin_ignore::IgnorePlugin;
+use rspack_plugin_import_cost::ImportCostPlugin;
+use rspack_plugin_isolated_dts::IsolatedDtsPlugin;
 use rspack_plugin_javascript::{
   FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, InferAsyncModulesPlugin,
   InlineExportsPlugin, JsPlugin, MangleExportsPlugin, ModuleConcatenationPlugin,
@@ -96,7 +102,7 @@ use rspack_plugin_size_limits::SizeLimitsPlugin;
 use rspack_plugin_sri::{SubresourceIntegrityPlugin, SubresourceIntegrityPluginOptions};
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_wasm::{
-  AsyncWasmPlugin, FetchCompileAsyncWasmPlugin, enable_wasm_loading_plugin,
+  AsyncWasmPlugin, FetchCompileAsyncWasmPlugin, SyncWasmPlugin, enable_wasm_loading_plugin,
 };
 use rspack_plugin_web_worker_template::web_worker_template_plugin;
 use rspack_plugin_worker::WorkerPlugin;
@@ -106,6 +112,7 @@ use self::{
   raw_banner::RawBannerPluginOptions,
   raw_bundle_info::{RawBundlerInfoModeWrapper, RawBundlerInfoPluginOptions},
   raw_circular_dependency::RawCircularDependencyRspackPluginOptions,
+  raw_compression::RawCompressionRspackPluginOptions,
   raw_context_replacement::RawContextReplacementPluginOptions,
   raw_copy::RawCopyRspackPluginOptions,
   raw_css_chunking::RawCssChunkingPluginOptions,
@@ -113,6 +120,8 @@ use self::{
   raw_dll::{RawDllEntryPluginOptions, RawLibManifestPluginOptions},
   raw_html::RawHtmlRspackPluginOptions,
   raw_ignore::RawIgnorePluginOptions,
+  raw_import_cost::RawImportCostRspackPluginOptions,
+  raw_isolated_dts::RawIsolatedDtsPluginOptions,
   raw_lazy_compilation::{JsBackend, RawLazyCompilationOption},
   raw_limit_chunk_count::RawLimitChunkCountPluginOptions,
   raw_mf::{
@@ -191,6 +200,7 @@ pub enum BuiltinPluginName {
   InferAsyncModulesPlugin,
   JavascriptModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
+  WebAssemblyModulesPlugin,
   AssetModulesPlugin,
   SourceMapDevToolPlugin,
   EvalSourceMapDevToolPlugin,
@@ -227,7 +237,10 @@ pub enum BuiltinPluginName {
   RstestPlugin,
   RslibPlugin,
   CircularDependencyRspackPlugin,
+  ImportCostRspackPlugin,
+  CompressionRspackPlugin,
   URLPlugin,
+  IsolatedDtsPlugin,
 
   // rspack js adapter plugins
   // naming format follow XxxRspackPlugin
@@ -400,9 +413,7 @@ impl<'a> BuiltinPlugin<'a> {
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)
           .map_err(|report| napi::Error::from_reason(report.to_string()))?;
-        plugins.push(enable_wasm_loading_plugin(
-          wasm_loading_type.as_str().into(),
-        ));
+        enable_wasm_loading_plugin(wasm_loading_type.as_str().into(), plugins);
       }
       BuiltinPluginName::FetchCompileAsyncWasmPlugin => {
         plugins.push(FetchCompileAsyncWasmPlugin::default().boxed());
@@ -419,6 +430,11 @@ impl<'a> BuiltinPlugin<'a> {
         plugins
           .push(EsmLibraryPlugin::new(options.preserve_modules.as_deref().map(Into::into)).boxed());
       }
+      BuiltinPluginName::IsolatedDtsPlugin => {
+        let options = downcast_into::<RawIsolatedDtsPluginOptions>(self.options)
+          .map_err(|report| napi::Error::from_reason(report.to_string()))?;
+        plugins.push(IsolatedDtsPlugin::new(options.into()).boxed());
+      }
       BuiltinPluginName::ArrayPushCallbackChunkFormatPlugin => {
         plugins.push(ArrayPushCallbackChunkFormatPlugin::default().boxed());
       }
@@ -565,6 +581,9 @@ impl<'a> BuiltinPlugin<'a> {
       BuiltinPluginName::AsyncWebAssemblyModulesPlugin => {
         plugins.push(AsyncWasmPlugin::default().boxed())
       }
+      BuiltinPluginName::WebAssemblyModulesPlugin => {
+        plugins.push(SyncWasmPlugin::default().boxed())
+      }
       BuiltinPluginName::AssetModulesPlugin => plugins.push(AssetPlugin::default().boxed()),
       BuiltinPluginName::SourceMapDevToolPlugin => {
         let options: rspack_plugin_devtool::SourceMapDevToolPluginOptions =
@@ -644,7 +663,8 @@ impl<'a> BuiltinPlugin<'a> {
         let plugin = SizeLimitsPlugin::new(
           downcast_into::<RawSizeLimitsPluginOptions>(self.options)
             .map_err(|report| napi::Error::from_reason(report.to_string()))?
-            .into(),
+            .try_into()
+            .map_err(|report: rspack_error::Error| napi::Error::from_reason(report.to_string()))?,
         )
         .boxed();
         plugins.push(plugin)
@@ -725,6 +745,23 @@ impl<'a> BuiltinPlugin<'a> {
         )
         .boxed(),
       ),
+      BuiltinPluginName::ImportCostRspackPlugin => plugins.push(
+        ImportCostPlugin::new(
+          downcast_into::<RawImportCostRspackPluginOptions>(self.options)
+            .map_err(|report| napi::Error::from_reason(report.to_string()))?
+            .into(),
+        )
+        .boxed(),
+      ),
+      BuiltinPluginName::CompressionRspackPlugin => plugins.push(
+        CompressionPlugin::new(
+          downcast_into::<RawCompressionRspackPluginOptions>(self.options)
+            .map_err(|report| napi::Error::from_reason(report.to_string()))?
+            .try_into()
+            .map_err(|report: rspack_error::Error| napi::Error::from_reason(report.to_string()))?,
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::JsLoaderRspackPlugin => {
         // Set the compiler._runLoader property on the JsObject to ensure that the runLoader
         // is not garbage collected by JS while the stats Object holds a reference to JsLoaderPlugin.
@@ -0,0 +1,42 @@
+use napi_derive::napi;
+use rspack_error::Result;
+use rspack_plugin_compression::{CompressionAlgorithm, CompressionPluginOptions};
+
+use crate::asset_condition::{RawAssetConditions, into_asset_conditions};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawCompressionRspackPluginOptions {
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub test: Option<RawAssetConditions>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub include: Option<RawAssetConditions>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub exclude: Option<RawAssetConditions>,
+  #[napi(ts_type = "Array<'gzip' | 'brotli' | 'zstd'>")]
+  pub algorithms: Option<Vec<String>>,
+  pub threshold: Option<f64>,
+  pub min_ratio: Option<f64>,
+}
+
+impl TryFrom<RawCompressionRspackPluginOptions> for CompressionPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCompressionRspackPluginOptions) -> Result<Self> {
+    let algorithms = match value.algorithms {
+      Some(algorithms) => algorithms
+        .iter()
+        .map(|algorithm| CompressionAlgorithm::try_from(algorithm.as_str()))
+        .collect::<Result<Vec<_>>>()?,
+      None => vec![CompressionAlgorithm::Gzip],
+    };
+    Ok(Self {
+      test: value.test.map(into_asset_conditions),
+      include: value.include.map(into_asset_conditions),
+      exclude: value.exclude.map(into_asset_conditions),
+      algorithms,
+      threshold: value.threshold.unwrap_or(0.0) as usize,
+      min_ratio: value.min_ratio.unwrap_or(0.8),
+    })
+  }
+}
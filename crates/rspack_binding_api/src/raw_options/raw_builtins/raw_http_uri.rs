@@ -6,14 +6,17 @@ use napi_derive::napi;
 use rspack_fs::WritableFileSystem;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_plugin_schemes::{
-  HttpClient, HttpResponse, HttpUriOptionsAllowedUris, HttpUriPlugin, HttpUriPluginOptions,
+  HttpClient, HttpResponse, HttpUriOptionsAllowedUris, HttpUriOptionsHeaders, HttpUriOptionsProxy,
+  HttpUriPlugin, HttpUriPluginOptions,
 };
 use rspack_regex::RspackRegex;
 use rspack_util::asset_condition::{AssetCondition, AssetConditions};
 use rustc_hash::FxHashMap as HashMap;
 
-type HttpClientRequest =
-  ThreadsafeFunction<FnArgs<(String, HashMap<String, String>)>, Promise<JsHttpResponseRaw>>;
+type HttpClientRequest = ThreadsafeFunction<
+  FnArgs<(String, HashMap<String, String>, Option<String>)>,
+  Promise<JsHttpResponseRaw>,
+>;
 
 #[napi(object, object_to_js = false)]
 #[derive(Debug)]
@@ -23,9 +26,14 @@ pub struct RawHttpUriPluginOptions {
   pub lockfile_location: Option<String>,
   pub cache_location: Option<String>,
   pub upgrade: bool,
-  // pub proxy: Option<String>,
-  // pub frozen: Option<bool>,
-  #[napi(ts_type = "(url: string, headers: Record<string, string>) => Promise<JsHttpResponseRaw>")]
+  pub proxy: Option<String>,
+  pub no_proxy: Option<Vec<String>>,
+  /// The request headers keyed by origin
+  pub headers: Option<HashMap<String, HashMap<String, String>>>,
+  pub frozen: bool,
+  #[napi(
+    ts_type = "(url: string, headers: Record<string, string>, proxy?: string) => Promise<JsHttpResponseRaw>"
+  )]
   pub http_client: HttpClientRequest,
 }
 
@@ -36,8 +44,10 @@ pub struct JsHttpResponseRaw {
   pub body: Buffer,
 }
 
-type JsHttpClientFunction =
-  ThreadsafeFunction<FnArgs<(String, HashMap<String, String>)>, Promise<JsHttpResponseRaw>>;
+type JsHttpClientFunction = ThreadsafeFunction<
+  FnArgs<(String, HashMap<String, String>, Option<String>)>,
+  Promise<JsHttpResponseRaw>,
+>;
 
 #[derive(Debug, Clone)]
 pub struct JsHttpClient {
@@ -50,13 +60,15 @@ impl HttpClient for JsHttpClient {
     &self,
     url: &str,
     headers: &HashMap<String, String>,
+    proxy: Option<&str>,
   ) -> anyhow::Result<HttpResponse> {
     let url_owned = url.to_string();
     let headers_owned = headers.clone();
+    let proxy_owned = proxy.map(|proxy| proxy.to_string());
     let func = self.function.clone();
 
     let result = func
-      .call_with_promise((url_owned, headers_owned).into())
+      .call_with_promise((url_owned, headers_owned, proxy_owned).into())
       .await
       .map_err(|e| anyhow::anyhow!("Error calling JavaScript HTTP client: {}", e))?;
 
@@ -92,8 +104,11 @@ fn create_http_uri_plugin_options(
     lockfile_location: options.lockfile_location,
     cache_location: options.cache_location,
     upgrade: options.upgrade,
-    // proxy: options.proxy,
-    // frozen: options.frozen,
+    proxy: options
+      .proxy
+      .map(|proxy| HttpUriOptionsProxy::new(proxy, options.no_proxy.unwrap_or_default())),
+    headers: HttpUriOptionsHeaders::new(options.headers.unwrap_or_default()),
+    frozen: options.frozen,
     http_client,
     filesystem,
   }
@@ -0,0 +1,16 @@
+use napi_derive::napi;
+use rspack_plugin_import_cost::ImportCostPluginOptions;
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawImportCostRspackPluginOptions {
+  pub filename: String,
+}
+
+impl From<RawImportCostRspackPluginOptions> for ImportCostPluginOptions {
+  fn from(value: RawImportCostRspackPluginOptions) -> Self {
+    Self {
+      filename: value.filename,
+    }
+  }
+}
@@ -0,0 +1,16 @@
+use napi_derive::napi;
+use rspack_plugin_isolated_dts::IsolatedDtsPluginOptions;
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawIsolatedDtsPluginOptions {
+  pub preserve_modules: Option<String>,
+}
+
+impl From<RawIsolatedDtsPluginOptions> for IsolatedDtsPluginOptions {
+  fn from(value: RawIsolatedDtsPluginOptions) -> Self {
+    Self {
+      preserve_modules: value.preserve_modules.map(Into::into),
+    }
+  }
+}
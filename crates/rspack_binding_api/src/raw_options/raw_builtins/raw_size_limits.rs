@@ -1,7 +1,62 @@
 use derive_more::Debug;
 use napi_derive::napi;
+use rspack_error::{Error, error};
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
-use rspack_plugin_size_limits::{AssetFilterFn, SizeLimitsPluginOptions};
+use rspack_plugin_size_limits::{
+  AssetFilterFn, SizeBudget, SizeBudgetTarget, SizeLimitsBaseline, SizeLimitsPluginOptions,
+};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawSizeBudget {
+  pub entrypoint: Option<String>,
+  pub chunk: Option<String>,
+  pub asset: Option<String>,
+  pub max_size: Option<f64>,
+  pub max_gzip_size: Option<f64>,
+  pub max_brotli_size: Option<f64>,
+}
+
+impl TryFrom<RawSizeBudget> for SizeBudget {
+  type Error = Error;
+
+  fn try_from(value: RawSizeBudget) -> Result<Self, Self::Error> {
+    let target = match (value.entrypoint, value.chunk, value.asset) {
+      (Some(entrypoint), None, None) => SizeBudgetTarget::Entrypoint(entrypoint),
+      (None, Some(chunk), None) => SizeBudgetTarget::Chunk(chunk),
+      (None, None, Some(asset)) => SizeBudgetTarget::Asset(asset),
+      _ => {
+        return Err(error!(
+          "performance.budgets: each budget should specify exactly one of `entrypoint`, `chunk` and `asset`"
+        ));
+      }
+    };
+    Ok(SizeBudget {
+      target,
+      max_size: value.max_size,
+      max_gzip_size: value.max_gzip_size,
+      max_brotli_size: value.max_brotli_size,
+    })
+  }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawSizeLimitsBaseline {
+  pub path: String,
+  pub threshold: f64,
+  pub update: bool,
+}
+
+impl From<RawSizeLimitsBaseline> for SizeLimitsBaseline {
+  fn from(value: RawSizeLimitsBaseline) -> Self {
+    SizeLimitsBaseline {
+      path: value.path,
+      threshold: value.threshold,
+      update: value.update,
+    }
+  }
+}
 
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
@@ -13,11 +68,15 @@ pub struct RawSizeLimitsPluginOptions {
   pub hints: Option<String>,
   pub max_asset_size: Option<f64>,
   pub max_entrypoint_size: Option<f64>,
+  pub budgets: Option<Vec<RawSizeBudget>>,
+  pub baseline: Option<RawSizeLimitsBaseline>,
 }
 
-impl From<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
-  fn from(value: RawSizeLimitsPluginOptions) -> Self {
-    SizeLimitsPluginOptions {
+impl TryFrom<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
+  type Error = Error;
+
+  fn try_from(value: RawSizeLimitsPluginOptions) -> Result<Self, Self::Error> {
+    Ok(SizeLimitsPluginOptions {
       asset_filter: value.asset_filter.map(|asset_filter| {
         let asset_filter_fn: AssetFilterFn = Box::new(move |name| {
           let f = asset_filter.clone();
@@ -29,6 +88,13 @@ impl From<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
       hints: value.hints,
       max_asset_size: value.max_asset_size,
       max_entrypoint_size: value.max_entrypoint_size,
-    }
+      budgets: value
+        .budgets
+        .unwrap_or_default()
+        .into_iter()
+        .map(SizeBudget::try_from)
+        .collect::<Result<Vec<_>, _>>()?,
+      baseline: value.baseline.map(Into::into),
+    })
   }
 }
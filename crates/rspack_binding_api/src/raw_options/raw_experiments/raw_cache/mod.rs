@@ -11,11 +11,12 @@ use napi_derive::napi;
 use raw_snapshot::RawExperimentSnapshotOptions;
 use raw_storage::RawStorageOptions;
 use rspack_core::{ExperimentCacheOptions, cache::persistent::PersistentCacheOptions};
+use rspack_error::Error;
 
 pub type RawExperimentCacheOptions = Either<bool, RawExperimentCache>;
 
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawExperimentCacheOptionsPersistent {
   pub build_dependencies: Option<Vec<String>>,
   pub version: Option<String>,
@@ -23,9 +24,11 @@ pub struct RawExperimentCacheOptionsPersistent {
   pub storage: Option<RawStorageOptions>,
 }
 
-impl From<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
-  fn from(value: RawExperimentCacheOptionsPersistent) -> Self {
-    Self {
+impl TryFrom<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
+  type Error = Error;
+
+  fn try_from(value: RawExperimentCacheOptionsPersistent) -> Result<Self, Self::Error> {
+    Ok(Self {
       build_dependencies: value
         .build_dependencies
         .unwrap_or_default()
@@ -34,8 +37,8 @@ impl From<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
         .collect(),
       version: value.version.unwrap_or_default(),
       snapshot: value.snapshot.unwrap_or_default().into(),
-      storage: value.storage.unwrap_or_default().into(),
-    }
+      storage: value.storage.unwrap_or_default().try_into()?,
+    })
   }
 }
 
@@ -43,7 +46,7 @@ impl From<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
 pub enum RawExperimentCache {
   #[default]
   Memory,
-  Persistent(RawExperimentCacheOptionsPersistent),
+  Persistent(Box<RawExperimentCacheOptionsPersistent>),
 }
 
 impl TypeName for RawExperimentCache {
@@ -70,7 +73,7 @@ impl FromNapiValue for RawExperimentCache {
       let v = match &*t {
         "persistent" => {
           let o = RawExperimentCacheOptionsPersistent::from_napi_value(env, napi_val)?;
-          Self::Persistent(o)
+          Self::Persistent(Box::new(o))
         }
         "memory" => Self::Memory,
         _ => panic!("Unexpected cache type: {t}, expected 'persistent' or 'memory'"),
@@ -83,8 +86,8 @@ impl FromNapiValue for RawExperimentCache {
 
 pub fn normalize_raw_experiment_cache_options(
   options: RawExperimentCacheOptions,
-) -> ExperimentCacheOptions {
-  match options {
+) -> Result<ExperimentCacheOptions, Error> {
+  Ok(match options {
     Either::A(options) => {
       if options {
         ExperimentCacheOptions::Memory
@@ -93,8 +96,10 @@ pub fn normalize_raw_experiment_cache_options(
       }
     }
     Either::B(options) => match options {
-      RawExperimentCache::Persistent(options) => ExperimentCacheOptions::Persistent(options.into()),
+      RawExperimentCache::Persistent(options) => {
+        ExperimentCacheOptions::Persistent(Box::new((*options).try_into()?))
+      }
       RawExperimentCache::Memory => ExperimentCacheOptions::Memory,
     },
-  }
+  })
 }
@@ -12,6 +12,7 @@ pub struct RawExperimentSnapshotOptions {
   pub unmanaged_paths: Vec<RawPathMatcher>,
   #[napi(ts_type = r#"Array<string|RegExp>"#)]
   pub managed_paths: Vec<RawPathMatcher>,
+  pub content_hash: bool,
 }
 
 type RawPathMatcher = Either<String, RspackRegex>;
@@ -34,6 +35,7 @@ impl From<RawExperimentSnapshotOptions> for SnapshotOptions {
         .into_iter()
         .map(normalize_raw_path_matcher)
         .collect(),
+      value.content_hash,
     )
   }
 }
@@ -1,21 +1,148 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use napi::bindgen_prelude::{Buffer, FnArgs, Promise};
 use napi_derive::napi;
-use rspack_core::cache::persistent::storage::StorageOptions;
+use rspack_core::cache::persistent::storage::{
+  RemoteHttpClient, RemoteHttpResponse, RemoteOptions, StorageBudget, StorageOptions,
+};
+use rspack_error::{Error, error};
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+
+type RemoteHttpClientFunction = ThreadsafeFunction<
+  FnArgs<(String, String, HashMap<String, String>, Option<Buffer>)>,
+  Promise<RawRemoteHttpResponse>,
+>;
 
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawStorageOptions {
   #[napi(ts_type = r#""filesystem""#)]
   pub r#type: String,
   pub directory: String,
+  pub remote: Option<RawRemoteStorageOptions>,
+  pub max_size: Option<f64>,
+  pub max_generations: Option<u32>,
+}
+
+#[derive(Debug, Default)]
+#[napi(object, object_to_js = false)]
+pub struct RawRemoteStorageOptions {
+  #[napi(ts_type = r#""http" | "directory""#)]
+  pub r#type: String,
+  pub url: Option<String>,
+  pub headers: Option<HashMap<String, String>>,
+  #[napi(
+    ts_type = "(method: string, url: string, headers: Record<string, string>, body?: Buffer) => Promise<RawRemoteHttpResponse>"
+  )]
+  pub http_client: Option<RemoteHttpClientFunction>,
+  pub directory: Option<String>,
+}
+
+#[napi(object)]
+pub struct RawRemoteHttpResponse {
+  pub status: u16,
+  pub body: Buffer,
+}
+
+#[derive(Debug)]
+struct JsRemoteHttpClient {
+  function: RemoteHttpClientFunction,
+}
+
+#[async_trait]
+impl RemoteHttpClient for JsRemoteHttpClient {
+  async fn request(
+    &self,
+    method: &str,
+    url: &str,
+    headers: &[(String, String)],
+    body: Option<Vec<u8>>,
+  ) -> rspack_error::Result<RemoteHttpResponse> {
+    let headers = headers.iter().cloned().collect::<HashMap<_, _>>();
+    let response = self
+      .function
+      .call_with_promise(
+        (
+          method.to_string(),
+          url.to_string(),
+          headers,
+          body.map(Buffer::from),
+        )
+          .into(),
+      )
+      .await?;
+    Ok(RemoteHttpResponse {
+      status: response.status,
+      body: response.body.to_vec(),
+    })
+  }
 }
 
-impl From<RawStorageOptions> for StorageOptions {
-  fn from(value: RawStorageOptions) -> Self {
+impl TryFrom<RawRemoteStorageOptions> for RemoteOptions {
+  type Error = Error;
+
+  fn try_from(value: RawRemoteStorageOptions) -> Result<Self, Self::Error> {
+    match value.r#type.as_str() {
+      "http" => {
+        let Some(url) = value.url else {
+          return Err(error!(
+            "experiments.cache.storage.remote: `url` is required for the http remote"
+          ));
+        };
+        let mut headers = value
+          .headers
+          .unwrap_or_default()
+          .into_iter()
+          .collect::<Vec<_>>();
+        headers.sort();
+        let client = value
+          .http_client
+          .map(|function| Arc::new(JsRemoteHttpClient { function }) as Arc<dyn RemoteHttpClient>);
+        Ok(RemoteOptions::Http {
+          url,
+          headers,
+          client,
+        })
+      }
+      "directory" => {
+        let Some(directory) = value.directory else {
+          return Err(error!(
+            "experiments.cache.storage.remote: `directory` is required for the directory remote"
+          ));
+        };
+        Ok(RemoteOptions::Directory { directory })
+      }
+      s => Err(error!(
+        "experiments.cache.storage.remote: unsupported remote type `{s}`, expected `http` or `directory`"
+      )),
+    }
+  }
+}
+
+impl TryFrom<RawStorageOptions> for StorageOptions {
+  type Error = Error;
+
+  fn try_from(value: RawStorageOptions) -> Result<Self, Self::Error> {
+    let budget = StorageBudget {
+      max_size: value.max_size.map(|size| size as u64),
+      max_generations: value.max_generations.map(|count| count as usize),
+    };
     match value.r#type.as_str() {
-      "filesystem" => StorageOptions::FileSystem {
-        directory: value.directory.into(),
+      "filesystem" => match value.remote {
+        Some(remote) => Ok(StorageOptions::Remote {
+          directory: value.directory.into(),
+          remote: remote.try_into()?,
+          budget,
+        }),
+        None => Ok(StorageOptions::FileSystem {
+          directory: value.directory.into(),
+          budget,
+        }),
       },
-      s => panic!("unsupported storage type {s}"),
+      s => Err(error!(
+        "experiments.cache.storage: unsupported storage type `{s}`, expected `filesystem`"
+      )),
     }
   }
 }
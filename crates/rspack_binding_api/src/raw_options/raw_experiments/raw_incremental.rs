@@ -5,6 +5,7 @@ use rspack_core::incremental::{IncrementalOptions, IncrementalPasses};
 #[napi(object)]
 pub struct RawIncremental {
   pub silent: bool,
+  pub explain: bool,
   // passes
   pub make: bool,
   pub infer_async_modules: bool,
@@ -73,6 +74,7 @@ impl From<RawIncremental> for IncrementalOptions {
     }
     Self {
       silent: value.silent,
+      explain: value.explain,
       passes,
     }
   }
@@ -16,14 +16,15 @@ use rspack_core::{
   AssetGeneratorDataUrl, AssetGeneratorDataUrlFnCtx, AssetGeneratorDataUrlOptions,
   AssetGeneratorOptions, AssetInlineGeneratorOptions, AssetParserDataUrl,
   AssetParserDataUrlOptions, AssetParserOptions, AssetResourceGeneratorOptions,
-  CssAutoGeneratorOptions, CssAutoParserOptions, CssGeneratorOptions, CssModuleGeneratorOptions,
-  CssModuleParserOptions, CssParserOptions, DescriptionData, DynamicImportFetchPriority,
-  DynamicImportMode, ExportPresenceMode, FuncUseCtx, GeneratorOptions, GeneratorOptionsMap,
-  JavascriptParserCommonjsExportsOption, JavascriptParserCommonjsOptions, JavascriptParserOptions,
-  JavascriptParserOrder, JavascriptParserUrl, JsonGeneratorOptions, JsonParserOptions,
-  ModuleNoParseRule, ModuleNoParseRules, ModuleNoParseTestFn, ModuleOptions, ModuleRule,
-  ModuleRuleEffect, ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, OverrideStrict,
-  ParseOption, ParserOptions, ParserOptionsMap, TypeReexportPresenceMode, UnsafeCachePredicate,
+  CssAutoGeneratorOptions, CssAutoParserOptions, CssExportsDeclaration, CssGeneratorOptions,
+  CssModuleGeneratorOptions, CssModuleParserOptions, CssParserOptions, DescriptionData,
+  DynamicImportFetchPriority, DynamicImportMode, ExportPresenceMode, FuncUseCtx, GeneratorOptions,
+  GeneratorOptionsMap, JavascriptParserCommonjsExportsOption, JavascriptParserCommonjsOptions,
+  JavascriptParserOptions, JavascriptParserOrder, JavascriptParserUrl, JsonGeneratorOptions,
+  JsonParserOptions, JsonSyntax, ModuleNoParseRule, ModuleNoParseRules, ModuleNoParseTestFn,
+  ModuleOptions, ModuleRule, ModuleRuleEffect, ModuleRuleEnforce, ModuleRuleUse,
+  ModuleRuleUseLoader, OverrideStrict, ParseOption, ParserOptions, ParserOptionsMap,
+  TypeReexportPresenceMode, UnsafeCachePredicate,
 };
 use rspack_error::error;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
@@ -201,9 +202,11 @@ pub struct RawParserOptions {
   pub json: Option<RawJsonParserOptions>,
 }
 
-impl From<RawParserOptions> for ParserOptions {
-  fn from(value: RawParserOptions) -> Self {
-    match value.r#type.as_str() {
+impl TryFrom<RawParserOptions> for ParserOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawParserOptions) -> rspack_error::Result<Self> {
+    Ok(match value.r#type.as_str() {
       "asset" => Self::Asset(
         value
           .asset
@@ -258,13 +261,13 @@ impl From<RawParserOptions> for ParserOptions {
         value
           .json
           .expect("should have an \"json\" when RawParserOptions.type is \"json\"")
-          .into(),
+          .try_into()?,
       ),
       _ => panic!(
         "Failed to resolve the RawParserOptions.type {}.",
         value.r#type
       ),
-    }
+    })
   }
 }
 
@@ -313,6 +316,9 @@ pub struct RawJavascriptParserOptions {
   /// @experimental
   pub jsx: Option<bool>,
   pub defer_import: Option<bool>,
+  /// This option is experimental in Rspack only and subject to change or be removed anytime.
+  /// @experimental
+  pub exported_enum: Option<bool>,
 }
 
 #[napi(object)]
@@ -392,6 +398,7 @@ impl From<RawJavascriptParserOptions> for JavascriptParserOptions {
       commonjs_magic_comments: value.commonjs_magic_comments,
       jsx: value.jsx,
       defer_import: value.defer_import,
+      exported_enum: value.exported_enum,
     }
   }
 }
@@ -519,10 +526,14 @@ pub struct RawJsonParserOptions {
   pub exports_depth: Option<u32>,
   #[napi(ts_type = "(source: string) => string")]
   pub parse: Option<ThreadsafeFunction<String, String>>,
+  #[napi(ts_type = r#""json" | "jsonc" | "json5""#)]
+  pub syntax: Option<String>,
 }
 
-impl From<RawJsonParserOptions> for JsonParserOptions {
-  fn from(value: RawJsonParserOptions) -> Self {
+impl TryFrom<RawJsonParserOptions> for JsonParserOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawJsonParserOptions) -> rspack_error::Result<Self> {
     let parse = match value.parse {
       Some(f) => ParseOption::Func(Arc::new(move |s: String| {
         let f = f.clone();
@@ -531,10 +542,15 @@ impl From<RawJsonParserOptions> for JsonParserOptions {
       _ => ParseOption::None,
     };
 
-    Self {
+    Ok(Self {
       exports_depth: value.exports_depth,
       parse,
-    }
+      syntax: value
+        .syntax
+        .as_deref()
+        .map(JsonSyntax::try_from)
+        .transpose()?,
+    })
   }
 }
 
@@ -781,6 +797,8 @@ pub struct RawCssAutoGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<String>,
   pub es_module: Option<bool>,
+  #[napi(ts_type = "boolean | RawCssExportsDeclarationOptions")]
+  pub exports_declaration: Option<Either<bool, RawCssExportsDeclarationOptions>>,
 }
 
 impl From<RawCssAutoGeneratorOptions> for CssAutoGeneratorOptions {
@@ -790,6 +808,7 @@ impl From<RawCssAutoGeneratorOptions> for CssAutoGeneratorOptions {
       exports_only: value.exports_only,
       local_ident_name: value.local_ident_name.map(|n| n.into()),
       es_module: value.es_module,
+      exports_declaration: value.exports_declaration.map(into_css_exports_declaration),
     }
   }
 }
@@ -802,6 +821,8 @@ pub struct RawCssModuleGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<String>,
   pub es_module: Option<bool>,
+  #[napi(ts_type = "boolean | RawCssExportsDeclarationOptions")]
+  pub exports_declaration: Option<Either<bool, RawCssExportsDeclarationOptions>>,
 }
 
 impl From<RawCssModuleGeneratorOptions> for CssModuleGeneratorOptions {
@@ -811,10 +832,29 @@ impl From<RawCssModuleGeneratorOptions> for CssModuleGeneratorOptions {
       exports_only: value.exports_only,
       local_ident_name: value.local_ident_name.map(|n| n.into()),
       es_module: value.es_module,
+      exports_declaration: value.exports_declaration.map(into_css_exports_declaration),
     }
   }
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawCssExportsDeclarationOptions {
+  pub output_dir: Option<String>,
+}
+
+fn into_css_exports_declaration(
+  value: Either<bool, RawCssExportsDeclarationOptions>,
+) -> CssExportsDeclaration {
+  match value {
+    Either::A(flag) => CssExportsDeclaration::from(flag),
+    Either::B(options) => match options.output_dir {
+      Some(output_dir) => CssExportsDeclaration::OutputDir(output_dir),
+      None => CssExportsDeclaration::Enable,
+    },
+  }
+}
+
 #[napi(object, object_to_js = false)]
 #[derive(Debug)]
 pub struct RawModuleOptions {
@@ -968,7 +1008,7 @@ impl TryFrom<RawModuleRule> for ModuleRule {
         r#use: uses.transpose()?.unwrap_or_default(),
         r#type: module_type,
         layer: value.layer,
-        parser: value.parser.map(|raw| raw.into()),
+        parser: value.parser.map(|raw| raw.try_into()).transpose()?,
         generator: value.generator.map(|raw| raw.into()),
         resolve: value.resolve.map(|raw| raw.try_into()).transpose()?,
         side_effects: value.side_effects,
@@ -1014,7 +1054,7 @@ impl TryFrom<RawModuleOptions> for ModuleOptions {
         .parser
         .map(|x| {
           x.into_iter()
-            .map(|(k, v)| Ok((k, v.into())))
+            .map(|(k, v)| Ok((k, v.try_into()?)))
             .collect::<std::result::Result<ParserOptionsMap, rspack_error::Error>>()
         })
         .transpose()?,
@@ -9,6 +9,7 @@ use napi_derive::napi;
 use rspack_collections::IdentifierMap;
 use rspack_core::{
   EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsChunk, StatsModule, StatsUsedExports,
+  incremental::IncrementalExplanation,
   rspack_sources::{RawBufferSource, Source, SourceValue},
 };
 use rspack_error::Severity;
@@ -372,6 +373,77 @@ impl<'a> From<(String, rspack_core::LogType)> for JsStatsLogging<'a> {
   }
 }
 
+#[napi(object, object_from_js = false)]
+pub struct JsStatsIncremental {
+  pub changed_files: Vec<String>,
+  pub removed_files: Vec<String>,
+  pub invalidated_modules: Vec<JsStatsIncrementalInvalidatedModule>,
+  pub affected_modules: Vec<JsStatsIncrementalAffectedModule>,
+  pub affected_chunks: Vec<String>,
+  pub passes: Vec<JsStatsIncrementalPass>,
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsIncrementalInvalidatedModule {
+  pub identifier: String,
+  #[napi(ts_type = "'add' | 'update' | 'remove'")]
+  pub kind: &'static str,
+  pub files: Vec<String>,
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsIncrementalAffectedModule {
+  pub identifier: String,
+  pub affected_by: Option<String>,
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsIncrementalPass {
+  pub name: &'static str,
+  pub incremental: bool,
+  pub affected: Option<u32>,
+  pub total: Option<u32>,
+  pub reason: Option<String>,
+}
+
+impl From<IncrementalExplanation> for JsStatsIncremental {
+  fn from(value: IncrementalExplanation) -> Self {
+    Self {
+      changed_files: value.changed_files,
+      removed_files: value.removed_files,
+      invalidated_modules: value
+        .invalidated_modules
+        .into_iter()
+        .map(|module| JsStatsIncrementalInvalidatedModule {
+          identifier: module.identifier,
+          kind: module.kind,
+          files: module.files,
+        })
+        .collect(),
+      affected_modules: value
+        .affected_modules
+        .into_iter()
+        .map(|module| JsStatsIncrementalAffectedModule {
+          identifier: module.identifier,
+          affected_by: module.affected_by,
+        })
+        .collect(),
+      affected_chunks: value.affected_chunks,
+      passes: value
+        .passes
+        .into_iter()
+        .map(|pass| JsStatsIncrementalPass {
+          name: pass.name,
+          incremental: pass.incremental,
+          affected: pass.affected.map(|affected| affected as u32),
+          total: pass.total.map(|total| total as u32),
+          reason: pass.reason,
+        })
+        .collect(),
+    }
+  }
+}
+
 #[napi(object, object_from_js = false)]
 pub struct JsStatsAsset<'a> {
   pub r#type: &'a str,
@@ -385,6 +457,24 @@ pub struct JsStatsAsset<'a> {
   pub auxiliary_chunk_names: Vec<&'a str>,
   pub auxiliary_chunk_id_hints: Vec<&'a str>,
   pub auxiliary_chunks: Vec<Option<&'a str>>,
+  pub compressed_sizes: Vec<JsStatsAssetCompressedSize<'a>>,
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsAssetCompressedSize<'a> {
+  pub algorithm: &'a str,
+  pub name: &'a str,
+  pub size: f64,
+}
+
+impl<'a> From<rspack_core::StatsAssetCompressedSize<'a>> for JsStatsAssetCompressedSize<'a> {
+  fn from(stats: rspack_core::StatsAssetCompressedSize<'a>) -> Self {
+    Self {
+      algorithm: stats.algorithm,
+      name: stats.name,
+      size: stats.size,
+    }
+  }
 }
 
 impl<'a> From<rspack_core::StatsAsset<'a>> for JsStatsAsset<'a> {
@@ -401,6 +491,7 @@ impl<'a> From<rspack_core::StatsAsset<'a>> for JsStatsAsset<'a> {
       auxiliary_chunk_id_hints: stats.auxiliary_chunk_id_hints,
       auxiliary_chunks: stats.auxiliary_chunks,
       auxiliary_chunk_names: stats.auxiliary_chunk_names,
+      compressed_sizes: stats.compressed_sizes.into_iter().map(Into::into).collect(),
     }
   }
 }
@@ -1227,6 +1318,11 @@ impl JsStats {
       .collect()
   }
 
+  #[napi]
+  pub fn get_incremental(&self) -> Option<JsStatsIncremental> {
+    self.inner.get_incremental_explanation().map(Into::into)
+  }
+
   fn hash(&self) -> Option<&str> {
     self.inner.get_hash()
   }
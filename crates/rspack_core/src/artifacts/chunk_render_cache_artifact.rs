@@ -28,6 +28,9 @@ impl ChunkRenderCacheArtifact {
       storage: Some(storage),
     }
   }
+  pub(crate) fn storage(&self) -> Option<&MemoryGCStorage<BoxSource>> {
+    self.storage.as_ref()
+  }
   pub fn start_next_generation(&self) {
     if let Some(storage) = &self.storage {
       storage.start_next_generation();
@@ -34,6 +34,10 @@ impl CodeGenerateCacheArtifact {
     }
   }
 
+  pub(crate) fn storage(&self) -> Option<&MemoryGCStorage<CodeGenerationResult>> {
+    self.storage.as_ref()
+  }
+
   pub fn start_next_generation(&self) {
     if let Some(storage) = &self.storage {
       storage.start_next_generation();
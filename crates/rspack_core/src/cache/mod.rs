@@ -4,12 +4,13 @@ pub mod persistent;
 
 use std::{fmt::Debug, sync::Arc};
 
+use rspack_error::Diagnostic;
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
 
 use self::{disable::DisableCache, memory::MemoryCache, persistent::PersistentCache};
 use crate::{
-  Compilation, CompilerOptions, ExperimentCacheOptions,
-  compilation::build_module_graph::BuildModuleGraphArtifact,
+  ChunkRenderCacheArtifact, CodeGenerateCacheArtifact, Compilation, CompilerOptions,
+  ExperimentCacheOptions, compilation::build_module_graph::BuildModuleGraphArtifact,
 };
 
 /// Cache trait
@@ -34,6 +35,17 @@ pub trait Cache: Debug + Send + Sync {
 
   async fn before_build_module_graph(&mut self, _make_artifact: &mut BuildModuleGraphArtifact) {}
   async fn after_build_module_graph(&mut self, _make_artifact: &BuildModuleGraphArtifact) {}
+
+  async fn before_code_generation(&mut self, _artifact: &mut CodeGenerateCacheArtifact) {}
+  async fn after_code_generation(&mut self, _artifact: &CodeGenerateCacheArtifact) {}
+
+  async fn before_create_chunk_assets(&mut self, _artifact: &mut ChunkRenderCacheArtifact) {}
+  async fn after_create_chunk_assets(&mut self, _artifact: &ChunkRenderCacheArtifact) {}
+
+  /// take the diagnostics generated by cache, such as corrupt cache files
+  fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+    vec![]
+  }
 }
 
 pub fn new_cache(
@@ -14,20 +14,22 @@ use rspack_cacheable::{
   utils::PortablePath,
   with::{As, AsVec},
 };
+use rspack_error::Diagnostic;
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
 use rspack_paths::ArcPathSet;
+use rspack_util::size::format_size;
 use rspack_workspace::rspack_pkg_version;
 
 use self::{
   build_dependencies::{BuildDeps, BuildDepsOptions},
   codec::CacheCodec,
-  occasion::{MakeOccasion, MetaOccasion},
+  occasion::{ChunkRenderOccasion, CodeGenerateOccasion, MakeOccasion, MetaOccasion},
   snapshot::{Snapshot, SnapshotOptions, SnapshotScope},
   storage::{Storage, StorageOptions, create_storage},
 };
 use super::Cache;
 use crate::{
-  Compilation, CompilerOptions, Logger,
+  ChunkRenderCacheArtifact, CodeGenerateCacheArtifact, Compilation, CompilerOptions, Logger,
   compilation::build_module_graph::{BuildModuleGraphArtifact, BuildModuleGraphArtifactState},
 };
 
@@ -41,6 +43,12 @@ pub struct PersistentCacheOptions {
   pub storage: StorageOptions,
 }
 
+#[derive(Debug, Default)]
+struct SealRecovered {
+  code_generate: bool,
+  chunk_render: bool,
+}
+
 /// Persistent cache implementation
 #[derive(Debug)]
 pub struct PersistentCache {
@@ -49,6 +57,13 @@ pub struct PersistentCache {
   snapshot: Arc<Snapshot>,
   make_occasion: MakeOccasion,
   meta_occasion: MetaOccasion,
+  code_generate_occasion: CodeGenerateOccasion,
+  chunk_render_occasion: ChunkRenderOccasion,
+  // the seal occasions only need to be recovered in the first compilation,
+  // the artifacts will be inherited by the following compilations.
+  seal_recovered: SealRecovered,
+  // the watcher start time of the compilation which saved the recovered cache
+  recovered_watcher_start_time: Option<u64>,
   async_mode: bool,
   storage: Arc<dyn Storage>,
   // TODO replace to logger and output warnings directly.
@@ -64,7 +79,11 @@ impl PersistentCache {
     intermediate_filesystem: Arc<dyn IntermediateFileSystem>,
   ) -> Self {
     let async_mode = compiler_options.mode.is_development();
-    let codec = Arc::new(CacheCodec::new(None));
+    // all of the paths in cache are stored relative to the context,
+    // so that the cache can be restored when the project is moved to another directory.
+    let codec = Arc::new(CacheCodec::new(Some(
+      compiler_options.context.as_path().to_path_buf(),
+    )));
     // use codec.encode to transform the absolute path in option,
     // it will ensure that same project in different directory have the same version.
     let option_bytes = codec
@@ -79,7 +98,8 @@ impl PersistentCache {
       compiler_options.mode.hash(&mut hasher);
       hex::encode(hasher.finish().to_ne_bytes())
     };
-    let storage = create_storage(option.storage.clone(), version, intermediate_filesystem);
+    let (storage, storage_warning) =
+      create_storage(option.storage.clone(), version, intermediate_filesystem);
     let snapshot = Arc::new(Snapshot::new(
       option.snapshot.clone(),
       input_filesystem.clone(),
@@ -97,8 +117,12 @@ impl PersistentCache {
       ),
       snapshot,
       make_occasion: MakeOccasion::new(storage.clone(), codec.clone()),
-      meta_occasion: MetaOccasion::new(storage.clone(), codec),
-      warnings: Default::default(),
+      meta_occasion: MetaOccasion::new(storage.clone(), codec.clone()),
+      code_generate_occasion: CodeGenerateOccasion::new(storage.clone(), codec.clone()),
+      chunk_render_occasion: ChunkRenderOccasion::new(storage.clone(), codec),
+      seal_recovered: Default::default(),
+      recovered_watcher_start_time: None,
+      warnings: storage_warning.into_iter().collect(),
       async_mode,
       storage,
     }
@@ -113,8 +137,9 @@ impl PersistentCache {
     if let Err(err) = self.build_deps.validate().await {
       self.warnings.push(err.to_string());
     }
-    if let Err(err) = self.meta_occasion.recovery().await {
-      self.warnings.push(err.to_string());
+    match self.meta_occasion.recovery().await {
+      Ok(start_time) => self.recovered_watcher_start_time = start_time,
+      Err(err) => self.warnings.push(err.to_string()),
     }
   }
 
@@ -147,6 +172,14 @@ impl Cache for PersistentCache {
     // rebuild will pass modified_files and removed_files from js side,
     // so only calculate them when build.
     if !compilation.is_rebuild {
+      // the resumed files contain all changes since the watcher state is saved,
+      // if the cache is saved by the compilation which the watcher state belongs to,
+      // the snapshot check is skipped.
+      if compilation.resumed_since.is_some()
+        && compilation.resumed_since == self.recovered_watcher_start_time
+      {
+        return false;
+      }
       let mut is_hot_start = false;
       let mut modified_paths = ArcPathSet::default();
       let mut removed_paths = ArcPathSet::default();
@@ -185,7 +218,7 @@ impl Cache for PersistentCache {
 
   async fn after_compile(&mut self, compilation: &Compilation) {
     // save meta
-    self.meta_occasion.save();
+    self.meta_occasion.save(compilation.watcher_start_time);
 
     // save snapshot
     // TODO add a all_dependencies to collect dependencies
@@ -228,11 +261,27 @@ impl Cache for PersistentCache {
     );
 
     self.save().await;
+    self.warnings.extend(self.storage.take_warnings());
 
     let logger = compilation.get_logger("rspack.persistentCache");
     for msg in std::mem::take(&mut self.warnings) {
       logger.warn(msg);
     }
+    // the result of async mode is reported in the next compilation
+    if let Some(result) = self.storage.take_eviction_result() {
+      if !result.evicted_packs.is_empty() {
+        logger.log(format!(
+          "evicted {} cache versions and {} packs, reclaimed {}, {} remains",
+          result.evicted_versions.len(),
+          result.evicted_packs.len(),
+          format_size(result.reclaimed_size as f64),
+          format_size(result.remain_size as f64)
+        ));
+      }
+      if result.compacted_packs > 0 {
+        logger.log(format!("compacted {} packs", result.compacted_packs));
+      }
+    }
   }
 
   async fn before_build_module_graph(&mut self, make_artifact: &mut BuildModuleGraphArtifact) {
@@ -251,4 +300,40 @@ impl Cache for PersistentCache {
   async fn after_build_module_graph(&mut self, make_artifact: &BuildModuleGraphArtifact) {
     self.make_occasion.save(make_artifact);
   }
+
+  async fn before_code_generation(&mut self, artifact: &mut CodeGenerateCacheArtifact) {
+    if std::mem::replace(&mut self.seal_recovered.code_generate, true) {
+      return;
+    }
+    if let Err(err) = self.code_generate_occasion.recovery(artifact).await {
+      self.warnings.push(err.to_string());
+    }
+  }
+
+  async fn after_code_generation(&mut self, artifact: &CodeGenerateCacheArtifact) {
+    self.code_generate_occasion.save(artifact);
+  }
+
+  async fn before_create_chunk_assets(&mut self, artifact: &mut ChunkRenderCacheArtifact) {
+    if std::mem::replace(&mut self.seal_recovered.chunk_render, true) {
+      return;
+    }
+    if let Err(err) = self.chunk_render_occasion.recovery(artifact).await {
+      self.warnings.push(err.to_string());
+    }
+  }
+
+  async fn after_create_chunk_assets(&mut self, artifact: &ChunkRenderCacheArtifact) {
+    self.chunk_render_occasion.save(artifact);
+  }
+
+  fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+    // the corrupt packs have been quarantined and only their scopes are invalidated
+    self
+      .storage
+      .take_corrupt_packs()
+      .into_iter()
+      .map(|corrupt_packs| rspack_error::Error::from(corrupt_packs).into())
+      .collect()
+  }
 }
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use rspack_cacheable::{cacheable, with::AsPreset};
+use rspack_collections::{Identifier, IdentifierSet};
+use rspack_error::Result;
+use rspack_sources::BoxSource;
+
+use super::super::{Storage, codec::CacheCodec};
+use crate::ChunkRenderCacheArtifact;
+
+pub const SCOPE: &str = "occasion_chunk_render";
+
+/// The value struct of current storage scope
+#[cacheable]
+struct Node {
+  #[cacheable(with=AsPreset)]
+  source: BoxSource,
+}
+
+/// Chunk Render Occasion is used to save ChunkRenderCacheArtifact
+///
+/// The cache key of the artifact is the content hash of chunk,
+/// so the saved sources can be used directly when the chunk is unchanged.
+#[derive(Debug)]
+pub struct ChunkRenderOccasion {
+  storage: Arc<dyn Storage>,
+  codec: Arc<CacheCodec>,
+  /// the cache keys which have been synced to storage
+  saved_keys: IdentifierSet,
+}
+
+impl ChunkRenderOccasion {
+  pub fn new(storage: Arc<dyn Storage>, codec: Arc<CacheCodec>) -> Self {
+    Self {
+      storage,
+      codec,
+      saved_keys: Default::default(),
+    }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::ChunkRender::save", skip_all)]
+  pub fn save(&mut self, artifact: &ChunkRenderCacheArtifact) {
+    let Some(memory_storage) = artifact.storage() else {
+      return;
+    };
+    let mut current_keys = IdentifierSet::default();
+    memory_storage.for_each(|key, source| {
+      current_keys.insert(*key);
+      if self.saved_keys.contains(key) {
+        return;
+      }
+      let node = Node {
+        source: source.clone(),
+      };
+      match self.codec.encode(&node) {
+        Ok(bytes) => self.storage.set(
+          SCOPE,
+          self.codec.encode(key).expect("should encode success"),
+          bytes,
+        ),
+        Err(err) => tracing::warn!("to bytes failed {:?}", err),
+      }
+    });
+    // remove the sources which have been released by memory storage
+    for key in self.saved_keys.difference(&current_keys) {
+      self.storage.remove(
+        SCOPE,
+        &self.codec.encode(key).expect("should encode success"),
+      );
+    }
+    self.saved_keys = current_keys;
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::ChunkRender::recovery", skip_all)]
+  pub async fn recovery(&mut self, artifact: &ChunkRenderCacheArtifact) -> Result<()> {
+    let Some(memory_storage) = artifact.storage() else {
+      return Ok(());
+    };
+    for (key, value) in self.storage.load(SCOPE).await? {
+      let key: Identifier = self.codec.decode(&key)?;
+      let node: Node = self.codec.decode(&value)?;
+      memory_storage.set(key, node.source);
+      self.saved_keys.insert(key);
+    }
+    tracing::debug!("recovery {} chunk render results", self.saved_keys.len());
+    Ok(())
+  }
+}
@@ -0,0 +1,225 @@
+use std::sync::Arc;
+
+use rspack_cacheable::{
+  cacheable,
+  with::{AsCacheable, AsMap, AsOption, AsPreset, AsVec},
+};
+use rspack_collections::{Identifier, IdentifierSet};
+use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
+use rspack_sources::BoxSource;
+use rspack_util::atom::Atom;
+use rustc_hash::FxHashMap as HashMap;
+
+use super::super::{Storage, codec::CacheCodec};
+use crate::{
+  AssetInfo, ChunkInitFragments, CodeGenerateCacheArtifact, CodeGenerationDataAssetInfo,
+  CodeGenerationDataFilename, CodeGenerationDataTopLevelDeclarations, CodeGenerationDataUrl,
+  CodeGenerationExportsFinalNames, CodeGenerationPublicPathAutoReplace, CodeGenerationResult,
+  RuntimeGlobals, SourceType, URLStaticMode,
+};
+
+pub const SCOPE: &str = "occasion_code_generate";
+
+/// The value struct of current storage scope
+///
+/// Only the known code generation data is saved, the results with other data will be regenerated.
+#[cacheable]
+struct Node {
+  #[cacheable(with=AsMap<AsCacheable, AsPreset>)]
+  sources: HashMap<SourceType, BoxSource>,
+  runtime_requirements: RuntimeGlobals,
+  hash: Option<RspackHashDigest>,
+  url: Option<String>,
+  filename: Option<(String, String)>,
+  asset_info: Option<AssetInfo>,
+  public_path_auto_replace: Option<bool>,
+  url_static_mode: bool,
+  #[cacheable(with=AsOption<AsVec<AsPreset>>)]
+  top_level_declarations: Option<Vec<Atom>>,
+  exports_final_names: Option<Vec<(String, String)>>,
+}
+
+impl Node {
+  fn from_result(result: &CodeGenerationResult) -> Option<Self> {
+    if !result.chunk_init_fragments.is_empty() || result.concatenation_scope.is_some() {
+      return None;
+    }
+    let data = &result.data;
+    let mut known_data_count = 0;
+    let mut count = |exists: bool| {
+      if exists {
+        known_data_count += 1;
+      }
+    };
+    let url = data
+      .get::<CodeGenerationDataUrl>()
+      .map(|item| item.inner().to_string());
+    count(url.is_some());
+    let filename = data
+      .get::<CodeGenerationDataFilename>()
+      .map(|item| (item.filename().to_string(), item.public_path().to_string()));
+    count(filename.is_some());
+    let asset_info = data
+      .get::<CodeGenerationDataAssetInfo>()
+      .map(|item| item.inner().clone());
+    count(asset_info.is_some());
+    let public_path_auto_replace = data
+      .get::<CodeGenerationPublicPathAutoReplace>()
+      .map(|item| item.0);
+    count(public_path_auto_replace.is_some());
+    let url_static_mode = data.get::<URLStaticMode>().is_some();
+    count(url_static_mode);
+    let top_level_declarations = data
+      .get::<CodeGenerationDataTopLevelDeclarations>()
+      .map(|item| item.inner().iter().cloned().collect());
+    count(top_level_declarations.is_some());
+    let exports_final_names = data.get::<CodeGenerationExportsFinalNames>().map(|item| {
+      item
+        .inner()
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+    });
+    count(exports_final_names.is_some());
+    // an empty chunk init fragments may be added by dependency templates
+    count(
+      data
+        .get::<ChunkInitFragments>()
+        .is_some_and(|fragments| fragments.is_empty()),
+    );
+    if known_data_count != data.len() {
+      return None;
+    }
+
+    Some(Self {
+      sources: result
+        .inner()
+        .iter()
+        .map(|(source_type, source)| (*source_type, source.clone()))
+        .collect(),
+      runtime_requirements: result.runtime_requirements,
+      hash: result.hash.clone(),
+      url,
+      filename,
+      asset_info,
+      public_path_auto_replace,
+      url_static_mode,
+      top_level_declarations,
+      exports_final_names,
+    })
+  }
+
+  fn into_result(self) -> CodeGenerationResult {
+    let mut result = CodeGenerationResult::default();
+    for (source_type, source) in self.sources {
+      result.add(source_type, source);
+    }
+    result.runtime_requirements = self.runtime_requirements;
+    result.hash = self.hash;
+    if let Some(url) = self.url {
+      result.data.insert(CodeGenerationDataUrl::new(url));
+    }
+    if let Some((filename, public_path)) = self.filename {
+      result
+        .data
+        .insert(CodeGenerationDataFilename::new(filename, public_path));
+    }
+    if let Some(asset_info) = self.asset_info {
+      result
+        .data
+        .insert(CodeGenerationDataAssetInfo::new(asset_info));
+    }
+    if let Some(value) = self.public_path_auto_replace {
+      result
+        .data
+        .insert(CodeGenerationPublicPathAutoReplace(value));
+    }
+    if self.url_static_mode {
+      result.data.insert(URLStaticMode);
+    }
+    if let Some(declarations) = self.top_level_declarations {
+      result
+        .data
+        .insert(CodeGenerationDataTopLevelDeclarations::new(
+          declarations.into_iter().collect(),
+        ));
+    }
+    if let Some(names) = self.exports_final_names {
+      result.data.insert(CodeGenerationExportsFinalNames::new(
+        names.into_iter().collect(),
+      ));
+    }
+    result
+  }
+}
+
+/// Code Generate Occasion is used to save CodeGenerateCacheArtifact
+///
+/// The cache key of the artifact is composed of module identifier and module hash of the runtime,
+/// so the saved results can be used directly when the module is unchanged.
+#[derive(Debug)]
+pub struct CodeGenerateOccasion {
+  storage: Arc<dyn Storage>,
+  codec: Arc<CacheCodec>,
+  /// the cache keys which have been synced to storage
+  saved_keys: IdentifierSet,
+}
+
+impl CodeGenerateOccasion {
+  pub fn new(storage: Arc<dyn Storage>, codec: Arc<CacheCodec>) -> Self {
+    Self {
+      storage,
+      codec,
+      saved_keys: Default::default(),
+    }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::CodeGenerate::save", skip_all)]
+  pub fn save(&mut self, artifact: &CodeGenerateCacheArtifact) {
+    let Some(memory_storage) = artifact.storage() else {
+      return;
+    };
+    let mut current_keys = IdentifierSet::default();
+    memory_storage.for_each(|key, result| {
+      current_keys.insert(*key);
+      if self.saved_keys.contains(key) {
+        return;
+      }
+      let Some(node) = Node::from_result(result) else {
+        return;
+      };
+      match self.codec.encode(&node) {
+        Ok(bytes) => self.storage.set(
+          SCOPE,
+          self.codec.encode(key).expect("should encode success"),
+          bytes,
+        ),
+        Err(err) => tracing::warn!("to bytes failed {:?}", err),
+      }
+    });
+    // remove the results which have been released by memory storage
+    for key in self.saved_keys.difference(&current_keys) {
+      self.storage.remove(
+        SCOPE,
+        &self.codec.encode(key).expect("should encode success"),
+      );
+    }
+    self.saved_keys = current_keys;
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::CodeGenerate::recovery", skip_all)]
+  pub async fn recovery(&mut self, artifact: &CodeGenerateCacheArtifact) -> Result<()> {
+    let Some(memory_storage) = artifact.storage() else {
+      return Ok(());
+    };
+    for (key, value) in self.storage.load(SCOPE).await? {
+      let key: Identifier = self.codec.decode(&key)?;
+      let node: Node = self.codec.decode(&value)?;
+      memory_storage.set(key, node.into_result());
+      self.saved_keys.insert(key);
+    }
+    tracing::debug!("recovery {} code generation results", self.saved_keys.len());
+    Ok(())
+  }
+}
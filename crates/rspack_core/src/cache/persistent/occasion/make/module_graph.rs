@@ -44,7 +44,10 @@ pub fn save_module_graph(
   codec: &CacheCodec,
 ) {
   for identifier in removed_modules {
-    storage.remove(SCOPE, identifier.as_bytes());
+    storage.remove(
+      SCOPE,
+      &codec.encode(identifier).expect("should encode success"),
+    );
   }
 
   // save module_graph
@@ -92,8 +95,10 @@ pub fn save_module_graph(
         blocks,
         lazy_info,
       };
+      // the identifier contains absolute paths, use the portable encoded one as key
+      let key = codec.encode(identifier).expect("should encode success");
       match codec.encode(&node) {
-        Ok(bytes) => (identifier.as_bytes().to_vec(), bytes),
+        Ok(bytes) => (key, bytes),
         Err(err) if err.to_string().contains("unsupported field") => {
           tracing::warn!("to bytes failed {:?}", err);
           // try use alternatives
@@ -105,7 +110,7 @@ pub fn save_module_graph(
             .collect();
           node.blocks = vec![];
           if let Ok(bytes) = codec.encode(&node) {
-            (identifier.as_bytes().to_vec(), bytes)
+            (key, bytes)
           } else {
             panic!("alternatives serialize failed")
           }
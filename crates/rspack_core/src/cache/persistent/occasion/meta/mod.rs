@@ -12,6 +12,10 @@ pub const SCOPE: &str = "meta";
 #[cacheable]
 struct Meta {
   pub max_dependencies_id: u32,
+  /// Unix timestamp in milliseconds of the start of the watcher which is watching the compilation which saves the meta.
+  ///
+  /// It is the version marker to match the saved state of watcher.
+  pub watcher_start_time: Option<u64>,
 }
 
 /// Meta Occasion is used to save compiler state.
@@ -27,9 +31,10 @@ impl MetaOccasion {
   }
 
   #[tracing::instrument("Cache::Occasion::Meta::save", skip_all)]
-  pub fn save(&self) {
+  pub fn save(&self, watcher_start_time: Option<u64>) {
     let meta = Meta {
       max_dependencies_id: get_current_dependency_id(),
+      watcher_start_time,
     };
     self.storage.set(
       SCOPE,
@@ -38,10 +43,11 @@ impl MetaOccasion {
     );
   }
 
+  /// Recovers the compiler state and returns the watcher start time of the compilation which saves the meta.
   #[tracing::instrument("Cache::Occasion::Meta::recovery", skip_all)]
-  pub async fn recovery(&self) -> Result<()> {
+  pub async fn recovery(&self) -> Result<Option<u64>> {
     let Some((_, value)) = self.storage.load(SCOPE).await?.pop() else {
-      return Ok(());
+      return Ok(None);
     };
 
     let meta: Meta = self.codec.decode(&value).expect("should decode success");
@@ -49,6 +55,6 @@ impl MetaOccasion {
       panic!("The global dependency id generator is not 0 when the persistent cache is restored.");
     }
     set_current_dependency_id(meta.max_dependencies_id);
-    Ok(())
+    Ok(meta.watcher_start_time)
   }
 }
@@ -1,5 +1,9 @@
+pub mod chunk_render;
+pub mod code_generate;
 pub mod make;
 pub mod meta;
 
+pub use chunk_render::ChunkRenderOccasion;
+pub use code_generate::CodeGenerateOccasion;
 pub use make::MakeOccasion;
 pub use meta::MetaOccasion;
@@ -7,16 +7,19 @@ use std::sync::Arc;
 use rspack_error::Result;
 use rspack_fs::ReadableFileSystem;
 use rspack_paths::{ArcPath, ArcPathSet};
+use tokio::sync::OnceCell;
 
-use self::strategy::{StrategyHelper, ValidateResult};
 pub use self::{
   option::{PathMatcher, SnapshotOptions},
   scope::SnapshotScope,
-  strategy::Strategy,
+  strategy::{HashMemo, HashMemoItem, Strategy, StrategyHelper, ValidateResult},
 };
 use super::{codec::CacheCodec, storage::Storage};
 use crate::FutureConsumer;
 
+/// The storage scope of hash memo
+pub const HASH_MEMO_SCOPE: &str = "snapshot_hash_memo";
+
 /// Snapshot is used to check if files have been modified or deleted.
 ///
 /// Snapshot will generate `Strategy` for target file, and check the modification
@@ -27,6 +30,8 @@ pub struct Snapshot {
   fs: Arc<dyn ReadableFileSystem>,
   storage: Arc<dyn Storage>,
   codec: Arc<CacheCodec>,
+  /// the hash memo used by content hash strategy, lazily loaded from storage
+  hash_memo: OnceCell<Arc<HashMemo>>,
 }
 
 impl Snapshot {
@@ -41,6 +46,43 @@ impl Snapshot {
       fs,
       storage,
       codec,
+      hash_memo: OnceCell::new(),
+    }
+  }
+
+  async fn create_helper(&self) -> Result<Arc<StrategyHelper>> {
+    if !self.options.content_hash() {
+      return Ok(Arc::new(StrategyHelper::new(self.fs.clone())));
+    }
+    let memo = self
+      .hash_memo
+      .get_or_try_init(|| async {
+        let memo = HashMemo::default();
+        for (key, value) in self.storage.load(HASH_MEMO_SCOPE).await? {
+          let path: ArcPath = self.codec.decode(&key)?;
+          let item: HashMemoItem = self.codec.decode(&value)?;
+          memo.insert(path, item);
+        }
+        Ok::<_, rspack_error::Error>(Arc::new(memo))
+      })
+      .await?;
+    Ok(Arc::new(StrategyHelper::with_hash_memo(
+      self.fs.clone(),
+      memo.clone(),
+    )))
+  }
+
+  /// Save the updated hash memo items to storage.
+  fn save_hash_memo(&self) {
+    let Some(memo) = self.hash_memo.get() else {
+      return;
+    };
+    for (path, item) in memo.take_changed() {
+      self.storage.set(
+        HASH_MEMO_SCOPE,
+        self.codec.encode(&path).expect("should encode success"),
+        self.codec.encode(&item).expect("should encode success"),
+      );
     }
   }
 
@@ -60,6 +102,7 @@ impl Snapshot {
       return Some(v);
     }
     Some(match scope {
+      SnapshotScope::FILE if options.content_hash() => helper.content_hash(path).await,
       SnapshotScope::FILE => helper.file_hash(path).await,
       SnapshotScope::MISSING => Strategy::Missing,
       SnapshotScope::CONTEXT | SnapshotScope::BUILD => helper.dir_hash(path).await,
@@ -68,7 +111,13 @@ impl Snapshot {
 
   #[tracing::instrument("Cache::Snapshot::add", skip_all)]
   pub async fn add(&self, scope: SnapshotScope, paths: impl Iterator<Item = ArcPath>) {
-    let helper = Arc::new(StrategyHelper::new(self.fs.clone()));
+    let helper = match self.create_helper().await {
+      Ok(helper) => helper,
+      Err(err) => {
+        tracing::warn!("load hash memo failed {:?}", err);
+        Arc::new(StrategyHelper::new(self.fs.clone()))
+      }
+    };
     let codec = self.codec.clone();
     // TODO merge package version file
     paths
@@ -90,13 +139,20 @@ impl Snapshot {
         }
       })
       .await;
+    self.save_hash_memo();
   }
 
   pub fn remove(&self, scope: SnapshotScope, paths: impl Iterator<Item = ArcPath>) {
     for item in paths {
-      self
-        .storage
-        .remove(scope.name(), item.as_os_str().as_encoded_bytes())
+      // the key must be encoded in the same way as `add`
+      let key = self.codec.encode(&item).expect("should encode success");
+      if matches!(scope, SnapshotScope::FILE) && self.options.content_hash() {
+        if let Some(memo) = self.hash_memo.get() {
+          memo.remove(&item);
+        }
+        self.storage.remove(HASH_MEMO_SCOPE, &key);
+      }
+      self.storage.remove(scope.name(), &key)
     }
   }
 
@@ -109,7 +165,7 @@ impl Snapshot {
     let mut modified_path = ArcPathSet::default();
     let mut deleted_path = ArcPathSet::default();
     let mut no_change_path = ArcPathSet::default();
-    let helper = Arc::new(StrategyHelper::new(self.fs.clone()));
+    let helper = self.create_helper().await?;
     let codec = self.codec.clone();
 
     let data = self.storage.load(scope.name()).await?;
@@ -138,6 +194,7 @@ impl Snapshot {
         }
       })
       .await;
+    self.save_hash_memo();
 
     Ok((is_hot_start, modified_path, deleted_path, no_change_path))
   }
@@ -151,8 +208,11 @@ mod tests {
   use rspack_paths::ArcPath;
 
   use super::{
-    super::{codec::CacheCodec, storage::MemoryStorage},
-    PathMatcher, Snapshot, SnapshotOptions, SnapshotScope,
+    super::{
+      codec::CacheCodec,
+      storage::{MemoryStorage, Storage},
+    },
+    HASH_MEMO_SCOPE, PathMatcher, Snapshot, SnapshotOptions, SnapshotScope, Strategy,
   };
 
   macro_rules! p {
@@ -170,6 +230,7 @@ mod tests {
       vec![PathMatcher::String("constant".into())],
       vec![PathMatcher::String("node_modules/project".into())],
       vec![PathMatcher::String("node_modules".into())],
+      false,
     );
 
     fs.create_dir_all("/node_modules/project".into())
@@ -258,4 +319,117 @@ mod tests {
     assert!(modified_paths.contains(&p!("/node_modules/lib/file1")));
     assert_eq!(no_change_paths.len(), 1);
   }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn should_snapshot_restore_in_another_directory() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+    for dir in ["/job-1", "/job-2"] {
+      fs.create_dir_all(dir.into()).await.unwrap();
+      fs.write(format!("{dir}/file1").as_str().into(), "abc".as_bytes())
+        .await
+        .unwrap();
+      fs.write(format!("{dir}/file2").as_str().into(), "abc".as_bytes())
+        .await
+        .unwrap();
+    }
+
+    let snapshot = Snapshot::new(
+      SnapshotOptions::new(vec![], vec![], vec![], false),
+      fs.clone(),
+      storage.clone(),
+      Arc::new(CacheCodec::new(Some("/job-1".into()))),
+    );
+    snapshot
+      .add(
+        SnapshotScope::FILE,
+        [p!("/job-1/file1"), p!("/job-1/file2")].into_iter(),
+      )
+      .await;
+    snapshot.remove(SnapshotScope::FILE, [p!("/job-1/file2")].into_iter());
+
+    let snapshot = Snapshot::new(
+      SnapshotOptions::new(vec![], vec![], vec![], false),
+      fs.clone(),
+      storage,
+      Arc::new(CacheCodec::new(Some("/job-2".into()))),
+    );
+    let (is_hot_start, modified_paths, deleted_paths, no_change_paths) = snapshot
+      .calc_modified_paths(SnapshotScope::FILE)
+      .await
+      .unwrap();
+    assert!(is_hot_start);
+    assert!(modified_paths.is_empty());
+    assert!(deleted_paths.is_empty());
+    assert_eq!(no_change_paths.len(), 1);
+    assert!(no_change_paths.contains(&p!("/job-2/file1")));
+  }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn should_snapshot_content_hash_work() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+    let codec = Arc::new(CacheCodec::new(None));
+    let options = SnapshotOptions::new(
+      vec![],
+      vec![],
+      vec![PathMatcher::String("node_modules".into())],
+      true,
+    );
+    fs.create_dir_all("/node_modules/lib".into()).await.unwrap();
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/file2".into(), "abc".as_bytes()).await.unwrap();
+    fs.write(
+      "/node_modules/lib/package.json".into(),
+      r#"{"version":"1.0.0"}"#.as_bytes(),
+    )
+    .await
+    .unwrap();
+    fs.write("/node_modules/lib/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+
+    let snapshot = Snapshot::new(options.clone(), fs.clone(), storage.clone(), codec.clone());
+    snapshot
+      .add(
+        SnapshotScope::FILE,
+        [p!("/file1"), p!("/file2"), p!("/node_modules/lib/file1")].into_iter(),
+      )
+      .await;
+
+    // the strategy of each file is recorded in the snapshot
+    let strategies = storage.load(SnapshotScope::FILE.name()).await.unwrap();
+    for (key, value) in strategies.iter() {
+      let path: ArcPath = codec.decode(key).unwrap();
+      let strategy: Strategy = codec.decode(value).unwrap();
+      if path == p!("/node_modules/lib/file1") {
+        assert!(matches!(strategy, Strategy::PackageVersion(_)));
+      } else {
+        assert!(matches!(strategy, Strategy::ContentHash { .. }));
+      }
+    }
+    // only the unmanaged files are memoized
+    assert_eq!(storage.load(HASH_MEMO_SCOPE).await.unwrap().len(), 2);
+
+    // touch file1 and change file2
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    fs.remove_file("/file2".into()).await.unwrap();
+    fs.write("/file2".into(), "abcd".as_bytes()).await.unwrap();
+
+    let snapshot = Snapshot::new(options, fs.clone(), storage.clone(), codec);
+    let (is_hot_start, modified_paths, deleted_paths, no_change_paths) = snapshot
+      .calc_modified_paths(SnapshotScope::FILE)
+      .await
+      .unwrap();
+    assert!(is_hot_start);
+    assert!(deleted_paths.is_empty());
+    assert_eq!(modified_paths.len(), 1);
+    assert!(modified_paths.contains(&p!("/file2")));
+    assert!(no_change_paths.contains(&p!("/file1")));
+    assert!(no_change_paths.contains(&p!("/node_modules/lib/file1")));
+
+    snapshot.remove(SnapshotScope::FILE, [p!("/file2")].into_iter());
+    assert_eq!(storage.load(HASH_MEMO_SCOPE).await.unwrap().len(), 1);
+  }
 }
@@ -29,6 +29,8 @@ pub struct SnapshotOptions {
   unmanaged_paths: Vec<PathMatcher>,
   /// managed_paths, snapshot will use lib version strategy
   managed_paths: Vec<PathMatcher>,
+  /// validate unmanaged files by content hash only, the modified time is ignored
+  content_hash: bool,
 }
 
 impl SnapshotOptions {
@@ -36,14 +38,20 @@ impl SnapshotOptions {
     immutable_paths: Vec<PathMatcher>,
     unmanaged_paths: Vec<PathMatcher>,
     managed_paths: Vec<PathMatcher>,
+    content_hash: bool,
   ) -> Self {
     Self {
       immutable_paths,
       unmanaged_paths,
       managed_paths,
+      content_hash,
     }
   }
 
+  pub fn content_hash(&self) -> bool {
+    self.content_hash
+  }
+
   pub fn is_immutable_path(&self, path_str: &str) -> bool {
     for item in &self.immutable_paths {
       if item.try_match(path_str) {
@@ -104,6 +112,7 @@ mod tests {
         PathMatcher::String("node_modules".into()),
         PathMatcher::Regexp(RspackRegex::new("test_modules/.+").unwrap()),
       ],
+      false,
     );
 
     assert!(options.is_immutable_path("/root/project/constant/var.js"));
@@ -3,8 +3,9 @@ use std::{
   sync::Arc,
 };
 
+use rspack_cacheable::cacheable;
 use rspack_fs::{FileMetadata, ReadableFileSystem};
-use rspack_paths::{ArcPath, ArcPathDashMap, AssertUtf8};
+use rspack_paths::{ArcPath, ArcPathDashMap, ArcPathDashSet, AssertUtf8};
 use rustc_hash::FxHasher;
 
 /// Content hash with modification time.
@@ -14,6 +15,89 @@ pub struct ContentHash {
   pub mtime: u64,
 }
 
+/// The memoized content hash of a file.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct HashMemoItem {
+  pub size: u64,
+  pub ino: u64,
+  pub mtime: u64,
+  pub ctime: u64,
+  pub hash: u64,
+}
+
+/// Memo of file content hashes which can be persisted.
+///
+/// The memoized hash is reused when the size, inode, modification time and change time
+/// of the file are unchanged, so that unchanged large files are not re-hashed. A file
+/// rewritten in place keeps its inode and may keep its size, so the times are always compared.
+#[derive(Debug, Default)]
+pub struct HashMemo {
+  items: ArcPathDashMap<HashMemoItem>,
+  /// the paths which are updated since the last `take_changed`
+  changed: ArcPathDashSet,
+}
+
+impl HashMemo {
+  pub fn insert(&self, path: ArcPath, item: HashMemoItem) {
+    self.items.insert(path, item);
+  }
+
+  pub fn remove(&self, path: &ArcPath) {
+    self.items.remove(path);
+    self.changed.remove(path);
+  }
+
+  /// Get the memoized hash if the file is unchanged.
+  fn get(&self, path: &ArcPath, metadata: &FileMetadata) -> Option<u64> {
+    if !Self::is_trackable(metadata) {
+      return None;
+    }
+    let item = self.items.get(path)?;
+    // the change time is 0 if the file system does not provide one
+    let unchanged = item.ino == metadata.ino
+      && item.size == metadata.size
+      && item.mtime == metadata.mtime_ms
+      && item.ctime == metadata.ctime_ms;
+    unchanged.then_some(item.hash)
+  }
+
+  fn set(&self, path: &ArcPath, metadata: &FileMetadata, hash: u64) {
+    if !Self::is_trackable(metadata) {
+      return;
+    }
+    self.items.insert(
+      path.clone(),
+      HashMemoItem {
+        size: metadata.size,
+        ino: metadata.ino,
+        mtime: metadata.mtime_ms,
+        ctime: metadata.ctime_ms,
+        hash,
+      },
+    );
+    self.changed.insert(path.clone());
+  }
+
+  /// Whether the file can be identified by its inode or modification time.
+  fn is_trackable(metadata: &FileMetadata) -> bool {
+    metadata.ino != 0 || metadata.mtime_ms != 0
+  }
+
+  /// Take the items which are updated since the last call.
+  pub fn take_changed(&self) -> Vec<(ArcPath, HashMemoItem)> {
+    let paths: Vec<ArcPath> = self.changed.iter().map(|item| item.clone()).collect();
+    paths
+      .into_iter()
+      .filter_map(|path| {
+        self.changed.remove(&path);
+        let item = self.items.get(&path)?.clone();
+        Some((path, item))
+      })
+      .collect()
+  }
+}
+
 /// A helper for computing content hashes of files and directories.
 #[derive(Debug)]
 pub struct HashHelper {
@@ -24,15 +108,18 @@ pub struct HashHelper {
   file_cache: ArcPathDashMap<Option<ContentHash>>,
   /// Cache for directory content hashes.
   dir_cache: ArcPathDashMap<Option<ContentHash>>,
+  /// Persistent memo for `content_hash`.
+  memo: Option<Arc<HashMemo>>,
 }
 
 impl HashHelper {
   /// Creates a new HashHelper instance with the given file system.
-  pub fn new(fs: Arc<dyn ReadableFileSystem>) -> Self {
+  pub fn new(fs: Arc<dyn ReadableFileSystem>, memo: Option<Arc<HashMemo>>) -> Self {
     Self {
       fs,
       file_cache: Default::default(),
       dir_cache: Default::default(),
+      memo,
     }
   }
 
@@ -88,6 +175,24 @@ impl HashHelper {
     self.inner_file_hash(path, None).await
   }
 
+  /// Get file content hash without modification time.
+  ///
+  /// The hash memo is used to skip reading the files whose size, inode and times are unchanged.
+  pub async fn content_hash(&self, path: &ArcPath) -> Option<u64> {
+    let metadata = self.fs.metadata(path.assert_utf8()).await.ok()?;
+    if let Some(memo) = &self.memo
+      && let Some(hash) = memo.get(path, &metadata)
+    {
+      return Some(hash);
+    }
+    let is_file = metadata.is_file && !metadata.is_symlink;
+    let ContentHash { hash, .. } = self.inner_file_hash(path, Some(metadata.clone())).await?;
+    if is_file && let Some(memo) = &self.memo {
+      memo.set(path, &metadata, hash);
+    }
+    Some(hash)
+  }
+
   /// Get directory content hash recursively.
   #[async_recursion::async_recursion]
   pub async fn dir_hash(&self, path: &ArcPath) -> Option<ContentHash> {
@@ -131,10 +236,10 @@ impl HashHelper {
 mod tests {
   use std::sync::Arc;
 
-  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+  use rspack_fs::{FileMetadata, MemoryFileSystem, WritableFileSystem};
   use rspack_paths::ArcPath;
 
-  use super::HashHelper;
+  use super::{HashHelper, HashMemo};
 
   #[tokio::test]
   async fn file_hash() {
@@ -142,7 +247,7 @@ mod tests {
     fs.create_dir_all("/".into()).await.unwrap();
     fs.write("/hash.js".into(), "abc".as_bytes()).await.unwrap();
 
-    let helper = HashHelper::new(fs.clone());
+    let helper = HashHelper::new(fs.clone(), None);
     assert!(
       helper
         .file_hash(&ArcPath::from("/not_exist.js"))
@@ -184,7 +289,7 @@ mod tests {
     fs.write("/a/a2.js".into(), "a2".as_bytes()).await.unwrap();
     fs.write("/b.js".into(), "b".as_bytes()).await.unwrap();
 
-    let helper = HashHelper::new(fs.clone());
+    let helper = HashHelper::new(fs.clone(), None);
 
     let hash1 = helper.dir_hash(&ArcPath::from("/")).await.unwrap();
 
@@ -211,4 +316,115 @@ mod tests {
     assert_ne!(hash1.hash, hash4.hash);
     assert_eq!(hash4.mtime, 0);
   }
+
+  #[tokio::test]
+  async fn content_hash() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all("/".into()).await.unwrap();
+    fs.write("/hash.js".into(), "abc".as_bytes()).await.unwrap();
+    let path = ArcPath::from("/hash.js");
+
+    let memo = Arc::new(HashMemo::default());
+    let helper = HashHelper::new(fs.clone(), Some(memo.clone()));
+    let hash1 = helper.content_hash(&path).await.unwrap();
+    assert_eq!(
+      hash1,
+      helper.file_hash(&path).await.unwrap().hash,
+      "content hash should be the same as file hash"
+    );
+    let changed = memo.take_changed();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].1.size, 3);
+    assert!(memo.take_changed().is_empty());
+
+    // the memoized hash is used when size, inode and times are unchanged
+    let mut item = changed[0].1.clone();
+    item.hash = 1;
+    memo.insert(path.clone(), item.clone());
+    let helper = HashHelper::new(fs.clone(), Some(memo.clone()));
+    assert_eq!(helper.content_hash(&path).await, Some(1));
+
+    // the memoized hash is ignored when size is changed
+    item.size = 10;
+    memo.insert(path.clone(), item);
+    let helper = HashHelper::new(fs.clone(), Some(memo.clone()));
+    assert_eq!(helper.content_hash(&path).await, Some(hash1));
+
+    // the memoized hash is ignored when the file is recreated
+    fs.remove_file("/hash.js".into()).await.unwrap();
+    fs.write("/hash.js".into(), "abd".as_bytes()).await.unwrap();
+    let helper = HashHelper::new(fs.clone(), Some(memo.clone()));
+    let hash2 = helper.content_hash(&path).await.unwrap();
+    assert_ne!(hash1, hash2);
+
+    fs.remove_file("/hash.js".into()).await.unwrap();
+    let helper = HashHelper::new(fs.clone(), Some(memo.clone()));
+    assert!(helper.content_hash(&path).await.is_none());
+  }
+
+  #[test]
+  fn memo_rewrite_in_place() {
+    let memo = HashMemo::default();
+    let path = ArcPath::from("/rewrite.js");
+    let metadata = FileMetadata {
+      is_file: true,
+      is_directory: false,
+      is_symlink: false,
+      atime_ms: 100,
+      mtime_ms: 100,
+      ctime_ms: 100,
+      size: 3,
+      ino: 1,
+    };
+    memo.set(&path, &metadata, 1);
+    assert_eq!(memo.get(&path, &metadata), Some(1));
+
+    // the file is rewritten in place with the same length, the inode is unchanged
+    let rewritten = FileMetadata {
+      mtime_ms: 200,
+      ctime_ms: 200,
+      ..metadata.clone()
+    };
+    assert_eq!(memo.get(&path, &rewritten), None);
+
+    // the modification time is restored after the rewrite, e.g. by `touch -d`
+    let restored = FileMetadata {
+      ctime_ms: 200,
+      ..metadata
+    };
+    assert_eq!(memo.get(&path, &restored), None);
+  }
+
+  #[test]
+  fn memo_without_ino() {
+    let memo = HashMemo::default();
+    let path = ArcPath::from("/virtual.js");
+    let metadata = FileMetadata {
+      is_file: true,
+      is_directory: false,
+      is_symlink: false,
+      atime_ms: 100,
+      mtime_ms: 100,
+      ctime_ms: 100,
+      size: 3,
+      ino: 0,
+    };
+    memo.set(&path, &metadata, 1);
+    assert_eq!(memo.get(&path, &metadata), Some(1));
+
+    // the memoized hash is ignored when the modification time is changed
+    let modified = FileMetadata {
+      mtime_ms: 200,
+      ..metadata.clone()
+    };
+    assert_eq!(memo.get(&path, &modified), None);
+
+    // the file can not be identified without inode and modification time
+    let unknown = FileMetadata {
+      mtime_ms: 0,
+      ..metadata
+    };
+    memo.set(&ArcPath::from("/unknown.js"), &unknown, 1);
+    assert_eq!(memo.get(&ArcPath::from("/unknown.js"), &unknown), None);
+  }
 }
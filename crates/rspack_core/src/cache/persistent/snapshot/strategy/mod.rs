@@ -7,6 +7,7 @@ use rspack_cacheable::cacheable;
 use rspack_fs::ReadableFileSystem;
 use rspack_paths::{ArcPath, AssertUtf8};
 
+pub use self::hash_helper::{HashMemo, HashMemoItem};
 use self::{
   hash_helper::{ContentHash, HashHelper},
   package_helper::PackageHelper,
@@ -28,6 +29,11 @@ pub enum Strategy {
   /// and then compare the file hash.
   FileHash { mtime: u64, hash: u64 },
 
+  /// Check by content hash
+  ///
+  /// This strategy only compares the file hash, the modified time is ignored.
+  ContentHash { hash: u64 },
+
   /// Check by dir hash
   ///
   /// This strategy will compare the content hash of all files within the directory.
@@ -51,6 +57,7 @@ impl PartialEq for Strategy {
     match (self, other) {
       (Self::PackageVersion(v1), Self::PackageVersion(v2)) => v1 == v2,
       (Self::FileHash { hash: h1, .. }, Self::FileHash { hash: h2, .. }) => h1 == h2,
+      (Self::ContentHash { hash: h1 }, Self::ContentHash { hash: h2 }) => h1 == h2,
       (Self::DirHash { hash: h1, .. }, Self::DirHash { hash: h2, .. }) => h1 == h2,
       (Self::Missing, Self::Missing) => true,
       (Self::Failed, Self::Failed) => true,
@@ -81,7 +88,16 @@ impl StrategyHelper {
     Self {
       fs: fs.clone(),
       package_helper: PackageHelper::new(fs.clone()),
-      hash_helper: HashHelper::new(fs),
+      hash_helper: HashHelper::new(fs, None),
+    }
+  }
+
+  /// Create a helper which uses the hash memo to compute content hash.
+  pub fn with_hash_memo(fs: Arc<dyn ReadableFileSystem>, memo: Arc<HashMemo>) -> Self {
+    Self {
+      fs: fs.clone(),
+      package_helper: PackageHelper::new(fs.clone()),
+      hash_helper: HashHelper::new(fs, Some(memo)),
     }
   }
 
@@ -117,6 +133,15 @@ impl StrategyHelper {
     }
   }
 
+  /// get path content hash strategy
+  pub async fn content_hash(&self, path: &ArcPath) -> Strategy {
+    if let Some(hash) = self.hash_helper.content_hash(path).await {
+      Strategy::ContentHash { hash }
+    } else {
+      Strategy::Failed
+    }
+  }
+
   /// get path context hash strategy
   pub async fn dir_hash(&self, path: &ArcPath) -> Strategy {
     if let Some(ContentHash { hash, .. }) = self.hash_helper.dir_hash(path).await {
@@ -156,6 +181,16 @@ impl StrategyHelper {
           ValidateResult::Modified
         }
       }
+      Strategy::ContentHash { hash } => {
+        let Some(cur_hash) = self.hash_helper.content_hash(path).await else {
+          return ValidateResult::Deleted;
+        };
+        if &cur_hash == hash {
+          ValidateResult::NoChanged
+        } else {
+          ValidateResult::Modified
+        }
+      }
       Strategy::DirHash { hash } => {
         let Some(ContentHash { hash: cur_hash, .. }) = self.hash_helper.dir_hash(path).await else {
           return ValidateResult::Deleted;
@@ -185,7 +220,7 @@ mod tests {
   use rspack_fs::{MemoryFileSystem, WritableFileSystem};
   use rspack_paths::ArcPath;
 
-  use super::{Strategy, StrategyHelper, ValidateResult};
+  use super::{HashMemo, Strategy, StrategyHelper, ValidateResult};
 
   #[tokio::test]
   async fn validate_package_version() {
@@ -289,6 +324,60 @@ mod tests {
     ));
   }
 
+  #[tokio::test]
+  async fn validate_content_hash() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all("/".into()).await.unwrap();
+    fs.write("/file1.js".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+
+    let memo = Arc::new(HashMemo::default());
+    let helper = StrategyHelper::with_hash_memo(fs.clone(), memo.clone());
+    let strategy = helper.content_hash(&ArcPath::from("/file1.js")).await;
+    assert!(matches!(strategy, Strategy::ContentHash { .. }));
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::NoChanged
+    ));
+
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    fs.remove_file("/file1.js".into()).await.unwrap();
+    fs.write("/file1.js".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    let helper = StrategyHelper::with_hash_memo(fs.clone(), memo.clone());
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::NoChanged
+    ));
+
+    fs.remove_file("/file1.js".into()).await.unwrap();
+    fs.write("/file1.js".into(), "abd".as_bytes())
+      .await
+      .unwrap();
+    let helper = StrategyHelper::with_hash_memo(fs.clone(), memo.clone());
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::Modified
+    ));
+
+    fs.remove_file("/file1.js".into()).await.unwrap();
+    let helper = StrategyHelper::with_hash_memo(fs.clone(), memo);
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::Deleted
+    ));
+  }
+
   #[tokio::test]
   async fn validate_missing() {
     let fs = Arc::new(MemoryFileSystem::default());
@@ -1,13 +1,24 @@
 // TODO add #[cfg(test)]
 mod memory;
 
-use std::{path::PathBuf, sync::Arc};
+use std::{
+  hash::{Hash, Hasher},
+  path::PathBuf,
+  sync::Arc,
+};
 
 pub use memory::MemoryStorage;
-use rspack_cacheable::{cacheable, utils::PortablePath, with::As};
+use rspack_cacheable::{
+  cacheable,
+  utils::PortablePath,
+  with::{As, Skip},
+};
 use rspack_fs::IntermediateFileSystem;
-pub use rspack_storage::Storage;
-use rspack_storage::{BridgeFileSystem, PackStorage, PackStorageOptions};
+use rspack_storage::{
+  BridgeFileSystem, DirectoryTransport, HttpTransport, PackStorage, PackStorageOptions,
+  RemoteStorage, RemoteStorageOptions, RemoteTransport,
+};
+pub use rspack_storage::{RemoteHttpClient, RemoteHttpResponse, Storage};
 
 /// Storage Options
 ///
@@ -19,29 +30,140 @@ pub enum StorageOptions {
   FileSystem {
     #[cacheable(with=As<PortablePath>)]
     directory: PathBuf,
+    #[cacheable(with=Skip)]
+    budget: StorageBudget,
   },
+  /// Same as `FileSystem`, but the packs are also shared through a remote cache,
+  /// the local directory is used as a read-through cache.
+  Remote {
+    #[cacheable(with=As<PortablePath>)]
+    directory: PathBuf,
+    remote: RemoteOptions,
+    #[cacheable(with=Skip)]
+    budget: StorageBudget,
+  },
+}
+
+/// The size budget of the cache directory.
+///
+/// When the budget is exceeded, the least recently accessed cache versions and packs will be
+/// evicted after saving. The budget does not affect the cache content, so it is skipped in the cache version.
+#[derive(Debug, Default, Clone, Hash)]
+pub struct StorageBudget {
+  /// the max bytes of all cache versions in the directory
+  pub max_size: Option<u64>,
+  /// the max count of cache versions in the directory
+  pub max_generations: Option<usize>,
+}
+
+/// The location of a remote cache.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub enum RemoteOptions {
+  /// A key/value http server, see `rspack_storage::HttpTransport`.
+  Http {
+    url: String,
+    // headers usually contain credentials, skip them to keep the cache version stable
+    #[cacheable(with=Skip)]
+    headers: Vec<(String, String)>,
+    /// The client which sends the http requests, provided by the js side.
+    #[cacheable(with=Skip)]
+    client: Option<Arc<dyn RemoteHttpClient>>,
+  },
+  /// A directory shared between machines.
+  Directory { directory: String },
 }
 
+// the http client is a runtime handle, so it is not a part of the hash
+impl Hash for RemoteOptions {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    std::mem::discriminant(self).hash(state);
+    match self {
+      Self::Http { url, headers, .. } => {
+        url.hash(state);
+        headers.hash(state);
+      }
+      Self::Directory { directory } => directory.hash(state),
+    }
+  }
+}
+
+fn create_pack_storage_options(
+  directory: PathBuf,
+  version: String,
+  budget: StorageBudget,
+  fs: Arc<dyn IntermediateFileSystem>,
+) -> PackStorageOptions {
+  PackStorageOptions {
+    temp_root: directory.join(".temp"),
+    root: directory,
+    clean: true,
+    bucket_size: 20,
+    pack_size: 500 * 1024,
+    expire: 7 * 24 * 60 * 60 * 1000,
+    fs: Arc::new(BridgeFileSystem(fs)),
+    fresh_generation: Some(1),
+    release_generation: Some(2),
+    max_size: budget.max_size,
+    max_generations: budget.max_generations,
+    compact_ratio: Some(0.5),
+    version,
+  }
+}
+
+/// Create the storage of the options.
+///
+/// A remote storage with an invalid remote falls back to the local pack storage, and the
+/// error is returned to be reported as a warning.
 pub fn create_storage(
   options: StorageOptions,
   version: String,
   fs: Arc<dyn IntermediateFileSystem>,
-) -> Arc<dyn Storage> {
+) -> (Arc<dyn Storage>, Option<String>) {
   match options {
-    StorageOptions::FileSystem { directory } => {
-      let option = PackStorageOptions {
-        temp_root: directory.join(".temp"),
-        root: directory,
-        clean: true,
-        bucket_size: 20,
-        pack_size: 500 * 1024,
-        expire: 7 * 24 * 60 * 60 * 1000,
-        fs: Arc::new(BridgeFileSystem(fs)),
-        fresh_generation: Some(1),
-        release_generation: Some(2),
-        version,
+    StorageOptions::FileSystem { directory, budget } => (
+      Arc::new(PackStorage::new(create_pack_storage_options(
+        directory, version, budget, fs,
+      ))),
+      None,
+    ),
+    StorageOptions::Remote {
+      directory,
+      remote,
+      budget,
+    } => {
+      let transport: Arc<dyn RemoteTransport> = match remote {
+        RemoteOptions::Http {
+          url,
+          headers,
+          client,
+        } => match client
+          .ok_or_else(|| format!("no http client is provided for `{url}`"))
+          .and_then(|client| {
+            HttpTransport::new(&url, headers, client).map_err(|err| err.to_string())
+          }) {
+          Ok(transport) => Arc::new(transport),
+          Err(err) => {
+            return (
+              Arc::new(PackStorage::new(create_pack_storage_options(
+                directory, version, budget, fs,
+              ))),
+              Some(format!("remote cache is disabled: {err}")),
+            );
+          }
+        },
+        RemoteOptions::Directory { directory } => Arc::new(DirectoryTransport::new(
+          directory.into(),
+          Arc::new(BridgeFileSystem(fs.clone())),
+        )),
       };
-      Arc::new(PackStorage::new(option))
+      (
+        Arc::new(RemoteStorage::new(RemoteStorageOptions {
+          local: create_pack_storage_options(directory, version, budget, fs),
+          transport,
+        })),
+        None,
+      )
     }
   }
 }
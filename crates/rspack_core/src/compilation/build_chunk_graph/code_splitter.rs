@@ -990,6 +990,11 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
         "{} cache missed by incorrect available modules",
         self.stat_cache_miss_by_available_modules,
       ));
+      compilation.incremental.explain_pass(
+        IncrementalPasses::BUILD_CHUNK_GRAPH,
+        self.stat_invalidated_chunk_group as usize,
+        compilation.chunk_group_by_ukey.keys().count(),
+      );
       self.update_cache(compilation);
     }
 
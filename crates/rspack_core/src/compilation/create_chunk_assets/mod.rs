@@ -1,13 +1,20 @@
 use super::*;
-use crate::logger::Logger;
+use crate::{cache::Cache, logger::Logger};
 
 pub async fn create_chunk_assets_pass(
   compilation: &mut Compilation,
   plugin_driver: SharedPluginDriver,
+  cache: &mut dyn Cache,
 ) -> Result<()> {
   let logger = compilation.get_logger("rspack.Compilation");
   let start = logger.time("create chunk assets");
+  cache
+    .before_create_chunk_assets(&mut compilation.chunk_render_cache_artifact)
+    .await;
   compilation.create_chunk_assets(plugin_driver).await?;
+  cache
+    .after_create_chunk_assets(&compilation.chunk_render_cache_artifact)
+    .await;
   logger.time_end(start);
   Ok(())
 }
@@ -70,6 +77,11 @@ impl Compilation {
         chunks.len(),
         self.chunk_by_ukey.len()
       ));
+      self.incremental.explain_pass(
+        IncrementalPasses::CHUNKS_RENDER,
+        chunks.len(),
+        self.chunk_by_ukey.len(),
+      );
       chunks
     } else {
       self.chunk_by_ukey.keys().copied().collect()
@@ -79,6 +79,11 @@ impl Compilation {
         chunks.len(),
         self.chunk_by_ukey.len(),
       ));
+      self.incremental.explain_pass(
+        IncrementalPasses::CHUNKS_HASHES,
+        chunks.len(),
+        self.chunk_by_ukey.len(),
+      );
       chunks
     } else {
       self.chunk_by_ukey.keys().copied().collect()
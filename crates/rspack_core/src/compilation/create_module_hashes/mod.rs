@@ -83,6 +83,11 @@ pub async fn create_module_hashes_pass(compilation: &mut Compilation) -> Result<
       modules.len(),
       mg.modules().len()
     ));
+    compilation.incremental.explain_pass(
+      IncrementalPasses::MODULES_HASHES,
+      modules.len(),
+      mg.modules().len(),
+    );
 
     modules
   } else {
@@ -119,6 +119,11 @@ impl Compilation {
             modules.len(),
             self.get_module_graph().modules().len()
           ));
+          self.incremental.explain_pass(
+            IncrementalPasses::DEPENDENCIES_DIAGNOSTICS,
+            modules.len(),
+            self.get_module_graph().modules().len(),
+          );
           (modules, true)
         } else {
           (
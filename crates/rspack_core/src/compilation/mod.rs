@@ -24,7 +24,7 @@ mod run_passes;
 mod runtime_requirements;
 mod seal;
 use std::{
-  collections::{VecDeque, hash_map},
+  collections::{BTreeMap, VecDeque, hash_map},
   fmt::{self, Debug},
   hash::{BuildHasherDefault, Hash},
   mem,
@@ -287,6 +287,14 @@ pub struct Compilation {
   ///
   /// Rebuild will include previous compilation data, so persistent cache will not recovery anything
   pub is_rebuild: bool,
+  /// The unix timestamp in milliseconds since which the modified and removed files are resumed from the watcher of previous process.
+  ///
+  /// Persistent cache will not check the snapshot unless it is saved by the compilation which the watcher state belongs to
+  pub resumed_since: Option<u64>,
+  /// The unix timestamp in milliseconds of the start of the watcher which is watching this compilation.
+  ///
+  /// It is saved with persistent cache to match the resumed watcher state
+  pub watcher_start_time: Option<u64>,
   pub compiler_context: Arc<CompilerContext>,
 }
 
@@ -417,6 +425,8 @@ impl Compilation {
       intermediate_filesystem,
       output_filesystem,
       is_rebuild,
+      resumed_since: None,
+      watcher_start_time: None,
       compiler_context,
     }
   }
@@ -1283,6 +1293,10 @@ pub struct AssetInfo {
   pub css_unused_idents: Option<HashSet<String>>,
   /// whether this asset is over the size limit
   pub is_over_size_limit: Option<bool>,
+  /// whether this asset is a compressed copy of another asset
+  pub compressed: Option<bool>,
+  /// the compressed copies of the asset, keyed by the compression algorithm
+  pub compressed_assets: BTreeMap<String, String>,
   /// the plugin that created the asset
   pub asset_type: ManifestAssetType,
 
@@ -1350,6 +1364,10 @@ impl AssetInfo {
   pub fn set_is_over_size_limit(&mut self, v: bool) {
     self.is_over_size_limit = Some(v);
   }
+
+  pub fn set_compressed(&mut self, v: bool) {
+    self.compressed = Some(v);
+  }
   // another should have high priority than self
   // self = { immutable:true}
   // merge_another_asset({immutable: false})
@@ -1367,6 +1385,7 @@ impl AssetInfo {
     self.chunk_hash.extend(another.chunk_hash);
     self.content_hash.extend(another.content_hash);
     self.extras.extend(another.extras);
+    self.compressed_assets.extend(another.compressed_assets);
     // self.full_hash.extend(another.full_hash.iter().cloned());
     // self.module_hash.extend(another.module_hash.iter().cloned());
 
@@ -1378,6 +1397,7 @@ impl AssetInfo {
       .hot_module_replacement
       .or(self.hot_module_replacement);
     self.is_over_size_limit = another.is_over_size_limit.or(self.is_over_size_limit);
+    self.compressed = another.compressed.or(self.compressed);
   }
 }
 
@@ -52,11 +52,11 @@ impl Compilation {
 
     optimize_code_generation_pass(self, plugin_driver.clone()).await?;
     create_module_hashes_pass(self).await?;
-    code_generation_pass(self, plugin_driver.clone()).await?;
+    code_generation_pass(self, plugin_driver.clone(), cache).await?;
     runtime_requirements_pass(self, plugin_driver.clone()).await?;
     create_hash_pass(self, plugin_driver.clone()).await?;
     create_module_assets_pass(self, plugin_driver.clone()).await?;
-    create_chunk_assets_pass(self, plugin_driver.clone()).await?;
+    create_chunk_assets_pass(self, plugin_driver.clone(), cache).await?;
     process_assets_pass(self, plugin_driver.clone()).await?;
     after_seal_pass(self, plugin_driver).await?;
 
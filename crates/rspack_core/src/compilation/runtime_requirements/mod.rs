@@ -34,6 +34,11 @@ pub async fn runtime_requirements_pass(
       modules.len(),
       compilation.get_module_graph().modules().len()
     ));
+    compilation.incremental.explain_pass(
+      IncrementalPasses::MODULES_RUNTIME_REQUIREMENTS,
+      modules.len(),
+      compilation.get_module_graph().modules().len(),
+    );
     modules
   } else {
     compilation.cgm_runtime_requirements_artifact = Default::default();
@@ -94,6 +99,11 @@ pub async fn runtime_requirements_pass(
       affected_chunks.len(),
       compilation.chunk_by_ukey.len()
     ));
+    compilation.incremental.explain_pass(
+      IncrementalPasses::CHUNKS_RUNTIME_REQUIREMENTS,
+      affected_chunks.len(),
+      compilation.chunk_by_ukey.len(),
+    );
     affected_chunks
   } else {
     compilation.chunk_by_ukey.keys().copied().collect()
@@ -1,11 +1,14 @@
 mod rebuild;
-use std::sync::{Arc, atomic::AtomicU32};
+use std::{
+  path::Path,
+  sync::{Arc, atomic::AtomicU32},
+};
 
 use futures::future::join_all;
 use rspack_error::Result;
 use rspack_fs::{IntermediateFileSystem, NativeFileSystem, ReadableFileSystem, WritableFileSystem};
 use rspack_hook::define_hook;
-use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_paths::{ArcPathSet, Utf8Path, Utf8PathBuf};
 use rspack_sources::BoxSource;
 use rspack_tasks::{CompilerContext, within_compiler_context};
 use rspack_util::{node_path::NodePath, tracing_preset::TRACING_BENCH_TARGET};
@@ -92,6 +95,8 @@ pub struct Compiler {
   /// the key of HashMap is filename, the value of HashMap is version
   pub emitted_asset_versions: HashMap<String, String>,
   pub platform: Arc<CompilerPlatform>,
+  /// The unix timestamp in milliseconds of the start of the watcher which is watching the next compilation
+  pub watcher_start_time: Option<u64>,
   compiler_context: Arc<CompilerContext>,
 }
 
@@ -188,6 +193,7 @@ impl Compiler {
       emitted_asset_versions: Default::default(),
       input_filesystem,
       platform,
+      watcher_start_time: None,
       compiler_context,
     }
   }
@@ -202,11 +208,37 @@ impl Compiler {
   }
   pub async fn build(&mut self) -> Result<()> {
     let compiler_context = self.compiler_context.clone();
-    within_compiler_context(compiler_context, self.build_inner()).await?;
+    within_compiler_context(compiler_context, self.build_inner(None)).await?;
+    Ok(())
+  }
+  /// Build with the changed and deleted files since the last process,
+  /// which are resumed from the saved state of watcher.
+  ///
+  /// `since` is the unix timestamp in milliseconds of the saved state. If persistent cache is
+  /// saved by the compilation watched since then, the snapshot of untouched paths is not checked.
+  pub async fn resume(
+    &mut self,
+    changed_files: std::collections::HashSet<String>,
+    deleted_files: std::collections::HashSet<String>,
+    since: u64,
+  ) -> Result<()> {
+    let resumed = (
+      since,
+      changed_files
+        .iter()
+        .map(|file| Path::new(file).into())
+        .collect::<ArcPathSet>(),
+      deleted_files
+        .iter()
+        .map(|file| Path::new(file).into())
+        .collect::<ArcPathSet>(),
+    );
+    let compiler_context = self.compiler_context.clone();
+    within_compiler_context(compiler_context, self.build_inner(Some(resumed))).await?;
     Ok(())
   }
   #[instrument("Compiler:build",target=TRACING_BENCH_TARGET, skip_all)]
-  async fn build_inner(&mut self) -> Result<()> {
+  async fn build_inner(&mut self, resumed: Option<(u64, ArcPathSet, ArcPathSet)>) -> Result<()> {
     // TODO: clear the outdated cache entries in resolver,
     // TODO: maybe it's better to use external entries.
     let plugin_driver_clone = self.plugin_driver.clone();
@@ -235,6 +267,12 @@ impl Compiler {
         self.compiler_context.clone(),
       ),
     );
+    self.compilation.watcher_start_time = self.watcher_start_time;
+    if let Some((since, modified_files, removed_files)) = resumed {
+      self.compilation.modified_files = modified_files;
+      self.compilation.removed_files = removed_files;
+      self.compilation.resumed_since = Some(since);
+    }
     let _is_hot = self.cache.before_compile(&mut self.compilation).await;
     // TODO: disable it for now, enable it once persistent cache is added to all artifacts
     // if is_hot {
@@ -281,6 +319,10 @@ impl Compiler {
       .compilation
       .extend_diagnostics(plugin_driver_diagnostics);
 
+    // Consume cache diagnostic
+    let cache_diagnostics = self.cache.take_diagnostics();
+    self.compilation.extend_diagnostics(cache_diagnostics);
+
     Ok(())
   }
 
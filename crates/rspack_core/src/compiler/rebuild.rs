@@ -73,6 +73,7 @@ impl Compiler {
         self.compiler_context.clone(),
       );
       new_compilation.hot_index = self.compilation.hot_index + 1;
+      new_compilation.watcher_start_time = self.watcher_start_time;
 
       if new_compilation
         .incremental
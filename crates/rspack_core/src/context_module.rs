@@ -7,7 +7,8 @@ use indoc::formatdoc;
 use itertools::Itertools;
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsOption, AsPreset, AsVec, Unsupported},
+  utils::{PortablePath, PortableString},
+  with::{As, AsOption, AsPreset, AsVec, Unsupported},
 };
 use rspack_collections::{Identifiable, Identifier};
 use rspack_error::{Result, impl_empty_diagnosable_trait};
@@ -129,7 +130,9 @@ pub struct ContextOptions {
   pub include: Option<RspackRegex>,
   pub exclude: Option<RspackRegex>,
   pub category: DependencyCategory,
+  #[cacheable(with=As<PortableString>)]
   pub request: String,
+  #[cacheable(with=As<PortablePath>)]
   pub context: String,
   pub namespace_object: ContextNameSpaceObject,
   pub group_options: Option<GroupOptions>,
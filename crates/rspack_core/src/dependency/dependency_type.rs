@@ -69,6 +69,8 @@ pub enum DependencyType {
   CssLocalIdent,
   // css modules self reference
   CssSelfReferenceLocalIdent,
+  // css modules @value import
+  CssValueImport,
   // context element
   ContextElement(ContextTypePrefix),
   // import context
@@ -162,6 +164,7 @@ impl DependencyType {
       DependencyType::CssExport => "css export",
       DependencyType::CssLocalIdent => "css local ident",
       DependencyType::CssSelfReferenceLocalIdent => "css self reference local ident",
+      DependencyType::CssValueImport => "css value import",
       DependencyType::ContextElement(type_prefix) => match type_prefix {
         ContextTypePrefix::Import => "import() context element",
         ContextTypePrefix::Normal => "context element",
@@ -0,0 +1,247 @@
+use rspack_collections::IdentifierSet;
+use rustc_hash::FxHashMap as HashMap;
+
+use super::{IncrementalPasses, Mutation};
+use crate::{Compilation, ModuleIdentifier};
+
+/// The passes recorded during the compilation for the explanation
+#[derive(Debug)]
+pub(crate) struct PassRecords {
+  /// The passes enabled by the options, before any of them is fallback
+  pub(crate) configured: IncrementalPasses,
+  pub(crate) fallbacks: Vec<(IncrementalPasses, &'static str, &'static str)>,
+  pub(crate) affected: Vec<(IncrementalPasses, usize, usize)>,
+}
+
+impl PassRecords {
+  pub(crate) fn new(configured: IncrementalPasses) -> Self {
+    Self {
+      configured,
+      fallbacks: Vec::new(),
+      affected: Vec::new(),
+    }
+  }
+}
+
+/// Why a rebuild recomputes what it recomputes, from the changed files to the passes
+#[derive(Debug, Default)]
+pub struct IncrementalExplanation {
+  pub changed_files: Vec<String>,
+  pub removed_files: Vec<String>,
+  /// The modules which are added, rebuilt or removed by make
+  pub invalidated_modules: Vec<InvalidatedModuleExplanation>,
+  /// The modules which are not rebuilt, but affected by the invalidated modules
+  pub affected_modules: Vec<AffectedModuleExplanation>,
+  pub affected_chunks: Vec<String>,
+  pub passes: Vec<PassExplanation>,
+}
+
+#[derive(Debug)]
+pub struct InvalidatedModuleExplanation {
+  pub identifier: String,
+  /// One of `add`, `update` and `remove`
+  pub kind: &'static str,
+  /// The changed or removed files that the module depends on
+  pub files: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct AffectedModuleExplanation {
+  pub identifier: String,
+  /// The module it depends on which is invalidated or affected, `None` if only the dependencies
+  /// of the module are updated
+  pub affected_by: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct PassExplanation {
+  pub name: &'static str,
+  pub incremental: bool,
+  /// The count of the recomputed modules or chunks for incremental passes
+  pub affected: Option<usize>,
+  pub total: Option<usize>,
+  /// Why the pass is fully recomputed
+  pub reason: Option<String>,
+}
+
+impl IncrementalExplanation {
+  /// Returns `None` for cold builds or if `incremental.explain` is not enabled
+  pub fn new(compilation: &Compilation) -> Option<Self> {
+    let incremental = &compilation.incremental;
+    let records = incremental.explain_records()?;
+    let mutations = incremental.explain_mutations()?;
+    let context = &compilation.options.context;
+    let module_graph = compilation.get_module_graph();
+    let readable_identifier = |identifier: &ModuleIdentifier| {
+      module_graph.module_by_identifier(identifier).map_or_else(
+        || identifier.to_string(),
+        |module| module.readable_identifier(context).to_string(),
+      )
+    };
+
+    let mut changed_files = compilation
+      .modified_files
+      .iter()
+      .map(|file| file.to_string_lossy().to_string())
+      .collect::<Vec<_>>();
+    changed_files.sort();
+    let mut removed_files = compilation
+      .removed_files
+      .iter()
+      .map(|file| file.to_string_lossy().to_string())
+      .collect::<Vec<_>>();
+    removed_files.sort();
+
+    let mut built_modules = IdentifierSet::default();
+    let mut invalidated_modules = HashMap::default();
+    for mutation in mutations.iter() {
+      let (module, kind) = match mutation {
+        Mutation::ModuleAdd { module } => (module, "add"),
+        Mutation::ModuleUpdate { module } => (module, "update"),
+        Mutation::ModuleRemove { module } => (module, "remove"),
+        _ => continue,
+      };
+      if kind != "remove" {
+        built_modules.insert(*module);
+      }
+      invalidated_modules.insert(*module, kind);
+    }
+    let mut invalidated_modules = invalidated_modules
+      .into_iter()
+      .map(|(identifier, kind)| {
+        let files = module_graph
+          .module_by_identifier(&identifier)
+          .map(|module| {
+            let build_info = module.build_info();
+            compilation
+              .modified_files
+              .iter()
+              .chain(compilation.removed_files.iter())
+              .filter(|file| {
+                build_info.file_dependencies.contains(*file)
+                  || build_info.missing_dependencies.contains(*file)
+                  || build_info
+                    .context_dependencies
+                    .iter()
+                    .any(|dir| file.starts_with(dir))
+              })
+              .map(|file| file.to_string_lossy().to_string())
+              .collect::<Vec<_>>()
+          })
+          .unwrap_or_default();
+        InvalidatedModuleExplanation {
+          identifier: readable_identifier(&identifier),
+          kind,
+          files,
+        }
+      })
+      .collect::<Vec<_>>();
+    invalidated_modules.sort_by(|a, b| a.identifier.cmp(&b.identifier));
+    for module in &mut invalidated_modules {
+      module.files.sort();
+    }
+
+    // the modules that referencing an invalidated module are affected by it, and the affection is
+    // propagated through the modules that referencing an affected module transitively
+    let all_affected_modules = mutations.get_affected_modules_with_module_graph(module_graph);
+    let mut affected_modules = all_affected_modules
+      .iter()
+      .filter(|module| !built_modules.contains(*module))
+      .map(|module| {
+        let affected_by = module_graph
+          .get_outgoing_connections(module)
+          .map(|connection| *connection.module_identifier())
+          .filter(|dependency| dependency != module && all_affected_modules.contains(dependency))
+          .min_by_key(|dependency| (!built_modules.contains(dependency), *dependency));
+        AffectedModuleExplanation {
+          identifier: readable_identifier(module),
+          affected_by: affected_by.map(|module| readable_identifier(&module)),
+        }
+      })
+      .collect::<Vec<_>>();
+    affected_modules.sort_by(|a, b| a.identifier.cmp(&b.identifier));
+
+    let mut affected_chunks = mutations
+      .get_affected_chunks_with_chunk_graph(compilation)
+      .into_iter()
+      .filter_map(|chunk| compilation.chunk_by_ukey.get(&chunk))
+      .map(|chunk| {
+        chunk
+          .name()
+          .map(ToString::to_string)
+          .or_else(|| chunk.id().map(ToString::to_string))
+          .unwrap_or_default()
+      })
+      .collect::<Vec<_>>();
+    affected_chunks.sort();
+
+    let passes = IncrementalPasses::all()
+      .iter()
+      .map(|pass| {
+        explain_pass(
+          pass,
+          &records,
+          built_modules.len(),
+          module_graph.modules().len(),
+        )
+      })
+      .collect();
+
+    Some(Self {
+      changed_files,
+      removed_files,
+      invalidated_modules,
+      affected_modules,
+      affected_chunks,
+      passes,
+    })
+  }
+}
+
+fn explain_pass(
+  pass: IncrementalPasses,
+  records: &PassRecords,
+  built_modules: usize,
+  total_modules: usize,
+) -> PassExplanation {
+  let mut explanation = PassExplanation {
+    name: pass.pass_name(),
+    incremental: false,
+    affected: None,
+    total: None,
+    reason: None,
+  };
+  if !records.configured.contains(pass) {
+    explanation.reason = Some(format!(
+      "incremental.{} is disabled by options",
+      pass.pass_name()
+    ));
+    return explanation;
+  }
+  if let Some((_, thing, reason)) = records
+    .fallbacks
+    .iter()
+    .find(|(passes, _, _)| passes.contains(pass))
+  {
+    explanation.reason = Some(format!("{thing} is not friendly for incremental, {reason}"));
+    return explanation;
+  }
+  explanation.incremental = true;
+  if let Some((_, affected, total)) = records
+    .affected
+    .iter()
+    .find(|(recorded, _, _)| *recorded == pass)
+  {
+    explanation.affected = Some(*affected);
+    explanation.total = Some(*total);
+  } else if pass == IncrementalPasses::MAKE {
+    explanation.affected = Some(built_modules);
+    explanation.total = Some(total_modules);
+  } else if pass != IncrementalPasses::EMIT_ASSETS {
+    // the pass is enabled but recomputed without the previous results, such as the results
+    // are cleared by the previous pass
+    explanation.incremental = false;
+    explanation.reason = Some("no previous results to reuse".to_string());
+  }
+  explanation
+}
@@ -1,3 +1,4 @@
+mod explanation;
 mod mutations;
 
 use std::{
@@ -9,6 +10,10 @@ use std::{
 };
 
 use bitflags::bitflags;
+use explanation::PassRecords;
+pub use explanation::{
+  AffectedModuleExplanation, IncrementalExplanation, InvalidatedModuleExplanation, PassExplanation,
+};
 pub use mutations::{Mutation, Mutations};
 use rspack_error::{Diagnostic, Error};
 
@@ -36,7 +41,7 @@ bitflags! {
 }
 
 impl IncrementalPasses {
-  pub fn pass_name(&self) -> &str {
+  pub fn pass_name(&self) -> &'static str {
     match *self {
       Self::MAKE => "make",
       Self::INFER_ASYNC_MODULES => "inferAsyncModules",
@@ -87,6 +92,8 @@ impl IncrementalPasses {
 #[derive(Debug, Clone, Copy)]
 pub struct IncrementalOptions {
   pub silent: bool,
+  /// Record why the modules, chunks and passes are recomputed in each rebuild
+  pub explain: bool,
   pub passes: IncrementalPasses,
 }
 
@@ -94,6 +101,7 @@ impl IncrementalOptions {
   pub fn empty_passes() -> Self {
     Self {
       silent: true,
+      explain: false,
       passes: IncrementalPasses::empty(),
     }
   }
@@ -126,6 +134,8 @@ pub struct Incremental {
   silent: bool,
   passes: AtomicU16,
   state: IncrementalState,
+  /// Only recorded for hot builds with `explain` enabled
+  records: Option<Mutex<PassRecords>>,
 }
 
 impl fmt::Debug for Incremental {
@@ -144,6 +154,7 @@ impl Incremental {
       silent: options.silent,
       passes: AtomicU16::new(options.passes.bits()),
       state: IncrementalState::Cold,
+      records: None,
     }
   }
 
@@ -154,6 +165,9 @@ impl Incremental {
       state: IncrementalState::Hot {
         mutations: Mutex::new(Mutations::default()),
       },
+      records: options
+        .explain
+        .then(|| Mutex::new(PassRecords::new(options.passes))),
     }
   }
 
@@ -172,6 +186,13 @@ impl Incremental {
       let current = IncrementalPasses::from_bits_retain(self.passes.load(Ordering::SeqCst));
       let passes_to_disable = current.intersection(passes);
       if !passes_to_disable.is_empty() {
+        if let Some(records) = &self.records {
+          records
+            .lock()
+            .expect("Mutex poisoned: failed to acquire lock on incremental records")
+            .fallbacks
+            .push((passes_to_disable, thing, reason));
+        }
         // Atomically remove the passes using fetch_and with the negated bits
         self
           .passes
@@ -241,6 +262,41 @@ impl Incremental {
   }
 }
 
+impl Incremental {
+  /// Record that `pass` is recomputed incrementally for `affected` of `total` modules or chunks
+  pub fn explain_pass(&self, pass: IncrementalPasses, affected: usize, total: usize) {
+    if let Some(records) = &self.records {
+      records
+        .lock()
+        .expect("Mutex poisoned: failed to acquire lock on incremental records")
+        .affected
+        .push((pass, affected, total));
+    }
+  }
+
+  pub(crate) fn explain_records(&self) -> Option<MutexGuard<'_, PassRecords>> {
+    self.records.as_ref().map(|records| {
+      records
+        .lock()
+        .expect("Mutex poisoned: failed to acquire lock on incremental records")
+    })
+  }
+
+  /// The mutations are readable for the explanation even if all the passes are fallback
+  pub(crate) fn explain_mutations(&self) -> Option<MutexGuard<'_, Mutations>> {
+    if let IncrementalState::Hot { mutations } = &self.state
+      && self.records.is_some()
+    {
+      return Some(
+        mutations
+          .lock()
+          .expect("Mutex poisoned: failed to acquire read lock on incremental mutations"),
+      );
+    }
+    None
+  }
+}
+
 #[derive(Debug)]
 pub struct NotFriendlyForIncremental {
   pub thing: &'static str,
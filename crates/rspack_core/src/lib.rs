@@ -184,6 +184,8 @@ impl From<&ModuleType> for SourceType {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModuleType {
   Json,
+  Yaml,
+  Toml,
   Css,
   CssModule,
   CssAuto,
@@ -253,6 +255,8 @@ impl ModuleType {
       ModuleType::CssAuto => "css/auto",
 
       ModuleType::Json => "json",
+      ModuleType::Yaml => "yaml",
+      ModuleType::Toml => "toml",
 
       ModuleType::WasmSync => "webassembly/sync",
       ModuleType::WasmAsync => "webassembly/async",
@@ -294,6 +298,8 @@ impl From<&str> for ModuleType {
       "css/auto" => Self::CssAuto,
 
       "json" => Self::Json,
+      "yaml" => Self::Yaml,
+      "toml" => Self::Toml,
 
       "webassembly/sync" => Self::WasmSync,
       "webassembly/async" => Self::WasmAsync,
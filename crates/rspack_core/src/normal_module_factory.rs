@@ -715,6 +715,17 @@ module.exports = "data:,";
             _ => unreachable!(),
           },
         ),
+        // YAML and TOML modules are converted to JSON data, so they share the options of JSON modules
+        ModuleType::Yaml | ModuleType::Toml => rspack_util::merge_from_optional_with(
+          p.get("json").cloned(),
+          options,
+          |json_options, options| match (json_options, options) {
+            (ParserOptions::Json(a), ParserOptions::Json(b)) => {
+              ParserOptions::Json(a.merge_from(b))
+            }
+            _ => unreachable!(),
+          },
+        ),
         _ => options.cloned(),
       }
     });
@@ -754,16 +765,18 @@ module.exports = "data:,";
             _ => unreachable!(),
           },
         ),
-        ModuleType::Json => rspack_util::merge_from_optional_with(
-          g.get("json").cloned(),
-          options,
-          |json_options, options| match (json_options, options) {
-            (GeneratorOptions::Json(a), GeneratorOptions::Json(b)) => {
-              GeneratorOptions::Json(a.merge_from(b))
-            }
-            _ => unreachable!(),
-          },
-        ),
+        ModuleType::Json | ModuleType::Yaml | ModuleType::Toml => {
+          rspack_util::merge_from_optional_with(
+            g.get("json").cloned(),
+            options,
+            |json_options, options| match (json_options, options) {
+              (GeneratorOptions::Json(a), GeneratorOptions::Json(b)) => {
+                GeneratorOptions::Json(a.merge_from(b))
+              }
+              _ => unreachable!(),
+            },
+          )
+        }
         _ => options.cloned(),
       }
     });
@@ -4,17 +4,20 @@ use std::{
   path::{Path, PathBuf},
 };
 
-use rspack_cacheable::{cacheable, with::AsPreset};
+use rspack_cacheable::{
+  ContextGuard, Error as CacheableError, cacheable,
+  utils::PortablePath,
+  with::{Custom, CustomConverter},
+};
 use rspack_loader_runner::ResourceData;
 use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
 use rspack_util::atom::Atom;
 
 use crate::{contextify, parse_resource};
 
-#[cacheable]
+#[cacheable(with=Custom)]
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Context {
-  #[cacheable(with=AsPreset)]
   inner: Atom,
 }
 
@@ -58,6 +61,19 @@ impl Deref for Context {
   }
 }
 
+impl CustomConverter for Context {
+  type Target = PortablePath;
+  fn serialize(&self, guard: &ContextGuard) -> Result<Self::Target, CacheableError> {
+    Ok(PortablePath::new(
+      self.as_path().as_std_path(),
+      guard.project_root(),
+    ))
+  }
+  fn deserialize(data: Self::Target, guard: &ContextGuard) -> Result<Self, CacheableError> {
+    Ok(Self::from(data.into_path_string(guard.project_root())))
+  }
+}
+
 impl From<String> for Context {
   fn from(v: String) -> Self {
     Self { inner: v.into() }
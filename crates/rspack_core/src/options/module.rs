@@ -9,7 +9,7 @@ use bitflags::bitflags;
 use derive_more::Debug;
 use futures::future::BoxFuture;
 use rspack_cacheable::{cacheable, with::Unsupported};
-use rspack_error::Result;
+use rspack_error::{Result, error};
 use rspack_macros::MergeFrom;
 use rspack_regex::RspackRegex;
 use rspack_util::{MergeFrom, try_all, try_any};
@@ -310,6 +310,7 @@ pub struct JavascriptParserOptions {
   pub commonjs_magic_comments: Option<bool>,
   pub jsx: Option<bool>,
   pub defer_import: Option<bool>,
+  pub exported_enum: Option<bool>,
 }
 
 #[cacheable]
@@ -402,11 +403,40 @@ impl MergeFrom for ParseOption {
   }
 }
 
+#[cacheable]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, MergeFrom)]
+pub enum JsonSyntax {
+  /// Strict JSON.
+  #[default]
+  Json,
+  /// JSON with comments and trailing commas, such as `tsconfig.json`.
+  Jsonc,
+  /// JSONC with unquoted property names, single quoted strings, extended string escapes and
+  /// JSON5 numbers such as `0xFF`, `+1` and `.5`.
+  Json5,
+}
+
+impl TryFrom<&str> for JsonSyntax {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    match value {
+      "json" => Ok(JsonSyntax::Json),
+      "jsonc" => Ok(JsonSyntax::Jsonc),
+      "json5" => Ok(JsonSyntax::Json5),
+      _ => Err(error!(
+        "Invalid json syntax \"{value}\", expected \"json\", \"jsonc\" or \"json5\""
+      )),
+    }
+  }
+}
+
 #[cacheable]
 #[derive(Debug, Clone, MergeFrom)]
 pub struct JsonParserOptions {
   pub exports_depth: Option<u32>,
   pub parse: ParseOption,
+  pub syntax: Option<JsonSyntax>,
 }
 
 #[derive(Debug, Default)]
@@ -679,6 +709,7 @@ pub struct CssAutoGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<LocalIdentName>,
   pub es_module: Option<bool>,
+  pub exports_declaration: Option<CssExportsDeclaration>,
 }
 
 impl From<CssGeneratorOptions> for CssAutoGeneratorOptions {
@@ -698,6 +729,7 @@ pub struct CssModuleGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<LocalIdentName>,
   pub es_module: Option<bool>,
+  pub exports_declaration: Option<CssExportsDeclaration>,
 }
 
 impl From<CssGeneratorOptions> for CssModuleGeneratorOptions {
@@ -738,6 +770,23 @@ impl From<&str> for LocalIdentName {
   }
 }
 
+#[cacheable]
+#[derive(Debug, Clone, MergeFrom)]
+pub enum CssExportsDeclaration {
+  Disable,
+  /// Write the declaration next to the css module, such as `button.module.css.d.ts`
+  Enable,
+  /// Write the declarations into the directory, keeping the paths of the css modules relative
+  /// to the context
+  OutputDir(String),
+}
+
+impl From<bool> for CssExportsDeclaration {
+  fn from(value: bool) -> Self {
+    if value { Self::Enable } else { Self::Disable }
+  }
+}
+
 #[cacheable]
 #[derive(Debug, Clone, Copy)]
 struct ExportsConventionFlags(u8);
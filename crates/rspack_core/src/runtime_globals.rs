@@ -269,6 +269,11 @@ bitflags! {
 
     // rspack only
     const ASYNC_STARTUP = 1 << 73;
+
+    /**
+     * an object containing all installed WebAssembly.Instance export objects keyed by module id
+     */
+    const WASM_INSTANCES = 1 << 74;
   }
 }
 
@@ -316,6 +321,7 @@ pub fn runtime_globals_to_string(
     RuntimeGlobals::GET_FULL_HASH => format!("{scope_name}.h"),
     RuntimeGlobals::GLOBAL => format!("{scope_name}.g"),
     RuntimeGlobals::RETURN_EXPORTS_FROM_RUNTIME => "return-exports-from-runtime".to_string(),
+    RuntimeGlobals::WASM_INSTANCES => format!("{scope_name}.w"),
     RuntimeGlobals::INSTANTIATE_WASM => format!("{scope_name}.v"),
     RuntimeGlobals::ASYNC_MODULE => format!("{scope_name}.a"),
     RuntimeGlobals::ASYNC_MODULE_EXPORT_SYMBOL => format!("{scope_name}.aE"),
@@ -17,7 +17,7 @@ use crate::{
   BoxModule, BoxRuntimeModule, Chunk, ChunkGraph, ChunkGroupOrderKey, ChunkGroupUkey, ChunkUkey,
   Compilation, LogType, ModuleGraph, ModuleGraphCacheArtifact, ModuleIdentifier,
   PrefetchExportsInfoMode, ProvidedExports, RuntimeSpec, SourceType, UsedExports,
-  compilation::build_module_graph::ExecutedRuntimeModule,
+  compilation::build_module_graph::ExecutedRuntimeModule, incremental::IncrementalExplanation,
 };
 
 #[derive(Debug, Clone)]
@@ -63,6 +63,25 @@ impl Stats<'_> {
               value: vec![source_map.as_str()],
             })
           }
+          let mut compressed_sizes = vec![];
+          for (algorithm, compressed) in &asset.info.compressed_assets {
+            related.push(StatsAssetInfoRelated {
+              name: algorithm.as_str(),
+              value: vec![compressed.as_str()],
+            });
+            if let Some(compressed_source) = self
+              .compilation
+              .assets()
+              .get(compressed)
+              .and_then(|asset| asset.get_source())
+            {
+              compressed_sizes.push(StatsAssetCompressedSize {
+                algorithm: algorithm.as_str(),
+                name: compressed.as_str(),
+                size: compressed_source.size() as f64,
+              });
+            }
+          }
           (
             name,
             StatsAsset {
@@ -105,6 +124,7 @@ impl Stats<'_> {
                 is_over_size_limit: asset.info.is_over_size_limit,
               },
               emitted: self.compilation.emitted_assets.contains(name),
+              compressed_sizes,
             },
           )
         })
@@ -728,6 +748,10 @@ impl Stats<'_> {
     self.compilation.get_hash()
   }
 
+  pub fn get_incremental_explanation(&self) -> Option<IncrementalExplanation> {
+    IncrementalExplanation::new(self.compilation)
+  }
+
   #[allow(clippy::too_many_arguments)]
   fn get_module<'a>(
     &'a self,
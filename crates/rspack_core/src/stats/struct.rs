@@ -119,6 +119,14 @@ pub struct StatsAsset<'a> {
   pub auxiliary_chunk_names: Vec<&'a str>,
   pub auxiliary_chunk_id_hints: Vec<&'a str>,
   pub auxiliary_chunks: Vec<Option<&'a str>>,
+  pub compressed_sizes: Vec<StatsAssetCompressedSize<'a>>,
+}
+
+#[derive(Debug)]
+pub struct StatsAssetCompressedSize<'a> {
+  pub algorithm: &'a str,
+  pub name: &'a str,
+  pub size: f64,
 }
 
 #[derive(Debug)]
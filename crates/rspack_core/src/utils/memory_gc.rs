@@ -49,6 +49,12 @@ where
       CacheData::new(data, self.generation.load(Ordering::Relaxed)),
     );
   }
+  /// visit all of the items without updating their generation
+  pub(crate) fn for_each(&self, mut f: impl FnMut(&Identifier, &Item)) {
+    for entry in self.data.iter() {
+      f(entry.key(), &entry.item);
+    }
+  }
   /// notify storage that the current generation is over and start a new one
   pub(crate) fn start_next_generation(&self) {
     let generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
@@ -13,6 +13,8 @@ pub struct FileMetadata {
   pub mtime_ms: u64,
   pub ctime_ms: u64,
   pub size: u64,
+  /// The inode number, 0 if the file system does not support it.
+  pub ino: u64,
 }
 
 impl FileMetadata {
@@ -29,6 +31,17 @@ impl FileMetadata {
     #[allow(unreachable_code)]
     0u64
   }
+
+  #[allow(unused_variables)]
+  fn get_ino(metadata: &Metadata) -> u64 {
+    #[cfg(unix)]
+    {
+      return std::os::unix::fs::MetadataExt::ino(metadata);
+    }
+    // the file index of windows is not stable in std
+    #[allow(unreachable_code)]
+    0u64
+  }
 }
 
 impl TryFrom<Metadata> for FileMetadata {
@@ -48,6 +61,7 @@ impl TryFrom<Metadata> for FileMetadata {
       .expect("atime is before unix epoch")
       .as_millis() as u64;
     let ctime_ms = Self::get_ctime_ms(&metadata);
+    let ino = Self::get_ino(&metadata);
 
     Ok(Self {
       is_directory: metadata.is_dir(),
@@ -57,6 +71,7 @@ impl TryFrom<Metadata> for FileMetadata {
       mtime_ms,
       ctime_ms,
       atime_ms,
+      ino,
     })
   }
 }
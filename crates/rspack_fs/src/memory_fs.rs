@@ -1,7 +1,10 @@
 use std::{
   collections::{HashMap, HashSet},
   io::{BufRead, Cursor, Read, Seek},
-  sync::{Arc, Mutex},
+  sync::{
+    Arc, Mutex,
+    atomic::{AtomicU64, Ordering},
+  },
 };
 
 use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
@@ -17,6 +20,12 @@ fn new_error(msg: &str) -> Error {
   Error::Io(std::io::Error::other(msg))
 }
 
+/// Every created file or directory gets a new inode number.
+fn next_ino() -> u64 {
+  static INO: AtomicU64 = AtomicU64::new(1);
+  INO.fetch_add(1, Ordering::Relaxed)
+}
+
 #[derive(Debug)]
 enum FileType {
   Dir(FileMetadata),
@@ -37,6 +46,7 @@ impl FileType {
       mtime_ms: now,
       ctime_ms: now,
       size: 0,
+      ino: next_ino(),
     })
   }
 
@@ -51,6 +61,7 @@ impl FileType {
         mtime_ms: now,
         ctime_ms: now,
         size: content.len() as u64,
+        ino: next_ino(),
       },
       content,
     }
@@ -313,6 +313,11 @@ async fn chunk_ids(
       named_chunks_len,
       unnamed_chunks.len(),
     ));
+    compilation.incremental.explain_pass(
+      IncrementalPasses::CHUNK_IDS,
+      mutations.len(),
+      compilation.chunk_by_ukey.len(),
+    );
   }
 
   if let Some(mut compilation_mutations) = compilation.incremental.mutations_write()
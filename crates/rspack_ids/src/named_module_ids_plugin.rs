@@ -231,6 +231,11 @@ async fn module_ids(
       mutations.len(),
       unnamed_modules_len,
     ));
+    compilation.incremental.explain_pass(
+      IncrementalPasses::MODULE_IDS,
+      modules_len,
+      module_graph.modules().len(),
+    );
   }
 
   if let Some(mut compilation_mutations) = compilation.incremental.mutations_write()
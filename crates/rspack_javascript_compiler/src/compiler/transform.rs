@@ -96,12 +96,19 @@ fn parse_swcrc(s: &str) -> Result<Rc, anyhow::Error> {
     ))
   }
 
-  let v = parse_to_serde_value(
+  let v = parse_to_serde_value::<Option<serde_json::Value>>(
     s.trim_start_matches('\u{feff}'),
     &jsonc_parser::ParseOptions {
       allow_comments: true,
       allow_trailing_commas: true,
+      allow_missing_commas: false,
       allow_loose_object_property_names: false,
+      allow_single_quoted_strings: true,
+      allow_hexadecimal_numbers: false,
+      allow_unary_plus_numbers: false,
+      allow_bare_decimal_point_numbers: false,
+      allow_non_finite_numbers: false,
+      allow_extended_string_escapes: false,
     },
   )?
   .ok_or_else(|| anyhow::Error::msg("failed to deserialize empty .swcrc (json) file"))?;
@@ -8,7 +8,7 @@ use anymap::CloneAny;
 use once_cell::sync::OnceCell;
 use rspack_cacheable::{
   cacheable,
-  utils::PortablePath,
+  utils::{PortablePath, PortableString},
   with::{As, AsInner, AsOption, AsPreset},
 };
 use rspack_error::{Error, Result, ToStringResultToRspackResultExt};
@@ -121,6 +121,7 @@ impl Debug for Content {
 #[derive(Debug, Clone)]
 pub struct ResourceData {
   /// Resource with absolute path, query and fragment
+  #[cacheable(with=As<PortableString>)]
   resource: String,
   /// Absolute resource path only
   #[cacheable(with=AsOption<AsPreset>)]
@@ -134,6 +135,7 @@ pub struct ResourceData {
   parameters: Option<String>,
   encoding: Option<String>,
   encoded_content: Option<String>,
+  #[cacheable(with=AsOption<As<PortablePath>>)]
   context: Option<String>,
   #[cacheable(with=AsInner)]
   scheme: OnceCell<Scheme>,
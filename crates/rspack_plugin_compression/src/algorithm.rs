@@ -0,0 +1,76 @@
+use std::{fmt, io::Write};
+
+use rspack_error::{Error, Result, ToStringResultToRspackResultExt, error};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CompressionAlgorithm {
+  Gzip,
+  Brotli,
+  Zstd,
+}
+
+impl CompressionAlgorithm {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      CompressionAlgorithm::Gzip => "gzip",
+      CompressionAlgorithm::Brotli => "brotli",
+      CompressionAlgorithm::Zstd => "zstd",
+    }
+  }
+
+  /// The extension of the compressed asset, which is appended to the original filename
+  pub fn extension(&self) -> &'static str {
+    match self {
+      CompressionAlgorithm::Gzip => "gz",
+      CompressionAlgorithm::Brotli => "br",
+      CompressionAlgorithm::Zstd => "zst",
+    }
+  }
+
+  /// Compress `content` with a high compression level, as the assets are compressed once
+  /// at build time and served many times.
+  pub fn compress(&self, content: &[u8]) -> Result<Vec<u8>> {
+    match self {
+      CompressionAlgorithm::Gzip => {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+        encoder.write_all(content).to_rspack_result()?;
+        encoder.finish().to_rspack_result()
+      }
+      CompressionAlgorithm::Brotli => {
+        let mut encoder = brotli::CompressorWriter::new(Vec::new(), 4096, 11, 22);
+        encoder.write_all(content).to_rspack_result()?;
+        // the stream is finished when the writer is consumed
+        Ok(encoder.into_inner())
+      }
+      // the levels above 19 take much more memory and time for a tiny gain
+      #[cfg(not(target_family = "wasm"))]
+      CompressionAlgorithm::Zstd => zstd::encode_all(content, 19).to_rspack_result(),
+      // zstd links the C library, which is not available in the wasm target
+      #[cfg(target_family = "wasm")]
+      CompressionAlgorithm::Zstd => Err(error!(
+        "The zstd compression algorithm is not supported in the wasm build"
+      )),
+    }
+  }
+}
+
+impl fmt::Display for CompressionAlgorithm {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl TryFrom<&str> for CompressionAlgorithm {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    match value {
+      "gzip" => Ok(CompressionAlgorithm::Gzip),
+      "brotli" => Ok(CompressionAlgorithm::Brotli),
+      "zstd" => Ok(CompressionAlgorithm::Zstd),
+      _ => Err(error!(
+        "Unsupported compression algorithm: '{value}'. Expected one of: gzip, brotli, zstd"
+      )),
+    }
+  }
+}
@@ -0,0 +1,176 @@
+mod algorithm;
+
+use std::{hash::Hasher, sync::Mutex};
+
+use rayon::prelude::*;
+use rspack_core::{
+  AssetInfo, Compilation, CompilationAsset, CompilationProcessAssets, Plugin,
+  rspack_sources::{BoxSource, RawBufferSource, SourceExt},
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::asset_condition::{AssetConditions, AssetConditionsObject, match_object};
+use rustc_hash::FxHashMap as HashMap;
+
+pub use crate::algorithm::CompressionAlgorithm;
+
+#[derive(Debug)]
+pub struct CompressionPluginOptions {
+  pub test: Option<AssetConditions>,
+  pub include: Option<AssetConditions>,
+  pub exclude: Option<AssetConditions>,
+  /// The algorithms to compress the assets with, each of them emits a sibling asset
+  pub algorithms: Vec<CompressionAlgorithm>,
+  /// Only the assets whose size in bytes is not less than the threshold are compressed
+  pub threshold: usize,
+  /// Only the compressed assets whose ratio (compressed size / original size) is less than this are emitted
+  pub min_ratio: f64,
+}
+
+/// The compressed sources keyed by the algorithm and the content hash of the original source
+type CompressionCache = HashMap<(CompressionAlgorithm, String), BoxSource>;
+
+#[plugin]
+#[derive(Debug)]
+pub struct CompressionPlugin {
+  options: CompressionPluginOptions,
+  cache: Mutex<CompressionCache>,
+}
+
+impl CompressionPlugin {
+  pub fn new(options: CompressionPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+}
+
+impl Plugin for CompressionPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.CompressionPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
+
+struct CompressedAsset {
+  filename: String,
+  algorithm: CompressionAlgorithm,
+  cache_key: (CompressionAlgorithm, String),
+  source: BoxSource,
+  ratio: f64,
+  info: AssetInfo,
+}
+
+#[plugin_hook(CompilationProcessAssets for CompressionPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_TRANSFER)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let options = &self.options;
+  let condition_object = AssetConditionsObject {
+    test: options.test.as_ref(),
+    include: options.include.as_ref(),
+    exclude: options.exclude.as_ref(),
+  };
+  // the entries which are not used by the current compilation are dropped
+  let previous_cache = std::mem::take(&mut *self.cache.lock().expect("should lock"));
+
+  let compressed_assets = compilation
+    .assets()
+    .par_iter()
+    .filter(|(filename, asset)| {
+      !asset.get_info().compressed.unwrap_or(false) && match_object(&condition_object, filename)
+    })
+    .filter_map(|(filename, asset)| {
+      asset
+        .get_source()
+        .map(|source| (filename, asset.get_info(), source))
+    })
+    .map(
+      |(filename, original_info, source)| -> Result<Vec<CompressedAsset>> {
+        let content = source.buffer();
+        if content.is_empty() || content.len() < options.threshold {
+          return Ok(vec![]);
+        }
+        let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+        hasher.write(&content);
+        let hash = hasher.digest(&HashDigest::Hex).encoded().to_string();
+        // the compressed asset can be cached as long as the original asset
+        let info = AssetInfo {
+          immutable: original_info.immutable,
+          content_hash: original_info.content_hash.clone(),
+          development: original_info.development,
+          hot_module_replacement: original_info.hot_module_replacement,
+          compressed: Some(true),
+          ..Default::default()
+        };
+
+        options
+          .algorithms
+          .iter()
+          .map(|algorithm| {
+            let cache_key = (*algorithm, hash.clone());
+            let source = match previous_cache.get(&cache_key) {
+              Some(source) => source.clone(),
+              None => RawBufferSource::from(algorithm.compress(&content)?).boxed(),
+            };
+            Ok(CompressedAsset {
+              filename: filename.clone(),
+              algorithm: *algorithm,
+              cache_key,
+              ratio: source.size() as f64 / content.len() as f64,
+              source,
+              info: info.clone(),
+            })
+          })
+          .collect()
+      },
+    )
+    .collect::<Result<Vec<_>>>()?;
+
+  let mut compressed_assets = compressed_assets.into_iter().flatten().collect::<Vec<_>>();
+  compressed_assets.sort_by(|a, b| {
+    a.filename
+      .cmp(&b.filename)
+      .then_with(|| a.algorithm.cmp(&b.algorithm))
+  });
+
+  let mut cache = CompressionCache::default();
+  for compressed in compressed_assets {
+    cache.insert(compressed.cache_key, compressed.source.clone());
+    if compressed.ratio >= options.min_ratio {
+      continue;
+    }
+    let compressed_filename = format!(
+      "{}.{}",
+      compressed.filename,
+      compressed.algorithm.extension()
+    );
+    // never overwrite the assets emitted by others, such as the precompressed files copied as is
+    if compilation.assets().contains_key(&compressed_filename) {
+      compilation.push_diagnostic(Diagnostic::warn(
+        "CompressionRspackPlugin".to_string(),
+        format!(
+          "Skip emitting {compressed_filename}, as an asset with the same name already exists"
+        ),
+      ));
+      continue;
+    }
+    if let Some(original) = compilation.assets_mut().get_mut(&compressed.filename) {
+      original.get_info_mut().compressed_assets.insert(
+        compressed.algorithm.as_str().to_string(),
+        compressed_filename.clone(),
+      );
+    }
+    compilation.emit_asset(
+      compressed_filename,
+      CompilationAsset::new(Some(compressed.source), compressed.info),
+    );
+  }
+  *self.cache.lock().expect("should lock") = cache;
+
+  Ok(())
+}
@@ -106,6 +106,20 @@ pub struct CssMedia(pub String);
 #[derive(Clone)]
 pub struct CssSupports(pub String);
 
+/// The conditions of an `@import`, the imported module is wrapped by `@media`, `@supports` and
+/// `@layer` in this order
+#[derive(Clone)]
+pub struct CssImportCondition {
+  pub media: Option<CssMedia>,
+  pub supports: Option<CssSupports>,
+  pub layer: Option<CssLayer>,
+}
+
+/// The conditions of the nested `@import`s that the module is imported through, from the
+/// outermost to the innermost
+#[derive(Clone)]
+pub struct CssImportConditions(pub Vec<CssImportCondition>);
+
 impl Display for CssMedia {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     self.0.fmt(f)
@@ -0,0 +1,218 @@
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsPreset, AsVec},
+};
+use rspack_core::{
+  AsContextDependency, Dependency, DependencyCategory, DependencyCodeGeneration, DependencyId,
+  DependencyRange, DependencyTemplate, DependencyTemplateType, DependencyType,
+  ExtendedReferencedExport, FactorizeInfo, ModuleDependency, ModuleGraph, RuntimeSpec,
+  TemplateContext, TemplateReplaceSource,
+};
+use rspack_error::Diagnostic;
+use rspack_util::atom::Atom;
+
+use crate::parser_and_generator::CssParserAndGenerator;
+
+/// The max length of the chain of the modules that re-export a value
+const MAX_VALUE_IMPORT_DEPTH: usize = 32;
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CssValueReplacement {
+  /// The name of the value in the imported module
+  pub name: String,
+  pub range: DependencyRange,
+}
+
+/// `@value a, b as c from "./values.css"`, the statement is removed and the references of the
+/// imported values are replaced with the values defined in the imported module
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CssValueImportDependency {
+  id: DependencyId,
+  request: String,
+  #[cacheable(with=AsVec<AsPreset>)]
+  names: Vec<Atom>,
+  range: DependencyRange,
+  replaces: Vec<CssValueReplacement>,
+  factorize_info: FactorizeInfo,
+}
+
+impl CssValueImportDependency {
+  pub fn new(
+    request: String,
+    names: Vec<Atom>,
+    range: DependencyRange,
+    replaces: Vec<CssValueReplacement>,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      names,
+      range,
+      replaces,
+      factorize_info: Default::default(),
+    }
+  }
+}
+
+/// Resolve the value exported by the module of `dependency_id`, the value may be imported
+/// from another module by the module
+pub fn resolve_imported_value(
+  module_graph: &ModuleGraph,
+  dependency_id: &DependencyId,
+  name: &str,
+) -> Option<String> {
+  let mut dependency_id = *dependency_id;
+  let mut name = name.to_string();
+  for _ in 0..MAX_VALUE_IMPORT_DEPTH {
+    let exports = module_graph
+      .get_module_by_dependency_id(&dependency_id)?
+      .as_normal_module()?
+      .parser_and_generator()
+      .downcast_ref::<CssParserAndGenerator>()?
+      .exports
+      .as_ref()?;
+    let export = exports
+      .get(&name)
+      .and_then(|exports| exports.first())
+      .or_else(|| {
+        exports
+          .values()
+          .flatten()
+          .find(|export| export.orig_name == name)
+      })?;
+    match (&export.from, export.id) {
+      (None, _) => return Some(export.ident.clone()),
+      (Some(_), Some(id)) => {
+        dependency_id = id;
+        name = export.ident.clone();
+      }
+      (Some(_), None) => return None,
+    }
+  }
+  None
+}
+
+#[cacheable_dyn]
+impl Dependency for CssValueImportDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    // resolved in the same way as `composes: a from "./a.css"`
+    &DependencyCategory::CssCompose
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::CssValueImport
+  }
+
+  fn range(&self) -> Option<DependencyRange> {
+    Some(self.range)
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::True
+  }
+
+  fn get_diagnostics(
+    &self,
+    module_graph: &ModuleGraph,
+    _module_graph_cache: &rspack_core::ModuleGraphCacheArtifact,
+  ) -> Option<Vec<Diagnostic>> {
+    // the module is not found or failed to build, which has been reported
+    module_graph.get_module_by_dependency_id(&self.id)?;
+    let diagnostics = self
+      .names
+      .iter()
+      .filter(|name| resolve_imported_value(module_graph, &self.id, name).is_none())
+      .map(|name| {
+        Diagnostic::error(
+          "CssValueImportError".into(),
+          format!(
+            "The value \"{name}\" is not exported by \"{}\"",
+            self.request
+          ),
+        )
+      })
+      .collect::<Vec<_>>();
+    (!diagnostics.is_empty()).then_some(diagnostics)
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _module_graph_cache: &rspack_core::ModuleGraphCacheArtifact,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    self
+      .names
+      .iter()
+      .map(|n| ExtendedReferencedExport::Array(vec![n.clone()]))
+      .collect()
+  }
+}
+
+#[cacheable_dyn]
+impl ModuleDependency for CssValueImportDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn factorize_info(&self) -> &FactorizeInfo {
+    &self.factorize_info
+  }
+
+  fn factorize_info_mut(&mut self) -> &mut FactorizeInfo {
+    &mut self.factorize_info
+  }
+}
+
+#[cacheable_dyn]
+impl DependencyCodeGeneration for CssValueImportDependency {
+  fn dependency_template(&self) -> Option<DependencyTemplateType> {
+    Some(CssValueImportDependencyTemplate::template_type())
+  }
+}
+
+impl AsContextDependency for CssValueImportDependency {}
+
+#[cacheable]
+#[derive(Debug, Clone, Default)]
+pub struct CssValueImportDependencyTemplate;
+
+impl CssValueImportDependencyTemplate {
+  pub fn template_type() -> DependencyTemplateType {
+    DependencyTemplateType::Dependency(DependencyType::CssValueImport)
+  }
+}
+
+impl DependencyTemplate for CssValueImportDependencyTemplate {
+  fn render(
+    &self,
+    dep: &dyn DependencyCodeGeneration,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let dep = dep
+      .as_any()
+      .downcast_ref::<CssValueImportDependency>()
+      .expect("CssValueImportDependencyTemplate should be used for CssValueImportDependency");
+
+    source.replace(dep.range.start, dep.range.end, "", None);
+
+    let module_graph = code_generatable_context.compilation.get_module_graph();
+    for replace in &dep.replaces {
+      // the reference is kept as is if the value is not found
+      if let Some(value) = resolve_imported_value(module_graph, &dep.id, &replace.name) {
+        source.replace(replace.range.start, replace.range.end, &value, None);
+      }
+    }
+  }
+}
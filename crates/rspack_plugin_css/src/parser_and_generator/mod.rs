@@ -1,3 +1,5 @@
+mod value;
+
 use std::{
   borrow::Cow,
   sync::{Arc, LazyLock},
@@ -10,32 +12,35 @@ use rspack_cacheable::{
   cacheable, cacheable_dyn,
   with::{AsCacheable, AsMap, AsOption, AsVec},
 };
+use rspack_collections::IdentifierSet;
 use rspack_core::{
   BoxDependencyTemplate, BoxModuleDependency, BuildMetaDefaultObject, BuildMetaExportsType,
-  ChunkGraph, Compilation, ConstDependency, CssExportsConvention, Dependency, DependencyId,
-  DependencyRange, DependencyType, GenerateContext, LocalIdentName, Module, ModuleGraph,
-  ModuleIdentifier, ModuleInitFragments, ModuleType, NormalModule, ParseContext, ParseResult,
-  ParserAndGenerator, PrefetchExportsInfoMode, RuntimeGlobals, RuntimeSpec, SourceType,
-  TemplateContext, UsageState,
+  ChunkGraph, Compilation, ConstDependency, Context, CssExportsConvention, CssExportsDeclaration,
+  Dependency, DependencyId, DependencyRange, DependencyType, GenerateContext, LocalIdentName,
+  Module, ModuleGraph, ModuleIdentifier, ModuleInitFragments, ModuleType, NormalModule,
+  ParseContext, ParseResult, ParserAndGenerator, PrefetchExportsInfoMode, RuntimeGlobals,
+  RuntimeSpec, SourceType, TemplateContext, UsageState,
   diagnostics::map_box_diagnostics_to_module_parse_diagnostics,
   remove_bom,
   rspack_sources::{BoxSource, ConcatSource, RawStringSource, ReplaceSource, Source, SourceExt},
 };
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, Severity, TWithDiagnosticArray};
 use rspack_hash::{RspackHash, RspackHashDigest};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rspack_util::{atom::Atom, ext::DynHash};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
   dependency::{
-    CssComposeDependency, CssExportDependency, CssImportDependency, CssLayer,
-    CssLocalIdentDependency, CssMedia, CssSelfReferenceLocalIdentDependency,
-    CssSelfReferenceLocalIdentReplacement, CssSupports, CssUrlDependency,
+    CssComposeDependency, CssExportDependency, CssImportCondition, CssImportConditions,
+    CssImportDependency, CssLayer, CssLocalIdentDependency, CssMedia,
+    CssSelfReferenceLocalIdentDependency, CssSelfReferenceLocalIdentReplacement, CssSupports,
+    CssUrlDependency, CssValueImportDependency, CssValueReplacement,
   },
   utils::{
     LocalIdentOptions, css_modules_exports_to_concatenate_module_string,
-    css_modules_exports_to_string, css_parsing_traceable_error, export_locals_convention,
-    normalize_url, replace_module_request_prefix, unescape,
+    css_modules_exports_to_declaration, css_modules_exports_to_string, css_parsing_traceable_error,
+    export_locals_convention, normalize_url, replace_module_request_prefix, unescape,
   },
 };
 
@@ -64,6 +69,17 @@ pub struct CssExport {
 
 pub type CssExports = IndexMap<String, IndexSet<CssExport>>;
 
+/// Whether the module is parsed as css modules, which has local idents and exports
+fn is_css_modules(module_type: &ModuleType, resource_path: Option<&Utf8Path>) -> bool {
+  match module_type {
+    ModuleType::CssModule => true,
+    ModuleType::CssAuto => {
+      resource_path.is_some_and(|path| REGEX_IS_MODULES.is_match(path.as_str()))
+    }
+    _ => false,
+  }
+}
+
 fn update_css_exports(exports: &mut CssExports, name: String, css_export: CssExport) -> bool {
   if let Some(existing) = exports.get_mut(&name) {
     existing.insert(css_export)
@@ -79,6 +95,7 @@ fn update_css_exports(exports: &mut CssExports, name: String, css_export: CssExp
 pub struct CssParserAndGenerator {
   pub convention: Option<CssExportsConvention>,
   pub local_ident_name: Option<LocalIdentName>,
+  pub exports_declaration: CssExportsDeclaration,
   pub exports_only: bool,
   pub named_exports: bool,
   pub es_module: bool,
@@ -89,6 +106,164 @@ pub struct CssParserAndGenerator {
   pub hot: bool,
 }
 
+impl CssParserAndGenerator {
+  /// Returns the path and the content of the `.d.ts` declaration for the exports of the css
+  /// module, `None` if the declaration is disabled or the module is not a css module.
+  ///
+  /// The css modules in `node_modules` are skipped, as their declarations should be shipped
+  /// with the packages.
+  pub(crate) fn exports_declaration(
+    &self,
+    module: &NormalModule,
+    context: &Context,
+  ) -> Result<Option<(Utf8PathBuf, String)>> {
+    if matches!(self.exports_declaration, CssExportsDeclaration::Disable) {
+      return Ok(None);
+    }
+    let resource_data = module
+      .match_resource()
+      .unwrap_or(module.resource_resolved_data().as_ref());
+    if !is_css_modules(module.module_type(), resource_data.path()) {
+      return Ok(None);
+    }
+    let Some(resource_path) = module.resource_resolved_data().path() else {
+      return Ok(None);
+    };
+    let Some(file_name) = resource_path.file_name() else {
+      return Ok(None);
+    };
+    if resource_path
+      .components()
+      .any(|component| component.as_str() == "node_modules")
+    {
+      return Ok(None);
+    }
+    let file_name = format!("{file_name}.d.ts");
+    let path = match &self.exports_declaration {
+      CssExportsDeclaration::Disable => return Ok(None),
+      CssExportsDeclaration::Enable => resource_path.with_file_name(file_name),
+      CssExportsDeclaration::OutputDir(output_dir) => {
+        let Ok(relative) = resource_path.strip_prefix(context.as_path()) else {
+          return Ok(None);
+        };
+        context
+          .as_path()
+          .join(output_dir)
+          .join(relative)
+          .with_file_name(file_name)
+      }
+    };
+    let content = css_modules_exports_to_declaration(self.exports.as_ref(), self.named_exports)?;
+    Ok(Some((path, content)))
+  }
+}
+
+impl CssParserAndGenerator {
+  /// Add the exports and the dependencies of the `@value` definitions and imports
+  fn add_values(
+    &mut self,
+    values: value::CssValues,
+    dependencies: &mut Vec<Box<dyn Dependency>>,
+    presentational_dependencies: &mut Vec<BoxDependencyTemplate>,
+  ) {
+    if values.is_empty() {
+      return;
+    }
+    let convention = self
+      .convention
+      .as_ref()
+      .expect("should have local_ident_name for module_type css/auto or css/module");
+    let exports = self.exports.get_or_insert_default();
+
+    let mut defined_values = FxHashMap::default();
+    for definition in values.definitions {
+      presentational_dependencies.push(Box::new(ConstDependency::new(
+        definition.range.into(),
+        "".into(),
+        None,
+      )));
+      let convention_names = export_locals_convention(&definition.name, convention);
+      for name in convention_names.iter() {
+        update_css_exports(
+          exports,
+          name.to_owned(),
+          CssExport {
+            ident: definition.value.clone(),
+            from: None,
+            id: None,
+            orig_name: definition.name.clone(),
+          },
+        );
+      }
+      dependencies.push(Box::new(CssExportDependency::new(convention_names)));
+      defined_values.insert(definition.name, definition.value);
+    }
+
+    for import in values.imports {
+      let replaces = values
+        .references
+        .iter()
+        .filter_map(|reference| {
+          let (_, remote) = import
+            .names
+            .iter()
+            .find(|(local, _)| *local == reference.name)?;
+          Some(CssValueReplacement {
+            name: remote.clone(),
+            range: reference.range.into(),
+          })
+        })
+        .collect::<Vec<_>>();
+      // the exports of the imported module are referenced with the names in the convention
+      let names = import
+        .names
+        .iter()
+        .map(|(local, remote)| {
+          let remote = export_locals_convention(remote, convention)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| remote.clone());
+          (local, remote)
+        })
+        .collect::<Vec<_>>();
+      let dep = CssValueImportDependency::new(
+        import.request.clone(),
+        names
+          .iter()
+          .map(|(_, remote)| remote.as_str().into())
+          .collect(),
+        import.range.into(),
+        replaces,
+      );
+      for (local, remote) in names {
+        for name in export_locals_convention(local, convention) {
+          update_css_exports(
+            exports,
+            name,
+            CssExport {
+              ident: remote.clone(),
+              from: Some(import.request.clone()),
+              id: Some(*dep.id()),
+              orig_name: local.clone(),
+            },
+          );
+        }
+      }
+      dependencies.push(Box::new(dep));
+    }
+
+    for reference in values.references {
+      if let Some(value) = defined_values.get(&reference.name) {
+        presentational_dependencies.push(Box::new(ConstDependency::new(
+          reference.range.into(),
+          value.as_str().into(),
+          None,
+        )));
+      }
+    }
+  }
+}
+
 #[cacheable_dyn]
 #[async_trait::async_trait]
 impl ParserAndGenerator for CssParserAndGenerator {
@@ -160,20 +335,10 @@ impl ParserAndGenerator for CssParserAndGenerator {
       s.clone()
     };
 
-    let mode = match module_type {
-      ModuleType::CssModule => css_module_lexer::Mode::Local,
-      ModuleType::CssAuto
-        if resource_path.is_some()
-          && REGEX_IS_MODULES.is_match(
-            resource_path
-              .as_ref()
-              .expect("should have resource_path for module_type css/auto")
-              .as_str(),
-          ) =>
-      {
-        css_module_lexer::Mode::Local
-      }
-      _ => css_module_lexer::Mode::Css,
+    let mode = if is_css_modules(module_type, resource_path) {
+      css_module_lexer::Mode::Local
+    } else {
+      css_module_lexer::Mode::Css
     };
 
     let mut diagnostics: Vec<Diagnostic> = vec![];
@@ -181,6 +346,14 @@ impl ParserAndGenerator for CssParserAndGenerator {
     let mut presentational_dependencies: Vec<BoxDependencyTemplate> = vec![];
     let mut code_generation_dependencies: Vec<BoxModuleDependency> = vec![];
 
+    // the `@value` statements are removed, so the local identifiers in them are ignored
+    let mut value_statement_ranges = vec![];
+    if matches!(mode, css_module_lexer::Mode::Local) {
+      let values = value::collect_values(&source_code);
+      value_statement_ranges = values.statement_ranges();
+      self.add_values(values, &mut dependencies, &mut presentational_dependencies);
+    }
+
     let (deps, warnings) = css_module_lexer::collect_dependencies(&source_code, mode);
     for dependency in deps {
       match dependency {
@@ -256,6 +429,12 @@ impl ParserAndGenerator for CssParserAndGenerator {
           ))),
         css_module_lexer::Dependency::LocalClass { name, range, .. }
         | css_module_lexer::Dependency::LocalId { name, range, .. } => {
+          if value_statement_ranges
+            .iter()
+            .any(|(start, end)| *start <= range.start && range.end <= *end)
+          {
+            continue;
+          }
           let (_prefix, name) = name.split_at(1); // split '#' or '.'
           let name = unescape(name);
 
@@ -552,30 +731,9 @@ impl ParserAndGenerator for CssParserAndGenerator {
           }
         });
 
-        for conn in module_graph.get_incoming_connections(&module.identifier()) {
-          let dep = module_graph.dependency_by_id(&conn.dependency_id);
-
-          if matches!(dep.dependency_type(), DependencyType::CssImport) {
-            let Some(css_import_dep) = dep.downcast_ref::<CssImportDependency>() else {
-              panic!(
-                "dependency with type DependencyType::CssImport should only be CssImportDependency"
-              );
-            };
-
-            if let Some(media) = css_import_dep.media() {
-              let media = CssMedia(media.to_string());
-              context.data.insert(media);
-            }
-
-            if let Some(supports) = css_import_dep.supports() {
-              let supports = CssSupports(supports.to_string());
-              context.data.insert(supports);
-            }
-
-            if let Some(layer) = css_import_dep.layer() {
-              context.data.insert(layer.clone());
-            }
-          }
+        let conditions = get_import_conditions(module.identifier(), module_graph);
+        if !conditions.is_empty() {
+          context.data.insert(CssImportConditions(conditions));
         }
 
         if let Some(dependencies) = module.get_presentational_dependencies() {
@@ -728,6 +886,52 @@ impl ParserAndGenerator for CssParserAndGenerator {
   }
 }
 
+/// Collect the conditions of the `@import`s from the module up to the module that is not
+/// imported by `@import`, the outer conditions wrap the inner ones.
+///
+/// The module is rendered only once, so when it's imported by more than one `@import`, the
+/// one parsed first is followed.
+fn get_import_conditions(
+  module: ModuleIdentifier,
+  module_graph: &ModuleGraph,
+) -> Vec<CssImportCondition> {
+  let mut conditions = vec![];
+  let mut visited = IdentifierSet::default();
+  let mut current = module;
+  while visited.insert(current) {
+    let Some((origin, dep)) = module_graph
+      .get_incoming_connections(&current)
+      .filter_map(|conn| {
+        let dep = module_graph.dependency_by_id(&conn.dependency_id);
+        if !matches!(dep.dependency_type(), DependencyType::CssImport) {
+          return None;
+        }
+        let Some(dep) = dep.downcast_ref::<CssImportDependency>() else {
+          panic!(
+            "dependency with type DependencyType::CssImport should only be CssImportDependency"
+          );
+        };
+        Some((conn.original_module_identifier?, dep))
+      })
+      .min_by_key(|(_, dep)| *dep.id())
+    else {
+      break;
+    };
+    if dep.media().is_some() || dep.supports().is_some() || dep.layer().is_some() {
+      conditions.push(CssImportCondition {
+        media: dep.media().map(|media| CssMedia(media.to_string())),
+        supports: dep
+          .supports()
+          .map(|supports| CssSupports(supports.to_string())),
+        layer: dep.layer().cloned(),
+      });
+    }
+    current = origin;
+  }
+  conditions.reverse();
+  conditions
+}
+
 fn get_used_exports<'a>(
   exports: &'a CssExports,
   identifier: ModuleIdentifier,
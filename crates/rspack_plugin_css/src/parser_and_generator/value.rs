@@ -0,0 +1,593 @@
+//! Collect the `@value` of css modules, which follows
+//! <https://github.com/css-modules/postcss-modules-values>.
+//!
+//! ```css
+//! @value primary: #0c77f8;
+//! @value small: (max-width: 599px);
+//! @value secondary, brand as accent from "./colors.css";
+//!
+//! @value heading: h1;
+//!
+//! .button {
+//!   color: primary;
+//! }
+//! heading {}
+//! @media small {}
+//! ```
+//!
+//! The values are substituted in the declaration values, the selectors and the params of
+//! `@media`/`@supports`.
+
+use std::sync::LazyLock;
+
+use css_module_lexer::{Lexer, Pos};
+use regex::Regex;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+static REGEX_VALUE_IMPORT: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r#"(?s)^(.+?)\s+from\s+("[^"]*"|'[^']*'|[\w-]+)$"#).expect("Invalid regex")
+});
+
+static REGEX_VALUE_IMPORT_NAME: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"^([\w-]+)(?:\s+as\s+([\w-]+))?$").expect("Invalid regex"));
+
+static REGEX_VALUE_DEFINITION: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"(?s)^([\w-]+)(?:\s*:\s*|\s+)(.+)$").expect("Invalid regex"));
+
+#[derive(Debug)]
+pub struct ValueDefinition {
+  pub name: String,
+  /// The value, in which the values defined before are substituted
+  pub value: String,
+  /// The range of the `@value` statement
+  pub range: (u32, u32),
+}
+
+#[derive(Debug)]
+pub struct ValueImport {
+  pub request: String,
+  /// The imported values as `(local, remote)`
+  pub names: Vec<(String, String)>,
+  /// The range of the `@value` statement
+  pub range: (u32, u32),
+}
+
+#[derive(Debug)]
+pub struct ValueReference {
+  pub name: String,
+  pub range: (u32, u32),
+}
+
+#[derive(Debug, Default)]
+pub struct CssValues {
+  pub definitions: Vec<ValueDefinition>,
+  pub imports: Vec<ValueImport>,
+  pub references: Vec<ValueReference>,
+}
+
+impl CssValues {
+  pub fn is_empty(&self) -> bool {
+    self.definitions.is_empty() && self.imports.is_empty()
+  }
+
+  /// The ranges of the `@value` statements
+  pub fn statement_ranges(&self) -> Vec<(u32, u32)> {
+    self
+      .definitions
+      .iter()
+      .map(|definition| definition.range)
+      .chain(self.imports.iter().map(|import| import.range))
+      .collect()
+  }
+}
+
+/// An identifier that may refer to a value
+#[derive(Debug, Clone, Copy)]
+struct Identifier {
+  start: Pos,
+  end: Pos,
+  /// Whether the identifier follows a `:`, which is a pseudo-class in a selector
+  after_colon: bool,
+}
+
+/// A top level `@value` statement
+#[derive(Debug)]
+struct Statement {
+  start: Pos,
+  end: Pos,
+  identifiers: Vec<Identifier>,
+}
+
+/// The tokens between two of `{`, `}` and `;`
+#[derive(Debug, Default)]
+struct Segment<'s> {
+  start: Option<Pos>,
+  at_rule: Option<&'s str>,
+  starts_with_identifier: bool,
+  identifiers: Vec<Identifier>,
+}
+
+/// Scan a css module with the css lexer, to find the `@value` statements and the identifiers in
+/// the selectors, the params of `@media`/`@supports` and the declaration values
+struct ValueScanner<'s> {
+  lexer: Lexer<'s>,
+  depth: usize,
+  parens: usize,
+  segment: Segment<'s>,
+  statements: Vec<Statement>,
+  identifiers: Vec<Identifier>,
+}
+
+fn is_ident_start(c: char) -> bool {
+  c.is_ascii_alphabetic() || c == '_' || c >= '\u{80}'
+}
+
+fn is_escape(c1: char, c2: Option<char>) -> bool {
+  c1 == '\\' && c2.is_some_and(|c2| c2 != '\n' && c2 != '\r' && c2 != '\x0c')
+}
+
+impl<'s> ValueScanner<'s> {
+  fn new(source: &'s str) -> Self {
+    Self {
+      lexer: Lexer::new(source),
+      depth: 0,
+      parens: 0,
+      segment: Segment::default(),
+      statements: vec![],
+      identifiers: vec![],
+    }
+  }
+
+  fn starts_identifier(&self) -> bool {
+    let Some(c) = self.lexer.cur() else {
+      return false;
+    };
+    let c2 = self.lexer.peek();
+    if c == '-' {
+      c2.is_some_and(|c2| is_ident_start(c2) || c2 == '-' || is_escape(c2, self.lexer.peek2()))
+    } else {
+      is_ident_start(c) || is_escape(c, c2)
+    }
+  }
+
+  fn starts_number(&self) -> bool {
+    let is_digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_digit());
+    match self.lexer.cur() {
+      Some('+' | '-') => {
+        is_digit(self.lexer.peek())
+          || (self.lexer.peek() == Some('.') && is_digit(self.lexer.peek2()))
+      }
+      Some('.') => is_digit(self.lexer.peek()),
+      c => is_digit(c),
+    }
+  }
+
+  /// Consume an identifier, the end is the end of the source if the identifier is the last token
+  fn consume_identifier(&mut self) -> Option<Pos> {
+    let _ = self.lexer.consume_ident_sequence();
+    self.lexer.cur_pos()
+  }
+
+  fn enter_segment(&mut self, start: Pos, is_identifier: bool) {
+    if self.segment.start.is_none() {
+      self.segment.start = Some(start);
+      self.segment.starts_with_identifier = is_identifier;
+    }
+  }
+
+  /// Finish the segment before `{`
+  fn finish_prelude(&mut self) {
+    let segment = std::mem::take(&mut self.segment);
+    match segment.at_rule {
+      // the pseudo-classes are skipped in the selectors
+      None => self.identifiers.extend(
+        segment
+          .identifiers
+          .into_iter()
+          .filter(|identifier| !identifier.after_colon),
+      ),
+      Some(at_rule)
+        if at_rule.eq_ignore_ascii_case("@media") || at_rule.eq_ignore_ascii_case("@supports") =>
+      {
+        self.identifiers.extend(segment.identifiers)
+      }
+      Some(_) => {}
+    }
+  }
+
+  /// Finish the segment before `;` or `}`, or at the end of the source
+  fn finish_statement(&mut self, end: Pos) {
+    let segment = std::mem::take(&mut self.segment);
+    if self.depth == 0 {
+      if let (Some(start), Some("@value")) = (segment.start, segment.at_rule) {
+        self.statements.push(Statement {
+          start,
+          end,
+          identifiers: segment.identifiers,
+        });
+      }
+    } else if segment.at_rule.is_none() && segment.starts_with_identifier {
+      // the first identifier is the property of the declaration
+      self
+        .identifiers
+        .extend(segment.identifiers.into_iter().skip(1));
+    }
+  }
+
+  fn consume_string(&mut self, quote: char) {
+    self.lexer.consume();
+    while let Some(c) = self.lexer.cur() {
+      if c == quote {
+        self.lexer.consume();
+        return;
+      }
+      if c == '\n' || c == '\r' || c == '\x0c' {
+        return;
+      }
+      if c == '\\' {
+        self.lexer.consume();
+      }
+      self.lexer.consume();
+    }
+  }
+
+  fn consume_url(&mut self) {
+    while let Some(c) = self.lexer.cur() {
+      self.lexer.consume();
+      if c == ')' {
+        return;
+      }
+    }
+  }
+
+  fn scan(&mut self) -> Option<()> {
+    self.lexer.consume();
+    while self.lexer.cur().is_some() {
+      self.lexer.consume_white_space_and_comments()?;
+      let c = self.lexer.cur()?;
+      let start = self.lexer.cur_pos()?;
+      if self.starts_number() {
+        self.enter_segment(start, false);
+        self.lexer.consume_numeric_token()?;
+        continue;
+      }
+      if self.starts_identifier() {
+        self.enter_segment(start, true);
+        let end = self.consume_identifier()?;
+        if self.lexer.cur() == Some('(') {
+          self.lexer.consume();
+          self.parens += 1;
+          if self.lexer.slice(start, end)?.eq_ignore_ascii_case("url") {
+            self.lexer.consume_white_space_and_comments()?;
+            if !matches!(self.lexer.cur()?, '"' | '\'') {
+              self.parens -= 1;
+              self.consume_url();
+            }
+          }
+          continue;
+        }
+        let after_colon = start > 0 && self.lexer.slice(start - 1, start) == Some(":");
+        self.segment.identifiers.push(Identifier {
+          start,
+          end,
+          after_colon,
+        });
+        continue;
+      }
+      match c {
+        '"' | '\'' => {
+          self.enter_segment(start, false);
+          self.consume_string(c);
+        }
+        // the hashes, such as colors and ids, and the classes
+        '#' | '.' => {
+          self.enter_segment(start, false);
+          self.lexer.consume();
+          if self.starts_identifier() {
+            self.consume_identifier()?;
+          }
+        }
+        '@' => {
+          self.enter_segment(start, false);
+          self.lexer.consume();
+          if self.starts_identifier() {
+            let end = self.consume_identifier()?;
+            if self.segment.start == Some(start) {
+              self.segment.at_rule = self.lexer.slice(start, end);
+            }
+          }
+        }
+        '(' => {
+          self.enter_segment(start, false);
+          self.lexer.consume();
+          self.parens += 1;
+        }
+        ')' => {
+          self.lexer.consume();
+          self.parens = self.parens.saturating_sub(1);
+        }
+        '{' if self.parens == 0 => {
+          self.lexer.consume();
+          self.finish_prelude();
+          self.depth += 1;
+        }
+        '}' if self.parens == 0 => {
+          self.lexer.consume();
+          if self.depth > 0 {
+            self.finish_statement(start);
+            self.depth -= 1;
+          }
+          self.segment = Segment::default();
+        }
+        ';' if self.parens == 0 => {
+          self.lexer.consume();
+          // the `;` is removed with the `@value` statement
+          self.finish_statement(start + 1);
+        }
+        _ => {
+          self.enter_segment(start, false);
+          self.lexer.consume();
+        }
+      }
+    }
+    Some(())
+  }
+}
+
+/// Replace the values defined before in the value of a definition
+fn substitute(
+  source: &str,
+  (start, end): (Pos, Pos),
+  identifiers: &[Identifier],
+  definitions: &HashMap<String, String>,
+) -> String {
+  let mut result = String::with_capacity((end - start) as usize);
+  let mut last = start as usize;
+  for identifier in identifiers
+    .iter()
+    .filter(|identifier| identifier.start >= start && identifier.end <= end)
+  {
+    let (name_start, name_end) = (identifier.start as usize, identifier.end as usize);
+    if let Some(value) = definitions.get(&source[name_start..name_end]) {
+      result.push_str(&source[last..name_start]);
+      result.push_str(value);
+      last = name_end;
+    }
+  }
+  result.push_str(&source[last..end as usize]);
+  result
+}
+
+fn unquote(text: &str) -> Option<&str> {
+  text
+    .strip_prefix('"')
+    .and_then(|text| text.strip_suffix('"'))
+    .or_else(|| {
+      text
+        .strip_prefix('\'')
+        .and_then(|text| text.strip_suffix('\''))
+    })
+}
+
+/// Collect the `@value` definitions, imports and references of a css module
+pub fn collect_values(source: &str) -> CssValues {
+  let mut values = CssValues::default();
+  if !source.contains("@value") {
+    return values;
+  }
+  let mut scanner = ValueScanner::new(source);
+  scanner.scan();
+  scanner.finish_statement(source.len() as Pos);
+
+  let mut definitions: HashMap<String, String> = HashMap::default();
+  for statement in &scanner.statements {
+    let range = (statement.start, statement.end);
+    let text = &source[statement.start as usize..statement.end as usize];
+    let text = text.strip_suffix(';').unwrap_or(text);
+    let Some(body) = text.strip_prefix("@value") else {
+      continue;
+    };
+    if !body.starts_with(|c: char| c.is_ascii_whitespace()) {
+      continue;
+    }
+    let body_start = statement.start as usize + text.len() - body.trim_start().len();
+    let body = body.trim();
+
+    if let Some(captures) = REGEX_VALUE_IMPORT.captures(body) {
+      let from = &captures[2];
+      let request = match unquote(from) {
+        Some(request) => request.to_string(),
+        // the path can also be a value, such as `@value colors: "./colors.css"`
+        None => match definitions.get(from).and_then(|value| unquote(value)) {
+          Some(request) => request.to_string(),
+          None => continue,
+        },
+      };
+      let names = captures[1]
+        .split(',')
+        .filter_map(|name| {
+          let captures = REGEX_VALUE_IMPORT_NAME.captures(name.trim())?;
+          let remote = captures[1].to_string();
+          let local = captures
+            .get(2)
+            .map(|local| local.as_str().to_string())
+            .unwrap_or_else(|| remote.clone());
+          Some((local, remote))
+        })
+        .collect::<Vec<_>>();
+      if names.is_empty() {
+        continue;
+      }
+      for (local, _) in &names {
+        definitions.remove(local);
+      }
+      values.imports.push(ValueImport {
+        request,
+        names,
+        range,
+      });
+    } else if let Some(captures) = REGEX_VALUE_DEFINITION.captures(body) {
+      let name = captures[1].to_string();
+      let value = captures.get(2).expect("should have value");
+      let value_range = (
+        (body_start + value.start()) as Pos,
+        (body_start + value.end()) as Pos,
+      );
+      let value = substitute(source, value_range, &statement.identifiers, &definitions);
+      definitions.insert(name.clone(), value.clone());
+      values
+        .definitions
+        .push(ValueDefinition { name, value, range });
+    }
+  }
+  if values.is_empty() {
+    return values;
+  }
+
+  // the values can be referenced before they are defined, so the references are collected
+  // after all the values are known
+  let names = values
+    .definitions
+    .iter()
+    .map(|definition| definition.name.as_str())
+    .chain(
+      values
+        .imports
+        .iter()
+        .flat_map(|import| import.names.iter().map(|(local, _)| local.as_str())),
+    )
+    .collect::<HashSet<_>>();
+  let references = scanner
+    .identifiers
+    .iter()
+    .filter_map(|identifier| {
+      let name = &source[identifier.start as usize..identifier.end as usize];
+      names.contains(name).then(|| ValueReference {
+        name: name.to_string(),
+        range: (identifier.start, identifier.end),
+      })
+    })
+    .collect();
+  values.references = references;
+  values
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// The referenced names and their offsets
+  fn references(source: &str) -> Vec<(&str, u32)> {
+    collect_values(source)
+      .references
+      .into_iter()
+      .map(|reference| {
+        assert_eq!(
+          &source[reference.range.0 as usize..reference.range.1 as usize],
+          reference.name
+        );
+        (
+          &source[reference.range.0 as usize..reference.range.1 as usize],
+          reference.range.0,
+        )
+      })
+      .collect()
+  }
+
+  fn offset(source: &str, pattern: &str) -> u32 {
+    source.find(pattern).expect("should find the pattern") as u32
+  }
+
+  #[test]
+  fn skip_url() {
+    let source = "@value img: red;\n.a { background: url(img); mask: url( img ); border-image: url(\"img\"); color: img; }";
+    assert_eq!(references(source), vec![("img", offset(source, "img; }"))]);
+  }
+
+  #[test]
+  fn skip_quoted_strings() {
+    let source =
+      "@value primary: red;\n.a::before { content: \"primary\"; quotes: 'primary' primary; }";
+    assert_eq!(
+      references(source),
+      vec![("primary", offset(source, "primary; }"))]
+    );
+  }
+
+  #[test]
+  fn skip_pseudo_classes() {
+    let source =
+      "@value hover: red;\n@value heading: h1;\nheading:hover, a:not(:hover) { color: hover; }";
+    assert_eq!(
+      references(source),
+      vec![
+        ("heading", offset(source, "heading:hover")),
+        ("hover", offset(source, "hover; }")),
+      ]
+    );
+  }
+
+  #[test]
+  fn substitute_in_nesting() {
+    let source = "@value primary: red;\n@value small: (max-width: 599px);\n.a {\n  color: primary;\n  .b { color: primary; }\n  @media small { &:hover { border-color: primary; } }\n}";
+    let values = collect_values(source);
+    assert_eq!(values.definitions.len(), 2);
+    assert_eq!(
+      references(source),
+      vec![
+        ("primary", offset(source, "primary;\n")),
+        ("primary", offset(source, "primary; }\n")),
+        ("small", offset(source, "small {")),
+        ("primary", offset(source, "primary; } }")),
+      ]
+    );
+  }
+
+  #[test]
+  fn substitute_in_custom_properties() {
+    let source = "@value primary: red;\n@value --gap: 8px;\n:root { --primary: primary; --gap: --gap; }\n.a { color: var(--primary); margin: var(--gap, --gap); }";
+    assert_eq!(
+      references(source),
+      vec![
+        ("primary", offset(source, "primary; --gap")),
+        ("--gap", offset(source, "--gap; }")),
+        ("--gap", offset(source, "--gap, --gap")),
+        ("--gap", offset(source, "--gap); }")),
+      ]
+    );
+  }
+
+  #[test]
+  fn collect_definitions_and_imports() {
+    let source = "@value gap: 8px;\n@value padding: gap gap;\n@value colors: \"./colors.css\";\n@value primary, secondary as accent from colors;\n.a { padding: padding; color: accent; }";
+    let values = collect_values(source);
+    assert_eq!(
+      values
+        .definitions
+        .iter()
+        .map(|definition| (definition.name.as_str(), definition.value.as_str()))
+        .collect::<Vec<_>>(),
+      vec![
+        ("gap", "8px"),
+        ("padding", "8px 8px"),
+        ("colors", "\"./colors.css\"")
+      ]
+    );
+    assert_eq!(values.imports.len(), 1);
+    assert_eq!(values.imports[0].request, "./colors.css");
+    assert_eq!(
+      values.imports[0].names,
+      vec![
+        ("primary".to_string(), "primary".to_string()),
+        ("accent".to_string(), "secondary".to_string())
+      ]
+    );
+    assert_eq!(
+      references(source),
+      vec![
+        ("padding", offset(source, "padding; color")),
+        ("accent", offset(source, "accent; }")),
+      ]
+    );
+  }
+}
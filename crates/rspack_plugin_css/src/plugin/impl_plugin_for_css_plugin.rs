@@ -7,14 +7,15 @@ use std::{
 };
 
 use atomic_refcell::AtomicRefCell;
-use rspack_collections::{DatabaseItem, ItemUkey};
+use rspack_collections::{DatabaseItem, IdentifierSet, ItemUkey};
 use rspack_core::{
-  AssetInfo, Chunk, ChunkGraph, ChunkKind, ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation,
-  CompilationContentHash, CompilationId, CompilationParams, CompilationRenderManifest,
-  CompilationRuntimeRequirementInTree, CompilerCompilation, DependencyType, ManifestAssetType,
-  Module, ModuleGraph, ModuleType, ParserAndGenerator, PathData, Plugin, PublicPath,
-  RenderManifestEntry, RuntimeGlobals, RuntimeModule, RuntimeModuleExt, SelfModuleFactory,
-  SourceType, get_css_chunk_filename_template,
+  AssetInfo, AsyncModulesArtifact, Chunk, ChunkGraph, ChunkKind, ChunkLoading, ChunkLoadingType,
+  ChunkUkey, Compilation, CompilationContentHash, CompilationFinishModules, CompilationId,
+  CompilationParams, CompilationRenderManifest, CompilationRuntimeRequirementInTree,
+  CompilerCompilation, CssExportsDeclaration, DependencyType, ManifestAssetType, Module,
+  ModuleGraph, ModuleType, ParserAndGenerator, PathData, Plugin, PublicPath, RenderManifestEntry,
+  RuntimeGlobals, RuntimeModule, RuntimeModuleExt, SelfModuleFactory, SourceType,
+  get_css_chunk_filename_template,
   rspack_sources::{
     BoxSource, CachedSource, ConcatSource, RawStringSource, ReplaceSource, Source, SourceExt,
   },
@@ -29,8 +30,9 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use crate::{
   CssPlugin,
   dependency::{
-    CssImportDependencyTemplate, CssLayer, CssLocalIdentDependencyTemplate, CssMedia,
-    CssSelfReferenceLocalIdentDependencyTemplate, CssSupports, CssUrlDependencyTemplate,
+    CssImportConditions, CssImportDependencyTemplate, CssLayer, CssLocalIdentDependencyTemplate,
+    CssSelfReferenceLocalIdentDependencyTemplate, CssUrlDependencyTemplate,
+    CssValueImportDependencyTemplate,
   },
   parser_and_generator::{CodeGenerationDataUnusedLocalIdent, CssParserAndGenerator},
   plugin::{CssModulesPluginHooks, CssModulesRenderSource, CssPluginInner},
@@ -196,28 +198,35 @@ impl CssPlugin {
                 let mut num_close_bracket = 0;
 
                 // TODO: use PrefixSource to create indent
-                if let Some(media) = data.get::<CssMedia>() {
-                  num_close_bracket += 1;
-                  container_source.add(RawStringSource::from(format!("@media {media}{{\n")));
-                }
-
-                if let Some(supports) = data.get::<CssSupports>() {
-                  num_close_bracket += 1;
-                  container_source.add(RawStringSource::from(format!(
-                    "@supports ({supports}) {{\n"
-                  )));
-                }
-
-                if let Some(layer) = data.get::<CssLayer>() {
-                  num_close_bracket += 1;
-                  container_source.add(RawStringSource::from(format!(
-                    "@layer{} {{\n",
-                    if let CssLayer::Named(layer) = &layer {
-                      Cow::Owned(format!(" {layer}"))
-                    } else {
-                      Cow::Borrowed("")
-                    }
-                  )));
+                // the conditions of the outer `@import`s wrap the ones of the inner `@import`s
+                let conditions = data
+                  .get::<CssImportConditions>()
+                  .map(|conditions| conditions.0.as_slice())
+                  .unwrap_or_default();
+                for condition in conditions {
+                  if let Some(media) = &condition.media {
+                    num_close_bracket += 1;
+                    container_source.add(RawStringSource::from(format!("@media {media}{{\n")));
+                  }
+
+                  if let Some(supports) = &condition.supports {
+                    num_close_bracket += 1;
+                    container_source.add(RawStringSource::from(format!(
+                      "@supports ({supports}) {{\n"
+                    )));
+                  }
+
+                  if let Some(layer) = &condition.layer {
+                    num_close_bracket += 1;
+                    container_source.add(RawStringSource::from(format!(
+                      "@layer{} {{\n",
+                      if let CssLayer::Named(layer) = &layer {
+                        Cow::Owned(format!(" {layer}"))
+                      } else {
+                        Cow::Borrowed("")
+                      }
+                    )));
+                  }
                 }
 
                 container_source.add(cur_source.clone());
@@ -277,6 +286,10 @@ async fn compilation(
     DependencyType::CssCompose,
     params.normal_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::CssValueImport,
+    params.normal_module_factory.clone(),
+  );
   compilation.set_dependency_factory(
     DependencyType::CssSelfReferenceLocalIdent,
     Arc::new(SelfModuleFactory {}),
@@ -297,6 +310,86 @@ async fn compilation(
     CssUrlDependencyTemplate::template_type(),
     Arc::new(CssUrlDependencyTemplate::default()),
   );
+  compilation.set_dependency_template(
+    CssValueImportDependencyTemplate::template_type(),
+    Arc::new(CssValueImportDependencyTemplate::default()),
+  );
+  Ok(())
+}
+
+#[plugin_hook(CompilationFinishModules for CssPlugin)]
+async fn finish_modules(
+  &self,
+  compilation: &mut Compilation,
+  _async_modules_artifact: &mut AsyncModulesArtifact,
+) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  let built_modules = compilation
+    .build_module_graph_artifact
+    .built_modules()
+    .copied()
+    .collect::<IdentifierSet>();
+  let mut written = std::mem::take(&mut *self.exports_declarations.lock().expect("should lock"));
+  // the declarations of the modules removed from the module graph are stale
+  let mut stale = vec![];
+  written.retain(|identifier, path| {
+    let exists = module_graph.module_by_identifier(identifier).is_some();
+    if !exists {
+      stale.push(path.clone());
+    }
+    exists
+  });
+
+  let mut declarations = vec![];
+  for (identifier, module) in module_graph.modules() {
+    // the declarations of the modules which are not rebuilt are up to date
+    if !built_modules.contains(&identifier) && written.contains_key(&identifier) {
+      continue;
+    }
+    let Some(normal_module) = module.as_normal_module() else {
+      continue;
+    };
+    let Some(parser_and_generator) = normal_module
+      .parser_and_generator()
+      .downcast_ref::<CssParserAndGenerator>()
+    else {
+      continue;
+    };
+    let declaration =
+      parser_and_generator.exports_declaration(normal_module, &compilation.options.context)?;
+    let previous = written.remove(&identifier);
+    if let Some((path, content)) = declaration {
+      stale.extend(previous.filter(|previous| previous != &path));
+      written.insert(identifier, path.clone());
+      declarations.push((path, content));
+    } else {
+      stale.extend(previous);
+    }
+  }
+
+  let fs = compilation.intermediate_filesystem.clone();
+  let written_paths = written.values().collect::<HashSet<_>>();
+  for path in stale {
+    if !written_paths.contains(&path) && fs.stat(&path).await.is_ok() {
+      fs.remove_file(&path).await?;
+    }
+  }
+  // only write the declarations whose content changes, so the type checkers in watch mode are
+  // not triggered by the untouched files
+  for (path, content) in declarations {
+    if fs
+      .read_file(&path)
+      .await
+      .is_ok_and(|existing| existing == content.as_bytes())
+    {
+      continue;
+    }
+    if let Some(parent) = path.parent() {
+      fs.create_dir_all(parent).await?;
+    }
+    fs.write(&path, content.as_bytes()).await?;
+  }
+  *self.exports_declarations.lock().expect("should lock") = written;
   Ok(())
 }
 
@@ -471,6 +564,33 @@ async fn render_manifest(
     .await?;
 
   diagnostics.extend(more_diagnostics);
+  // the cascade order depends on the other chunks of the chunk groups, so it's checked outside
+  // of the render cache, and each conflict is reported by the chunk that loads the module
+  let mut chunk_group_ukeys = chunk.groups().iter().copied().collect::<Vec<_>>();
+  chunk_group_ukeys.sort_unstable();
+  for chunk_group_ukey in chunk_group_ukeys {
+    let chunk_group = compilation
+      .chunk_group_by_ukey
+      .expect_get(&chunk_group_ukey);
+    let conflicts = Self::get_cascade_order_conflicts(chunk_group, compilation, |chunk_ukey| {
+      compilation.chunk_graph.get_chunk_modules_by_source_type(
+        chunk_ukey,
+        SourceType::Css,
+        module_graph,
+      )
+    });
+    diagnostics.extend(
+      conflicts
+        .iter()
+        .filter(|conflict| conflict.chunk == *chunk_ukey)
+        .map(|conflict| {
+          let mut diagnostic = conflict.to_diagnostic(compilation);
+          diagnostic.file = Some(output_path.clone().into());
+          diagnostic.chunk = Some(chunk_ukey.as_u32());
+          diagnostic
+        }),
+    );
+  }
   manifest.push(RenderManifestEntry {
     source: source.boxed(),
     filename: output_path,
@@ -488,6 +608,10 @@ impl Plugin for CssPlugin {
 
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
     ctx.compiler_hooks.compilation.tap(compilation::new(self));
+    ctx
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
     ctx
       .compilation_hooks
       .runtime_requirement_in_tree
@@ -515,6 +639,7 @@ impl Plugin for CssPlugin {
           local_names: None,
           convention: None,
           local_ident_name: None,
+          exports_declaration: CssExportsDeclaration::Disable,
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
           es_module: g.es_module.expect("should have es_module"),
@@ -544,6 +669,10 @@ impl Plugin for CssPlugin {
               .clone()
               .expect("should have local_ident_name"),
           ),
+          exports_declaration: g
+            .exports_declaration
+            .clone()
+            .expect("should have exports_declaration"),
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
           es_module: g.es_module.expect("should have es_module"),
@@ -573,6 +702,10 @@ impl Plugin for CssPlugin {
               .clone()
               .expect("should have local_ident_name"),
           ),
+          exports_declaration: g
+            .exports_declaration
+            .clone()
+            .expect("should have exports_declaration"),
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
           es_module: g.es_module.expect("should have es_module"),
@@ -1,18 +1,27 @@
 #![allow(clippy::comparison_chain)]
 mod drive;
 mod impl_plugin_for_css_plugin;
-use std::cmp::{self, Reverse};
+use std::{
+  cmp::{self, Reverse},
+  sync::Mutex,
+};
 
 pub use drive::*;
-use rspack_collections::{DatabaseItem, IdentifierSet};
+use rspack_collections::{DatabaseItem, IdentifierMap, IdentifierSet};
 use rspack_core::{
-  Chunk, ChunkUkey, Compilation, Module, ModuleIdentifier, compare_modules_by_identifier,
+  Chunk, ChunkGroup, ChunkGroupUkey, ChunkUkey, Compilation, Module, ModuleIdentifier,
+  compare_modules_by_identifier,
 };
+use rspack_error::Diagnostic;
 use rspack_hook::plugin;
+use rspack_paths::Utf8PathBuf;
 
 #[plugin]
 #[derive(Debug, Default)]
-pub struct CssPlugin;
+pub struct CssPlugin {
+  /// The `.d.ts` files written for the exports of css modules, keyed by the modules
+  exports_declarations: Mutex<IdentifierMap<Utf8PathBuf>>,
+}
 
 #[derive(Debug)]
 pub struct CssOrderConflicts {
@@ -21,6 +30,41 @@ pub struct CssOrderConflicts {
   pub selected_module: ModuleIdentifier,
 }
 
+/// A css module that is loaded after another module of the chunk group, while it comes
+/// first in the import order and the cascade order should be kept
+#[derive(Debug)]
+pub struct CssCascadeOrderConflict {
+  pub chunk_group: ChunkGroupUkey,
+  /// The chunk that loads the module
+  pub chunk: ChunkUkey,
+  pub module: ModuleIdentifier,
+  pub loaded_after: ModuleIdentifier,
+}
+
+impl CssCascadeOrderConflict {
+  pub fn to_diagnostic(&self, compilation: &Compilation) -> Diagnostic {
+    let module_graph = compilation.get_module_graph();
+    let chunk_group = compilation
+      .chunk_group_by_ukey
+      .expect_get(&self.chunk_group);
+    let module = module_graph
+      .module_by_identifier(&self.module)
+      .expect("should have module");
+    let loaded_after = module_graph
+      .module_by_identifier(&self.loaded_after)
+      .expect("should have module");
+    Diagnostic::warn(
+      "Conflicting order".into(),
+      format!(
+        "chunk group {}\nConflicting order between {} and {}, the latter is imported first but loaded after the former",
+        chunk_group.name().unwrap_or("(unnamed)"),
+        loaded_after.readable_identifier(&compilation.options.context),
+        module.readable_identifier(&compilation.options.context),
+      ),
+    )
+  }
+}
+
 impl CssPlugin {
   pub(crate) fn get_ordered_chunk_css_modules<'a>(
     chunk: &Chunk,
@@ -202,6 +246,55 @@ impl CssPlugin {
     }
     (final_modules, conflicts)
   }
+
+  /// Check that the css modules are loaded in the import order of the chunk group, following the
+  /// order of the chunks in the chunk group and the order of the modules in each chunk.
+  ///
+  /// The modules of a chunk are selected by `get_chunk_modules`, so the css modules extracted by
+  /// other plugins can be checked as well.
+  pub fn get_cascade_order_conflicts<'a>(
+    chunk_group: &ChunkGroup,
+    compilation: &'a Compilation,
+    get_chunk_modules: impl Fn(&ChunkUkey) -> Vec<&'a dyn Module>,
+  ) -> Vec<CssCascadeOrderConflict> {
+    let mut conflicts = vec![];
+    let mut visited = IdentifierSet::default();
+    // The loaded module that comes last in the import order
+    let mut last: Option<(usize, ModuleIdentifier)> = None;
+    for chunk_ukey in &chunk_group.chunks {
+      let mut modules = get_chunk_modules(chunk_ukey);
+      if modules.is_empty() {
+        continue;
+      }
+      // same as rendering the chunk, the modules are sorted before ordering them, so the order
+      // doesn't depend on the order of the modules in the chunk graph
+      modules.sort_unstable_by_key(|module| module.identifier());
+      let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+      let (ordered_modules, _) = Self::get_modules_in_order(chunk, modules, compilation);
+      for module in ordered_modules {
+        let module_identifier = module.identifier();
+        if !visited.insert(module_identifier) {
+          // The module is already loaded by a previous chunk
+          continue;
+        }
+        let Some(index) = chunk_group.module_post_order_index(&module_identifier) else {
+          continue;
+        };
+        match last {
+          Some((last_index, last_module)) if index < last_index => {
+            conflicts.push(CssCascadeOrderConflict {
+              chunk_group: chunk_group.ukey,
+              chunk: *chunk_ukey,
+              module: module_identifier,
+              loaded_after: last_module,
+            });
+          }
+          _ => last = Some((index, module_identifier)),
+        }
+      }
+    }
+    conflicts
+  }
 }
 
 #[derive(Debug)]
@@ -12,7 +12,8 @@ use indexmap::{IndexMap, IndexSet};
 use regex::{Captures, Regex};
 use rspack_core::{
   ChunkGraph, Compilation, CompilerOptions, CssExportsConvention, GenerateContext, LocalIdentName,
-  PathData, RESERVED_IDENTIFIER, ResourceData, RuntimeGlobals, RuntimeSpec, UsedNameItem,
+  PathData, RESERVED_IDENTIFIER, ResourceData, RuntimeGlobals, RuntimeSpec, SAFE_IDENTIFIER,
+  UsedNameItem,
   rspack_sources::{ConcatSource, RawStringSource},
   to_identifier,
 };
@@ -21,7 +22,7 @@ use rspack_hash::RspackHash;
 use rspack_util::{atom::Atom, identifier::make_paths_relative, itoa, json_stringify};
 use rustc_hash::FxHashSet as HashSet;
 
-use crate::parser_and_generator::CssExport;
+use crate::parser_and_generator::{CssExport, CssExports};
 
 pub const AUTO_PUBLIC_PATH_PLACEHOLDER: &str = "__RSPACK_PLUGIN_CSS_AUTO_PUBLIC_PATH__";
 pub static LEADING_DIGIT_REGEX: LazyLock<Regex> =
@@ -179,6 +180,44 @@ module.hot.dispose(function(data) {{ data.exports = stringified_exports; }});"
   Ok(code)
 }
 
+/// Render the TypeScript declaration for the exports of a css module, which are typed as
+/// named exports or the properties of the default export according to `named_exports`
+pub fn css_modules_exports_to_declaration(
+  exports: Option<&CssExports>,
+  named_exports: bool,
+) -> Result<String> {
+  let names = exports.into_iter().flat_map(|exports| exports.keys());
+  let mut code = String::from("// This file is generated by Rspack, do not edit it manually.\n");
+  if named_exports {
+    if exports.is_none_or(|exports| exports.is_empty()) {
+      // keep the declaration as a module
+      code.push_str("export {};\n");
+    }
+    for (index, name) in names.enumerate() {
+      if SAFE_IDENTIFIER.is_match(name) && !RESERVED_IDENTIFIER.contains(name.as_str()) {
+        writeln!(code, "export declare const {name}: string;").to_rspack_result()?;
+      } else {
+        // the names that are not valid identifiers are exported as string literals
+        let local = format!("__css_export_{index}__");
+        writeln!(code, "declare const {local}: string;").to_rspack_result()?;
+        writeln!(code, "export {{ {local} as {} }};", json_stringify(name)).to_rspack_result()?;
+      }
+    }
+  } else {
+    code.push_str("declare const styles: {\n");
+    for name in names {
+      let key = if SAFE_IDENTIFIER.is_match(name) {
+        Cow::Borrowed(name.as_str())
+      } else {
+        Cow::Owned(json_stringify(name))
+      };
+      writeln!(code, "  readonly {key}: string;").to_rspack_result()?;
+    }
+    code.push_str("};\nexport default styles;\n");
+  }
+  Ok(code)
+}
+
 pub fn stringified_exports<'a>(
   exports: IndexMap<&'a str, &'a IndexSet<CssExport>>,
   compilation: &Compilation,
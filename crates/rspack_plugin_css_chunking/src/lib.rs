@@ -8,8 +8,8 @@ use rspack_collections::{
   UkeySet,
 };
 use rspack_core::{
-  ChunkUkey, Compilation, CompilationOptimizeChunks, CompilationParams, CompilerCompilation,
-  Logger, Module, ModuleIdentifier, Plugin, SourceType,
+  ChunkGroupUkey, ChunkUkey, Compilation, CompilationOptimizeChunks, CompilationParams,
+  CompilerCompilation, Logger, Module, ModuleIdentifier, Plugin, SourceType,
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
@@ -89,11 +89,7 @@ async fn optimize_chunks(&self, compilation: &mut Compilation) -> Result<Option<
     Default::default();
 
   // Collect all css modules in chunks and the execpted order of them
-  let chunk_graph = &compilation.chunk_graph;
-  let chunks = &compilation.chunk_by_ukey;
-  let module_graph = compilation.get_module_graph();
-
-  for (chunk_ukey, chunk) in chunks.iter() {
+  for (chunk_ukey, chunk) in compilation.chunk_by_ukey.iter() {
     if let Some(name) = chunk.name()
       && let Some(exclude) = &self.exclude
       && exclude.test(name)
@@ -101,23 +97,10 @@ async fn optimize_chunks(&self, compilation: &mut Compilation) -> Result<Option<
       continue;
     }
 
-    let modules: Vec<&dyn Module> = chunk_graph
-      .get_chunk_modules(chunk_ukey, module_graph)
-      .into_iter()
-      .filter(|module| {
-        module.source_types(module_graph).iter().any(|t| match t {
-          SourceType::Css => true,
-          SourceType::CssImport => true,
-          SourceType::Custom(str) => str == "css/mini-extract",
-          _ => false,
-        })
-      })
-      .map(|module| module.as_ref())
-      .collect();
+    let modules = get_chunk_css_modules(chunk_ukey, compilation);
     if modules.is_empty() {
       continue;
     }
-    let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
     let (ordered_modules, _) = CssPlugin::get_modules_in_order(chunk, modules, compilation);
     let mut module_identifiers: Vec<ModuleIdentifier> = Vec::with_capacity(ordered_modules.len());
     for (i, module) in ordered_modules.iter().enumerate() {
@@ -410,9 +393,72 @@ async fn optimize_chunks(&self, compilation: &mut Compilation) -> Result<Option<
   }
   logger.time_end(start);
 
+  // The order of the rules is kept only if the modules of every chunk group are still loaded in
+  // the import order, which is not guaranteed in loose mode. The modules of `experiments.css`
+  // are checked by CssPlugin when rendering the chunks, only the extracted ones are left here
+  let start = logger.time("check the cascade order");
+  let mut chunk_group_ukeys: Vec<ChunkGroupUkey> = chunk_states
+    .keys()
+    .flat_map(|chunk_ukey| compilation.chunk_by_ukey.expect_get(chunk_ukey).groups())
+    .copied()
+    .collect::<UkeySet<_>>()
+    .into_iter()
+    .collect();
+  chunk_group_ukeys.sort_unstable();
+  let mut diagnostics = vec![];
+  for chunk_group_ukey in chunk_group_ukeys {
+    let chunk_group = compilation
+      .chunk_group_by_ukey
+      .expect_get(&chunk_group_ukey);
+    let conflicts =
+      CssPlugin::get_cascade_order_conflicts(chunk_group, compilation, |chunk_ukey| {
+        get_chunk_css_modules(chunk_ukey, compilation)
+          .into_iter()
+          .filter(|module| is_extracted_css_module(*module, compilation))
+          .collect()
+      });
+    diagnostics.extend(
+      conflicts
+        .iter()
+        .map(|conflict| conflict.to_diagnostic(compilation)),
+    );
+  }
+  compilation.extend_diagnostics(diagnostics);
+  logger.time_end(start);
+
   Ok(None)
 }
 
+/// The css modules of the chunk, including the ones extracted by CssExtractRspackPlugin
+fn get_chunk_css_modules<'a>(
+  chunk_ukey: &ChunkUkey,
+  compilation: &'a Compilation,
+) -> Vec<&'a dyn Module> {
+  let module_graph = compilation.get_module_graph();
+  compilation
+    .chunk_graph
+    .get_chunk_modules(chunk_ukey, module_graph)
+    .into_iter()
+    .filter(|module| {
+      module.source_types(module_graph).iter().any(|t| match t {
+        SourceType::Css => true,
+        SourceType::CssImport => true,
+        SourceType::Custom(str) => str == "css/mini-extract",
+        _ => false,
+      })
+    })
+    .map(|module| module.as_ref())
+    .collect()
+}
+
+/// The css module extracted by CssExtractRspackPlugin
+fn is_extracted_css_module(module: &dyn Module, compilation: &Compilation) -> bool {
+  module
+    .source_types(compilation.get_module_graph())
+    .iter()
+    .any(|t| matches!(t, SourceType::Custom(str) if str == "css/mini-extract"))
+}
+
 impl Plugin for CssChunkingPlugin {
   fn name(&self) -> &'static str {
     "rspack.CssChunkingPlugin"
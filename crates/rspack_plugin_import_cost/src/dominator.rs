@@ -0,0 +1,174 @@
+/// The dominator tree of a directed graph whose nodes are indexed from `0`, the node `0` is the root.
+///
+/// It's computed with the iterative algorithm in "A Simple, Fast Dominance Algorithm"
+/// by Cooper, Harvey and Kennedy, which is fast enough for the graph of modules in a chunk.
+#[derive(Debug)]
+pub struct DominatorTree {
+  /// The immediate dominator of each node, `None` if the node is the root or is unreachable from the root.
+  idoms: Vec<Option<usize>>,
+  /// The reachable nodes in reverse post order.
+  order: Vec<usize>,
+}
+
+impl DominatorTree {
+  pub fn new(successors: &[Vec<usize>]) -> Self {
+    let len = successors.len();
+    let order = reverse_post_order(successors);
+    let mut order_index = vec![usize::MAX; len];
+    for (index, node) in order.iter().enumerate() {
+      order_index[*node] = index;
+    }
+
+    let mut predecessors = vec![vec![]; len];
+    for (node, successors) in successors.iter().enumerate() {
+      if order_index[node] == usize::MAX {
+        continue;
+      }
+      for successor in successors {
+        predecessors[*successor].push(node);
+      }
+    }
+
+    let mut idoms: Vec<Option<usize>> = vec![None; len];
+    if len == 0 {
+      return Self { idoms, order };
+    }
+    idoms[0] = Some(0);
+    let mut changed = true;
+    while changed {
+      changed = false;
+      for node in order.iter().skip(1) {
+        let mut new_idom = None;
+        for predecessor in &predecessors[*node] {
+          if idoms[*predecessor].is_none() {
+            continue;
+          }
+          new_idom = Some(match new_idom {
+            None => *predecessor,
+            Some(new_idom) => intersect(&idoms, &order_index, *predecessor, new_idom),
+          });
+        }
+        if new_idom.is_some() && idoms[*node] != new_idom {
+          idoms[*node] = new_idom;
+          changed = true;
+        }
+      }
+    }
+    idoms[0] = None;
+    Self { idoms, order }
+  }
+
+  /// Returns the immediate dominator of `node`.
+  pub fn immediate_dominator(&self, node: usize) -> Option<usize> {
+    self.idoms[node]
+  }
+
+  /// Returns the retained size of each node, which is the total size of the nodes dominated by it.
+  ///
+  /// The retained size of an unreachable node is `0`.
+  pub fn retained_sizes(&self, sizes: &[f64]) -> Vec<f64> {
+    let mut retained = vec![0.0; sizes.len()];
+    for node in &self.order {
+      retained[*node] = sizes[*node];
+    }
+    // a node is always placed after its dominator in reverse post order
+    for node in self.order.iter().rev() {
+      if let Some(idom) = self.idoms[*node] {
+        retained[idom] += retained[*node];
+      }
+    }
+    retained
+  }
+}
+
+fn intersect(
+  idoms: &[Option<usize>],
+  order_index: &[usize],
+  mut left: usize,
+  mut right: usize,
+) -> usize {
+  while left != right {
+    while order_index[left] > order_index[right] {
+      left = idoms[left].expect("should have immediate dominator");
+    }
+    while order_index[right] > order_index[left] {
+      right = idoms[right].expect("should have immediate dominator");
+    }
+  }
+  left
+}
+
+fn reverse_post_order(successors: &[Vec<usize>]) -> Vec<usize> {
+  let len = successors.len();
+  let mut order = Vec::with_capacity(len);
+  if len == 0 {
+    return order;
+  }
+  let mut visited = vec![false; len];
+  // the stack of (node, index of the next successor to visit)
+  let mut stack = vec![(0, 0)];
+  visited[0] = true;
+  while let Some((node, next)) = stack.last_mut() {
+    if let Some(successor) = successors[*node].get(*next) {
+      *next += 1;
+      if !visited[*successor] {
+        visited[*successor] = true;
+        stack.push((*successor, 0));
+      }
+    } else {
+      order.push(*node);
+      stack.pop();
+    }
+  }
+  order.reverse();
+  order
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_compute_retained_sizes() {
+    // 0 -> 1 -> 2 -> 4
+    //      1 -> 3 -> 4
+    //                4 -> 5
+    // 0 -> 6, 6 is unreachable from others
+    // 7 is unreachable from the root
+    let successors = vec![
+      vec![1, 6],
+      vec![2, 3],
+      vec![4],
+      vec![4],
+      vec![5],
+      vec![],
+      vec![],
+      vec![5],
+    ];
+    let tree = DominatorTree::new(&successors);
+    assert_eq!(tree.immediate_dominator(0), None);
+    assert_eq!(tree.immediate_dominator(2), Some(1));
+    assert_eq!(tree.immediate_dominator(4), Some(1));
+    assert_eq!(tree.immediate_dominator(5), Some(4));
+    assert_eq!(tree.immediate_dominator(7), None);
+
+    let sizes = vec![0.0, 1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0];
+    let retained = tree.retained_sizes(&sizes);
+    assert_eq!(retained[2], 2.0);
+    assert_eq!(retained[4], 24.0);
+    assert_eq!(retained[1], 31.0);
+    assert_eq!(retained[0], 63.0);
+    assert_eq!(retained[7], 0.0);
+  }
+
+  #[test]
+  fn should_handle_cycles() {
+    // 0 -> 1 -> 2 -> 3 -> 1
+    let successors = vec![vec![1], vec![2], vec![3], vec![1]];
+    let tree = DominatorTree::new(&successors);
+    assert_eq!(tree.immediate_dominator(3), Some(2));
+    assert_eq!(tree.immediate_dominator(1), Some(0));
+    let retained = tree.retained_sizes(&[0.0, 1.0, 2.0, 4.0]);
+    assert_eq!(retained[1], 7.0);
+  }
+}
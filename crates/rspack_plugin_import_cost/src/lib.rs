@@ -0,0 +1,220 @@
+mod dominator;
+
+use rspack_collections::IdentifierMap;
+use rspack_core::{
+  ChunkUkey, Compilation, CompilationAsset, CompilationProcessAssets, ModuleIdentifier, Plugin,
+  rspack_sources::{RawStringSource, SourceExt},
+};
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+use rspack_hook::{plugin, plugin_hook};
+use serde::Serialize;
+
+use crate::dominator::DominatorTree;
+
+#[derive(Debug)]
+pub struct ImportCostPluginOptions {
+  /// The filename of the emitted report.
+  pub filename: String,
+}
+
+/// The import cost report, which contains the retained size of each module in each chunk.
+#[derive(Debug, Serialize)]
+pub struct ImportCostReport {
+  pub chunks: Vec<ChunkImportCost>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkImportCost {
+  pub id: Option<String>,
+  pub names: Vec<String>,
+  /// The total size of the modules in the chunk.
+  pub size: f64,
+  /// The modules sorted by retained size in descending order.
+  pub modules: Vec<ModuleImportCost>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleImportCost {
+  pub identifier: String,
+  pub name: String,
+  pub size: f64,
+  /// The bytes that would leave the chunk if the incoming edges of the module were removed,
+  /// which is the total size of the modules dominated by it in the chunk.
+  pub retained_size: f64,
+  /// The readable identifier of the immediate dominator,
+  /// `None` if the module is imported from outside of the chunk.
+  pub dominator: Option<String>,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ImportCostPlugin {
+  options: ImportCostPluginOptions,
+}
+
+impl ImportCostPlugin {
+  pub fn new(options: ImportCostPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+impl Plugin for ImportCostPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ImportCostPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
+
+#[plugin_hook(CompilationProcessAssets for ImportCostPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_REPORT)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let mut chunks = compilation
+    .chunk_by_ukey
+    .keys()
+    .copied()
+    .collect::<Vec<_>>();
+  chunks.sort_by_cached_key(|ukey| {
+    let chunk = compilation.chunk_by_ukey.expect_get(ukey);
+    (
+      chunk.id().map(|id| id.to_string()),
+      chunk.name().map(ToString::to_string),
+    )
+  });
+
+  let report = ImportCostReport {
+    chunks: chunks
+      .iter()
+      .map(|ukey| chunk_import_cost(compilation, ukey))
+      .collect(),
+  };
+  let json = serde_json::to_string_pretty(&report).to_rspack_result()?;
+  compilation.emit_asset(
+    self.options.filename.clone(),
+    CompilationAsset::new(
+      Some(RawStringSource::from(json).boxed()),
+      Default::default(),
+    ),
+  );
+  Ok(())
+}
+
+/// Computes the retained size of each module in the chunk with a dominator tree.
+///
+/// The graph contains the modules in the chunk and a virtual root, the edges are the active connections
+/// between modules in the chunk, and the root links to the modules which are imported from outside of the chunk,
+/// so a module dominates another module when all the import paths in the chunk go through it.
+fn chunk_import_cost(compilation: &Compilation, chunk_ukey: &ChunkUkey) -> ChunkImportCost {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  let module_graph = compilation.get_module_graph();
+  let module_graph_cache = &compilation.module_graph_cache_artifact;
+  let runtime = Some(chunk.runtime());
+  let context = &compilation.options.context;
+
+  let mut modules = compilation
+    .chunk_graph
+    .get_chunk_modules_identifier(chunk_ukey)
+    .iter()
+    .copied()
+    .collect::<Vec<ModuleIdentifier>>();
+  modules.sort();
+  // the node `0` is the virtual root
+  let index_by_module = modules
+    .iter()
+    .enumerate()
+    .map(|(index, module)| (*module, index + 1))
+    .collect::<IdentifierMap<_>>();
+  let entry_modules = compilation.chunk_graph.get_chunk_entry_modules(chunk_ukey);
+
+  let mut successors = vec![vec![]; modules.len() + 1];
+  for (index, module) in modules.iter().enumerate() {
+    let node = index + 1;
+    let mut has_incoming_in_chunk = false;
+    let mut has_incoming_outside_chunk = entry_modules.contains(module);
+    for connection in module_graph.get_incoming_connections(module) {
+      if !connection.is_active(module_graph, runtime, module_graph_cache) {
+        continue;
+      }
+      match connection
+        .original_module_identifier
+        .and_then(|origin| index_by_module.get(&origin))
+      {
+        Some(origin) if *origin != node => {
+          has_incoming_in_chunk = true;
+          successors[*origin].push(node);
+        }
+        Some(_) => {}
+        None => has_incoming_outside_chunk = true,
+      }
+    }
+    if has_incoming_outside_chunk || !has_incoming_in_chunk {
+      successors[0].push(node);
+    }
+  }
+  for successors in successors.iter_mut() {
+    successors.sort_unstable();
+    successors.dedup();
+  }
+
+  let mut tree = DominatorTree::new(&successors);
+  // modules which are only imported by unreachable cycles are treated as imported from outside
+  let unreachable = (1..successors.len())
+    .filter(|node| tree.immediate_dominator(*node).is_none())
+    .collect::<Vec<_>>();
+  if !unreachable.is_empty() {
+    successors[0].extend(unreachable);
+    tree = DominatorTree::new(&successors);
+  }
+
+  let sizes = std::iter::once(0.0)
+    .chain(modules.iter().map(|module| {
+      module_graph
+        .module_by_identifier(module)
+        .map_or(0.0, |module| module.size(None, Some(compilation)))
+    }))
+    .collect::<Vec<_>>();
+  let retained_sizes = tree.retained_sizes(&sizes);
+  let readable_identifier = |module: &ModuleIdentifier| {
+    module_graph.module_by_identifier(module).map_or_else(
+      || module.to_string(),
+      |module| module.readable_identifier(context).to_string(),
+    )
+  };
+
+  let mut module_costs = modules
+    .iter()
+    .enumerate()
+    .map(|(index, module)| {
+      let node = index + 1;
+      ModuleImportCost {
+        identifier: module.to_string(),
+        name: readable_identifier(module),
+        size: sizes[node],
+        retained_size: retained_sizes[node],
+        dominator: tree
+          .immediate_dominator(node)
+          .filter(|idom| *idom != 0)
+          .map(|idom| readable_identifier(&modules[idom - 1])),
+      }
+    })
+    .collect::<Vec<_>>();
+  module_costs.sort_by(|a, b| {
+    b.retained_size
+      .total_cmp(&a.retained_size)
+      .then_with(|| a.name.cmp(&b.name))
+  });
+
+  ChunkImportCost {
+    id: chunk.id().map(|id| id.to_string()),
+    names: chunk.name().map(ToString::to_string).into_iter().collect(),
+    size: sizes.iter().sum(),
+    modules: module_costs,
+  }
+}
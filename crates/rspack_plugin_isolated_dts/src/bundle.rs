@@ -0,0 +1,668 @@
+use rspack_paths::Utf8Path;
+use rspack_util::fx_hash::{FxHashMap, FxHashSet, FxIndexMap, FxIndexSet};
+use swc_core::{
+  atoms::{Atom, Wtf8Atom},
+  common::{DUMMY_SP, SyntaxContext},
+  ecma::{
+    ast::{
+      ClassDecl, Decl, DefaultDecl, ExportAll, ExportNamedSpecifier, ExportSpecifier, FnDecl,
+      Ident, ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportPhase,
+      ImportSpecifier, ImportStarAsSpecifier, Module, ModuleDecl, ModuleExportName, ModuleItem,
+      NamedExport, Pat, Stmt, Str, TsModuleBlock, TsModuleDecl, TsModuleName, TsNamespaceBody,
+    },
+    visit::{VisitMut, VisitMutWith},
+  },
+};
+
+use crate::module::{DtsModule, DtsModuleGraph};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Imported {
+  Named(Atom),
+  Namespace,
+}
+
+/// What an exported or imported name refers to in the bundle
+#[derive(Debug, Clone)]
+enum Binding {
+  /// A top level declaration of an internal module
+  Local(usize, Atom),
+  /// A binding imported from an external module
+  External(Atom, Imported),
+  /// The namespace object of an internal module
+  Namespace(usize),
+}
+
+#[derive(Debug)]
+enum ExportEntry {
+  /// `export { a as b }`, the local may be a declaration or an imported binding
+  Local(Atom),
+  /// `export { a as b } from "x"` and `export * as b from "x"`
+  Reexport(Atom, Imported),
+}
+
+#[derive(Debug)]
+struct BundleModule<'a> {
+  dts: &'a DtsModule,
+  /// The declarations of the module, the import and export declarations are removed
+  items: Vec<ModuleItem>,
+  locals: FxIndexSet<Atom>,
+  imports: FxHashMap<Atom, (Atom, Imported)>,
+  exports: FxIndexMap<Atom, ExportEntry>,
+  star_exports: Vec<Atom>,
+}
+
+impl<'a> BundleModule<'a> {
+  fn new(dts: &'a DtsModule, is_entry: bool) -> Self {
+    let mut module = Self {
+      dts,
+      items: vec![],
+      locals: Default::default(),
+      imports: Default::default(),
+      exports: Default::default(),
+      star_exports: vec![],
+    };
+    for item in &dts.module.body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+          let src = import.src.value.to_atom_lossy().into_owned();
+          for specifier in &import.specifiers {
+            let (local, imported) = match specifier {
+              ImportSpecifier::Named(specifier) => (
+                &specifier.local,
+                Imported::Named(specifier.imported.as_ref().map_or_else(
+                  || specifier.local.sym.clone(),
+                  |imported| imported.atom().into_owned(),
+                )),
+              ),
+              ImportSpecifier::Default(specifier) => {
+                (&specifier.local, Imported::Named("default".into()))
+              }
+              ImportSpecifier::Namespace(specifier) => (&specifier.local, Imported::Namespace),
+            };
+            module
+              .imports
+              .insert(local.sym.clone(), (src.clone(), imported));
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+          for name in decl_names(&export_decl.decl) {
+            module
+              .exports
+              .insert(name.clone(), ExportEntry::Local(name.clone()));
+            module.locals.insert(name);
+          }
+          module
+            .items
+            .push(ModuleItem::Stmt(Stmt::Decl(export_decl.decl.clone())));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export_default)) => {
+          let ident = |ident: &Option<Ident>| {
+            ident
+              .clone()
+              .unwrap_or_else(|| Ident::new("_default".into(), DUMMY_SP, dts.top_level_ctxt))
+          };
+          let decl = match &export_default.decl {
+            DefaultDecl::Class(class_expr) => Decl::Class(ClassDecl {
+              ident: ident(&class_expr.ident),
+              declare: true,
+              class: class_expr.class.clone(),
+            }),
+            DefaultDecl::Fn(fn_expr) => Decl::Fn(FnDecl {
+              ident: ident(&fn_expr.ident),
+              declare: true,
+              function: fn_expr.function.clone(),
+            }),
+            DefaultDecl::TsInterfaceDecl(interface) => Decl::TsInterface(interface.clone()),
+          };
+          for name in decl_names(&decl) {
+            module
+              .exports
+              .insert("default".into(), ExportEntry::Local(name.clone()));
+            module.locals.insert(name);
+          }
+          module.items.push(ModuleItem::Stmt(Stmt::Decl(decl)));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_default)) => {
+          // the default exported expressions are declared as `_default` already
+          if let Some(ident) = export_default.expr.as_ident() {
+            module
+              .exports
+              .insert("default".into(), ExportEntry::Local(ident.sym.clone()));
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) => {
+          let src = named_export
+            .src
+            .as_ref()
+            .map(|src| src.value.to_atom_lossy().into_owned());
+          for specifier in &named_export.specifiers {
+            let (exported, entry) = match specifier {
+              ExportSpecifier::Named(specifier) => {
+                let orig = specifier.orig.atom().into_owned();
+                let exported = specifier
+                  .exported
+                  .as_ref()
+                  .map_or_else(|| orig.clone(), |exported| exported.atom().into_owned());
+                let entry = match &src {
+                  Some(src) => ExportEntry::Reexport(src.clone(), Imported::Named(orig)),
+                  None => ExportEntry::Local(orig),
+                };
+                (exported, entry)
+              }
+              ExportSpecifier::Namespace(specifier) => {
+                let Some(src) = &src else {
+                  continue;
+                };
+                (
+                  specifier.name.atom().into_owned(),
+                  ExportEntry::Reexport(src.clone(), Imported::Namespace),
+                )
+              }
+              ExportSpecifier::Default(_) => continue,
+            };
+            module.exports.insert(exported, entry);
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export_all)) => {
+          module
+            .star_exports
+            .push(export_all.src.value.to_atom_lossy().into_owned());
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import_equals)) => {
+          module.locals.insert(import_equals.id.sym.clone());
+          if import_equals.is_export {
+            module.exports.insert(
+              import_equals.id.sym.clone(),
+              ExportEntry::Local(import_equals.id.sym.clone()),
+            );
+          }
+          let mut import_equals = import_equals.clone();
+          import_equals.is_export = false;
+          module
+            .items
+            .push(ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(
+              import_equals,
+            )));
+        }
+        // `export =` is only meaningful in the entry module
+        ModuleItem::ModuleDecl(
+          ModuleDecl::TsExportAssignment(_) | ModuleDecl::TsNamespaceExport(_),
+        ) => {
+          if is_entry {
+            module.items.push(item.clone());
+          }
+        }
+        ModuleItem::Stmt(Stmt::Decl(decl)) => {
+          module.locals.extend(decl_names(decl));
+          module.items.push(item.clone());
+        }
+        ModuleItem::Stmt(_) => {}
+      }
+    }
+    module
+  }
+}
+
+/// Flatten the declarations of the entry module and the internal modules it depends on into
+/// a single module, the top level names are renamed when they are conflicted
+pub(crate) fn bundle(graph: &DtsModuleGraph, entry: &Utf8Path) -> Option<Module> {
+  if !graph.modules.contains_key(entry) {
+    return None;
+  }
+  let mut order = vec![];
+  let mut visited = FxHashSet::default();
+  module_order(graph, entry, &mut visited, &mut order);
+  let index = order
+    .iter()
+    .enumerate()
+    .map(|(index, path)| (*path, index))
+    .collect::<FxHashMap<_, _>>();
+  let entry_index = order.len() - 1;
+  let modules = order
+    .iter()
+    .enumerate()
+    .map(|(index, path)| BundleModule::new(&graph.modules[*path], index == entry_index))
+    .collect();
+
+  let mut bundler = Bundler {
+    modules,
+    index,
+    used_names: Default::default(),
+    local_names: Default::default(),
+    external_names: Default::default(),
+    namespace_names: Default::default(),
+  };
+  Some(bundler.bundle(entry_index))
+}
+
+/// The modules in the post order, so the dependencies are emitted before the dependents
+fn module_order<'a>(
+  graph: &'a DtsModuleGraph,
+  path: &'a Utf8Path,
+  visited: &mut FxHashSet<&'a Utf8Path>,
+  order: &mut Vec<&'a Utf8Path>,
+) {
+  if !visited.insert(path) {
+    return;
+  }
+  let Some(module) = graph.modules.get(path) else {
+    return;
+  };
+  for dependency in module.dependencies.values() {
+    if graph.modules.contains_key(dependency) {
+      module_order(graph, dependency, visited, order);
+    }
+  }
+  order.push(path);
+}
+
+struct Bundler<'a> {
+  modules: Vec<BundleModule<'a>>,
+  index: FxHashMap<&'a Utf8Path, usize>,
+  used_names: FxHashSet<Atom>,
+  local_names: FxHashMap<(usize, Atom), Atom>,
+  external_names: FxIndexMap<(Atom, Imported), Atom>,
+  namespace_names: FxIndexMap<usize, Atom>,
+}
+
+impl Bundler<'_> {
+  fn bundle(&mut self, entry: usize) -> Module {
+    // the declarations are named before the imported bindings, so they keep the names as
+    // long as possible
+    for (module_index, module) in self.modules.iter().enumerate() {
+      for local in &module.locals {
+        let name = unique_name(&mut self.used_names, local);
+        self.local_names.insert((module_index, local.clone()), name);
+      }
+    }
+
+    let mut body = vec![];
+    for module_index in 0..self.modules.len() {
+      let mut names = FxHashMap::default();
+      for local in &self.modules[module_index].locals {
+        names.insert(
+          local.clone(),
+          self.local_names[&(module_index, local.clone())].clone(),
+        );
+      }
+      let imports = self.modules[module_index]
+        .imports
+        .iter()
+        .map(|(local, (src, imported))| (local.clone(), src.clone(), imported.clone()))
+        .collect::<Vec<_>>();
+      for (local, src, imported) in imports {
+        let binding = self.resolve_import(module_index, &src, &imported, &mut Default::default());
+        let name = self.binding_name(&binding, &local);
+        names.insert(local, name);
+      }
+
+      let module = &mut self.modules[module_index];
+      let mut renamer = Renamer {
+        top_level_ctxt: module.dts.top_level_ctxt,
+        names: &names,
+      };
+      for mut item in std::mem::take(&mut module.items) {
+        item.visit_mut_with(&mut renamer);
+        body.push(item);
+      }
+    }
+
+    let mut specifiers = vec![];
+    let mut external_stars = FxIndexSet::default();
+    let mut export_names = FxIndexSet::default();
+    self.export_names(
+      entry,
+      true,
+      &mut Default::default(),
+      &mut export_names,
+      &mut external_stars,
+    );
+    for exported in export_names {
+      let Some(binding) = self.resolve_export(entry, &exported, &mut Default::default()) else {
+        continue;
+      };
+      let name = self.binding_name(&binding, &exported);
+      specifiers.push(ExportSpecifier::Named(ExportNamedSpecifier {
+        span: DUMMY_SP,
+        orig: ModuleExportName::Ident(Ident::new_no_ctxt(name.clone(), DUMMY_SP)),
+        exported: (name != exported)
+          .then(|| ModuleExportName::Ident(Ident::new_no_ctxt(exported, DUMMY_SP))),
+        is_type_only: false,
+      }));
+    }
+
+    // the namespaces may refer to the other namespaces, which are named on demand
+    let mut namespace_index = 0;
+    while let Some((&module_index, name)) = self.namespace_names.get_index(namespace_index) {
+      let name = name.clone();
+      body.push(self.namespace_decl(module_index, name));
+      namespace_index += 1;
+    }
+
+    let mut items = self.external_imports();
+    items.extend(body);
+    items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+      NamedExport {
+        span: DUMMY_SP,
+        specifiers,
+        src: None,
+        type_only: false,
+        with: None,
+      },
+    )));
+    for src in external_stars {
+      items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ExportAll {
+        span: DUMMY_SP,
+        src: Box::new(str_lit(&src)),
+        type_only: false,
+        with: None,
+      })));
+    }
+
+    Module {
+      span: DUMMY_SP,
+      body: items,
+      shebang: None,
+    }
+  }
+
+  fn dependency(&self, module_index: usize, src: &Atom) -> Option<usize> {
+    self.modules[module_index]
+      .dts
+      .dependencies
+      .get(src)
+      .and_then(|path| self.index.get(path.as_path()))
+      .copied()
+  }
+
+  fn resolve_local(
+    &self,
+    module_index: usize,
+    local: &Atom,
+    visited: &mut FxHashSet<(usize, Atom)>,
+  ) -> Binding {
+    match self.modules[module_index].imports.get(local) {
+      Some((src, imported)) => self.resolve_import(module_index, src, imported, visited),
+      None => Binding::Local(module_index, local.clone()),
+    }
+  }
+
+  fn resolve_import(
+    &self,
+    module_index: usize,
+    src: &Atom,
+    imported: &Imported,
+    visited: &mut FxHashSet<(usize, Atom)>,
+  ) -> Binding {
+    let Some(dependency) = self.dependency(module_index, src) else {
+      return Binding::External(src.clone(), imported.clone());
+    };
+    match imported {
+      Imported::Named(name) => self
+        .resolve_export(dependency, name, visited)
+        .unwrap_or_else(|| Binding::Local(dependency, name.clone())),
+      Imported::Namespace => Binding::Namespace(dependency),
+    }
+  }
+
+  fn resolve_export(
+    &self,
+    module_index: usize,
+    name: &Atom,
+    visited: &mut FxHashSet<(usize, Atom)>,
+  ) -> Option<Binding> {
+    if !visited.insert((module_index, name.clone())) {
+      return None;
+    }
+    let module = &self.modules[module_index];
+    if let Some(entry) = module.exports.get(name) {
+      return Some(match entry {
+        ExportEntry::Local(local) => self.resolve_local(module_index, local, visited),
+        ExportEntry::Reexport(src, imported) => {
+          self.resolve_import(module_index, src, imported, visited)
+        }
+      });
+    }
+    if name == "default" {
+      return None;
+    }
+    let mut external_star = None;
+    for src in &module.star_exports {
+      match self.dependency(module_index, src) {
+        Some(dependency) => {
+          if let Some(binding) = self.resolve_export(dependency, name, visited) {
+            return Some(binding);
+          }
+        }
+        None => {
+          external_star.get_or_insert(src);
+        }
+      }
+    }
+    // the name may be exported by the external module
+    external_star.map(|src| Binding::External(src.clone(), Imported::Named(name.clone())))
+  }
+
+  /// The exported names of the module, the `export *` from the external modules are
+  /// collected separately as they can't be resolved
+  fn export_names(
+    &self,
+    module_index: usize,
+    include_default: bool,
+    visited: &mut FxHashSet<usize>,
+    names: &mut FxIndexSet<Atom>,
+    external_stars: &mut FxIndexSet<Atom>,
+  ) {
+    if !visited.insert(module_index) {
+      return;
+    }
+    let module = &self.modules[module_index];
+    names.extend(
+      module
+        .exports
+        .keys()
+        .filter(|name| include_default || *name != "default")
+        .cloned(),
+    );
+    for src in &module.star_exports {
+      match self.dependency(module_index, src) {
+        Some(dependency) => self.export_names(dependency, false, visited, names, external_stars),
+        None => {
+          external_stars.insert(src.clone());
+        }
+      }
+    }
+  }
+
+  fn binding_name(&mut self, binding: &Binding, preferred: &Atom) -> Atom {
+    match binding {
+      Binding::Local(module_index, local) => self
+        .local_names
+        .entry((*module_index, local.clone()))
+        .or_insert_with(|| unique_name(&mut self.used_names, local))
+        .clone(),
+      Binding::External(src, imported) => self
+        .external_names
+        .entry((src.clone(), imported.clone()))
+        .or_insert_with(|| {
+          let preferred = match imported {
+            _ if preferred != "default" => preferred.clone(),
+            Imported::Named(name) if name != "default" => name.clone(),
+            _ => "_default".into(),
+          };
+          unique_name(&mut self.used_names, &preferred)
+        })
+        .clone(),
+      Binding::Namespace(module_index) => {
+        let preferred = if preferred == "default" {
+          "_namespace".into()
+        } else {
+          preferred.clone()
+        };
+        self
+          .namespace_names
+          .entry(*module_index)
+          .or_insert_with(|| unique_name(&mut self.used_names, &preferred))
+          .clone()
+      }
+    }
+  }
+
+  /// `declare namespace ns { export { a, b as c }; }` for the namespace object of an
+  /// internal module
+  fn namespace_decl(&mut self, module_index: usize, name: Atom) -> ModuleItem {
+    let mut export_names = FxIndexSet::default();
+    self.export_names(
+      module_index,
+      true,
+      &mut Default::default(),
+      &mut export_names,
+      &mut Default::default(),
+    );
+    let specifiers = export_names
+      .into_iter()
+      .filter_map(|exported| {
+        let binding = self.resolve_export(module_index, &exported, &mut Default::default())?;
+        let local = self.binding_name(&binding, &exported);
+        Some(ExportSpecifier::Named(ExportNamedSpecifier {
+          span: DUMMY_SP,
+          orig: ModuleExportName::Ident(Ident::new_no_ctxt(local.clone(), DUMMY_SP)),
+          exported: (local != exported)
+            .then(|| ModuleExportName::Ident(Ident::new_no_ctxt(exported, DUMMY_SP))),
+          is_type_only: false,
+        }))
+      })
+      .collect();
+    ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(Box::new(TsModuleDecl {
+      span: DUMMY_SP,
+      declare: true,
+      global: false,
+      namespace: true,
+      id: TsModuleName::Ident(Ident::new_no_ctxt(name, DUMMY_SP)),
+      body: Some(TsNamespaceBody::TsModuleBlock(TsModuleBlock {
+        span: DUMMY_SP,
+        body: vec![ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+          NamedExport {
+            span: DUMMY_SP,
+            specifiers,
+            src: None,
+            type_only: false,
+            with: None,
+          },
+        ))],
+      })),
+    }))))
+  }
+
+  /// The imports of the external modules, grouped by the requests
+  fn external_imports(&self) -> Vec<ModuleItem> {
+    let mut grouped: FxIndexMap<&Atom, (Vec<ImportSpecifier>, Vec<ImportSpecifier>)> =
+      Default::default();
+    for ((src, imported), local) in &self.external_names {
+      let local_ident = Ident::new_no_ctxt(local.clone(), DUMMY_SP);
+      let (specifiers, namespaces) = grouped.entry(src).or_default();
+      match imported {
+        Imported::Named(name) if name == "default" => {
+          specifiers.insert(
+            0,
+            ImportSpecifier::Default(ImportDefaultSpecifier {
+              span: DUMMY_SP,
+              local: local_ident,
+            }),
+          );
+        }
+        Imported::Named(name) => specifiers.push(ImportSpecifier::Named(ImportNamedSpecifier {
+          span: DUMMY_SP,
+          local: local_ident,
+          imported: (name != local)
+            .then(|| ModuleExportName::Ident(Ident::new_no_ctxt(name.clone(), DUMMY_SP))),
+          is_type_only: false,
+        })),
+        Imported::Namespace => namespaces.push(ImportSpecifier::Namespace(ImportStarAsSpecifier {
+          span: DUMMY_SP,
+          local: local_ident,
+        })),
+      }
+    }
+
+    let import_decl = |src: &Atom, specifiers: Vec<ImportSpecifier>| {
+      ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers,
+        src: Box::new(str_lit(src)),
+        type_only: false,
+        with: None,
+        phase: ImportPhase::default(),
+      }))
+    };
+    let mut items = vec![];
+    for (src, (specifiers, namespaces)) in grouped {
+      // a namespace import can't be combined with the named imports
+      for namespace in namespaces {
+        items.push(import_decl(src, vec![namespace]));
+      }
+      if !specifiers.is_empty() {
+        items.push(import_decl(src, specifiers));
+      }
+    }
+    items
+  }
+}
+
+fn unique_name(used_names: &mut FxHashSet<Atom>, preferred: &Atom) -> Atom {
+  let mut name = preferred.clone();
+  let mut index = 1;
+  while !used_names.insert(name.clone()) {
+    name = format!("{preferred}${index}").into();
+    index += 1;
+  }
+  name
+}
+
+fn str_lit(value: &Atom) -> Str {
+  Str {
+    span: DUMMY_SP,
+    value: Wtf8Atom::from(value.as_str()),
+    raw: None,
+  }
+}
+
+fn decl_names(decl: &Decl) -> Vec<Atom> {
+  match decl {
+    Decl::Class(class_decl) => vec![class_decl.ident.sym.clone()],
+    Decl::Fn(fn_decl) => vec![fn_decl.ident.sym.clone()],
+    Decl::Var(var_decl) => var_decl
+      .decls
+      .iter()
+      .filter_map(|declarator| match &declarator.name {
+        Pat::Ident(binding) => Some(binding.id.sym.clone()),
+        _ => None,
+      })
+      .collect(),
+    Decl::TsInterface(interface) => vec![interface.id.sym.clone()],
+    Decl::TsTypeAlias(type_alias) => vec![type_alias.id.sym.clone()],
+    Decl::TsEnum(enum_decl) => vec![enum_decl.id.sym.clone()],
+    Decl::TsModule(module_decl) => match &module_decl.id {
+      TsModuleName::Ident(ident) if !module_decl.global => vec![ident.sym.clone()],
+      _ => vec![],
+    },
+    Decl::Using(_) => vec![],
+  }
+}
+
+/// Rename the references to the top level bindings of a module to the names in the bundle
+struct Renamer<'a> {
+  top_level_ctxt: SyntaxContext,
+  names: &'a FxHashMap<Atom, Atom>,
+}
+
+impl VisitMut for Renamer<'_> {
+  fn visit_mut_ident(&mut self, ident: &mut Ident) {
+    if ident.ctxt == self.top_level_ctxt
+      && let Some(name) = self.names.get(&ident.sym)
+    {
+      ident.sym = name.clone();
+    }
+  }
+}
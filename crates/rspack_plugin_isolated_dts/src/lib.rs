@@ -0,0 +1,266 @@
+mod bundle;
+mod module;
+
+use std::{path::PathBuf, sync::Mutex};
+
+use rspack_core::{
+  AssetInfo, Compilation, CompilationAsset, CompilationProcessAssets, DependencyCategory, Plugin,
+  ResolveOptionsWithDependencyType,
+  rspack_sources::{RawStringSource, SourceExt},
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_swc_plugin_ts_collector::print_declarations;
+use swc_core::{
+  atoms::Wtf8Atom,
+  ecma::ast::{Module, ModuleDecl, ModuleItem},
+};
+
+use crate::{
+  bundle::bundle,
+  module::{DtsModuleGraph, is_internal},
+};
+
+#[derive(Debug)]
+pub struct IsolatedDtsPluginOptions {
+  /// Emit a declaration file for each module under this directory instead of bundling the
+  /// declarations per entry, which follows the layout of `preserveModules` of the
+  /// EsmLibraryPlugin
+  pub preserve_modules: Option<PathBuf>,
+}
+
+/// Emit the declaration files of the TypeScript modules with isolated declarations, the
+/// declarations are generated from the sources without type checking
+#[plugin]
+#[derive(Debug)]
+pub struct IsolatedDtsPlugin {
+  options: IsolatedDtsPluginOptions,
+  graph: Mutex<DtsModuleGraph>,
+}
+
+impl IsolatedDtsPlugin {
+  pub fn new(options: IsolatedDtsPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+}
+
+impl Plugin for IsolatedDtsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.IsolatedDtsPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
+
+#[plugin_hook(CompilationProcessAssets for IsolatedDtsPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let resolver = compilation
+    .resolver_factory
+    .get(ResolveOptionsWithDependencyType {
+      resolve_options: None,
+      resolve_to_context: false,
+      dependency_category: DependencyCategory::Esm,
+    });
+  let mut graph = std::mem::take(&mut *self.graph.lock().expect("should lock"));
+  invalidate_changed_files(compilation, &mut graph);
+
+  let mut entries = vec![];
+  if let Some(root) = &self.options.preserve_modules {
+    for path in typescript_modules(compilation) {
+      if path.as_std_path().starts_with(root) {
+        graph.load(compilation, &resolver, &path).await;
+      }
+    }
+  } else {
+    for (name, entrypoint_ukey) in &compilation.entrypoints {
+      let Some(entry_path) = entry_module(compilation, name) else {
+        continue;
+      };
+      let entry_chunk = compilation
+        .chunk_group_by_ukey
+        .expect_get(entrypoint_ukey)
+        .get_entrypoint_chunk();
+      let mut files = compilation
+        .chunk_by_ukey
+        .expect_get(&entry_chunk)
+        .files()
+        .iter()
+        .filter(|file| file.ends_with(".js") || file.ends_with(".mjs") || file.ends_with(".cjs"))
+        .collect::<Vec<_>>();
+      files.sort();
+      let Some(file) = files.first() else {
+        continue;
+      };
+      graph.load(compilation, &resolver, &entry_path).await;
+      entries.push((
+        declaration_filename(Utf8Path::new(file.as_str())),
+        entry_path,
+      ));
+    }
+  }
+  // the modules that are no longer loaded are removed from the graph
+  let diagnostics = graph.finish();
+
+  let mut assets = vec![];
+  if let Some(root) = &self.options.preserve_modules {
+    for (path, dts) in &graph.modules {
+      let Ok(relative) = path.as_std_path().strip_prefix(root) else {
+        continue;
+      };
+      let Some(relative) = Utf8Path::from_path(relative) else {
+        continue;
+      };
+      let mut module = dts.module.clone();
+      rewrite_requests(&mut module);
+      assets.push((
+        declaration_filename(relative),
+        print_declarations(&module, Default::default()),
+      ));
+    }
+  }
+  for (filename, entry_path) in entries {
+    if let Some(module) = bundle(&graph, &entry_path) {
+      assets.push((filename, print_declarations(&module, Default::default())));
+    }
+  }
+
+  for (filename, code) in assets {
+    compilation.emit_asset(
+      filename,
+      CompilationAsset::new(
+        Some(RawStringSource::from(code).boxed()),
+        AssetInfo::default(),
+      ),
+    );
+  }
+  // the type only dependencies are not in the module graph, watch them as well
+  compilation.file_dependencies.extend(
+    graph
+      .modules
+      .keys()
+      .map(|path| path.clone().into_std_path_buf().into()),
+  );
+  compilation.extend_diagnostics(diagnostics);
+  *self.graph.lock().expect("should lock") = graph;
+  Ok(())
+}
+
+/// Invalidate the declarations of the changed files, which are the TypeScript modules built
+/// in this compilation and the changed files reported by the watcher, as the type only
+/// dependencies are not in the module graph
+fn invalidate_changed_files(compilation: &Compilation, graph: &mut DtsModuleGraph) {
+  let module_graph = compilation.get_module_graph();
+  let built_modules = compilation
+    .build_module_graph_artifact
+    .built_modules()
+    .filter_map(|module_id| module_graph.module_by_identifier(module_id))
+    .filter_map(|module| {
+      let path = module.as_normal_module()?.resource_resolved_data().path()?;
+      is_internal(path).then(|| path.to_path_buf())
+    })
+    .collect::<Vec<_>>();
+  for path in built_modules {
+    graph.invalidate(&path, false);
+  }
+  for path in compilation.modified_files.iter() {
+    if let Some(path) = Utf8Path::from_path(path) {
+      graph.invalidate(path, false);
+    }
+  }
+  for path in compilation.removed_files.iter() {
+    if let Some(path) = Utf8Path::from_path(path) {
+      graph.invalidate(path, true);
+    }
+  }
+}
+
+/// The TypeScript sources of the modules in the chunks
+fn typescript_modules(compilation: &Compilation) -> Vec<Utf8PathBuf> {
+  let module_graph = compilation.get_module_graph();
+  let mut paths = module_graph
+    .modules()
+    .iter()
+    .filter(|(module_id, _)| {
+      !compilation
+        .chunk_graph
+        .get_module_chunks(**module_id)
+        .is_empty()
+    })
+    .filter_map(|(_, module)| {
+      let path = module.as_normal_module()?.resource_resolved_data().path()?;
+      is_internal(path).then(|| path.to_path_buf())
+    })
+    .collect::<Vec<_>>();
+  paths.sort();
+  paths.dedup();
+  paths
+}
+
+/// The TypeScript source of the entry, the first one is used if there are multiple modules
+fn entry_module(compilation: &Compilation, name: &str) -> Option<Utf8PathBuf> {
+  let module_graph = compilation.get_module_graph();
+  compilation
+    .entries
+    .get(name)?
+    .all_dependencies()
+    .filter_map(|dep_id| module_graph.module_identifier_by_dependency_id(dep_id))
+    .filter_map(|module_id| module_graph.module_by_identifier(module_id))
+    .find_map(|module| {
+      let path = module.as_normal_module()?.resource_resolved_data().path()?;
+      is_internal(path).then(|| path.to_path_buf())
+    })
+}
+
+/// `index.ts` => `index.d.ts`, `index.mjs` => `index.d.mts`
+fn declaration_filename(path: &Utf8Path) -> String {
+  let extension = match path.extension() {
+    Some("mts" | "mjs") => "d.mts",
+    Some("cts" | "cjs") => "d.cts",
+    _ => "d.ts",
+  };
+  path
+    .with_extension(extension)
+    .components()
+    .map(|component| component.as_str())
+    .filter(|component| *component != ".")
+    .collect::<Vec<_>>()
+    .join("/")
+}
+
+/// `./a.ts` => `./a.js`, as the declarations of the preserved modules are emitted next to the
+/// JavaScript files
+fn rewrite_requests(module: &mut Module) {
+  for item in &mut module.body {
+    let src = match item {
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => &mut import.src,
+      ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export_all)) => &mut export_all.src,
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) => {
+        let Some(src) = &mut named_export.src else {
+          continue;
+        };
+        src
+      }
+      _ => continue,
+    };
+    let request = src.value.to_atom_lossy().into_owned();
+    if !request.starts_with('.') {
+      continue;
+    }
+    let request = Utf8Path::new(request.as_str());
+    let extension = match request.extension() {
+      Some("ts" | "tsx") => "js",
+      Some("mts") => "mjs",
+      Some("cts") => "cjs",
+      _ => continue,
+    };
+    src.value = Wtf8Atom::from(request.with_extension(extension).as_str());
+    src.raw = None;
+  }
+}
@@ -0,0 +1,296 @@
+use std::sync::Arc;
+
+use rspack_core::{Compilation, ResolveResult, Resolver};
+use rspack_error::{Diagnostic, Error};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_swc_plugin_ts_collector::isolated_declarations;
+use rspack_util::fx_hash::{FxHashMap, FxHashSet, FxIndexMap};
+use swc_core::{
+  atoms::Atom,
+  common::{
+    DUMMY_SP, FileName, GLOBALS, Globals, Mark, SourceFile, SourceMap, Span, Spanned, SyntaxContext,
+  },
+  ecma::{
+    ast::{EsVersion, ModuleDecl, ModuleItem},
+    parser::{Syntax, TsSyntax, parse_file_as_module},
+    transforms::base::resolver,
+    visit::{VisitMut, VisitMutWith},
+  },
+};
+
+/// The declarations of a TypeScript module
+#[derive(Debug)]
+pub(crate) struct DtsModule {
+  pub(crate) module: swc_core::ecma::ast::Module,
+  /// The syntax context of the references to the top level bindings
+  pub(crate) top_level_ctxt: SyntaxContext,
+  /// The internal modules resolved from the requests of the import and export declarations,
+  /// the other requests are external
+  pub(crate) dependencies: FxIndexMap<Atom, Utf8PathBuf>,
+  /// The errors of the isolated declarations
+  diagnostics: Vec<Diagnostic>,
+}
+
+/// The declarations of the TypeScript modules reachable from the modules of the compilation.
+///
+/// The type only imports are removed from the module graph of the compilation, so the
+/// requests in the declarations are resolved again here.
+///
+/// The graph is kept across compilations, only the modules of the changed files are loaded
+/// again. Each file is parsed with its own source map, which is dropped after the spans of
+/// the declarations are removed, so the graph doesn't keep the sources of the old files.
+pub(crate) struct DtsModuleGraph {
+  globals: Globals,
+  pub(crate) modules: FxIndexMap<Utf8PathBuf, DtsModule>,
+  /// The modules that can't be read or parsed, with the errors
+  failed: FxHashMap<Utf8PathBuf, Vec<Diagnostic>>,
+  /// The modules loaded in the current compilation
+  loaded: FxHashSet<Utf8PathBuf>,
+}
+
+impl std::fmt::Debug for DtsModuleGraph {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("DtsModuleGraph")
+      .field("modules", &self.modules)
+      .field("failed", &self.failed)
+      .field("loaded", &self.loaded)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Default for DtsModuleGraph {
+  fn default() -> Self {
+    Self {
+      globals: Globals::new(),
+      modules: Default::default(),
+      failed: Default::default(),
+      loaded: Default::default(),
+    }
+  }
+}
+
+impl DtsModuleGraph {
+  /// Remove the module of the changed file, and the modules depending on it if the file is
+  /// removed, as their requests may be resolved to other files
+  pub(crate) fn invalidate(&mut self, path: &Utf8Path, removed: bool) {
+    self.modules.shift_remove(path);
+    self.failed.remove(path);
+    if removed {
+      self
+        .modules
+        .retain(|_, module| !module.dependencies.values().any(|dep| dep == path));
+    }
+  }
+
+  /// Remove the modules that are not loaded in the current compilation, and take the errors
+  /// of the loaded modules
+  pub(crate) fn finish(&mut self) -> Vec<Diagnostic> {
+    let loaded = std::mem::take(&mut self.loaded);
+    self.modules.retain(|path, _| loaded.contains(path));
+    self.failed.retain(|path, _| loaded.contains(path));
+    self
+      .modules
+      .values()
+      .flat_map(|module| module.diagnostics.iter())
+      .chain(self.failed.values().flatten())
+      .cloned()
+      .collect()
+  }
+
+  /// Load the declarations of the module and the internal modules it depends on
+  pub(crate) async fn load(
+    &mut self,
+    compilation: &Compilation,
+    resolver: &Resolver,
+    path: &Utf8Path,
+  ) {
+    let mut queue = vec![path.to_path_buf()];
+    while let Some(path) = queue.pop() {
+      if !self.loaded.insert(path.clone()) {
+        continue;
+      }
+      if let Some(module) = self.modules.get(&path) {
+        queue.extend(module.dependencies.values().cloned());
+        continue;
+      }
+      if self.failed.contains_key(&path) {
+        continue;
+      }
+      let source = match compilation.input_filesystem.read(&path).await {
+        Ok(source) => String::from_utf8_lossy(&source).into_owned(),
+        Err(err) => {
+          let mut diagnostic = Diagnostic::from(Error::from(err));
+          diagnostic.file = Some(path.clone());
+          self.failed.insert(path, vec![diagnostic]);
+          continue;
+        }
+      };
+      let mut diagnostics = vec![];
+      let Some((module, top_level_ctxt)) = self.transform(&path, source, &mut diagnostics) else {
+        self.failed.insert(path, diagnostics);
+        continue;
+      };
+
+      let mut dependencies = FxIndexMap::default();
+      let context = path.parent().unwrap_or(&path);
+      for request in requests(&module) {
+        if dependencies.contains_key(&request) {
+          continue;
+        }
+        // the requests that can't be resolved are kept as external
+        if let Ok(ResolveResult::Resource(resource)) =
+          resolver.resolve(context.as_std_path(), &request).await
+          && is_internal(&resource.path)
+        {
+          queue.push(resource.path.clone());
+          dependencies.insert(request, resource.path);
+        }
+      }
+      self.modules.insert(
+        path,
+        DtsModule {
+          module,
+          top_level_ctxt,
+          dependencies,
+          diagnostics,
+        },
+      );
+    }
+  }
+
+  fn transform(
+    &mut self,
+    path: &Utf8Path,
+    source: String,
+    diagnostics: &mut Vec<Diagnostic>,
+  ) -> Option<(swc_core::ecma::ast::Module, SyntaxContext)> {
+    let cm: Arc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(
+      Arc::new(FileName::Real(path.as_std_path().to_path_buf())),
+      source.clone(),
+    );
+    let syntax = Syntax::Typescript(TsSyntax {
+      tsx: path.extension() == Some("tsx"),
+      ..Default::default()
+    });
+    let mut errors = vec![];
+    let module = parse_file_as_module(&fm, syntax, EsVersion::EsNext, None, &mut errors);
+    let module = match module {
+      Ok(module) if errors.is_empty() => module,
+      Ok(_) => {
+        for error in errors {
+          let message = error.kind().msg().to_string();
+          push_error(
+            diagnostics,
+            path,
+            &fm,
+            &source,
+            error.span(),
+            "TypeScript parse error",
+            message,
+          );
+        }
+        return None;
+      }
+      Err(error) => {
+        let message = error.kind().msg().to_string();
+        push_error(
+          diagnostics,
+          path,
+          &fm,
+          &source,
+          error.span(),
+          "TypeScript parse error",
+          message,
+        );
+        return None;
+      }
+    };
+
+    let (mut dts, violations) = isolated_declarations(&module);
+    for violation in violations {
+      push_error(
+        diagnostics,
+        path,
+        &fm,
+        &source,
+        violation.span,
+        "Isolated declarations error",
+        violation.message.to_string(),
+      );
+    }
+
+    let top_level_ctxt = GLOBALS.set(&self.globals, || {
+      let unresolved_mark = Mark::new();
+      let top_level_mark = Mark::new();
+      dts.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, true));
+      SyntaxContext::empty().apply_mark(top_level_mark)
+    });
+    // the spans point to the source map of this file, the declarations are printed without it
+    dts.visit_mut_with(&mut DropSpan);
+    Some((dts, top_level_ctxt))
+  }
+}
+
+struct DropSpan;
+
+impl VisitMut for DropSpan {
+  fn visit_mut_span(&mut self, span: &mut Span) {
+    *span = DUMMY_SP;
+  }
+}
+
+fn push_error(
+  diagnostics: &mut Vec<Diagnostic>,
+  path: &Utf8Path,
+  fm: &SourceFile,
+  source: &str,
+  span: Span,
+  title: &str,
+  message: String,
+) {
+  let start = span.lo.0.saturating_sub(fm.start_pos.0) as usize;
+  let end = span.hi.0.saturating_sub(fm.start_pos.0) as usize;
+  let error = Error::from_string(
+    Some(source.to_string()),
+    start,
+    end,
+    title.to_string(),
+    message,
+  );
+  let mut diagnostic = Diagnostic::from(error);
+  diagnostic.file = Some(path.to_path_buf());
+  diagnostics.push(diagnostic);
+}
+
+/// The requests of the import and export declarations in the source order
+fn requests(module: &swc_core::ecma::ast::Module) -> Vec<Atom> {
+  module
+    .body
+    .iter()
+    .filter_map(|item| match item {
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => Some(&import.src),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) => named_export.src.as_ref(),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export_all)) => Some(&export_all.src),
+      _ => None,
+    })
+    .map(|src| src.value.to_atom_lossy().into_owned())
+    .collect()
+}
+
+/// Whether the declarations of the file are emitted from its TypeScript source, the
+/// declaration files and the packages are external
+pub(crate) fn is_internal(path: &Utf8Path) -> bool {
+  if path.components().any(|c| c.as_str() == "node_modules") {
+    return false;
+  }
+  let Some(extension) = path.extension() else {
+    return false;
+  };
+  if !matches!(extension, "ts" | "tsx" | "mts" | "cts") {
+    return false;
+  }
+  !path
+    .file_stem()
+    .is_some_and(|stem| stem.ends_with(".d") || stem.contains(".d."))
+}
@@ -26,7 +26,10 @@ use swc_node_comments::SwcComments;
 use crate::{
   BoxJavascriptParserPlugin,
   dependency::ESMCompatibilityDependency,
-  visitors::{ScanDependenciesResult, scan_dependencies, semicolon, swc_visitor::resolver},
+  visitors::{
+    ScanDependenciesResult, scan_dependencies, semicolon, swc_visitor::resolver,
+    transpiled_enum::TranspiledEnumCollector,
+  },
 };
 
 fn module_type_to_is_module(value: &ModuleType) -> IsModule {
@@ -230,6 +233,28 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
 
     let unresolved_mark = ast.get_context().unresolved_mark;
 
+    // collect the exported enums from the transpiled code if the loader doesn't collect them
+    let exported_enum = module_parser_options
+      .and_then(|options| options.get_javascript())
+      .and_then(|options| options.exported_enum)
+      .unwrap_or(false);
+    if exported_enum
+      && build_info.collected_typescript_info.is_none()
+      && resource_data
+        .path()
+        .and_then(|path| path.extension())
+        .is_some_and(|ext| matches!(ext, "ts" | "tsx" | "mts" | "cts"))
+    {
+      let mut collector = TranspiledEnumCollector::default();
+      ast.visit(|program, _| program.visit_with(&mut collector));
+      if !collector.exported_enums.is_empty() {
+        build_info.collected_typescript_info = Some(CollectedTypeScriptInfo {
+          type_exports: Default::default(),
+          exported_enums: collector.exported_enums,
+        });
+      }
+    }
+
     let ScanDependenciesResult {
       dependencies,
       blocks,
@@ -190,6 +190,11 @@ async fn finish_modules(
       modules.len(),
       compilation.get_module_graph().modules().len()
     ));
+    compilation.incremental.explain_pass(
+      IncrementalPasses::PROVIDED_EXPORTS,
+      modules.len(),
+      compilation.get_module_graph().modules().len(),
+    );
     modules
   } else {
     compilation
@@ -77,6 +77,11 @@ async fn finish_modules(
       "{} modules are updated by set_async",
       mutations.len()
     ));
+    compilation.incremental.explain_pass(
+      IncrementalPasses::INFER_ASYNC_MODULES,
+      mutations.len(),
+      compilation.get_module_graph().modules().len(),
+    );
   }
 
   if let Some(mut compilation_mutations) = compilation.incremental.mutations_write()
@@ -238,6 +238,11 @@ async fn optimize_dependencies(
       modules.len(),
       all_modules.len()
     ));
+    compilation.incremental.explain_pass(
+      IncrementalPasses::SIDE_EFFECTS,
+      modules.len(),
+      all_modules.len(),
+    );
 
     modules
   } else {
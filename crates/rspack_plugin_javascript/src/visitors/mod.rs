@@ -2,5 +2,6 @@ mod dependency;
 pub mod scope_info;
 pub mod semicolon;
 pub mod swc_visitor;
+pub(crate) mod transpiled_enum;
 
 pub use self::{JavascriptParser, dependency::*};
@@ -0,0 +1,217 @@
+use rspack_core::{EvaluatedInlinableValue, TSEnumValue};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::{
+  atoms::Atom,
+  ecma::{
+    ast::{
+      AssignExpr, AssignTarget, BinaryOp, BlockStmtOrExpr, CallExpr, Callee, Decl, ExportSpecifier,
+      Expr, Lit, MemberExpr, MemberProp, ModuleDecl, ModuleItem, Pat, Program, SimpleAssignTarget,
+      Stmt, UnaryOp, VarDecl,
+    },
+    visit::Visit,
+  },
+};
+
+type EnumMembers = FxHashMap<Atom, Option<EvaluatedInlinableValue>>;
+
+/// Collect the exported enums from the code transpiled from TypeScript, so the enums can be
+/// inlined across modules no matter which loader transpiles the TypeScript code.
+///
+/// The enums are recognized by the code emitted by tsc, swc, babel and esbuild:
+///
+/// ```js
+/// export var E;
+/// (function (E) {
+///   E[E["A"] = 0] = "A";
+///   E["B"] = "b";
+/// })(E || (E = {}));
+///
+/// export var F = /* @__PURE__ */ ((F2) => {
+///   F2[F2["A"] = 0] = "A";
+///   return F2;
+/// })(F || {});
+/// ```
+#[derive(Debug, Default)]
+pub(crate) struct TranspiledEnumCollector {
+  pub(crate) exported_enums: FxHashMap<Atom, TSEnumValue>,
+}
+
+impl Visit for TranspiledEnumCollector {
+  fn visit_program(&mut self, node: &Program) {
+    let Program::Module(node) = node else {
+      return;
+    };
+    let mut export_idents: FxHashSet<Atom> = Default::default();
+    let mut collected: FxHashMap<Atom, EnumMembers> = Default::default();
+    for item in &node.body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+          if let Decl::Var(var_decl) = &export_decl.decl {
+            for declarator in &var_decl.decls {
+              if let Pat::Ident(name) = &declarator.name {
+                export_idents.insert(name.id.sym.clone());
+              }
+            }
+            collect_var_decl(var_decl, &mut collected);
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) => {
+          if named_export.src.is_some() {
+            continue;
+          }
+          for specifier in &named_export.specifiers {
+            if let ExportSpecifier::Named(specifier) = specifier {
+              export_idents.insert(specifier.orig.atom().into_owned());
+            }
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(expr)) => {
+          if let Some(ident) = expr.expr.as_ident() {
+            export_idents.insert(ident.sym.clone());
+          }
+        }
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+          collect_var_decl(var_decl, &mut collected);
+        }
+        ModuleItem::Stmt(Stmt::Expr(expr_stmt)) => {
+          // (function (E) { ... })(E || (E = {}))
+          if let Some(call) = expr_stmt.expr.as_call()
+            && let Some(first) = call.args.first()
+            && first.spread.is_none()
+            && let Some(bin) = first.expr.as_bin()
+            && matches!(bin.op, BinaryOp::LogicalOr)
+            && let Some(enum_id) = bin.left.as_ident()
+          {
+            collect_enum_iife(call, &enum_id.sym, &mut collected);
+          }
+        }
+        _ => {}
+      }
+    }
+    self.exported_enums = collected
+      .into_iter()
+      .filter(|(enum_id, _)| export_idents.contains(enum_id))
+      .map(|(enum_id, members)| (enum_id, TSEnumValue::new(members)))
+      .collect();
+  }
+}
+
+/// `var E = (function (E) { ... })({})`
+fn collect_var_decl(var_decl: &VarDecl, collected: &mut FxHashMap<Atom, EnumMembers>) {
+  for declarator in &var_decl.decls {
+    if let Pat::Ident(name) = &declarator.name
+      && let Some(call) = declarator.init.as_deref().and_then(Expr::as_call)
+    {
+      collect_enum_iife(call, &name.id.sym, collected);
+    }
+  }
+}
+
+/// Collect the members of an enum from the IIFE that initializes it, the enum is skipped if
+/// there are statements other than the member assignments in the IIFE
+fn collect_enum_iife(
+  call: &CallExpr,
+  enum_id: &Atom,
+  collected: &mut FxHashMap<Atom, EnumMembers>,
+) -> Option<()> {
+  let Callee::Expr(callee) = &call.callee else {
+    return None;
+  };
+  let (param, stmts) = match &**callee {
+    Expr::Fn(fn_expr) => {
+      let [param] = fn_expr.function.params.as_slice() else {
+        return None;
+      };
+      (&param.pat, &fn_expr.function.body.as_ref()?.stmts)
+    }
+    Expr::Arrow(arrow) => {
+      let [param] = arrow.params.as_slice() else {
+        return None;
+      };
+      let BlockStmtOrExpr::BlockStmt(block) = &*arrow.body else {
+        return None;
+      };
+      (param, &block.stmts)
+    }
+    _ => return None,
+  };
+  let param = &param.as_ident()?.id.sym;
+
+  let mut members = EnumMembers::default();
+  for stmt in stmts {
+    match stmt {
+      Stmt::Expr(expr_stmt) => {
+        let (key, value) = enum_member(expr_stmt.expr.as_assign()?, param)?;
+        members.insert(key, value);
+      }
+      Stmt::Return(return_stmt)
+        if return_stmt
+          .arg
+          .as_deref()
+          .and_then(Expr::as_ident)
+          .is_some_and(|ident| &ident.sym == param) => {}
+      _ => return None,
+    }
+  }
+  if members.is_empty() {
+    return None;
+  }
+  // merge the members of the enums with the same name
+  collected
+    .entry(enum_id.clone())
+    .or_default()
+    .extend(members);
+  Some(())
+}
+
+/// `E[E["A"] = 0] = "A"` for numeric members and `E["B"] = "b"` for string members
+fn enum_member(
+  assign: &AssignExpr,
+  param: &Atom,
+) -> Option<(Atom, Option<EvaluatedInlinableValue>)> {
+  let prop = enum_member_target(&assign.left, param)?;
+  if let MemberProp::Computed(computed) = prop
+    && let Some(inner) = computed.expr.as_assign()
+  {
+    let key = enum_member_key(enum_member_target(&inner.left, param)?)?;
+    let value = match &*inner.right {
+      Expr::Lit(Lit::Num(num)) => Some(EvaluatedInlinableValue::new_number(num.value)),
+      Expr::Unary(unary) if matches!(unary.op, UnaryOp::Minus) => match &*unary.arg {
+        Expr::Lit(Lit::Num(num)) => Some(EvaluatedInlinableValue::new_number(-num.value)),
+        _ => None,
+      },
+      _ => None,
+    };
+    return Some((key, value));
+  }
+  let key = enum_member_key(prop)?;
+  let value = match &*assign.right {
+    Expr::Lit(Lit::Str(str)) => Some(EvaluatedInlinableValue::new_string(
+      str.value.to_atom_lossy().into_owned(),
+    )),
+    _ => None,
+  };
+  Some((key, value))
+}
+
+fn enum_member_target<'a>(target: &'a AssignTarget, param: &Atom) -> Option<&'a MemberProp> {
+  let AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr { obj, prop, .. })) = target
+  else {
+    return None;
+  };
+  if &obj.as_ident()?.sym != param {
+    return None;
+  }
+  Some(prop)
+}
+
+fn enum_member_key(prop: &MemberProp) -> Option<Atom> {
+  match prop {
+    MemberProp::Ident(ident) => Some(ident.sym.clone()),
+    MemberProp::Computed(computed) => match &*computed.expr {
+      Expr::Lit(Lit::Str(str)) => Some(str.value.to_atom_lossy().into_owned()),
+      _ => None,
+    },
+    MemberProp::PrivateName(_) => None,
+  }
+}
@@ -0,0 +1,591 @@
+//! Parsers of the data formats which are converted to JSON, so that YAML and TOML modules
+//! share the exports info and code generation of JSON modules.
+
+use json::{JsonValue, number::Number, object::Object};
+use jsonc_parser::{CollectOptions, CommentCollectionStrategy, ParseOptions, ast, parse_to_ast};
+use rspack_cacheable::cacheable;
+use rspack_core::JsonSyntax;
+use rspack_error::{Error, Result, error};
+use rspack_util::fx_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use yaml_rust2::{
+  Event, Yaml,
+  parser::{MarkedEventReceiver, Parser, Tag},
+  scanner::{Marker, TScalarStyle},
+};
+
+#[cacheable]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFormat {
+  Json,
+  Yaml,
+  Toml,
+}
+
+impl DataFormat {
+  pub fn error_title(&self) -> &'static str {
+    match self {
+      DataFormat::Json => "JSON parse error",
+      DataFormat::Yaml => "YAML parse error",
+      DataFormat::Toml => "TOML parse error",
+    }
+  }
+}
+
+/// Parse the JSONC or JSON5 `content`, the `offset` is the byte offset of `content` in `source`,
+/// which is used to point the diagnostics to the original source.
+pub fn parse_jsonc(
+  source: &str,
+  content: &str,
+  offset: usize,
+  syntax: JsonSyntax,
+) -> Result<JsonValue> {
+  let to_error = |start: usize, end: usize, message: String| {
+    Error::from_string(
+      Some(source.to_string()),
+      start + offset,
+      end.max(start + 1) + offset,
+      DataFormat::Json.error_title().to_string(),
+      message,
+    )
+  };
+  let json5 = matches!(syntax, JsonSyntax::Json5);
+  let result = parse_to_ast(
+    content,
+    &CollectOptions {
+      comments: CommentCollectionStrategy::Off,
+      tokens: false,
+    },
+    &ParseOptions {
+      allow_comments: true,
+      allow_trailing_commas: true,
+      allow_missing_commas: false,
+      allow_loose_object_property_names: json5,
+      allow_single_quoted_strings: json5,
+      allow_hexadecimal_numbers: json5,
+      allow_unary_plus_numbers: json5,
+      allow_bare_decimal_point_numbers: json5,
+      allow_non_finite_numbers: json5,
+      allow_extended_string_escapes: json5,
+    },
+  )
+  .map_err(|e| {
+    let range = e.range();
+    to_error(range.start, range.end, e.kind().to_string())
+  })?;
+  let Some(value) = result.value else {
+    return Err(to_error(
+      content.len(),
+      content.len(),
+      "Unexpected end of JSON".to_string(),
+    ));
+  };
+  jsonc_to_json(value, json5, &to_error)
+}
+
+fn jsonc_to_json(
+  value: ast::Value<'_>,
+  json5: bool,
+  to_error: &impl Fn(usize, usize, String) -> Error,
+) -> Result<JsonValue> {
+  Ok(match value {
+    ast::Value::NullKeyword(_) => JsonValue::Null,
+    ast::Value::BooleanLit(lit) => JsonValue::Boolean(lit.value),
+    ast::Value::StringLit(lit) => JsonValue::String(lit.value.into_owned()),
+    ast::Value::NumberLit(lit) => {
+      let range = lit.range;
+      let number = if json5 {
+        json5_number(lit.value)
+      } else {
+        json_number(lit.value).ok_or_else(|| format!("Unsupported number {}", lit.value))
+      };
+      number.map_err(|message| to_error(range.start, range.end, message))?
+    }
+    ast::Value::Array(array) => JsonValue::Array(
+      array
+        .elements
+        .into_iter()
+        .map(|element| jsonc_to_json(element, json5, to_error))
+        .collect::<Result<Vec<_>>>()?,
+    ),
+    ast::Value::Object(object) => {
+      let mut result = Object::with_capacity(object.properties.len());
+      for prop in object.properties {
+        let key = prop.name.into_string();
+        result.insert(&key, jsonc_to_json(prop.value, json5, to_error)?);
+      }
+      JsonValue::Object(result)
+    }
+  })
+}
+
+/// Reuse the number parsing of JSON to keep the same precision as strict JSON.
+fn json_number(raw: &str) -> Option<JsonValue> {
+  json::parse(raw).ok().filter(JsonValue::is_number)
+}
+
+/// JSON5 numbers additionally allow hexadecimal integers, `Infinity`, `NaN`, a leading `+`
+/// and a leading or trailing decimal point, such as `0xFF`, `+1`, `.5` and `5.`.
+fn json5_number(raw: &str) -> std::result::Result<JsonValue, String> {
+  let (negative, unsigned) = match raw.as_bytes().first() {
+    Some(b'-') => (true, &raw[1..]),
+    Some(b'+') => (false, &raw[1..]),
+    _ => (false, raw),
+  };
+  if let Some(hex) = unsigned
+    .strip_prefix("0x")
+    .or_else(|| unsigned.strip_prefix("0X"))
+  {
+    let value = u64::from_str_radix(hex, 16).map_err(|_| format!("Unsupported number {raw}"))?;
+    return Ok(match i64::try_from(value) {
+      Ok(value) => JsonValue::Number(Number::from(if negative { -value } else { value })),
+      Err(_) => finite_number(if negative {
+        -(value as f64)
+      } else {
+        value as f64
+      })?,
+    });
+  }
+  if unsigned == "Infinity" || unsigned == "NaN" {
+    return Err(format!("{raw} can not be represented in JSON"));
+  }
+  // complete the omitted zeros around the decimal point to get a strict JSON number
+  let (mantissa, exponent) = unsigned
+    .find(['e', 'E'])
+    .map_or((unsigned, ""), |i| unsigned.split_at(i));
+  let mut normalized = String::with_capacity(raw.len() + 2);
+  if negative {
+    normalized.push('-');
+  }
+  if mantissa.starts_with('.') {
+    normalized.push('0');
+  }
+  normalized.push_str(mantissa);
+  if mantissa.ends_with('.') {
+    normalized.push('0');
+  }
+  normalized.push_str(exponent);
+  json_number(&normalized).ok_or_else(|| format!("Unsupported number {raw}"))
+}
+
+/// Parse the YAML `content`, the `offset` is the byte offset of `content` in `source`,
+/// which is used to point the diagnostics to the original source.
+pub fn parse_yaml(source: &str, content: &str, offset: usize) -> Result<JsonValue> {
+  let to_error = |marker: Marker, message: String| {
+    // the index of marker is counted in chars
+    let start = content
+      .char_indices()
+      .nth(marker.index())
+      .map_or(content.len(), |(i, _)| i)
+      + offset;
+    Error::from_string(
+      Some(source.to_string()),
+      start,
+      start + 1,
+      DataFormat::Yaml.error_title().to_string(),
+      message,
+    )
+  };
+  let mut receiver = YamlToJson::default();
+  Parser::new_from_str(content)
+    .load(&mut receiver, true)
+    .map_err(|e| to_error(*e.marker(), e.info().to_string()))?;
+  if let Some((marker, message)) = receiver.error {
+    return Err(to_error(marker, message));
+  }
+  Ok(receiver.value.unwrap_or(JsonValue::Null))
+}
+
+enum YamlContainer {
+  Array(Vec<JsonValue>, usize),
+  Object {
+    object: Object,
+    // the pending key is set when the key of an entry is received
+    key: Option<YamlKey>,
+    // the keys merged by `<<`, which can be overridden by the explicit keys
+    merged: HashSet<String>,
+    anchor_id: usize,
+  },
+}
+
+enum YamlKey {
+  Name(String),
+  /// The merge key `<<` of YAML 1.1, see <https://yaml.org/type/merge.html>.
+  Merge,
+}
+
+/// Converts the YAML events to JSON directly, so that the errors can be pointed to the markers of events.
+#[derive(Default)]
+struct YamlToJson {
+  documents: usize,
+  stack: Vec<YamlContainer>,
+  anchors: HashMap<usize, JsonValue>,
+  value: Option<JsonValue>,
+  error: Option<(Marker, String)>,
+}
+
+impl MarkedEventReceiver for YamlToJson {
+  fn on_event(&mut self, event: Event, marker: Marker) {
+    if self.error.is_some() {
+      return;
+    }
+    if let Err(message) = self.on_event_impl(event) {
+      self.error = Some((marker, message));
+    }
+  }
+}
+
+impl YamlToJson {
+  fn on_event_impl(&mut self, event: Event) -> std::result::Result<(), String> {
+    match event {
+      Event::DocumentStart => {
+        self.documents += 1;
+        if self.documents > 1 {
+          return Err("Multiple documents in a single YAML file are not supported".to_string());
+        }
+      }
+      Event::Scalar(value, style, anchor_id, tag) => {
+        if style == TScalarStyle::Plain
+          && tag.is_none()
+          && value == "<<"
+          && let Some(YamlContainer::Object {
+            key: key @ None, ..
+          }) = self.stack.last_mut()
+        {
+          *key = Some(YamlKey::Merge);
+          return Ok(());
+        }
+        let value = yaml_scalar_to_json(resolve_yaml_scalar(value, style, tag))?;
+        self.insert(value, anchor_id)?;
+      }
+      Event::Alias(anchor_id) => {
+        let Some(value) = self.anchors.get(&anchor_id).cloned() else {
+          return Err("Unknown alias".to_string());
+        };
+        self.insert(value, 0)?;
+      }
+      Event::SequenceStart(anchor_id, _) => {
+        self.check_container_key()?;
+        self.stack.push(YamlContainer::Array(vec![], anchor_id));
+      }
+      Event::MappingStart(anchor_id, _) => {
+        self.check_container_key()?;
+        self.stack.push(YamlContainer::Object {
+          object: Object::new(),
+          key: None,
+          merged: Default::default(),
+          anchor_id,
+        });
+      }
+      Event::SequenceEnd | Event::MappingEnd => {
+        let (value, anchor_id) = match self.stack.pop().expect("should have container") {
+          YamlContainer::Array(items, anchor_id) => (JsonValue::Array(items), anchor_id),
+          YamlContainer::Object {
+            object, anchor_id, ..
+          } => (JsonValue::Object(object), anchor_id),
+        };
+        self.insert(value, anchor_id)?;
+      }
+      Event::Nothing | Event::StreamStart | Event::StreamEnd | Event::DocumentEnd => {}
+    }
+    Ok(())
+  }
+
+  /// Only scalars can be the keys of mappings, since the keys of JSON objects are strings.
+  fn check_container_key(&self) -> std::result::Result<(), String> {
+    if let Some(YamlContainer::Object { key: None, .. }) = self.stack.last() {
+      return Err("Only scalar keys are supported in YAML mappings".to_string());
+    }
+    Ok(())
+  }
+
+  fn insert(&mut self, value: JsonValue, anchor_id: usize) -> std::result::Result<(), String> {
+    // valid anchor id starts from 1
+    if anchor_id > 0 {
+      self.anchors.insert(anchor_id, value.clone());
+    }
+    match self.stack.last_mut() {
+      None => self.value = Some(value),
+      Some(YamlContainer::Array(items, _)) => items.push(value),
+      Some(YamlContainer::Object {
+        object,
+        key,
+        merged,
+        ..
+      }) => match key.take() {
+        None => {
+          *key = Some(YamlKey::Name(match value {
+            JsonValue::String(s) => s,
+            JsonValue::Short(s) => s.as_str().to_string(),
+            JsonValue::Number(_) | JsonValue::Boolean(_) | JsonValue::Null => value.dump(),
+            JsonValue::Array(_) | JsonValue::Object(_) => {
+              return Err("Only scalar keys are supported in YAML mappings".to_string());
+            }
+          }));
+        }
+        Some(YamlKey::Name(key)) => {
+          if object.get(&key).is_some() && !merged.remove(&key) {
+            return Err(format!("Duplicated key {key} in mapping"));
+          }
+          object.insert(&key, value);
+        }
+        Some(YamlKey::Merge) => {
+          let sources = match value {
+            JsonValue::Object(source) => vec![source],
+            JsonValue::Array(items) => items
+              .into_iter()
+              .map(|item| match item {
+                JsonValue::Object(source) => Ok(source),
+                _ => Err(()),
+              })
+              .collect::<std::result::Result<Vec<_>, _>>()
+              .map_err(|_| MERGE_KEY_ERROR.to_string())?,
+            _ => return Err(MERGE_KEY_ERROR.to_string()),
+          };
+          // the explicit keys and the keys of former mappings take precedence
+          for source in sources {
+            for (name, value) in source.iter() {
+              if object.get(name).is_none() {
+                object.insert(name, value.clone());
+                merged.insert(name.to_string());
+              }
+            }
+          }
+        }
+      },
+    }
+    Ok(())
+  }
+}
+
+const MERGE_KEY_ERROR: &str =
+  "The value of merge key `<<` should be a mapping or a sequence of mappings";
+
+/// Resolve the type of scalar in the same way as [`yaml_rust2::YamlLoader`].
+fn resolve_yaml_scalar(value: String, style: TScalarStyle, tag: Option<Tag>) -> Yaml {
+  if style != TScalarStyle::Plain {
+    return Yaml::String(value);
+  }
+  let Some(tag) = tag else {
+    return Yaml::from_str(&value);
+  };
+  if tag.handle != "tag:yaml.org,2002:" {
+    return Yaml::String(value);
+  }
+  match tag.suffix.as_str() {
+    "bool" => match value.as_str() {
+      "true" | "True" | "TRUE" => Yaml::Boolean(true),
+      "false" | "False" | "FALSE" => Yaml::Boolean(false),
+      _ => Yaml::BadValue,
+    },
+    "int" => value.parse::<i64>().map_or(Yaml::BadValue, Yaml::Integer),
+    "float" => {
+      let real = Yaml::Real(value);
+      if real.as_f64().is_some() {
+        real
+      } else {
+        Yaml::BadValue
+      }
+    }
+    "null" => match value.as_str() {
+      "~" | "null" => Yaml::Null,
+      _ => Yaml::BadValue,
+    },
+    _ => Yaml::String(value),
+  }
+}
+
+fn yaml_scalar_to_json(value: Yaml) -> std::result::Result<JsonValue, String> {
+  Ok(match value {
+    Yaml::Null => JsonValue::Null,
+    Yaml::Boolean(b) => JsonValue::Boolean(b),
+    Yaml::Integer(i) => JsonValue::Number(Number::from(i)),
+    Yaml::Real(_) => finite_number(value.as_f64().expect("should be a valid YAML float"))?,
+    Yaml::String(s) => JsonValue::String(s),
+    _ => return Err("Invalid value".to_string()),
+  })
+}
+
+/// Parse the TOML `content`, the `offset` is the byte offset of `content` in `source`,
+/// which is used to point the diagnostics to the original source.
+pub fn parse_toml(source: &str, content: &str, offset: usize) -> Result<JsonValue> {
+  let table = content.parse::<toml::Table>().map_err(|e| {
+    let (start, end) = e.span().map_or((content.len(), content.len()), |span| {
+      (span.start, span.end)
+    });
+    Error::from_string(
+      Some(source.to_string()),
+      start + offset,
+      end.max(start + 1) + offset,
+      DataFormat::Toml.error_title().to_string(),
+      e.message().to_string(),
+    )
+  })?;
+  toml_to_json(toml::Value::Table(table))
+}
+
+fn toml_to_json(value: toml::Value) -> Result<JsonValue> {
+  Ok(match value {
+    toml::Value::String(s) => JsonValue::String(s),
+    toml::Value::Integer(i) => JsonValue::Number(Number::from(i)),
+    toml::Value::Float(f) => {
+      finite_number(f).map_err(|e| error!("{}: {e}", DataFormat::Toml.error_title()))?
+    }
+    toml::Value::Boolean(b) => JsonValue::Boolean(b),
+    // there is no date type in JSON, keep the RFC 3339 string as is
+    toml::Value::Datetime(datetime) => JsonValue::String(datetime.to_string()),
+    toml::Value::Array(items) => JsonValue::Array(
+      items
+        .into_iter()
+        .map(toml_to_json)
+        .collect::<Result<Vec<_>>>()?,
+    ),
+    toml::Value::Table(table) => {
+      let mut object = Object::with_capacity(table.len());
+      for (key, value) in table {
+        object.insert(&key, toml_to_json(value)?);
+      }
+      JsonValue::Object(object)
+    }
+  })
+}
+
+fn finite_number(f: f64) -> std::result::Result<JsonValue, String> {
+  if !f.is_finite() {
+    return Err(format!("{f} can not be represented in JSON"));
+  }
+  Ok(JsonValue::Number(Number::from(f)))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn yaml_error_offset(content: &str) -> (usize, String) {
+    let error = parse_yaml(content, content, 0).expect_err("should fail");
+    let label = error
+      .labels
+      .as_ref()
+      .and_then(|labels| labels.first())
+      .expect("should have label");
+    (label.offset, error.to_string())
+  }
+
+  #[test]
+  fn yaml_errors_have_location() {
+    let (offset, message) = yaml_error_offset("a: 1\n---\nb: 2\n");
+    assert_eq!(offset, 5);
+    assert!(message.contains("Multiple documents"), "{message}");
+
+    let (offset, message) = yaml_error_offset("a: 1\nb: *unknown\n");
+    assert_eq!(offset, 8);
+    assert!(message.contains("unknown anchor"), "{message}");
+
+    let (offset, message) = yaml_error_offset("a: 1\n? [b]\n: 2\n");
+    assert_eq!(offset, 7);
+    assert!(message.contains("Only scalar keys"), "{message}");
+  }
+
+  #[test]
+  fn json5_numbers() {
+    for (raw, expected) in [
+      ("0xFF", "255"),
+      ("-0x10", "-16"),
+      ("+1", "1"),
+      (".5", "0.5"),
+      ("-.5e1", "-0.5e1"),
+      ("5.", "5.0"),
+      ("5.e2", "5.0e2"),
+      ("1.5", "1.5"),
+    ] {
+      assert_eq!(
+        json5_number(raw).expect("should be a number"),
+        json::parse(expected).expect("should be strict JSON"),
+        "{raw}"
+      );
+    }
+    for raw in ["Infinity", "-Infinity", "NaN"] {
+      let message = json5_number(raw).expect_err("should fail");
+      assert!(message.contains("can not be represented"), "{message}");
+    }
+    assert!(json5_number("0xZZ").is_err());
+  }
+
+  fn jsonc(content: &str, syntax: JsonSyntax) -> std::result::Result<String, String> {
+    parse_jsonc(content, content, 0, syntax)
+      .map(|value| value.dump())
+      .map_err(|error| error.to_string())
+  }
+
+  #[test]
+  fn jsonc_syntax() {
+    let content = "{\n  // comment\n  \"a\": [1, 2,],\n}";
+    assert_eq!(
+      jsonc(content, JsonSyntax::Jsonc).as_deref(),
+      Ok(r#"{"a":[1,2]}"#)
+    );
+    for content in [
+      "{ a: 1 }",
+      "{ 'a': 1 }",
+      "['a']",
+      r#""\x41""#,
+      "0xFF",
+      "+1",
+      ".5",
+      "5.",
+      "Infinity",
+      "NaN",
+      "[1 2]",
+    ] {
+      assert!(
+        jsonc(content, JsonSyntax::Jsonc).is_err(),
+        "{content} should be rejected in JSONC"
+      );
+    }
+  }
+
+  #[test]
+  fn json5_syntax() {
+    for (content, expected) in [
+      ("{ a: 1, 'b': 'c' }", r#"{"a":1,"b":"c"}"#),
+      (r#""\x41""#, r#""A""#),
+      ("[0xFF, -0x10]", "[255,-16]"),
+      ("+1", "1"),
+      ("[.5, 5.]", "[0.5,5.0]"),
+    ] {
+      assert_eq!(
+        jsonc(content, JsonSyntax::Json5).as_deref(),
+        Ok(expected),
+        "{content}"
+      );
+    }
+    for content in ["Infinity", "-Infinity", "NaN", "[1, NaN]"] {
+      let message = jsonc(content, JsonSyntax::Json5).expect_err("should fail");
+      assert!(message.contains("can not be represented"), "{message}");
+    }
+  }
+
+  #[test]
+  fn yaml_to_json() {
+    let content = "a: &anchor\n  b: [1, 2.5, true, ~]\nc: *anchor\n1: one\n";
+    let value = parse_yaml(content, content, 0).expect("should parse");
+    assert_eq!(
+      value.dump(),
+      r#"{"a":{"b":[1,2.5,true,null]},"c":{"b":[1,2.5,true,null]},"1":"one"}"#
+    );
+  }
+
+  #[test]
+  fn yaml_merge_keys() {
+    let content = "base: &base\n  a: 1\n  b: 2\nextra: &extra\n  b: 3\n  c: 4\nfoo:\n  a: 0\n  <<: [*base, *extra]\n  c: 5\nbar:\n  <<: *base\n  '<<': quoted\n";
+    let value = parse_yaml(content, content, 0).expect("should parse");
+    assert_eq!(value["foo"].dump(), r#"{"a":0,"b":2,"c":5}"#);
+    assert_eq!(value["bar"].dump(), r#"{"a":1,"b":2,"<<":"quoted"}"#);
+
+    let (offset, message) = yaml_error_offset("a: &a 1\nb:\n  <<: *a\n");
+    assert_eq!(offset, 17);
+    assert!(message.contains("merge key"), "{message}");
+
+    let (_, message) = yaml_error_offset("a:\n  b: 1\n  <<: {b: 2}\n  b: 3\n");
+    assert!(message.contains("Duplicated key b"), "{message}");
+  }
+}
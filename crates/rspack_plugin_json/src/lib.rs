@@ -13,17 +13,21 @@ use json::{
 use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_core::{
   BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, ExportsInfoGetter, GenerateContext,
-  Module, ModuleGraph, NAMESPACE_OBJECT_EXPORT, ParseOption, ParserAndGenerator, Plugin,
-  PrefetchExportsInfoMode, PrefetchedExportsInfoWrapper, RuntimeGlobals, RuntimeSpec, SourceType,
-  UsageState, UsedNameItem,
+  JsonParserOptions, JsonSyntax, Module, ModuleGraph, NAMESPACE_OBJECT_EXPORT, ParseOption,
+  ParserAndGenerator, Plugin, PrefetchExportsInfoMode, PrefetchedExportsInfoWrapper,
+  RuntimeGlobals, RuntimeSpec, SourceType, UsageState, UsedNameItem,
   diagnostics::ModuleParseError,
   rspack_sources::{BoxSource, RawStringSource, Source, SourceExt},
 };
 use rspack_error::{Error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray, error};
 use rspack_util::itoa;
 
-use crate::json_exports_dependency::JsonExportsDependency;
+use crate::{
+  formats::{DataFormat, parse_jsonc, parse_toml, parse_yaml},
+  json_exports_dependency::JsonExportsDependency,
+};
 
+mod formats;
 mod json_exports_dependency;
 mod utils;
 
@@ -32,6 +36,9 @@ mod utils;
 struct JsonParserAndGenerator {
   pub exports_depth: u32,
   pub json_parse: bool,
+  /// The format of the module source, YAML and TOML sources are converted to JSON data.
+  pub format: DataFormat,
+  pub syntax: JsonSyntax,
 }
 
 #[cacheable_dyn]
@@ -66,59 +73,78 @@ impl ParserAndGenerator for JsonParserAndGenerator {
     let strip_bom_source = source.strip_prefix('\u{feff}');
     let need_strip_bom = strip_bom_source.is_some();
     let strip_bom_source = strip_bom_source.unwrap_or(&source);
+    let bom_offset = source.len() - strip_bom_source.len();
 
-    // If there is a custom parse, execute it to obtain the returned string.
-    let parse_result_str = if let Some(p) = module_parser_options.and_then(|p| p.get_json()) {
-      match &p.parse {
-        ParseOption::Func(f) => {
-          let parse_result = f(strip_bom_source.to_string()).await;
-          parse_result.ok()
-        }
-        _ => None,
+    let parse_result = match self.format {
+      DataFormat::Yaml => parse_yaml(&source, strip_bom_source, bom_offset),
+      DataFormat::Toml => parse_toml(&source, strip_bom_source, bom_offset),
+      // The custom parse returns strict JSON, so the syntax is only used without it.
+      DataFormat::Json
+        if self.syntax != JsonSyntax::Json
+          && !matches!(
+            module_parser_options.and_then(|p| p.get_json()),
+            Some(JsonParserOptions {
+              parse: ParseOption::Func(_),
+              ..
+            })
+          ) =>
+      {
+        parse_jsonc(&source, strip_bom_source, bom_offset, self.syntax)
       }
-    } else {
-      None
-    };
-
-    let parse_result = json::parse(parse_result_str.as_deref().unwrap_or(strip_bom_source))
-      .map_err(|e| {
-        match e {
-          UnexpectedCharacter { ch, line, column } => {
-            let rope = ropey::Rope::from_str(&source);
-            let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
-            let start_offset = source[line_offset..]
-              .chars()
-              .take(column)
-              .fold(line_offset, |acc, cur| acc + cur.len_utf8());
-            let start_offset = if need_strip_bom {
-              start_offset + 1
-            } else {
-              start_offset
-            };
-            Error::from_string(
-              Some(source.into_owned()),
-              // one character offset
-              start_offset,
-              start_offset + 1,
-              "JSON parse error".to_string(),
-              format!("Unexpected character {ch}"),
-            )
+      DataFormat::Json => {
+        // If there is a custom parse, execute it to obtain the returned string.
+        let parse_result_str = if let Some(p) = module_parser_options.and_then(|p| p.get_json()) {
+          match &p.parse {
+            ParseOption::Func(f) => {
+              let parse_result = f(strip_bom_source.to_string()).await;
+              parse_result.ok()
+            }
+            _ => None,
           }
-          ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => error!("{}", e),
-          UnexpectedEndOfJson => {
-            // End offset of json file
-            let length = source.len();
-            let offset = if length > 0 { length - 1 } else { length };
-            Error::from_string(
-              Some(source.into_owned()),
-              offset,
-              offset,
-              "JSON parse error".to_string(),
-              format!("{e}"),
-            )
+        } else {
+          None
+        };
+
+        json::parse(parse_result_str.as_deref().unwrap_or(strip_bom_source)).map_err(|e| {
+          match e {
+            UnexpectedCharacter { ch, line, column } => {
+              let rope = ropey::Rope::from_str(&source);
+              let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
+              let start_offset = source[line_offset..]
+                .chars()
+                .take(column)
+                .fold(line_offset, |acc, cur| acc + cur.len_utf8());
+              let start_offset = if need_strip_bom {
+                start_offset + 1
+              } else {
+                start_offset
+              };
+              Error::from_string(
+                Some(source.into_owned()),
+                // one character offset
+                start_offset,
+                start_offset + 1,
+                "JSON parse error".to_string(),
+                format!("Unexpected character {ch}"),
+              )
+            }
+            ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => error!("{}", e),
+            UnexpectedEndOfJson => {
+              // End offset of json file
+              let length = source.len();
+              let offset = if length > 0 { length - 1 } else { length };
+              Error::from_string(
+                Some(source.into_owned()),
+                offset,
+                offset,
+                "JSON parse error".to_string(),
+                format!("{e}"),
+              )
+            }
           }
-        }
-      });
+        })
+      }
+    };
 
     let data = match parse_result {
       Ok(data) => data,
@@ -252,23 +278,31 @@ impl Plugin for JsonPlugin {
   }
 
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
-    ctx.register_parser_and_generator_builder(
-      rspack_core::ModuleType::Json,
-      Box::new(|p, g| {
-        let p = p
-          .and_then(|p| p.get_json())
-          .expect("should have JsonParserOptions");
+    for (module_type, format) in [
+      (rspack_core::ModuleType::Json, DataFormat::Json),
+      (rspack_core::ModuleType::Yaml, DataFormat::Yaml),
+      (rspack_core::ModuleType::Toml, DataFormat::Toml),
+    ] {
+      ctx.register_parser_and_generator_builder(
+        module_type,
+        Box::new(move |p, g| {
+          let p = p
+            .and_then(|p| p.get_json())
+            .expect("should have JsonParserOptions");
 
-        let g = g
-          .and_then(|g| g.get_json())
-          .expect("should have JsonGeneratorOptions");
+          let g = g
+            .and_then(|g| g.get_json())
+            .expect("should have JsonGeneratorOptions");
 
-        Box::new(JsonParserAndGenerator {
-          exports_depth: p.exports_depth.expect("should have exports_depth"),
-          json_parse: g.json_parse.expect("should have json_parse"),
-        })
-      }),
-    );
+          Box::new(JsonParserAndGenerator {
+            exports_depth: p.exports_depth.expect("should have exports_depth"),
+            json_parse: g.json_parse.expect("should have json_parse"),
+            format,
+            syntax: p.syntax.unwrap_or_default(),
+          })
+        }),
+      );
+    }
 
     Ok(())
   }
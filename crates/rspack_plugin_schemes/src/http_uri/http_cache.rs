@@ -12,7 +12,7 @@ use sha2::{Digest, Sha512};
 use url::Url;
 
 use super::lockfile::{LockfileCache, LockfileEntry};
-use crate::http_uri::HttpUriPluginOptions;
+use crate::http_uri::{HttpUriPluginOptions, sanitize_message_for_error, sanitize_url_for_error};
 
 /// This enum is used for avoiding [Buffer::to_vec] overhead
 pub enum BufferOrBytes {
@@ -37,7 +37,13 @@ pub struct HttpResponse {
 
 #[async_trait]
 pub trait HttpClient: Send + Sync + std::fmt::Debug {
-  async fn get(&self, url: &str, headers: &FxHashMap<String, String>) -> Result<HttpResponse>;
+  /// Sends a GET request, through `proxy` if it is given.
+  async fn get(
+    &self,
+    url: &str,
+    headers: &FxHashMap<String, String>,
+    proxy: Option<&str>,
+  ) -> Result<HttpResponse>;
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -107,6 +113,10 @@ impl HttpCache {
     url: &str,
     options: &HttpUriPluginOptions,
   ) -> Result<FetchResultType> {
+    if options.frozen {
+      return self.fetch_frozen_content(url).await;
+    }
+
     let cached_result = self.read_from_cache(url).await?;
 
     if let Some(ref cached) = cached_result
@@ -115,13 +125,44 @@ impl HttpCache {
       return Ok(FetchResultType::Content(cached.clone()));
     }
 
-    self.fetch_content_raw(url, cached_result).await
+    self.fetch_content_raw(url, cached_result, options).await
+  }
+
+  /// Serve the content from the lockfile and cache only, the network is never touched in frozen mode.
+  async fn fetch_frozen_content(&self, url: &str) -> Result<FetchResultType> {
+    let entry = {
+      let lockfile = self.lockfile_cache.get_lockfile().await?;
+      let lock_guard = lockfile.lock().await;
+      lock_guard.get_entry(url).cloned()
+    };
+    let Some(entry) = entry else {
+      return Err(anyhow::anyhow!(
+        "{} has no lockfile entry and lockfile is frozen",
+        sanitize_url_for_error(url)
+      ));
+    };
+    let Some(cached) = self.read_from_cache(url).await? else {
+      return Err(anyhow::anyhow!(
+        "{} has no cached content and lockfile is frozen",
+        sanitize_url_for_error(url)
+      ));
+    };
+    let integrity = compute_integrity(cached.content());
+    if integrity != entry.integrity {
+      return Err(anyhow::anyhow!(
+        "{} has an integrity mismatch and lockfile is frozen\nexpected: {}\nreceived: {integrity}",
+        sanitize_url_for_error(url),
+        entry.integrity
+      ));
+    }
+    Ok(FetchResultType::Content(cached))
   }
 
   async fn fetch_content_raw(
     &self,
     url: &str,
     cached_result: Option<ContentFetchResult>,
+    options: &HttpUriPluginOptions,
   ) -> Result<FetchResultType> {
     let request_time = current_time();
     let mut headers = FxHashMap::default();
@@ -139,7 +180,27 @@ impl HttpCache {
       headers.insert("if-none-match".to_string(), etag.clone());
     }
 
-    let response = self.http_client.get(url, &headers).await?;
+    let parsed_url = Url::parse(url)?;
+    for (key, value) in options.headers.get(&parsed_url) {
+      headers.insert(key.clone(), value.clone());
+    }
+    let proxy = options
+      .proxy
+      .as_ref()
+      .and_then(|proxy| proxy.get_proxy(&parsed_url));
+
+    let response = self
+      .http_client
+      .get(url, &headers, proxy)
+      .await
+      .map_err(|e| {
+        let sanitized_url = sanitize_url_for_error(url);
+        anyhow::anyhow!(
+          "Failed to fetch {sanitized_url}: {}",
+          sanitize_message_for_error(&e.to_string(), &options.headers, options.proxy.as_ref())
+            .cow_replace(url, &sanitized_url)
+        )
+      })?;
     let status = response.status;
     let headers = response.headers;
     let etag = headers.get("etag").cloned();
@@ -219,7 +280,11 @@ impl HttpCache {
       return Err(anyhow::anyhow!(
         "Request failed with status: {}\n{}",
         status,
-        String::from_utf8_lossy(&response.body)
+        sanitize_message_for_error(
+          &String::from_utf8_lossy(&response.body),
+          &options.headers,
+          options.proxy.as_ref(),
+        )
       ));
     }
 
@@ -5,5 +5,6 @@ mod http_uri;
 pub use data_uri::DataUriPlugin;
 pub use file_uri::FileUriPlugin;
 pub use http_uri::{
-  HttpClient, HttpResponse, HttpUriOptionsAllowedUris, HttpUriPlugin, HttpUriPluginOptions,
+  HttpClient, HttpResponse, HttpUriOptionsAllowedUris, HttpUriOptionsHeaders, HttpUriOptionsProxy,
+  HttpUriPlugin, HttpUriPluginOptions,
 };
@@ -0,0 +1,448 @@
+use std::{collections::BTreeMap, fmt, hash::Hasher};
+
+use rspack_core::{ChunkUkey, Compilation};
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_paths::Utf8PathBuf;
+use rspack_plugin_compression::CompressionAlgorithm;
+use rspack_util::size::format_size;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::{Deserialize, Serialize};
+
+/// The max count of modules listed in the diagnostic of a budget
+const MAX_OFFENDING_MODULES: usize = 5;
+
+#[derive(Debug, Clone)]
+pub enum SizeBudgetTarget {
+  /// The total size of the assets of the entrypoint
+  Entrypoint(String),
+  /// The total size of the files of the named chunk
+  Chunk(String),
+  /// The size of each asset whose name matches the glob
+  Asset(String),
+}
+
+#[derive(Debug)]
+pub struct SizeBudget {
+  pub target: SizeBudgetTarget,
+  pub max_size: Option<f64>,
+  pub max_gzip_size: Option<f64>,
+  pub max_brotli_size: Option<f64>,
+}
+
+impl SizeBudget {
+  fn limits(&self) -> impl Iterator<Item = (SizeKind, Option<f64>)> {
+    [
+      (SizeKind::Raw, self.max_size),
+      (SizeKind::Gzip, self.max_gzip_size),
+      (SizeKind::Brotli, self.max_brotli_size),
+    ]
+    .into_iter()
+  }
+}
+
+#[derive(Debug)]
+pub struct SizeLimitsBaseline {
+  /// The path of the baseline file, relative paths are resolved from the context
+  pub path: String,
+  /// The allowed regression in percent compared to the baseline
+  pub threshold: f64,
+  /// Overwrite the baseline file with the sizes of the current build
+  pub update: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SizeKind {
+  Raw,
+  Gzip,
+  Brotli,
+}
+
+impl fmt::Display for SizeKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SizeKind::Raw => write!(f, "size"),
+      SizeKind::Gzip => write!(f, "gzip size"),
+      SizeKind::Brotli => write!(f, "brotli size"),
+    }
+  }
+}
+
+/// The measured sizes of a budget target, which is also the entry of the baseline file
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeasuredSize {
+  pub size: f64,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub gzip_size: Option<f64>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub brotli_size: Option<f64>,
+}
+
+impl MeasuredSize {
+  fn get(&self, kind: SizeKind) -> Option<f64> {
+    match kind {
+      SizeKind::Raw => Some(self.size),
+      SizeKind::Gzip => self.gzip_size,
+      SizeKind::Brotli => self.brotli_size,
+    }
+  }
+
+  fn set(&mut self, kind: SizeKind, size: f64) {
+    match kind {
+      SizeKind::Raw => self.size = size,
+      SizeKind::Gzip => self.gzip_size = Some(size),
+      SizeKind::Brotli => self.brotli_size = Some(size),
+    }
+  }
+}
+
+/// The baseline file maps the budget targets, such as `entrypoint:main`, to their sizes
+pub type BaselineSizes = BTreeMap<String, MeasuredSize>;
+
+pub enum BudgetLimit {
+  Max(f64),
+  Baseline { baseline: f64, threshold: f64 },
+}
+
+pub struct BudgetViolation {
+  pub target: String,
+  pub kind: SizeKind,
+  pub size: f64,
+  pub limit: BudgetLimit,
+  pub files: Vec<String>,
+  /// The largest modules in the chunks of the target with their sizes
+  pub modules: Vec<(String, f64)>,
+}
+
+impl BudgetViolation {
+  pub fn message(&self) -> String {
+    let mut message = match self.limit {
+      BudgetLimit::Max(max) => format!(
+        "size budget: {} exceeds the {} budget ({} > {}).",
+        self.target,
+        self.kind,
+        format_size(self.size),
+        format_size(max)
+      ),
+      BudgetLimit::Baseline {
+        baseline,
+        threshold,
+      } => format!(
+        "size budget: {} {} regressed by {:.2}% compared to the baseline ({} -> {}), which exceeds the allowed {}%.",
+        self.target,
+        self.kind,
+        (self.size - baseline) / baseline * 100.0,
+        format_size(baseline),
+        format_size(self.size),
+        threshold
+      ),
+    };
+    message.push_str("\nFiles:");
+    for file in &self.files {
+      message.push_str(&format!("\n  {file}"));
+    }
+    if !self.modules.is_empty() {
+      message.push_str("\nLargest modules:");
+      for (name, size) in &self.modules {
+        message.push_str(&format!("\n  {} ({})", name, format_size(*size)));
+      }
+    }
+    message
+  }
+}
+
+/// A budget target resolved to the files and chunks of the current build
+struct ResolvedTarget {
+  key: String,
+  display: String,
+  files: Vec<String>,
+  chunks: Vec<ChunkUkey>,
+}
+
+fn resolve_targets(
+  budget: &SizeBudget,
+  compilation: &Compilation,
+  assets: &BTreeMap<String, Vec<u8>>,
+) -> Vec<ResolvedTarget> {
+  let filter_files = |files: Vec<String>| {
+    let mut files = files
+      .into_iter()
+      .filter(|file| assets.contains_key(file))
+      .collect::<Vec<_>>();
+    files.sort();
+    files.dedup();
+    files
+  };
+  match &budget.target {
+    SizeBudgetTarget::Entrypoint(name) => compilation
+      .entrypoints
+      .get(name)
+      .map(|ukey| {
+        let entrypoint = compilation.chunk_group_by_ukey.expect_get(ukey);
+        ResolvedTarget {
+          key: format!("entrypoint:{name}"),
+          display: format!("entrypoint \"{name}\""),
+          files: filter_files(entrypoint.get_files(&compilation.chunk_by_ukey)),
+          chunks: entrypoint.chunks.clone(),
+        }
+      })
+      .into_iter()
+      .collect(),
+    SizeBudgetTarget::Chunk(name) => compilation
+      .named_chunks
+      .get(name)
+      .map(|ukey| {
+        let chunk = compilation.chunk_by_ukey.expect_get(ukey);
+        ResolvedTarget {
+          key: format!("chunk:{name}"),
+          display: format!("chunk \"{name}\""),
+          files: filter_files(chunk.files().iter().cloned().collect()),
+          chunks: vec![*ukey],
+        }
+      })
+      .into_iter()
+      .collect(),
+    SizeBudgetTarget::Asset(glob) => assets
+      .keys()
+      .filter(|name| fast_glob::glob_match(glob, name.as_str()))
+      .map(|name| {
+        let chunks = compilation
+          .chunk_by_ukey
+          .iter()
+          .filter(|(_, chunk)| {
+            chunk.files().contains(name) || chunk.auxiliary_files().contains(name)
+          })
+          .map(|(ukey, _)| *ukey)
+          .collect::<Vec<_>>();
+        ResolvedTarget {
+          key: format!("asset:{name}"),
+          display: format!("asset \"{name}\""),
+          files: vec![name.clone()],
+          chunks,
+        }
+      })
+      .collect(),
+  }
+}
+
+/// The assets measured by the budgets, so that the other assets are never read
+pub struct BudgetAssets<'a> {
+  files: HashSet<String>,
+  globs: Vec<&'a str>,
+}
+
+impl<'a> BudgetAssets<'a> {
+  pub fn new(budgets: &'a [SizeBudget], compilation: &Compilation) -> Self {
+    let mut files = HashSet::default();
+    let mut globs = vec![];
+    for budget in budgets {
+      match &budget.target {
+        SizeBudgetTarget::Entrypoint(name) => {
+          if let Some(ukey) = compilation.entrypoints.get(name) {
+            files.extend(
+              compilation
+                .chunk_group_by_ukey
+                .expect_get(ukey)
+                .get_files(&compilation.chunk_by_ukey),
+            );
+          }
+        }
+        SizeBudgetTarget::Chunk(name) => {
+          if let Some(ukey) = compilation.named_chunks.get(name) {
+            files.extend(
+              compilation
+                .chunk_by_ukey
+                .expect_get(ukey)
+                .files()
+                .iter()
+                .cloned(),
+            );
+          }
+        }
+        SizeBudgetTarget::Asset(glob) => globs.push(glob.as_str()),
+      }
+    }
+    Self { files, globs }
+  }
+
+  pub fn contains(&self, name: &str) -> bool {
+    self.files.contains(name)
+      || self
+        .globs
+        .iter()
+        .any(|glob| fast_glob::glob_match(glob, name))
+  }
+}
+
+/// The compressed sizes keyed by the kind and the content hash of assets,
+/// so that the unchanged assets are not compressed again in rebuilds
+pub type CompressedSizes = HashMap<(SizeKind, String), f64>;
+
+fn compressed_size(kind: SizeKind, content: &[u8]) -> Result<f64> {
+  let size = match kind {
+    SizeKind::Raw => content.len(),
+    SizeKind::Gzip => CompressionAlgorithm::Gzip.compress(content)?.len(),
+    SizeKind::Brotli => CompressionAlgorithm::Brotli.compress(content)?.len(),
+  };
+  Ok(size as f64)
+}
+
+fn largest_modules(compilation: &Compilation, chunks: &[ChunkUkey]) -> Vec<(String, f64)> {
+  let module_graph = compilation.get_module_graph();
+  let mut visited = HashSet::default();
+  let mut modules = chunks
+    .iter()
+    .flat_map(|chunk| compilation.chunk_graph.get_chunk_modules_identifier(chunk))
+    .filter(|identifier| visited.insert(**identifier))
+    .filter_map(|identifier| module_graph.module_by_identifier(identifier))
+    .map(|module| {
+      (
+        module
+          .readable_identifier(&compilation.options.context)
+          .to_string(),
+        module.size(None, Some(compilation)),
+      )
+    })
+    .collect::<Vec<_>>();
+  modules.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+  modules.truncate(MAX_OFFENDING_MODULES);
+  modules
+}
+
+/// Measure the budget targets and check them against the limits.
+///
+/// The sizes of a target are compared with the max sizes of the budget, and also with the
+/// baseline if it's recorded in `baseline`.
+/// Returns the violations and the measured sizes, which can be saved as the next baseline.
+/// The `compressed_sizes` of the previous compilation are reused, and replaced with the ones
+/// used by this compilation.
+pub fn check_budgets(
+  budgets: &[SizeBudget],
+  compilation: &Compilation,
+  assets: &BTreeMap<String, Vec<u8>>,
+  baseline: Option<(&BaselineSizes, f64)>,
+  compressed_sizes: &mut CompressedSizes,
+) -> Result<(Vec<BudgetViolation>, BaselineSizes)> {
+  let mut violations = vec![];
+  let mut measured = BaselineSizes::new();
+  let mut size_cache: HashMap<(&str, SizeKind), f64> = HashMap::default();
+  let mut content_hashes: HashMap<&str, String> = HashMap::default();
+  // the entries which are not used by the current compilation are dropped
+  let previous_sizes = std::mem::take(compressed_sizes);
+
+  for budget in budgets {
+    for target in resolve_targets(budget, compilation, assets) {
+      let mut sizes = MeasuredSize::default();
+      for (kind, max) in budget.limits() {
+        if kind != SizeKind::Raw && max.is_none() {
+          continue;
+        }
+        let mut size = 0.0;
+        for file in &target.files {
+          let (file, content) = assets
+            .get_key_value(file)
+            .expect("should have filtered asset");
+          size += match size_cache.get(&(file.as_str(), kind)) {
+            Some(size) => *size,
+            None => {
+              let file_size = if kind == SizeKind::Raw {
+                content.len() as f64
+              } else {
+                let hash = content_hashes
+                  .entry(file.as_str())
+                  .or_insert_with(|| {
+                    let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+                    hasher.write(content);
+                    hasher.digest(&HashDigest::Hex).encoded().to_string()
+                  })
+                  .clone();
+                let cache_key = (kind, hash);
+                let file_size = match previous_sizes.get(&cache_key) {
+                  Some(size) => *size,
+                  None => compressed_size(kind, content)?,
+                };
+                compressed_sizes.insert(cache_key, file_size);
+                file_size
+              };
+              size_cache.insert((file.as_str(), kind), file_size);
+              file_size
+            }
+          };
+        }
+        sizes.set(kind, size);
+
+        let baseline_size = baseline.and_then(|(baseline, threshold)| {
+          baseline
+            .get(&target.key)
+            .and_then(|sizes| sizes.get(kind))
+            .map(|baseline_size| (baseline_size, threshold))
+        });
+        let exceeded_max = max.filter(|max| size > *max).map(BudgetLimit::Max);
+        let regressed = baseline_size
+          .filter(|(baseline, threshold)| {
+            *baseline > 0.0 && size > baseline * (1.0 + threshold / 100.0)
+          })
+          .map(|(baseline, threshold)| BudgetLimit::Baseline {
+            baseline,
+            threshold,
+          });
+        for limit in exceeded_max.into_iter().chain(regressed) {
+          violations.push(BudgetViolation {
+            target: target.display.clone(),
+            kind,
+            size,
+            limit,
+            files: target.files.clone(),
+            modules: largest_modules(compilation, &target.chunks),
+          });
+        }
+      }
+      measured.insert(target.key, sizes);
+    }
+  }
+
+  Ok((violations, measured))
+}
+
+impl SizeLimitsBaseline {
+  fn resolve_path(&self, compilation: &Compilation) -> Utf8PathBuf {
+    compilation.options.context.as_path().join(&self.path)
+  }
+
+  /// Loads the baseline file, returns `None` if the file does not exist
+  pub async fn load(&self, compilation: &Compilation) -> Result<Option<BaselineSizes>> {
+    let path = self.resolve_path(compilation);
+    let content = match compilation.intermediate_filesystem.read_file(&path).await {
+      Ok(content) => content,
+      Err(rspack_fs::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+      Err(e) => {
+        return Err(rspack_error::error!(
+          "read size limits baseline {} failed: {}",
+          path,
+          e
+        ));
+      }
+    };
+    serde_json::from_slice(&content)
+      .map(Some)
+      .map_err(|e| rspack_error::error!("parse size limits baseline {} failed: {}", path, e))
+  }
+
+  pub async fn save(&self, compilation: &Compilation, sizes: &BaselineSizes) -> Result<()> {
+    let path = self.resolve_path(compilation);
+    let mut content = serde_json::to_string_pretty(sizes).to_rspack_result()?;
+    content.push('\n');
+    if let Some(parent) = path.parent() {
+      compilation
+        .intermediate_filesystem
+        .create_dir_all(parent)
+        .await?;
+    }
+    compilation
+      .intermediate_filesystem
+      .write(&path, content.as_bytes())
+      .await?;
+    Ok(())
+  }
+}
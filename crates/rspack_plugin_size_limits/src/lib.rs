@@ -1,4 +1,9 @@
-use std::collections::HashMap;
+mod budget;
+
+use std::{
+  collections::{BTreeMap, HashMap},
+  sync::Mutex,
+};
 
 use derive_more::Debug;
 use futures::future::BoxFuture;
@@ -9,6 +14,8 @@ use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::size::format_size;
 
+pub use crate::budget::{SizeBudget, SizeBudgetTarget, SizeLimitsBaseline};
+
 pub type AssetFilterFn = Box<dyn for<'a> Fn(&'a str) -> BoxFuture<'a, Result<bool>> + Sync + Send>;
 
 #[derive(Debug)]
@@ -18,17 +25,22 @@ pub struct SizeLimitsPluginOptions {
   pub hints: Option<String>,
   pub max_asset_size: Option<f64>,
   pub max_entrypoint_size: Option<f64>,
+  /// The budgets of entrypoints, named chunks and assets, which are checked besides the global limits
+  pub budgets: Vec<SizeBudget>,
+  /// Compare the budgets with the sizes of a previous build besides their max sizes
+  pub baseline: Option<SizeLimitsBaseline>,
 }
 
 #[plugin]
 #[derive(Debug)]
 pub struct SizeLimitsPlugin {
   options: SizeLimitsPluginOptions,
+  compressed_sizes: Mutex<budget::CompressedSizes>,
 }
 
 impl SizeLimitsPlugin {
   pub fn new(options: SizeLimitsPluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, Default::default())
   }
 
   async fn asset_filter(&self, name: &str, asset: &CompilationAsset) -> bool {
@@ -131,6 +143,45 @@ impl SizeLimitsPlugin {
 
     Self::add_diagnostic(hints, title, message, diagnostics);
   }
+
+  /// Check the budgets, and update the baseline file if it does not exist or `update` is enabled
+  async fn check_budgets(&self, compilation: &Compilation) -> Result<Vec<budget::BudgetViolation>> {
+    let budget_assets = budget::BudgetAssets::new(&self.options.budgets, compilation);
+    let mut assets = BTreeMap::new();
+    for (name, asset) in compilation.assets() {
+      if !budget_assets.contains(name) || !self.asset_filter(name, asset).await {
+        continue;
+      }
+      if let Some(source) = asset.get_source() {
+        assets.insert(name.clone(), source.buffer().to_vec());
+      }
+    }
+
+    let baseline = match &self.options.baseline {
+      Some(baseline) => baseline.load(compilation).await?,
+      None => None,
+    };
+    let (violations, measured) = budget::check_budgets(
+      &self.options.budgets,
+      compilation,
+      &assets,
+      baseline.as_ref().zip(
+        self
+          .options
+          .baseline
+          .as_ref()
+          .map(|baseline| baseline.threshold),
+      ),
+      &mut self.compressed_sizes.lock().expect("should lock"),
+    )?;
+
+    if let Some(options) = &self.options.baseline
+      && (options.update || baseline.is_none())
+    {
+      options.save(compilation, &measured).await?;
+    }
+    Ok(violations)
+  }
 }
 
 #[plugin_hook(CompilerAfterEmit for SizeLimitsPlugin)]
@@ -198,6 +249,12 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
     }
   }
 
+  let budget_violations = if self.options.budgets.is_empty() {
+    vec![]
+  } else {
+    self.check_budgets(compilation).await?
+  };
+
   if let Some(hints) = hints {
     let mut diagnostics = vec![];
 
@@ -219,6 +276,15 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
       );
     }
 
+    for violation in &budget_violations {
+      Self::add_diagnostic(
+        hints,
+        String::from("size budget exceeded"),
+        violation.message(),
+        &mut diagnostics,
+      );
+    }
+
     if !diagnostics.is_empty() {
       let has_async_chunk = compilation
         .chunk_by_ukey
@@ -1,2 +1,2 @@
 mod wasm_import_dependency;
-pub use wasm_import_dependency::WasmImportDependency;
+pub use wasm_import_dependency::{WasmImportDependency, WasmImportDescription};
@@ -2,10 +2,39 @@ use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPreset};
 use rspack_core::{
   AsContextDependency, AsDependencyCodeGeneration, Dependency, DependencyCategory, DependencyId,
   DependencyRange, DependencyType, ExtendedReferencedExport, FactorizeInfo, ModuleDependency,
-  ModuleGraph, ModuleGraphCacheArtifact, RuntimeSpec,
+  ModuleGraph, ModuleGraphCacheArtifact, ModuleType, RuntimeSpec,
 };
+use rspack_error::Diagnostic;
 use swc_core::ecma::atoms::Atom;
 
+/// The kind of an imported entity, as described by the import section of a wasm binary.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub enum WasmImportDescription {
+  /// A function import, with the value types of its parameters.
+  Func {
+    params: Vec<String>,
+  },
+  Table,
+  Memory,
+  Global,
+  Tag,
+}
+
+impl WasmImportDescription {
+  /// Non-function imports can't be wrapped by a js function, they have to be
+  /// provided by another wasm instance directly.
+  pub fn only_direct_import(&self) -> Option<&'static str> {
+    match self {
+      WasmImportDescription::Func { .. } => None,
+      WasmImportDescription::Table => Some("Table"),
+      WasmImportDescription::Memory => Some("Memory"),
+      WasmImportDescription::Global => Some("Global"),
+      WasmImportDescription::Tag => Some("Tag"),
+    }
+  }
+}
+
 #[allow(dead_code)]
 #[cacheable]
 #[derive(Debug, Clone)]
@@ -14,7 +43,8 @@ pub struct WasmImportDependency {
   #[cacheable(with=AsPreset)]
   name: Atom,
   request: String,
-  // only_direct_import: bool,
+  // only available for sync wasm modules
+  description: Option<WasmImportDescription>,
   span: Option<DependencyRange>,
   factorize_info: FactorizeInfo,
 }
@@ -25,15 +55,37 @@ impl WasmImportDependency {
       id: DependencyId::new(),
       name: name.into(),
       request,
-      // only_direct_import,
+      description: None,
       span: None,
       factorize_info: Default::default(),
     }
   }
 
+  pub fn new_with_description(
+    request: String,
+    name: String,
+    description: WasmImportDescription,
+  ) -> Self {
+    Self {
+      description: Some(description),
+      ..Self::new(request, name)
+    }
+  }
+
   pub fn name(&self) -> &Atom {
     &self.name
   }
+
+  pub fn description(&self) -> Option<&WasmImportDescription> {
+    self.description.as_ref()
+  }
+
+  pub fn only_direct_import(&self) -> Option<&'static str> {
+    self
+      .description
+      .as_ref()
+      .and_then(WasmImportDescription::only_direct_import)
+  }
 }
 
 #[cacheable_dyn]
@@ -50,6 +102,25 @@ impl Dependency for WasmImportDependency {
     &DependencyType::WasmImport
   }
 
+  fn get_diagnostics(
+    &self,
+    module_graph: &ModuleGraph,
+    _module_graph_cache: &ModuleGraphCacheArtifact,
+  ) -> Option<Vec<Diagnostic>> {
+    let only_direct_import = self.only_direct_import()?;
+    let module = module_graph.get_module_by_dependency_id(&self.id)?;
+    if module.module_type() == &ModuleType::WasmSync {
+      return None;
+    }
+    Some(vec![Diagnostic::error(
+      "UnsupportedWebAssemblyFeatureError".into(),
+      format!(
+        "Import \"{}\" from \"{}\" with {only_direct_import} can only be used for direct wasm to wasm dependencies",
+        self.name, self.request
+      ),
+    )])
+  }
+
   fn get_referenced_exports(
     &self,
     _module_graph: &ModuleGraph,
@@ -2,9 +2,14 @@ mod dependency;
 mod loading_plugin;
 mod parser_and_generator;
 mod runtime;
+mod sync_parser_and_generator;
+mod sync_runtime;
+mod sync_wasm_plugin;
 mod wasm_plugin;
 
 pub use loading_plugin::{
-  FetchCompileAsyncWasmPlugin, UniversalCompileAsyncWasmPlugin, enable_wasm_loading_plugin,
+  FetchCompileAsyncWasmPlugin, FetchCompileWasmPlugin, UniversalCompileAsyncWasmPlugin,
+  enable_wasm_loading_plugin,
 };
+pub use sync_wasm_plugin::SyncWasmPlugin;
 pub use wasm_plugin::AsyncWasmPlugin;
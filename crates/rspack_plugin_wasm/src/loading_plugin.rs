@@ -5,13 +5,169 @@ use rspack_core::{
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::runtime::AsyncWasmLoadingRuntimeModule;
+use crate::{
+  runtime::AsyncWasmLoadingRuntimeModule,
+  sync_runtime::{WasmChunkLoadingRuntimeModule, get_all_sync_wasm_modules},
+};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(
+  wasm_loading_type: WasmLoadingType,
+  plugins: &mut Vec<BoxPlugin>,
+) {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin::default().boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new().boxed(),
-    WasmLoadingType::Universal => UniversalCompileAsyncWasmPlugin::default().boxed(),
+    WasmLoadingType::Fetch => {
+      plugins.push(FetchCompileWasmPlugin::default().boxed());
+      plugins.push(FetchCompileAsyncWasmPlugin::default().boxed());
+    }
+    WasmLoadingType::AsyncNode => {
+      plugins.push(ReadFileCompileWasmPlugin::new().boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new().boxed());
+    }
+    WasmLoadingType::Universal => {
+      plugins.push(UniversalCompileAsyncWasmPlugin::default().boxed());
+    }
+  }
+}
+
+fn is_enabled_for_chunk(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  wasm_loading_type: WasmLoadingType,
+) -> bool {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  let wasm_loading = chunk
+    .get_entry_options(&compilation.chunk_group_by_ukey)
+    .and_then(|options| options.wasm_loading.clone())
+    .unwrap_or_else(|| compilation.options.output.wasm_loading.clone());
+  matches!(wasm_loading, WasmLoading::Enable(ty) if ty == wasm_loading_type)
+}
+
+fn read_file_load_binary_code(compilation: &Compilation) -> String {
+  let import_enabled = compilation.options.output.module
+    && compilation
+      .options
+      .output
+      .environment
+      .dynamic_import
+      .unwrap_or_default();
+
+  if import_enabled {
+    include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
+  } else {
+    include_str!("runtime/read_file_compile_async_wasm.js").to_string()
+  }
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct FetchCompileWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for FetchCompileWasmPlugin)]
+async fn fetch_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+  runtime_modules_to_add: &mut Vec<(ChunkUkey, Box<dyn RuntimeModule>)>,
+) -> Result<Option<()>> {
+  if !runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    || !is_enabled_for_chunk(compilation, chunk_ukey, WasmLoadingType::Fetch)
+    || get_all_sync_wasm_modules(compilation, chunk_ukey).is_empty()
+  {
+    return Ok(None);
+  }
+
+  runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+  runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+  runtime_requirements_mut.insert(RuntimeGlobals::WASM_INSTANCES);
+  runtime_modules_to_add.push((
+    *chunk_ukey,
+    WasmChunkLoadingRuntimeModule::new(
+      &compilation.runtime_template,
+      format!(
+        "fetch({} + $PATH)",
+        compilation
+          .runtime_template
+          .render_runtime_globals(&RuntimeGlobals::PUBLIC_PATH)
+      ),
+      true,
+      *chunk_ukey,
+    )
+    .boxed(),
+  ));
+
+  Ok(None)
+}
+
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compilation_hooks.runtime_requirement_in_tree.tap(
+      fetch_compile_wasm_plugin_runtime_requirements_in_tree::new(self),
+    );
+    Ok(())
+  }
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ReadFileCompileWasmPlugin {}
+
+impl ReadFileCompileWasmPlugin {
+  fn new() -> Self {
+    Self::new_inner()
+  }
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for ReadFileCompileWasmPlugin)]
+async fn read_file_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+  runtime_modules_to_add: &mut Vec<(ChunkUkey, Box<dyn RuntimeModule>)>,
+) -> Result<Option<()>> {
+  if !runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    || !is_enabled_for_chunk(compilation, chunk_ukey, WasmLoadingType::AsyncNode)
+    || get_all_sync_wasm_modules(compilation, chunk_ukey).is_empty()
+  {
+    return Ok(None);
+  }
+
+  runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+  runtime_requirements_mut.insert(RuntimeGlobals::WASM_INSTANCES);
+  runtime_modules_to_add.push((
+    *chunk_ukey,
+    WasmChunkLoadingRuntimeModule::new(
+      &compilation.runtime_template,
+      read_file_load_binary_code(compilation),
+      false,
+      *chunk_ukey,
+    )
+    .boxed(),
+  ));
+
+  Ok(None)
+}
+
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(read_file_compile_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
   }
 }
 
@@ -91,23 +247,11 @@ async fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
     return Ok(None);
   }
 
-  let import_enabled = compilation.options.output.module
-    && compilation
-      .options
-      .output
-      .environment
-      .dynamic_import
-      .unwrap_or_default();
-
   runtime_modules_to_add.push((
     *chunk_ukey,
     AsyncWasmLoadingRuntimeModule::new(
       &compilation.runtime_template,
-      if import_enabled {
-        include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
-      } else {
-        include_str!("runtime/read_file_compile_async_wasm.js").to_string()
-      },
+      read_file_load_binary_code(compilation),
       false,
       *chunk_ukey,
     )
@@ -149,18 +293,7 @@ async fn universal_compile_async_wasm_plugin_runtime_requirements_in_tree(
     return Ok(None);
   }
 
-  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
-  let wasm_loading = chunk
-    .get_entry_options(&compilation.chunk_group_by_ukey)
-    .and_then(|options| options.wasm_loading.clone())
-    .unwrap_or_else(|| compilation.options.output.wasm_loading.clone());
-
-  let is_enabled_for_chunk = matches!(
-    wasm_loading,
-    WasmLoading::Enable(WasmLoadingType::Universal)
-  );
-
-  if !is_enabled_for_chunk {
+  if !is_enabled_for_chunk(compilation, chunk_ukey, WasmLoadingType::Universal) {
     return Ok(None);
   }
 
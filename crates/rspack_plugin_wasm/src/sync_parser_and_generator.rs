@@ -0,0 +1,247 @@
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_core::{
+  BoxDependency, BuildMetaExportsType, DependencyId, DependencyType, GenerateContext, ImportPhase,
+  Module, ModuleGraph, ModuleIdentifier, ParseContext, ParseResult, ParserAndGenerator,
+  RuntimeGlobals, SourceType, StaticExportsDependency, StaticExportsSpec,
+  rspack_sources::{BoxSource, RawStringSource, Source, SourceExt},
+};
+use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_util::itoa;
+use swc_core::atoms::Atom;
+use wasmparser::{CompositeInnerType, Import, Parser, Payload, TypeRef};
+
+use crate::{
+  dependency::{WasmImportDependency, WasmImportDescription},
+  parser_and_generator::WASM_SOURCE_TYPE,
+};
+
+/// Parser and generator for `webassembly/sync` modules.
+///
+/// The wasm instance is created while loading the chunk that contains the module,
+/// the generated js module only re-exports the instance exports and evaluates the
+/// imported modules in order, so it can be consumed synchronously.
+#[cacheable]
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator;
+
+#[cacheable_dyn]
+#[async_trait::async_trait]
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self, _module: &dyn Module, _module_graph: &ModuleGraph) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  async fn parse<'a>(
+    &mut self,
+    parse_context: ParseContext<'a>,
+  ) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+
+    let mut exports = Vec::with_capacity(1);
+    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+    let mut diagnostic = Vec::with_capacity(1);
+    // params of each type in the type section, `None` for non-function types
+    let mut func_types: Vec<Option<Vec<String>>> = vec![];
+
+    for payload in Parser::new(0).parse_all(&source.buffer()) {
+      match payload {
+        Ok(payload) => match payload {
+          Payload::TypeSection(s) => {
+            for rec_group in s {
+              match rec_group {
+                Ok(rec_group) => {
+                  func_types.extend(rec_group.into_types().map(|sub_type| {
+                    match sub_type.composite_type.inner {
+                      CompositeInnerType::Func(func_type) => Some(
+                        func_type
+                          .params()
+                          .iter()
+                          .map(|param| param.to_string())
+                          .collect(),
+                      ),
+                      _ => None,
+                    }
+                  }));
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Type Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          Payload::ExportSection(s) => {
+            for export in s {
+              match export {
+                Ok(export) => exports.push(export.name.to_string()),
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Export Parse Error".into(),
+                  err.to_string(),
+                )),
+              };
+            }
+          }
+          Payload::ImportSection(s) => {
+            for import in s {
+              match import {
+                Ok(Import { module, name, ty }) => {
+                  let description = match ty {
+                    TypeRef::Func(index) => WasmImportDescription::Func {
+                      params: func_types
+                        .get(index as usize)
+                        .cloned()
+                        .flatten()
+                        .unwrap_or_default(),
+                    },
+                    TypeRef::Table(_) => WasmImportDescription::Table,
+                    TypeRef::Memory(_) => WasmImportDescription::Memory,
+                    TypeRef::Global(_) => WasmImportDescription::Global,
+                    TypeRef::Tag(_) => WasmImportDescription::Tag,
+                  };
+                  dependencies.push(Box::new(WasmImportDependency::new_with_description(
+                    module.into(),
+                    name.into(),
+                    description,
+                  )));
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Import Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          _ => {}
+        },
+        Err(err) => {
+          diagnostic.push(Diagnostic::error(
+            "Wasm Parse Error".into(),
+            err.to_string(),
+          ));
+        }
+      }
+    }
+
+    dependencies.push(Box::new(StaticExportsDependency::new(
+      StaticExportsSpec::Array(exports.iter().cloned().map(Atom::from).collect::<Vec<_>>()),
+      false,
+    )));
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        code_generation_dependencies: vec![],
+        source,
+        side_effects_bailout: None,
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: Option<&SourceType>) -> f64 {
+    match source_type.unwrap_or(&SourceType::Wasm) {
+      SourceType::JavaScript => {
+        40.0
+          + module
+            .get_presentational_dependencies()
+            .map_or(0.0, |i| i.len() as f64 * 10.0)
+      }
+      SourceType::Wasm => module.source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  async fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let compilation = generate_context.compilation;
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+
+        let module_graph = &compilation.get_module_graph();
+
+        let mut imported_modules =
+          IndexMap::<ModuleIdentifier, (DependencyId, &str, String)>::new();
+        for dep in module
+          .get_dependencies()
+          .iter()
+          .map(|id| module_graph.dependency_by_id(id))
+          .filter(|dep| dep.dependency_type() == &DependencyType::WasmImport)
+        {
+          let Some(imported_module) = module_graph.module_identifier_by_dependency_id(dep.id())
+          else {
+            continue;
+          };
+          let Some(dep) = dep.as_module_dependency() else {
+            continue;
+          };
+          let len = imported_modules.len();
+          imported_modules.entry(*imported_module).or_insert_with(|| {
+            let mut len_buffer = itoa::Buffer::new();
+            let len_str = len_buffer.format(len);
+            (*dep.id(), dep.request(), format!("rspack_import_{len_str}"))
+          });
+        }
+
+        let imports_code = imported_modules
+          .values()
+          .map(|(dep_id, request, import_var)| {
+            let (import_code, compat_code) = compilation.runtime_template.import_statement(
+              module,
+              compilation,
+              runtime_requirements,
+              dep_id,
+              import_var,
+              request,
+              ImportPhase::Evaluation,
+              false,
+            );
+            format!("{import_code}{compat_code}")
+          })
+          .collect::<String>();
+
+        let wasm_instances = compilation
+          .runtime_template
+          .render_runtime_globals(&RuntimeGlobals::WASM_INSTANCES);
+
+        Ok(
+          RawStringSource::from(format!(
+            r#""use strict";
+// Instantiate WebAssembly module
+var wasmExports = {wasm_instances}[module.id];
+// export exports from WebAssembly module
+module.exports = wasmExports;
+// exec imports from WebAssembly module (for esm order)
+{imports_code}"#
+          ))
+          .boxed(),
+        )
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+
+  fn get_concatenation_bailout_reason(
+    &self,
+    _module: &dyn Module,
+    _mg: &rspack_core::ModuleGraph,
+    _cg: &rspack_core::ChunkGraph,
+  ) -> Option<Cow<'static, str>> {
+    Some("Module Concatenation is not implemented for SyncWasmParserAndGenerator".into())
+  }
+}
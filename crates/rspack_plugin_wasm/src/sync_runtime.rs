@@ -0,0 +1,400 @@
+use cow_utils::CowUtils;
+use indexmap::IndexMap;
+use rspack_collections::Identifier;
+use rspack_core::{
+  BoxModule, ChunkGraph, ChunkUkey, Compilation, ExportsInfoGetter, GetUsedNameParam, Module,
+  ModuleDependency, ModuleType, PathData, PrefetchExportsInfoMode, RuntimeGlobals, RuntimeModule,
+  RuntimeModuleStage, RuntimeSpec, RuntimeTemplate, UsedName, get_filename_without_hash_length,
+  impl_runtime_module,
+};
+use rspack_util::{itoa, json_stringify};
+
+use crate::dependency::{WasmImportDependency, WasmImportDescription};
+
+/// Collect the sync wasm modules in all async chunks of the chunk, grouped by chunk.
+pub(crate) fn get_all_sync_wasm_modules<'a>(
+  compilation: &'a Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> Vec<(ChunkUkey, Vec<&'a BoxModule>)> {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  let module_graph = compilation.get_module_graph();
+  chunk
+    .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+    .into_iter()
+    .filter_map(|chunk_ukey| {
+      let modules = compilation
+        .chunk_graph
+        .get_ordered_chunk_modules(&chunk_ukey, module_graph)
+        .into_iter()
+        .filter(|m| m.module_type() == &ModuleType::WasmSync)
+        .collect::<Vec<_>>();
+      (!modules.is_empty()).then_some((chunk_ukey, modules))
+    })
+    .collect()
+}
+
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct WasmChunkLoadingRuntimeModule {
+  id: Identifier,
+  generate_load_binary_code: String,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(
+    runtime_template: &RuntimeTemplate,
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self::with_default(
+      Identifier::from(format!(
+        "{}wasm_chunk_loading",
+        runtime_template.runtime_module_prefix()
+      )),
+      generate_load_binary_code,
+      supports_streaming,
+      chunk,
+    )
+  }
+}
+
+#[async_trait::async_trait]
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  async fn generate(&self, compilation: &Compilation) -> rspack_error::Result<String> {
+    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
+    let runtime_template = &compilation.runtime_template;
+
+    let mut wasm_module_map = vec![];
+    let mut wasm_modules = IndexMap::new();
+    for (chunk_ukey, modules) in get_all_sync_wasm_modules(compilation, &self.chunk) {
+      let Some(chunk_id) = compilation.chunk_by_ukey.expect_get(&chunk_ukey).id() else {
+        continue;
+      };
+      let module_ids = modules
+        .iter()
+        .filter_map(|m| {
+          ChunkGraph::get_module_id(&compilation.module_ids_artifact, m.identifier())
+            .map(json_stringify)
+        })
+        .collect::<Vec<_>>();
+      wasm_module_map.push(format!(
+        "{}: [{}]",
+        json_stringify(chunk_id),
+        module_ids.join(", ")
+      ));
+      for m in modules {
+        wasm_modules.insert(m.identifier(), m);
+      }
+    }
+
+    let mut declarations = vec![];
+    let mut import_objects = vec![];
+    let mut module_hashes = vec![];
+    for module in wasm_modules.values() {
+      let Some(module_id) =
+        ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier())
+      else {
+        continue;
+      };
+      let module_id = json_stringify(module_id);
+      if let Some(hash) = &module.build_info().hash {
+        module_hashes.push(format!(
+          "{module_id}: {}",
+          json_stringify(hash.rendered(16))
+        ));
+      }
+      import_objects.push(generate_import_object(
+        compilation,
+        module.as_ref(),
+        &module_id,
+        chunk.runtime(),
+        &mut declarations,
+      ));
+    }
+
+    let (fake_filename, hash_len_map) =
+      get_filename_without_hash_length(&compilation.options.output.webassembly_module_filename);
+
+    // Even use content hash when [hash] in webpack
+    let hash = match hash_len_map
+      .get("[contenthash]")
+      .or(hash_len_map.get("[hash]"))
+    {
+      Some(hash_len) => {
+        let mut hash_len_buffer = itoa::Buffer::new();
+        let hash_len_str = hash_len_buffer.format(*hash_len);
+        format!("\" + wasmModuleHash.slice(0, {}) + \"", hash_len_str)
+      }
+      None => "\" + wasmModuleHash + \"".to_string(),
+    };
+
+    let path = compilation
+      .get_path(
+        &fake_filename,
+        PathData::default()
+          .hash(&hash)
+          .content_hash(&hash)
+          .id(&PathData::prepare_id("\" + wasmModuleId + \""))
+          .runtime(chunk.runtime().as_str()),
+      )
+      .await?;
+
+    let req = self
+      .generate_load_binary_code
+      .cow_replace(
+        "$IMPORT_META_NAME",
+        compilation.options.output.import_meta_name.as_str(),
+      )
+      .cow_replace("$PATH", &format!("\"{path}\""))
+      .trim_end_matches(';')
+      .to_string();
+
+    let instantiate_code = if self.supports_streaming {
+      r#"if(importObject && typeof importObject.then === 'function' && typeof WebAssembly.compileStreaming === 'function') {
+          promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {
+            return WebAssembly.instantiate(items[0], items[1]);
+          });
+        } else if(typeof WebAssembly.instantiateStreaming === 'function') {
+          promise = WebAssembly.instantiateStreaming(req, importObject);
+        } else {
+          var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+          promise = bytesPromise.then(function(bytes) {
+            return WebAssembly.instantiate(bytes, importObject);
+          });
+        }"#
+    } else {
+      r#"var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+        if(importObject && typeof importObject.then === 'function') {
+          promise = Promise.all([
+            bytesPromise.then(function(bytes) { return WebAssembly.compile(bytes); }),
+            importObject
+          ]).then(function(items) {
+            return WebAssembly.instantiate(items[0], items[1]);
+          });
+        } else {
+          promise = bytesPromise.then(function(bytes) {
+            return WebAssembly.instantiate(bytes, importObject);
+          });
+        }"#
+    };
+
+    let wasm_instances = runtime_template.render_runtime_globals(&RuntimeGlobals::WASM_INSTANCES);
+
+    Ok(format!(
+      r#"
+// object to store loaded and loading wasm modules
+var installedWasmModules = {{}};
+
+function promiseResolve() {{ return Promise.resolve(); }}
+
+{declarations}
+var wasmImportObjects = {{
+{import_objects}
+}};
+
+var wasmModuleMap = {{
+{wasm_module_map}
+}};
+
+var wasmModuleHashMap = {{
+{module_hashes}
+}};
+
+// object with all WebAssembly.instance exports
+{wasm_instances} = {{}};
+
+// Fetch + compile chunk loading for webassembly
+{ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+  var wasmModules = wasmModuleMap[chunkId] || [];
+  wasmModules.forEach(function(wasmModuleId, idx) {{
+    var installedWasmModuleData = installedWasmModules[wasmModuleId];
+    // a Promise means "currently loading" or "already loaded".
+    if(installedWasmModuleData)
+      promises.push(installedWasmModuleData);
+    else {{
+      var importObject = wasmImportObjects[wasmModuleId]();
+      var wasmModuleHash = wasmModuleHashMap[wasmModuleId];
+      var req = {req};
+      var promise;
+      {instantiate_code}
+      promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+        return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+      }}));
+    }}
+  }});
+}};
+"#,
+      declarations = declarations.join("\n"),
+      import_objects = import_objects.join(",\n"),
+      wasm_module_map = wasm_module_map.join(",\n"),
+      module_hashes = module_hashes.join(",\n"),
+      ensure_chunk_handlers =
+        runtime_template.render_runtime_globals(&RuntimeGlobals::ENSURE_CHUNK_HANDLERS),
+    ))
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+}
+
+/// Generate the factory of the import object passed to `WebAssembly.instantiate`.
+///
+/// Function imports are bound lazily to the exports of the imported module, as the
+/// module is only evaluated after the wasm instance has been created. Other imports
+/// (memory, table, global) have to come from another wasm instance, so the factory
+/// waits for that instance before creating the import object.
+fn generate_import_object(
+  compilation: &Compilation,
+  module: &dyn Module,
+  module_id: &str,
+  runtime: &RuntimeSpec,
+  declarations: &mut Vec<String>,
+) -> String {
+  let module_graph = compilation.get_module_graph();
+  let module_cache = compilation
+    .runtime_template
+    .render_runtime_globals(&RuntimeGlobals::MODULE_CACHE);
+
+  let mut wait_for_instances = IndexMap::<String, String>::new();
+  let mut properties = IndexMap::<&str, Vec<String>>::new();
+
+  for dep_id in module.get_dependencies() {
+    let Some(dep) = module_graph
+      .dependency_by_id(dep_id)
+      .downcast_ref::<WasmImportDependency>()
+    else {
+      continue;
+    };
+    let Some(description) = dep.description() else {
+      continue;
+    };
+    let Some(imported_module) = module_graph.get_module_by_dependency_id(dep_id) else {
+      continue;
+    };
+    let Some(imported_module_id) = ChunkGraph::get_module_id(
+      &compilation.module_ids_artifact,
+      imported_module.identifier(),
+    ) else {
+      continue;
+    };
+    let exports_info = module_graph.get_prefetched_exports_info(
+      &imported_module.identifier(),
+      PrefetchExportsInfoMode::Default,
+    );
+    let Some(UsedName::Normal(used_name)) = ExportsInfoGetter::get_used_name(
+      GetUsedNameParam::WithNames(&exports_info),
+      Some(runtime),
+      std::slice::from_ref(dep.name()),
+    ) else {
+      continue;
+    };
+    let used_name = used_name
+      .iter()
+      .map(|name| format!("[{}]", json_stringify(name.as_str())))
+      .collect::<String>();
+    let imported_module_id = json_stringify(imported_module_id);
+
+    let value = match description {
+      WasmImportDescription::Func { params } => {
+        let params = params
+          .iter()
+          .enumerate()
+          .map(|(index, ty)| {
+            let ty = ty
+              .chars()
+              .filter(char::is_ascii_alphanumeric)
+              .collect::<String>();
+            format!("p{index}{ty}")
+          })
+          .collect::<Vec<_>>()
+          .join(", ");
+        let mut len_buffer = itoa::Buffer::new();
+        let cache = format!(
+          "wasmImportedFuncCache{}",
+          len_buffer.format(declarations.len())
+        );
+        declarations.push(format!("var {cache};"));
+        format!(
+          r#"function({params}) {{
+    if({cache} === undefined) {cache} = {module_cache}[{imported_module_id}].exports;
+    return {cache}{used_name}({params});
+  }}"#
+        )
+      }
+      _ => {
+        if imported_module.module_type() != &ModuleType::WasmSync {
+          // reported by `WasmImportDependency::get_diagnostics`
+          continue;
+        }
+        let mut len_buffer = itoa::Buffer::new();
+        let instance_var = format!("m{}", len_buffer.format(wait_for_instances.len()));
+        let value = format!("{instance_var}{used_name}");
+        wait_for_instances.insert(instance_var, imported_module_id);
+        value
+      }
+    };
+
+    properties
+      .entry(dep.request())
+      .or_default()
+      .push(format!("{}: {value}", json_stringify(dep.name().as_str())));
+  }
+
+  let import_object = format!(
+    "return {{\n{}\n}};",
+    properties
+      .iter()
+      .map(|(request, values)| format!(
+        "{}: {{\n{}\n}}",
+        json_stringify(request),
+        values.join(",\n")
+      ))
+      .collect::<Vec<_>>()
+      .join(",\n")
+  );
+
+  match wait_for_instances.len() {
+    0 => format!("{module_id}: function() {{\n{import_object}\n}}"),
+    1 => {
+      let (variable, instance_module_id) = wait_for_instances
+        .first()
+        .expect("should have one instance to wait for");
+      format!(
+        r#"{module_id}: function() {{
+return promiseResolve().then(function() {{ return installedWasmModules[{instance_module_id}]; }}).then(function({variable}) {{
+{import_object}
+}});
+}}"#
+      )
+    }
+    _ => {
+      let promises = wait_for_instances
+        .values()
+        .map(|id| format!("installedWasmModules[{id}]"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let variables = wait_for_instances
+        .keys()
+        .enumerate()
+        .map(|(index, name)| format!("{name} = array[{index}]"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      format!(
+        r#"{module_id}: function() {{
+return promiseResolve().then(function() {{ return Promise.all([{promises}]); }}).then(function(array) {{
+var {variables};
+{import_object}
+}});
+}}"#
+      )
+    }
+  }
+}
@@ -0,0 +1,109 @@
+use std::fmt::Debug;
+
+use rspack_core::{
+  ChunkUkey, Compilation, CompilationOptimizeChunks, CompilationParams, CompilationRenderManifest,
+  CompilerCompilation, DependencyType, ModuleType, ParserAndGenerator, Plugin, RenderManifestEntry,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::{
+  sync_parser_and_generator::SyncWasmParserAndGenerator, wasm_plugin::render_wasm_manifest,
+};
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin {}
+
+#[plugin_hook(CompilerCompilation for SyncWasmPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::WasmImport,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+// Sync wasm modules are instantiated during chunk loading, so they can't be placed
+// in initial chunks which are loaded without `ensureChunk`.
+#[plugin_hook(CompilationOptimizeChunks for SyncWasmPlugin, stage = Compilation::OPTIMIZE_CHUNKS_STAGE_BASIC)]
+async fn optimize_chunks(&self, compilation: &mut Compilation) -> Result<Option<bool>> {
+  let module_graph = compilation.get_module_graph();
+  let mut initial_wasm_modules = compilation
+    .chunk_by_ukey
+    .iter()
+    .filter(|(_, chunk)| chunk.can_be_initial(&compilation.chunk_group_by_ukey))
+    .flat_map(|(chunk_ukey, _)| {
+      compilation
+        .chunk_graph
+        .get_chunk_modules(chunk_ukey, module_graph)
+    })
+    .filter(|module| module.module_type() == &ModuleType::WasmSync)
+    .map(|module| {
+      (
+        module.identifier(),
+        module
+          .readable_identifier(&compilation.options.context)
+          .to_string(),
+      )
+    })
+    .collect::<Vec<_>>();
+  initial_wasm_modules.sort_unstable();
+  initial_wasm_modules.dedup();
+
+  let diagnostics = initial_wasm_modules
+    .into_iter()
+    .map(|(module_identifier, readable_identifier)| {
+      let mut diagnostic = Diagnostic::error(
+        "WebAssemblyInInitialChunkError".into(),
+        format!(
+          "WebAssembly module {readable_identifier} is included in initial chunk.\nThis is not allowed, because WebAssembly download and compilation must happen asynchronous.\nAdd an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module."
+        ),
+      );
+      diagnostic.module_identifier = Some(module_identifier);
+      diagnostic
+    })
+    .collect::<Vec<_>>();
+  compilation.extend_diagnostics(diagnostics);
+  Ok(None)
+}
+
+#[plugin_hook(CompilationRenderManifest for SyncWasmPlugin)]
+async fn render_manifest(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  manifest: &mut Vec<RenderManifestEntry>,
+  _diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  render_wasm_manifest(compilation, chunk_ukey, manifest, &ModuleType::WasmSync).await
+}
+
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.WebAssemblyModulesPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compiler_hooks.compilation.tap(compilation::new(self));
+    ctx
+      .compilation_hooks
+      .optimize_chunks
+      .tap(optimize_chunks::new(self));
+    ctx
+      .compilation_hooks
+      .render_manifest
+      .tap(render_manifest::new(self));
+
+    ctx.register_parser_and_generator_builder(
+      ModuleType::WasmSync,
+      Box::new(move |_, _| Box::new(SyncWasmParserAndGenerator) as Box<dyn ParserAndGenerator>),
+    );
+
+    Ok(())
+  }
+}
@@ -38,6 +38,15 @@ async fn render_manifest(
   chunk_ukey: &ChunkUkey,
   manifest: &mut Vec<RenderManifestEntry>,
   _diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  render_wasm_manifest(compilation, chunk_ukey, manifest, &ModuleType::WasmAsync).await
+}
+
+pub(crate) async fn render_wasm_manifest(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  manifest: &mut Vec<RenderManifestEntry>,
+  module_type: &ModuleType,
 ) -> Result<()> {
   let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
   let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
@@ -48,7 +57,7 @@ async fn render_manifest(
     .get_chunk_modules(chunk_ukey, module_graph);
 
   for m in ordered_modules {
-    if m.module_type() != &ModuleType::WasmAsync {
+    if m.module_type() != module_type {
       continue;
     }
     let Some(source) = compilation
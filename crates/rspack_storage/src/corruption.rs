@@ -0,0 +1,33 @@
+use rspack_paths::Utf8PathBuf;
+
+/// The packs of a scope which failed the checksum verification on load
+///
+/// The corrupt packs are moved to the quarantine directory and the scope is invalidated,
+/// the other scopes are not affected.
+#[derive(Debug, Clone)]
+pub struct CorruptPacks {
+  pub scope: String,
+  /// The original paths of corrupt packs
+  pub packs: Vec<Utf8PathBuf>,
+  /// The directory which keeps the corrupt packs for inspection
+  pub quarantine: Utf8PathBuf,
+}
+
+impl From<CorruptPacks> for rspack_error::Error {
+  fn from(value: CorruptPacks) -> rspack_error::Error {
+    let mut error = rspack_error::Error::warning(format!(
+      "Persistent cache scope `{}` is invalidated due to {} corrupt packs, they are moved to {}:\n{}",
+      value.scope,
+      value.packs.len(),
+      value.quarantine,
+      value
+        .packs
+        .iter()
+        .map(|pack| format!("  {pack}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+    ));
+    error.code = Some("PersistentCacheCorruptPacks".into());
+    error
+  }
+}
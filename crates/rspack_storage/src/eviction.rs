@@ -0,0 +1,16 @@
+use rspack_paths::Utf8PathBuf;
+
+/// The result of garbage collection of a cache directory
+#[derive(Debug, Default, Clone)]
+pub struct EvictionResult {
+  /// The evicted versions, the least recently accessed first
+  pub evicted_versions: Vec<String>,
+  /// The pack files of evicted versions
+  pub evicted_packs: Vec<Utf8PathBuf>,
+  /// The bytes reclaimed by eviction
+  pub reclaimed_size: u64,
+  /// The number of partially stale packs which are merged during save
+  pub compacted_packs: usize,
+  /// The total bytes of the cache directory after collection
+  pub remain_size: u64,
+}
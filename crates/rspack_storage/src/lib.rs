@@ -1,12 +1,21 @@
+mod corruption;
 mod error;
+mod eviction;
 mod fs;
 mod pack;
+mod remote;
 
 use std::sync::Arc;
 
+pub use corruption::CorruptPacks;
 pub use error::Result;
+pub use eviction::EvictionResult;
 pub use fs::{BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, Reader, Writer};
 pub use pack::{PackStorage, PackStorageOptions};
+pub use remote::{
+  DirectoryTransport, HttpTransport, RemoteHttpClient, RemoteHttpResponse, RemoteStorage,
+  RemoteStorageOptions, RemoteTransport,
+};
 use tokio::sync::oneshot::Receiver;
 
 type ItemKey = Vec<u8>;
@@ -22,6 +31,18 @@ pub trait Storage: std::fmt::Debug + Sync + Send {
   async fn reset(&self);
   /// Get list of all available scopes in the storage
   async fn scopes(&self) -> Result<Vec<String>>;
+  /// Take the garbage collection result of the last save since last call
+  fn take_eviction_result(&self) -> Option<EvictionResult> {
+    None
+  }
+  /// Take the corrupt packs found on load since last call
+  fn take_corrupt_packs(&self) -> Vec<CorruptPacks> {
+    vec![]
+  }
+  /// Take the warnings of the errors recovered by fallback since last call
+  fn take_warnings(&self) -> Vec<String> {
+    vec![]
+  }
 }
 
 pub type ArcStorage = Arc<dyn Storage>;
@@ -6,11 +6,14 @@ use super::options::PackOptions;
 
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct PackFileMeta {
+  /// the checksum of pack file, which is verified before reading the pack
   pub hash: String,
   pub name: String,
   pub size: usize,
   pub wrote: bool,
   pub generation: usize,
+  /// the last time the pack is loaded or wrote, the least recently accessed packs are evicted first
+  pub last_access: u64,
 }
 
 #[derive(Debug, Default, Clone)]
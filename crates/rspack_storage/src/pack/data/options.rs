@@ -11,4 +11,8 @@ pub struct RootOptions {
   pub root: Utf8PathBuf,
   pub expire: u64,
   pub clean: bool,
+  /// The max bytes of the cache directory, the least recently accessed versions will be evicted
+  pub max_size: Option<u64>,
+  /// The max number of generations stored in the cache directory, each version is a generation
+  pub max_generations: Option<usize>,
 }
@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use futures::future::join_all;
 use queue::TaskQueue;
+use rspack_paths::Utf8PathBuf;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use tokio::sync::{Mutex, oneshot, oneshot::Receiver};
 
@@ -13,7 +14,7 @@ use super::{
   strategy::{ScopeStrategy, WriteScopeResult},
 };
 use crate::{
-  ItemPairs, Result,
+  CorruptPacks, EvictionResult, ItemPairs, Result,
   error::{Error, ErrorType, ValidateResult},
 };
 
@@ -27,6 +28,8 @@ pub struct ScopeManager {
   pub scopes: Arc<Mutex<ScopeMap>>,
   pub root_meta: Arc<Mutex<RootMetaState>>,
   pub queue: TaskQueue,
+  pub eviction: Arc<std::sync::Mutex<Option<EvictionResult>>>,
+  pub corrupt_packs: std::sync::Mutex<Vec<CorruptPacks>>,
 }
 
 impl ScopeManager {
@@ -42,6 +45,8 @@ impl ScopeManager {
       scopes: Default::default(),
       queue: TaskQueue::new(),
       root_meta: Default::default(),
+      eviction: Default::default(),
+      corrupt_packs: Default::default(),
     }
   }
 
@@ -51,6 +56,7 @@ impl ScopeManager {
     let root_meta = self.root_meta.clone();
     let pack_options = self.pack_options.clone();
     let root_options = self.root_options.clone();
+    let eviction = self.eviction.clone();
     let (tx, rx) = oneshot::channel();
     self.queue.add_task(Box::pin(async move {
       let mut scopes_lock = scopes.lock().await;
@@ -87,8 +93,9 @@ impl ScopeManager {
       let old_scopes = std::mem::take(&mut *scopes_lock);
       let res = save_scopes(old_scopes, &root_meta, strategy.as_ref(), &root_options).await;
       let _ = match res {
-        Ok(new_scopes) => {
+        Ok((new_scopes, eviction_result)) => {
           let _ = std::mem::replace(&mut *scopes_lock, new_scopes);
+          *eviction.lock().expect("should get lock") = Some(eviction_result);
           tx.send(Ok(()))
         }
         Err(e) => tx.send(Err(e)),
@@ -156,6 +163,9 @@ impl ScopeManager {
         // clear scope if invalid
         ValidateResult::Invalid(detail) => {
           self.clear_scope(name).await;
+          if !detail.packs.is_empty() {
+            self.quarantine_packs(name, &detail.packs).await;
+          }
           Err(Error::from_detail(
             Some(ErrorType::Validate),
             Some(name),
@@ -175,6 +185,23 @@ impl ScopeManager {
     }
   }
 
+  /// Move the corrupt packs out of scope, so that they will not be read again
+  async fn quarantine_packs(&self, name: &'static str, packs: &[String]) {
+    let packs = packs.iter().map(Utf8PathBuf::from).collect::<Vec<_>>();
+    match self.strategy.quarantine_packs(&packs).await {
+      Ok(quarantine) => self
+        .corrupt_packs
+        .lock()
+        .expect("should get lock")
+        .push(CorruptPacks {
+          scope: name.to_string(),
+          packs,
+          quarantine,
+        }),
+      Err(e) => tracing::warn!("quarantine packs of scope `{name}` failed: {e}"),
+    }
+  }
+
   async fn validate_scope(&self, name: &'static str) -> Result<ValidateResult> {
     let root_meta_guard = self.root_meta.lock().await;
     // no root, no scope
@@ -193,8 +220,13 @@ impl ScopeManager {
       self.strategy.ensure_meta(scope).await?;
       let validated = self.strategy.validate_meta(scope).await?;
       if validated.is_valid() {
-        self.strategy.ensure_keys(scope).await?;
-        self.strategy.validate_packs(scope).await
+        // verify the checksums before reading packs
+        self.strategy.ensure_packs(scope).await?;
+        let validated = self.strategy.validate_packs(scope).await?;
+        if validated.is_valid() {
+          self.strategy.ensure_keys(scope).await?;
+        }
+        Ok(validated)
       } else {
         Ok(validated)
       }
@@ -211,6 +243,16 @@ impl ScopeManager {
     *self.root_meta.lock().await = RootMetaState::Pending;
   }
 
+  /// Take the garbage collection result of the last save since last call
+  pub fn take_eviction_result(&self) -> Option<EvictionResult> {
+    self.eviction.lock().expect("should get lock").take()
+  }
+
+  /// Take the corrupt packs found on load since last call
+  pub fn take_corrupt_packs(&self) -> Vec<CorruptPacks> {
+    std::mem::take(&mut *self.corrupt_packs.lock().expect("should get lock"))
+  }
+
   /// Get list of all available scopes in the storage
   pub async fn scopes(&self) -> Result<Vec<String>> {
     // only check lock file and root meta for the first time
@@ -282,19 +324,21 @@ async fn save_scopes(
   root_meta: &RootMeta,
   strategy: &dyn ScopeStrategy,
   root_options: &RootOptions,
-) -> Result<ScopeMap> {
+) -> Result<(ScopeMap, EvictionResult)> {
   scopes.retain(|_, scope| scope.loaded());
 
   strategy.before_all(&mut scopes).await?;
 
-  join_all(
+  let compacted_packs: usize = join_all(
     scopes
       .values_mut()
       .map(|scope| strategy.optimize_scope(scope)),
   )
   .await
   .into_iter()
-  .collect::<Result<Vec<_>>>()?;
+  .collect::<Result<Vec<_>>>()?
+  .into_iter()
+  .sum();
 
   let changed = join_all(scopes.values_mut().map(|scope| async move {
     let mut res = WriteScopeResult::default();
@@ -319,7 +363,14 @@ async fn save_scopes(
   strategy.after_all(&mut scopes).await?;
   strategy.clean(root_meta, &scopes, root_options).await?;
 
-  Ok(scopes.into_iter().collect())
+  // the cache has been saved, failing to collect garbage should not break it
+  let mut eviction_result = strategy.gc(root_options).await.unwrap_or_else(|e| {
+    tracing::warn!("collect cache garbage failed: {e}");
+    EvictionResult::default()
+  });
+  eviction_result.compacted_packs = compacted_packs;
+
+  Ok((scopes.into_iter().collect(), eviction_result))
 }
 
 #[cfg(test)]
@@ -371,6 +422,8 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
+      max_generations: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 10,
@@ -383,6 +436,7 @@ mod tests {
       fs.clone(),
       Some(1),
       Some(2),
+      None,
     ));
     let manager = ScopeManager::new(root_options, pack_options, strategy);
 
@@ -425,6 +479,8 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
+      max_generations: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 10,
@@ -437,6 +493,7 @@ mod tests {
       fs.clone(),
       Some(1),
       Some(2),
+      None,
     ));
     let manager = ScopeManager::new(root_options, pack_options, strategy);
 
@@ -498,6 +555,8 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
+      max_generations: None,
     });
     let pack_options = Arc::new(PackOptions {
       // different bucket size
@@ -511,6 +570,7 @@ mod tests {
       fs.clone(),
       Some(1),
       Some(2),
+      None,
     ));
     let manager = ScopeManager::new(root_options.clone(), pack_options.clone(), strategy.clone());
     // should report error when invalid failed
@@ -545,6 +605,8 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
+      max_generations: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 100,
@@ -557,6 +619,7 @@ mod tests {
       fs.clone(),
       Some(1),
       Some(2),
+      None,
     ));
     let manager = ScopeManager::new(root_options, pack_options, strategy);
 
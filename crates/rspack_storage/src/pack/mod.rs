@@ -14,7 +14,9 @@ use rustc_hash::FxHashMap as HashMap;
 use strategy::{ScopeUpdate, SplitPackStrategy};
 use tokio::sync::oneshot::Receiver;
 
-use crate::{FileSystem, ItemKey, ItemPairs, ItemValue, Storage, error::Result};
+use crate::{
+  CorruptPacks, EvictionResult, FileSystem, ItemKey, ItemPairs, ItemValue, Storage, error::Result,
+};
 
 pub type ScopeUpdates = HashMap<&'static str, ScopeUpdate>;
 #[derive(Debug)]
@@ -34,6 +36,9 @@ pub struct PackStorageOptions {
   pub clean: bool,
   pub fresh_generation: Option<usize>,
   pub release_generation: Option<usize>,
+  pub max_size: Option<u64>,
+  pub max_generations: Option<usize>,
+  pub compact_ratio: Option<f64>,
 }
 
 impl PackStorage {
@@ -44,6 +49,8 @@ impl PackStorage {
           root: options.root.clone().assert_utf8(),
           expire: options.expire,
           clean: options.clean,
+          max_size: options.max_size,
+          max_generations: options.max_generations,
         }),
         Arc::new(PackOptions {
           bucket_size: options.bucket_size,
@@ -55,6 +62,7 @@ impl PackStorage {
           options.fs,
           options.fresh_generation,
           options.release_generation,
+          options.compact_ratio,
         )),
       ),
       updates: Default::default(),
@@ -88,4 +96,10 @@ impl Storage for PackStorage {
   async fn scopes(&self) -> Result<Vec<String>> {
     self.manager.scopes().await
   }
+  fn take_eviction_result(&self) -> Option<EvictionResult> {
+    self.manager.take_eviction_result()
+  }
+  fn take_corrupt_packs(&self) -> Vec<CorruptPacks> {
+    self.manager.take_corrupt_packs()
+  }
 }
@@ -10,7 +10,7 @@ use super::data::{
   RootOptions,
 };
 use crate::{
-  ItemKey, ItemValue,
+  EvictionResult, ItemKey, ItemValue,
   error::{Result, ValidateResult},
 };
 
@@ -44,6 +44,7 @@ pub trait RootStrategy {
     scopes: &HashMap<String, PackScope>,
     root_options: &RootOptions,
   ) -> Result<()>;
+  async fn gc(&self, root_options: &RootOptions) -> Result<EvictionResult>;
   async fn reset(&self);
 }
 
@@ -90,7 +91,10 @@ pub trait ScopeReadStrategy {
 #[async_trait]
 pub trait ScopeValidateStrategy {
   async fn validate_meta(&self, scope: &mut PackScope) -> Result<ValidateResult>;
+  /// Verify the checksums of packs, the keys of packs are not required
   async fn validate_packs(&self, scope: &mut PackScope) -> Result<ValidateResult>;
+  /// Move the corrupt packs out of the scope and return the quarantine directory
+  async fn quarantine_packs(&self, packs: &[Utf8PathBuf]) -> Result<Utf8PathBuf>;
 }
 
 #[derive(Debug, Default, Clone)]
@@ -111,7 +115,8 @@ pub type ScopeUpdate = HashMap<ItemKey, Option<ItemValue>>;
 pub trait ScopeWriteStrategy {
   async fn update_scope(&self, scope: &mut PackScope, updates: ScopeUpdate) -> Result<()>;
   async fn before_all(&self, scopes: &mut HashMap<String, PackScope>) -> Result<()>;
-  async fn optimize_scope(&self, scope: &mut PackScope) -> Result<()>;
+  /// Optimize the packs of scope, return the number of compacted packs
+  async fn optimize_scope(&self, scope: &mut PackScope) -> Result<usize>;
   async fn release_scope(&self, scope: &mut PackScope) -> Result<()>;
   async fn write_packs(&self, scope: &mut PackScope) -> Result<WriteScopeResult>;
   async fn write_meta(&self, scope: &mut PackScope) -> Result<WriteScopeResult>;
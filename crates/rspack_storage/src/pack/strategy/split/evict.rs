@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use futures::future::join_all;
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashSet as HashSet;
+use tokio::task::JoinError;
+
+use super::{
+  handle_file::{read_expire_time, walk_dir},
+  read_scope::read_scope_meta,
+};
+use crate::{
+  EvictionResult, FileSystem,
+  error::Result,
+  fs::{BatchFSError, BatchFSResult},
+  pack::data::{RootMeta, ScopeMeta},
+};
+
+#[derive(Debug)]
+struct VersionUsage {
+  version: String,
+  dir: Utf8PathBuf,
+  // the root meta is rewritten on every save, so the expire time is in the order of last access
+  expire_time: u64,
+  files: HashSet<Utf8PathBuf>,
+  size: u64,
+}
+
+async fn get_version_usage(
+  version: String,
+  dir: Utf8PathBuf,
+  fs: Arc<dyn FileSystem>,
+) -> BatchFSResult<Option<VersionUsage>> {
+  // skip hidden dirs, lock files and unknown directories
+  if version.starts_with(".") || version.contains(".lock") || !fs.metadata(&dir).await?.is_directory
+  {
+    return Ok(None);
+  }
+  let meta = RootMeta::get_path(&dir);
+  if !fs.exists(&meta).await? {
+    return Ok(None);
+  }
+  let expire_time = read_expire_time(&meta, fs.clone()).await?;
+  let files = walk_dir(&dir, fs.clone()).await?;
+  let mut size = 0;
+  for file in &files {
+    size += fs.metadata(file).await?.size;
+  }
+  Ok(Some(VersionUsage {
+    version,
+    dir,
+    expire_time,
+    files,
+    size,
+  }))
+}
+
+async fn get_version_usages(
+  root: &Utf8Path,
+  fs: Arc<dyn FileSystem>,
+) -> BatchFSResult<Vec<VersionUsage>> {
+  let dirs = fs.read_dir(root).await?;
+  let tasks = dirs.into_iter().map(|version| {
+    let dir = root.join(&version);
+    let fs = fs.clone();
+    tokio::spawn(async move { get_version_usage(version, dir, fs).await })
+  });
+  let usages = BatchFSError::try_from_joined_result(
+    "get version usages failed",
+    join_all(tasks)
+      .await
+      .into_iter()
+      .collect::<std::result::Result<Vec<_>, JoinError>>(),
+  )?;
+  Ok(usages.into_iter().flatten().collect())
+}
+
+/// Remove the versions and collect them into the eviction result.
+///
+/// The root meta is removed first, so that an interrupted removal leaves a version without
+/// root meta, whose scopes are not exist, instead of a version with incomplete scopes.
+async fn remove_versions(
+  evicted: Vec<VersionUsage>,
+  result: &mut EvictionResult,
+  fs: Arc<dyn FileSystem>,
+) -> BatchFSResult<()> {
+  let tasks = evicted.iter().map(|usage| {
+    let fs = fs.clone();
+    let dir = usage.dir.clone();
+    tokio::spawn(async move {
+      fs.remove_file(&RootMeta::get_path(&dir)).await?;
+      fs.remove_dir(&dir).await
+    })
+  });
+  BatchFSError::try_from_joined_result(
+    "evict versions failed",
+    join_all(tasks)
+      .await
+      .into_iter()
+      .collect::<std::result::Result<Vec<_>, JoinError>>(),
+  )?;
+
+  for usage in evicted {
+    result.reclaimed_size += usage.size;
+    result.evicted_versions.push(usage.version);
+    let meta = RootMeta::get_path(&usage.dir);
+    let mut packs = usage
+      .files
+      .into_iter()
+      .filter(|file| *file != meta && file.file_name() != Some("scope_meta"))
+      .collect::<Vec<_>>();
+    packs.sort();
+    result.evicted_packs.extend(packs);
+  }
+  Ok(())
+}
+
+/// Evict the least recently accessed versions until the count of versions in the cache
+/// directory fits in `max_generations`.
+///
+/// The current version is never evicted.
+pub async fn evict_versions(
+  current: &Utf8Path,
+  root: &Utf8Path,
+  max_generations: usize,
+  fs: Arc<dyn FileSystem>,
+) -> BatchFSResult<EvictionResult> {
+  let (current, mut others): (Vec<_>, Vec<_>) = get_version_usages(root, fs.clone())
+    .await?
+    .into_iter()
+    .partition(|usage| usage.dir.as_path() == current);
+  // the least recently accessed first
+  others.sort_by(|a, b| {
+    a.expire_time
+      .cmp(&b.expire_time)
+      .then_with(|| a.version.cmp(&b.version))
+  });
+
+  let total_size = current
+    .iter()
+    .chain(others.iter())
+    .map(|usage| usage.size)
+    .sum::<u64>();
+  // the current version is always counted as a generation
+  let evict_count = (others.len() + 1).saturating_sub(max_generations.max(1));
+  let evicted = others.into_iter().take(evict_count).collect::<Vec<_>>();
+
+  let mut result = EvictionResult::default();
+  remove_versions(evicted, &mut result, fs).await?;
+  result.remain_size = total_size - result.reclaimed_size;
+  Ok(result)
+}
+
+/// Get the last access time of the packs in a version.
+async fn get_last_access(dir: &Utf8Path, fs: Arc<dyn FileSystem>) -> Result<u64> {
+  let mut last_access = 0;
+  for scope in fs.read_dir(dir).await? {
+    let scope_dir = dir.join(&scope);
+    if scope.starts_with(".") || !fs.metadata(&scope_dir).await?.is_directory {
+      continue;
+    }
+    // the broken scopes will be reset when they are loaded, skip them here
+    let Ok(Some(meta)) = read_scope_meta(None, &ScopeMeta::get_path(&scope_dir), fs.clone()).await
+    else {
+      continue;
+    };
+    for pack_meta in meta.packs.iter().flatten() {
+      last_access = last_access.max(pack_meta.last_access);
+    }
+  }
+  Ok(last_access)
+}
+
+/// Evict the versions with the least recently accessed packs until the cache directory fits
+/// in `max_size`.
+///
+/// The scopes of a version depend on each other, e.g. the module graph is only reusable with
+/// the snapshot of the same version, so a version is always evicted as a whole instead of
+/// leaving incomplete scopes. The current version is never evicted.
+pub async fn evict_packs(
+  current: &Utf8Path,
+  root: &Utf8Path,
+  max_size: u64,
+  fs: Arc<dyn FileSystem>,
+) -> Result<EvictionResult> {
+  let usages = get_version_usages(root, fs.clone()).await?;
+  let total_size = usages.iter().map(|usage| usage.size).sum::<u64>();
+  let mut result = EvictionResult::default();
+  if total_size <= max_size {
+    result.remain_size = total_size;
+    return Ok(result);
+  }
+
+  let mut others = vec![];
+  for usage in usages {
+    if usage.dir.as_path() != current {
+      others.push((get_last_access(&usage.dir, fs.clone()).await?, usage));
+    }
+  }
+  // the least recently accessed first
+  others.sort_by(|(a_access, a), (b_access, b)| {
+    a_access
+      .cmp(b_access)
+      .then_with(|| a.version.cmp(&b.version))
+  });
+
+  let mut remain_size = total_size;
+  let mut evicted = vec![];
+  for (_, usage) in others {
+    if remain_size <= max_size {
+      break;
+    }
+    remain_size -= usage.size;
+    evicted.push(usage);
+  }
+  remove_versions(evicted, &mut result, fs).await?;
+  result.remain_size = remain_size;
+  Ok(result)
+}
@@ -350,14 +350,7 @@ async fn try_remove_version(
   }
 
   // remove direcotires of expired versions
-  let mut reader = fs.read_file(&meta).await?;
-  let expire_time = reader.read_line().await?.parse::<u64>().map_err(|e| {
-    FSError::from_message(
-      &meta,
-      FSOperation::Read,
-      format!("parse option meta failed: {e}"),
-    )
-  })?;
+  let expire_time = read_expire_time(&meta, fs.clone()).await?;
   let current = current_time();
 
   if current > expire_time {
@@ -395,3 +388,14 @@ pub async fn remove_expired_versions(
   )
   .map(|_| ())
 }
+
+pub async fn read_expire_time(meta: &Utf8Path, fs: Arc<dyn FileSystem>) -> FSResult<u64> {
+  let mut reader = fs.read_file(meta).await?;
+  reader.read_line().await?.parse::<u64>().map_err(|e| {
+    FSError::from_message(
+      meta,
+      FSOperation::Read,
+      format!("parse option meta failed: {e}"),
+    )
+  })
+}
@@ -1,3 +1,4 @@
+mod evict;
 mod handle_file;
 mod read_pack;
 mod read_scope;
@@ -11,6 +12,7 @@ use std::{
   sync::Arc,
 };
 
+use evict::{evict_packs, evict_versions};
 use handle_file::{
   recovery_move_lock, recovery_remove_lock, remove_expired_versions, remove_unused_scope_files,
   remove_unused_scopes,
@@ -18,16 +20,13 @@ use handle_file::{
 use itertools::Itertools;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
-use util::get_name;
 
 use super::{RootStrategy, ScopeStrategy};
 use crate::{
-  FileSystem,
+  EvictionResult, FileSystem,
   error::{Result, ValidateResult},
   fs::{FSError, FSOperation},
-  pack::data::{
-    PackContents, PackKeys, PackScope, RootMeta, RootMetaFrom, RootOptions, current_time,
-  },
+  pack::data::{PackScope, RootMeta, RootMetaFrom, RootOptions, current_time},
 };
 
 #[derive(Debug, Clone)]
@@ -37,6 +36,8 @@ pub struct SplitPackStrategy {
   pub temp_root: Arc<Utf8PathBuf>,
   pub fresh_generation: Option<usize>,
   pub release_generation: Option<usize>,
+  /// Packs smaller than `pack_size * compact_ratio` will be merged during save
+  pub compact_ratio: Option<f64>,
 }
 
 impl SplitPackStrategy {
@@ -46,6 +47,7 @@ impl SplitPackStrategy {
     fs: Arc<dyn FileSystem>,
     fresh_generation: Option<usize>,
     release_generation: Option<usize>,
+    compact_ratio: Option<f64>,
   ) -> Self {
     Self {
       fs,
@@ -53,18 +55,17 @@ impl SplitPackStrategy {
       temp_root: Arc::new(temp_root),
       fresh_generation,
       release_generation,
+      compact_ratio,
     }
   }
 
-  pub async fn get_pack_hash(
-    &self,
-    path: &Utf8Path,
-    keys: &PackKeys,
-    contents: &PackContents,
-  ) -> Result<String> {
+  /// Get the checksum of pack file
+  ///
+  /// The pack name is generated from the keys, so the checksum can be verified
+  /// before reading the pack, a truncated or modified pack will not be parsed.
+  pub async fn get_pack_hash(&self, path: &Utf8Path, name: &str) -> Result<String> {
     let mut hasher = FxHasher::default();
-    let file_name = get_name(keys, contents);
-    hasher.write(file_name.as_bytes());
+    hasher.write(name.as_bytes());
 
     // TODO read file one time only.
     let mut reader = self.fs.read_file(path).await?;
@@ -157,6 +158,30 @@ impl RootStrategy for SplitPackStrategy {
     Ok(())
   }
 
+  async fn gc(&self, root_options: &RootOptions) -> Result<EvictionResult> {
+    let mut result = match root_options.max_generations {
+      Some(max_generations) => {
+        evict_versions(
+          &self.root,
+          &root_options.root,
+          max_generations,
+          self.fs.clone(),
+        )
+        .await?
+      }
+      None => EvictionResult::default(),
+    };
+    if let Some(max_size) = root_options.max_size {
+      let size_result =
+        evict_packs(&self.root, &root_options.root, max_size, self.fs.clone()).await?;
+      result.evicted_versions.extend(size_result.evicted_versions);
+      result.evicted_packs.extend(size_result.evicted_packs);
+      result.reclaimed_size += size_result.reclaimed_size;
+      result.remain_size = size_result.remain_size;
+    }
+    Ok(result)
+  }
+
   async fn reset(&self) {
     let _ = self.fs.remove_dir(&self.root).await;
   }
@@ -20,7 +20,7 @@ impl ScopeReadStrategy for SplitPackStrategy {
   async fn ensure_meta(&self, scope: &mut PackScope) -> Result<()> {
     if !scope.meta.loaded() {
       let meta_path = ScopeMeta::get_path(&scope.path);
-      let meta = read_scope_meta(scope.name, &meta_path, self.fs.clone())
+      let meta = read_scope_meta(Some(scope.name), &meta_path, self.fs.clone())
         .await?
         .unwrap_or_else(|| ScopeMeta::new(&scope.path, &scope.options));
       scope.meta.set_value(meta);
@@ -90,8 +90,8 @@ impl ScopeReadStrategy for SplitPackStrategy {
   }
 }
 
-async fn read_scope_meta(
-  scope: &'static str,
+pub async fn read_scope_meta(
+  scope: Option<&'static str>,
   path: &Utf8Path,
   fs: Arc<dyn FileSystem>,
 ) -> Result<Option<ScopeMeta>> {
@@ -109,7 +109,7 @@ async fn read_scope_meta(
       item.parse::<usize>().map_err(|e| {
         Error::from_reason(
           Some(ErrorType::Load),
-          Some(scope),
+          scope,
           format!("parse option meta failed: {e}"),
         )
       })
@@ -119,7 +119,7 @@ async fn read_scope_meta(
   if option_items.len() < 3 {
     return Err(Error::from_reason(
       Some(ErrorType::Load),
-      Some(scope),
+      scope,
       "option meta not match".to_string(),
     ));
   }
@@ -141,7 +141,7 @@ async fn read_scope_meta(
           if i.len() < 3 {
             Err(Error::from_reason(
               Some(ErrorType::Load),
-              Some(scope),
+              scope,
               "file meta not match".to_string(),
             ))
           } else {
@@ -151,18 +151,23 @@ async fn read_scope_meta(
               size: i[2].parse::<usize>().map_err(|e| {
                 Error::from_reason(
                   Some(ErrorType::Load),
-                  Some(scope),
+                  scope,
                   format!("parse file meta failed: {e}"),
                 )
               })?,
               generation: i[3].parse::<usize>().map_err(|e| {
                 Error::from_reason(
                   Some(ErrorType::Load),
-                  Some(scope),
+                  scope,
                   format!("parse file meta failed: {e}"),
                 )
               })?,
               wrote: true,
+              // the scope metas written before have no access time, evict them first
+              last_access: i
+                .get(4)
+                .and_then(|time| time.parse::<u64>().ok())
+                .unwrap_or_default(),
             })
           }
         })
@@ -173,7 +178,7 @@ async fn read_scope_meta(
   if packs.len() < bucket_size {
     return Err(Error::from_reason(
       Some(ErrorType::Load),
-      Some(scope),
+      scope,
       "bucket size not match".to_string(),
     ));
   }
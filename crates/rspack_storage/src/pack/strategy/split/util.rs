@@ -297,6 +297,7 @@ pub mod test_pack_utils {
           fs.clone(),
           Some(1_usize),
           Some(2_usize),
+          None,
         )
       })
       .collect_vec()
@@ -1,8 +1,9 @@
 use async_trait::async_trait;
 use futures::{TryFutureExt, future::join_all};
 use itertools::Itertools;
+use rspack_paths::Utf8PathBuf;
 
-use super::{SplitPackStrategy, util::get_indexed_packs};
+use super::{SplitPackStrategy, handle_file::redirect_to_path, util::get_indexed_packs};
 use crate::{
   error::{Error, ErrorType, Result, ValidateResult},
   pack::{data::PackScope, strategy::ScopeValidateStrategy},
@@ -34,12 +35,9 @@ impl ScopeValidateStrategy for SplitPackStrategy {
         let strategy = self.clone();
         let path = pack.path.to_owned();
         let hash = pack_meta.hash.to_owned();
-        let keys = pack.keys.expect_value().to_owned();
+        let name = pack_meta.name.to_owned();
         tokio::spawn(async move {
-          match strategy
-            .get_pack_hash(&path, &keys, &Default::default())
-            .await
-          {
+          match strategy.get_pack_hash(&path, &name).await {
             Ok(res) => hash == res,
             Err(_) => false,
           }
@@ -72,6 +70,16 @@ impl ScopeValidateStrategy for SplitPackStrategy {
       ))
     }
   }
+
+  async fn quarantine_packs(&self, packs: &[Utf8PathBuf]) -> Result<Utf8PathBuf> {
+    let quarantine_root = self.root.join(".quarantine");
+    for pack in packs {
+      // the missing packs will be skipped
+      let target = redirect_to_path(pack, &self.root, &quarantine_root)?;
+      self.fs.move_file(pack, &target).await?;
+    }
+    Ok(quarantine_root)
+  }
 }
 
 #[cfg(test)]
@@ -12,7 +12,7 @@ use crate::{
   error::Result,
   pack::{
     ScopeUpdate,
-    data::{Pack, PackFileMeta, PackOptions},
+    data::{Pack, PackFileMeta, PackOptions, current_time},
     strategy::{PackReadStrategy, PackWriteStrategy, UpdatePacksResult, split::util::get_name},
   },
 };
@@ -45,9 +45,12 @@ impl PackWriteStrategy for SplitPackStrategy {
     packs: Vec<(PackFileMeta, Pack)>,
   ) -> Result<UpdatePacksResult> {
     let mut removed_files = vec![];
+    // the contents of compacted packs may have been released
+    let packs =
+      reload_released_packs(packs.into_iter().map(|(_, pack)| pack).collect_vec(), self).await?;
     let items = packs
       .into_iter()
-      .fold(HashSet::default(), |mut acc, (_, mut pack)| {
+      .fold(HashSet::default(), |mut acc, mut pack| {
         removed_files.push(pack.path.clone());
         pour_pack(&mut acc, &mut pack);
         acc
@@ -376,6 +379,7 @@ fn create_pack(dir: &Utf8Path, candidates: Vec<PackItemCandidate>) -> (PackFileM
       size: new_pack.size(),
       wrote: false,
       generation: latest_generation,
+      last_access: current_time(),
     },
     new_pack,
   )
@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use futures::future::join_all;
 use itertools::Itertools;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use rspack_paths::Utf8Path;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use tokio::task::JoinError;
 
@@ -10,13 +13,14 @@ use super::{
   handle_file::{
     move_files, prepare_scope_dirs, redirect_to_path, remove_files, remove_lock, write_lock,
   },
-  util::{choose_bucket, flag_scope_wrote},
+  util::{choose_bucket, flag_scope_wrote, get_name},
 };
 use crate::{
+  FileSystem,
   error::Result,
   fs::BatchFSError,
   pack::{
-    data::{Pack, PackScope},
+    data::{Pack, PackScope, ScopeMeta, current_time},
     strategy::{PackWriteStrategy, ScopeUpdate, ScopeWriteStrategy, WriteScopeResult},
   },
 };
@@ -179,13 +183,14 @@ impl ScopeWriteStrategy for SplitPackStrategy {
     Ok(())
   }
 
-  async fn optimize_scope(&self, scope: &mut PackScope) -> Result<()> {
+  async fn optimize_scope(&self, scope: &mut PackScope) -> Result<usize> {
     if !scope.loaded() {
       panic!("scope not loaded, run `load` first");
     }
 
     let mut scope_meta = scope.meta.take_value().expect("should have scope meta");
     let mut scope_packs = scope.packs.take_value().expect("should have scope packs");
+    let mut compacted_packs = 0;
 
     for (bucket_id, raw_pack_metas) in scope_meta.packs.iter_mut().enumerate() {
       let bucket_packs = std::mem::take(raw_pack_metas)
@@ -193,7 +198,7 @@ impl ScopeWriteStrategy for SplitPackStrategy {
         .zip(std::mem::take(&mut scope_packs[bucket_id]))
         .collect_vec();
 
-      let (fresh_packs, freezed_packs): (Vec<_>, Vec<_>) =
+      let (mut fresh_packs, freezed_packs): (Vec<_>, Vec<_>) =
         bucket_packs.into_iter().partition(|(meta, _)| {
           if meta.wrote {
             if let Some(fresh_generation) = self.fresh_generation {
@@ -206,6 +211,21 @@ impl ScopeWriteStrategy for SplitPackStrategy {
           }
         });
 
+      // compact the partially stale packs which have been shrunk by removing items
+      let (stale_packs, freezed_packs): (Vec<_>, Vec<_>) = match self.compact_ratio {
+        Some(compact_ratio) => freezed_packs.into_iter().partition(|(meta, _)| {
+          (meta.size as f64) < (scope.options.pack_size as f64) * compact_ratio
+        }),
+        None => (vec![], freezed_packs),
+      };
+      let freezed_packs = if stale_packs.len() > 1 {
+        compacted_packs += stale_packs.len();
+        fresh_packs.extend(stale_packs);
+        freezed_packs
+      } else {
+        freezed_packs.into_iter().chain(stale_packs).collect_vec()
+      };
+
       let mut final_packs = vec![];
       final_packs.extend(freezed_packs);
       final_packs.extend(if fresh_packs.is_empty() {
@@ -234,7 +254,7 @@ impl ScopeWriteStrategy for SplitPackStrategy {
     scope.packs.set_value(scope_packs);
     scope.meta.set_value(scope_meta);
 
-    Ok(())
+    Ok(compacted_packs)
   }
 
   async fn write_packs(&self, scope: &mut PackScope) -> Result<WriteScopeResult> {
@@ -287,45 +307,14 @@ impl ScopeWriteStrategy for SplitPackStrategy {
   }
 
   async fn write_meta(&self, scope: &mut PackScope) -> Result<WriteScopeResult> {
-    let meta = scope.meta.expect_value();
-    let path = redirect_to_path(&meta.path, &self.root, &self.temp_root)?;
-    self
-      .fs
-      .ensure_dir(path.parent().expect("should have parent"))
-      .await?;
-
-    let mut writer = self.fs.write_file(&path).await?;
-
-    writer
-      .write_line(
-        format!(
-          "{} {} {}",
-          meta.bucket_size, meta.pack_size, meta.generation
-        )
-        .as_str(),
-      )
-      .await?;
-
-    for bucket_id in 0..meta.bucket_size {
-      let line = meta
-        .packs
-        .get(bucket_id)
-        .map(|packs| {
-          packs
-            .iter()
-            .map(|meta| {
-              format!(
-                "{},{},{},{}",
-                meta.name, meta.hash, meta.size, meta.generation
-              )
-            })
-            .join(" ")
-        })
-        .unwrap_or_default();
-      writer.write_line(&line).await?;
+    let meta = scope.meta.expect_value_mut();
+    // all packs of the loaded scope are accessed in this session
+    let now = current_time();
+    for pack_meta in meta.packs.iter_mut().flatten() {
+      pack_meta.last_access = now;
     }
-
-    writer.flush().await?;
+    let path = redirect_to_path(&meta.path, &self.root, &self.temp_root)?;
+    write_scope_meta(meta, &path, self.fs.clone()).await?;
 
     Ok(WriteScopeResult {
       wrote_files: HashSet::from_iter(vec![meta.path.clone()]),
@@ -334,6 +323,49 @@ impl ScopeWriteStrategy for SplitPackStrategy {
   }
 }
 
+pub async fn write_scope_meta(
+  meta: &ScopeMeta,
+  path: &Utf8Path,
+  fs: Arc<dyn FileSystem>,
+) -> Result<()> {
+  fs.ensure_dir(path.parent().expect("should have parent"))
+    .await?;
+
+  let mut writer = fs.write_file(path).await?;
+
+  writer
+    .write_line(
+      format!(
+        "{} {} {}",
+        meta.bucket_size, meta.pack_size, meta.generation
+      )
+      .as_str(),
+    )
+    .await?;
+
+  for bucket_id in 0..meta.bucket_size {
+    let line = meta
+      .packs
+      .get(bucket_id)
+      .map(|packs| {
+        packs
+          .iter()
+          .map(|meta| {
+            format!(
+              "{},{},{},{},{}",
+              meta.name, meta.hash, meta.size, meta.generation, meta.last_access
+            )
+          })
+          .join(" ")
+      })
+      .unwrap_or_default();
+    writer.write_line(&line).await?;
+  }
+
+  writer.flush().await?;
+  Ok(())
+}
+
 async fn save_pack(pack: &Pack, strategy: &SplitPackStrategy) -> Result<String> {
   let keys = pack.keys.expect_value();
   let contents = pack.contents.expect_value();
@@ -344,8 +376,7 @@ async fn save_pack(pack: &Pack, strategy: &SplitPackStrategy) -> Result<String>
   let hash = strategy
     .get_pack_hash(
       &redirect_to_path(&pack.path, &strategy.root, &strategy.temp_root)?,
-      keys,
-      contents,
+      &get_name(keys, contents),
     )
     .await?;
   Ok(hash)
@@ -379,12 +410,12 @@ mod tests {
     error::Result,
     pack::{
       SplitPackStrategy,
-      data::{PackOptions, PackScope},
+      data::{PackOptions, PackScope, ScopeMeta},
       strategy::{
         ScopeReadStrategy, ScopeWriteStrategy,
         split::util::test_pack_utils::{
           UpdateVal, clean_strategy, count_bucket_packs, count_scope_packs, create_strategies,
-          get_bucket_pack_sizes, mock_updates, save_scope,
+          get_bucket_pack_sizes, mock_pack_file, mock_scope_meta_file, mock_updates, save_scope,
         },
       },
     },
@@ -636,4 +667,56 @@ mod tests {
     }
     Ok(())
   }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn should_compact_stale_packs() -> Result<()> {
+    for mut strategy in create_strategies("compact_stale") {
+      strategy.fresh_generation = None;
+      strategy.compact_ratio = Some(0.5);
+      let options = Arc::new(PackOptions {
+        bucket_size: 1,
+        pack_size: 2000,
+      });
+      let mut scope = PackScope::new(
+        "scope_name",
+        strategy.get_path("scope_name"),
+        options.clone(),
+      );
+      clean_strategy(&strategy).await;
+
+      // 3 wrote packs with 100 bytes, which are smaller than half of pack size
+      mock_scope_meta_file(
+        &ScopeMeta::get_path(&scope.path),
+        strategy.fs.as_ref(),
+        &options,
+        3,
+      )
+      .await?;
+      for pack_no in 0..3 {
+        mock_pack_file(
+          &scope.path.join(format!("0/pack_name_0_{pack_no}")),
+          &format!("0_{pack_no}"),
+          10,
+          strategy.fs.as_ref(),
+        )
+        .await?;
+      }
+      strategy.ensure_contents(&mut scope).await?;
+      assert_eq!(count_scope_packs(&scope), 3);
+
+      let compacted = strategy.optimize_scope(&mut scope).await?;
+      assert_eq!(compacted, 3);
+      assert_eq!(count_scope_packs(&scope), 1);
+      assert_eq!(scope.removed.len(), 3);
+      assert_eq!(scope.get_contents().len(), 30);
+
+      // a single small pack is not compacted
+      let res = save_scope(&mut scope, &strategy).await?;
+      assert_eq!(res.removed_files.len(), 3);
+      assert_eq!(strategy.optimize_scope(&mut scope).await?, 0);
+      assert_eq!(count_scope_packs(&scope), 1);
+    }
+    Ok(())
+  }
 }
@@ -0,0 +1,429 @@
+mod transport;
+
+use std::sync::{Arc, Mutex};
+
+use futures::future::join_all;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use tokio::sync::oneshot::{self, Receiver};
+pub use transport::{
+  DirectoryTransport, HttpTransport, RemoteHttpClient, RemoteHttpResponse, RemoteTransport,
+};
+
+use crate::{
+  CorruptPacks, EvictionResult, ItemKey, ItemPairs, ItemValue, PackStorage, PackStorageOptions,
+  Storage,
+  error::{Error, ErrorType, Result},
+};
+
+pub struct RemoteStorageOptions {
+  /// Options of the local pack storage, which is used as a read-through cache.
+  pub local: PackStorageOptions,
+  pub transport: Arc<dyn RemoteTransport>,
+}
+
+#[derive(Debug, Default)]
+struct RemoteScope {
+  /// Content hashes of the published buckets, `None` if the remote state is unknown.
+  published: Option<Vec<String>>,
+  /// Buckets changed since the last save.
+  dirty: HashSet<usize>,
+  /// Keys set or removed since the last save, used to merge the concurrent remote changes.
+  changed: HashSet<ItemKey>,
+}
+
+#[derive(Debug, Default)]
+struct DirtyScope {
+  buckets: HashSet<usize>,
+  changed: HashSet<ItemKey>,
+}
+
+#[derive(Debug)]
+struct RemoteStorageInner {
+  local: PackStorage,
+  transport: Arc<dyn RemoteTransport>,
+  version: String,
+  bucket_size: usize,
+  scopes: Mutex<HashMap<&'static str, RemoteScope>>,
+  publish_lock: tokio::sync::Mutex<()>,
+  /// Errors of the remote which are recovered by falling back to the local packs.
+  warnings: Mutex<Vec<String>>,
+}
+
+/// A storage which shares packs through a remote transport.
+///
+/// Each scope is split into buckets, every bucket is published as a content-addressed
+/// blob `blobs/{hash}` and the scope manifest `{version}/{scope}` lists the blob hashes.
+/// Scopes found in the local packs are used directly, and scopes fetched from the
+/// remote are written to the local packs on the next save. A missing or broken remote
+/// falls back to the local packs with a warning.
+#[derive(Debug)]
+pub struct RemoteStorage {
+  inner: Arc<RemoteStorageInner>,
+}
+
+impl RemoteStorage {
+  pub fn new(options: RemoteStorageOptions) -> Self {
+    let version = options.local.version.clone();
+    let bucket_size = options.local.bucket_size.max(1);
+    Self {
+      inner: Arc::new(RemoteStorageInner {
+        local: PackStorage::new(options.local),
+        transport: options.transport,
+        version,
+        bucket_size,
+        scopes: Default::default(),
+        publish_lock: Default::default(),
+        warnings: Default::default(),
+      }),
+    }
+  }
+}
+
+impl RemoteStorageInner {
+  fn get_manifest_key(&self, scope: &str) -> String {
+    format!("{}/{}", self.version, scope)
+  }
+
+  fn mark_dirty(&self, scope: &'static str, key: &[u8]) {
+    let mut scopes = self.scopes.lock().expect("should get lock");
+    let scope = scopes.entry(scope).or_default();
+    scope.dirty.insert(choose_bucket(key, self.bucket_size));
+    scope.changed.insert(key.to_vec());
+  }
+
+  /// Read the blob hashes of the remote scope manifest, `None` if the scope is not published.
+  async fn load_manifest(&self, scope: &'static str) -> Result<Option<Vec<String>>> {
+    let Some(manifest) = self.transport.get(&self.get_manifest_key(scope)).await? else {
+      return Ok(None);
+    };
+    let to_error = |reason: String| Error::from_reason(Some(ErrorType::Load), Some(scope), reason);
+
+    let hashes = String::from_utf8(manifest)
+      .map_err(|_| to_error("invalid remote manifest".into()))?
+      .lines()
+      .map(|line| line.to_string())
+      .collect::<Vec<_>>();
+    if hashes.len() != self.bucket_size {
+      return Err(to_error(format!(
+        "remote manifest has {} buckets, expected {}",
+        hashes.len(),
+        self.bucket_size
+      )));
+    }
+    Ok(Some(hashes))
+  }
+
+  async fn load_bucket(&self, scope: &'static str, hash: &str) -> Result<ItemPairs> {
+    let to_error = |reason: String| Error::from_reason(Some(ErrorType::Load), Some(scope), reason);
+    // the empty hash is a bucket which has never been published
+    if hash.is_empty() {
+      return Ok(vec![]);
+    }
+    let Some(blob) = self.transport.get(&get_blob_key(hash)).await? else {
+      return Err(to_error(format!("remote blob `{hash}` not exists")));
+    };
+    if get_content_hash(&blob) != hash {
+      return Err(to_error(format!("remote blob `{hash}` is corrupted")));
+    }
+    decode_bucket(&blob).ok_or_else(|| to_error(format!("remote blob `{hash}` is invalid")))
+  }
+
+  async fn load_remote(&self, scope: &'static str) -> Result<Option<ItemPairs>> {
+    let Some(hashes) = self.load_manifest(scope).await? else {
+      return Ok(None);
+    };
+    let buckets = join_all(hashes.iter().map(|hash| self.load_bucket(scope, hash))).await;
+    let mut items = vec![];
+    for bucket in buckets {
+      items.extend(bucket?);
+    }
+
+    self.scopes.lock().expect("should get lock").insert(
+      scope,
+      RemoteScope {
+        published: Some(hashes),
+        ..Default::default()
+      },
+    );
+    Ok(Some(items))
+  }
+
+  async fn publish(&self, dirty_scopes: HashMap<&'static str, DirtyScope>) -> Result<()> {
+    let _guard = self.publish_lock.lock().await;
+    for (scope, dirty) in dirty_scopes {
+      let to_save_error =
+        |e: Error| Error::from_error(Some(ErrorType::Save), Some(scope), Box::new(e));
+      let published = self
+        .scopes
+        .lock()
+        .expect("should get lock")
+        .get(scope)
+        .and_then(|s| s.published.clone());
+      // other machines may have published the scope since it was loaded, so the current
+      // remote manifest is read again and only the dirty buckets are replaced
+      let remote = match self.load_manifest(scope).await {
+        Ok(remote) => remote,
+        Err(e) => {
+          self.warn(&e);
+          None
+        }
+      };
+      // publish all buckets if the remote state is unknown or the scope is not published
+      let dirty_buckets = match (&published, &remote) {
+        (Some(_), Some(_)) => dirty.buckets,
+        _ => (0..self.bucket_size).collect(),
+      };
+      let mut hashes = remote.unwrap_or_else(|| vec![String::new(); self.bucket_size]);
+
+      let mut buckets = vec![vec![]; self.bucket_size];
+      for (key, value) in self.local.load(scope).await.map_err(to_save_error)? {
+        let bucket = choose_bucket(&key, self.bucket_size);
+        if dirty_buckets.contains(&bucket) {
+          buckets[bucket].push((key, value));
+        }
+      }
+
+      let changed = &dirty.changed;
+      let published = &published;
+      let remote_hashes = &hashes;
+      let tasks = buckets
+        .into_iter()
+        .enumerate()
+        .filter(|(bucket, _)| dirty_buckets.contains(bucket))
+        .map(|(bucket, mut items)| async move {
+          let remote_hash = &remote_hashes[bucket];
+          let published_hash = published.as_ref().map(|hashes| &hashes[bucket]);
+          if published_hash != Some(remote_hash) {
+            // the bucket is published by others since it was loaded, merge the changes
+            match self.load_bucket(scope, remote_hash).await {
+              Ok(remote_items) => {
+                items = merge_bucket(
+                  items,
+                  remote_items,
+                  published_hash.is_some().then_some(changed),
+                )
+              }
+              Err(e) => self.warn(&e),
+            }
+          }
+          items.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+          let blob = encode_bucket(&items);
+          let hash = get_content_hash(&blob);
+          let key = get_blob_key(&hash);
+          if !self.transport.exists(&key).await? {
+            self.transport.put(&key, blob).await?;
+          }
+          Ok::<_, Error>((bucket, hash))
+        });
+      let published_buckets = join_all(tasks).await;
+      for res in published_buckets {
+        let (bucket, hash) = res.map_err(to_save_error)?;
+        hashes[bucket] = hash;
+      }
+
+      // blobs are published before the manifest, so the manifest always points to existing blobs
+      self
+        .transport
+        .put(
+          &self.get_manifest_key(scope),
+          hashes.join("\n").into_bytes(),
+        )
+        .await
+        .map_err(to_save_error)?;
+
+      self
+        .scopes
+        .lock()
+        .expect("should get lock")
+        .entry(scope)
+        .or_default()
+        .published = Some(hashes);
+    }
+    Ok(())
+  }
+
+  fn warn(&self, error: &Error) {
+    self
+      .warnings
+      .lock()
+      .expect("should get lock")
+      .push(format!("remote cache is skipped: {error}"));
+  }
+}
+
+#[async_trait::async_trait]
+impl Storage for RemoteStorage {
+  async fn load(&self, scope: &'static str) -> Result<ItemPairs> {
+    // read the local packs first, fallback to remote when local cache is missing or broken
+    let local = self.inner.local.load(scope).await;
+    if matches!(&local, Ok(items) if !items.is_empty()) {
+      return local;
+    }
+    let remote = match self.inner.load_remote(scope).await {
+      Ok(remote) => remote,
+      Err(e) => {
+        // a broken remote cache should not fail the build, fallback to the local packs
+        self.inner.warn(&e);
+        None
+      }
+    };
+    match remote {
+      Some(items) => {
+        // fill the local packs, they will be written on next save
+        for (key, value) in &items {
+          self
+            .inner
+            .local
+            .set(scope, key.as_ref().clone(), value.as_ref().clone());
+        }
+        Ok(items)
+      }
+      None => local,
+    }
+  }
+  fn set(&self, scope: &'static str, key: ItemKey, value: ItemValue) {
+    self.inner.mark_dirty(scope, &key);
+    self.inner.local.set(scope, key, value);
+  }
+  fn remove(&self, scope: &'static str, key: &[u8]) {
+    self.inner.mark_dirty(scope, key);
+    self.inner.local.remove(scope, key);
+  }
+  fn trigger_save(&self) -> Result<Receiver<Result<()>>> {
+    let dirty_scopes = self
+      .inner
+      .scopes
+      .lock()
+      .expect("should get lock")
+      .iter_mut()
+      .filter(|(_, scope)| !scope.dirty.is_empty())
+      .map(|(name, scope)| {
+        (
+          *name,
+          DirtyScope {
+            buckets: std::mem::take(&mut scope.dirty),
+            changed: std::mem::take(&mut scope.changed),
+          },
+        )
+      })
+      .collect::<HashMap<_, _>>();
+    let local_rx = self.inner.local.trigger_save()?;
+    let inner = self.inner.clone();
+    let (tx, rx) = oneshot::channel();
+    tokio::spawn(async move {
+      let res = match local_rx.await {
+        Ok(Ok(())) => inner.publish(dirty_scopes).await,
+        Ok(Err(e)) => Err(e),
+        Err(_) => Err(Error::from_reason(
+          Some(ErrorType::Save),
+          None,
+          "local storage save is cancelled".into(),
+        )),
+      };
+      let _ = tx.send(res);
+    });
+    Ok(rx)
+  }
+  async fn reset(&self) {
+    self.inner.local.reset().await;
+    self.inner.scopes.lock().expect("should get lock").clear();
+  }
+  /// Only the scopes of local packs are listed, since a key/value remote can't be enumerated.
+  async fn scopes(&self) -> Result<Vec<String>> {
+    self.inner.local.scopes().await
+  }
+  fn take_eviction_result(&self) -> Option<EvictionResult> {
+    self.inner.local.take_eviction_result()
+  }
+  /// The corrupt local packs are healed by the remote if it has the scope.
+  fn take_corrupt_packs(&self) -> Vec<CorruptPacks> {
+    self.inner.local.take_corrupt_packs()
+  }
+  fn take_warnings(&self) -> Vec<String> {
+    std::mem::take(&mut *self.inner.warnings.lock().expect("should get lock"))
+  }
+}
+
+/// Merge the local items of a bucket with the items published by others.
+///
+/// If the local changes are known, the remote items are kept except the locally changed keys,
+/// otherwise the local items take precedence over the remote ones.
+fn merge_bucket(
+  local_items: ItemPairs,
+  remote_items: ItemPairs,
+  changed: Option<&HashSet<ItemKey>>,
+) -> ItemPairs {
+  match changed {
+    Some(changed) => remote_items
+      .into_iter()
+      .filter(|(key, _)| !changed.contains(key.as_ref()))
+      .chain(
+        local_items
+          .into_iter()
+          .filter(|(key, _)| changed.contains(key.as_ref())),
+      )
+      .collect(),
+    None => {
+      let local_keys = local_items
+        .iter()
+        .map(|(key, _)| key.clone())
+        .collect::<HashSet<_>>();
+      remote_items
+        .into_iter()
+        .filter(|(key, _)| !local_keys.contains(key))
+        .chain(local_items)
+        .collect()
+    }
+  }
+}
+
+fn choose_bucket(key: &[u8], total: usize) -> usize {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  std::hash::Hasher::write(&mut hasher, key);
+  (std::hash::Hasher::finish(&hasher) % total as u64) as usize
+}
+
+fn get_blob_key(hash: &str) -> String {
+  format!("blobs/{hash}")
+}
+
+fn get_content_hash(content: &[u8]) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::SHA256);
+  std::hash::Hasher::write(&mut hasher, content);
+  hasher.digest(&HashDigest::Hex).encoded().to_string()
+}
+
+/// Encode items as `key_len key value_len value ...`, lengths are u32 little endian.
+fn encode_bucket(items: &ItemPairs) -> Vec<u8> {
+  let mut res = Vec::with_capacity(
+    items
+      .iter()
+      .map(|(key, value)| key.len() + value.len() + 8)
+      .sum(),
+  );
+  for (key, value) in items {
+    res.extend_from_slice(&(key.len() as u32).to_le_bytes());
+    res.extend_from_slice(key);
+    res.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    res.extend_from_slice(value);
+  }
+  res
+}
+
+fn decode_bucket(mut content: &[u8]) -> Option<ItemPairs> {
+  fn read_part<'a>(content: &mut &'a [u8]) -> Option<&'a [u8]> {
+    let len = u32::from_le_bytes(content.get(..4)?.try_into().ok()?) as usize;
+    let part = content.get(4..4 + len)?;
+    *content = &content[4 + len..];
+    Some(part)
+  }
+
+  let mut res = vec![];
+  while !content.is_empty() {
+    let key = read_part(&mut content)?;
+    let value = read_part(&mut content)?;
+    res.push((Arc::new(key.to_vec()), Arc::new(value.to_vec())));
+  }
+  Some(res)
+}
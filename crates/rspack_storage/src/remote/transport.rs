@@ -0,0 +1,205 @@
+use std::{
+  sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+  },
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use cow_utils::CowUtils;
+use rspack_paths::Utf8PathBuf;
+
+use crate::{
+  FileSystem,
+  error::{Error, Result},
+};
+
+/// The transport used by `RemoteStorage` to read and publish cache objects.
+///
+/// Keys are relative paths like `{version}/{scope}` or `blobs/{hash}`.
+#[async_trait::async_trait]
+pub trait RemoteTransport: std::fmt::Debug + Sync + Send {
+  /// Get the value of `key`, returns `None` if it does not exist.
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+  /// Publish `value` as `key`.
+  ///
+  /// The publish must be atomic: readers either get the previous value or the new one.
+  async fn put(&self, key: &str, value: Vec<u8>) -> Result<()>;
+  async fn exists(&self, key: &str) -> Result<bool>;
+}
+
+/// A transport on a directory shared between machines, e.g. a network mount.
+///
+/// Values are written to a temporary file first and then renamed to the target path.
+#[derive(Debug)]
+pub struct DirectoryTransport {
+  root: Utf8PathBuf,
+  fs: Arc<dyn FileSystem>,
+  counter: AtomicUsize,
+}
+
+impl DirectoryTransport {
+  pub fn new(root: Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Self {
+    Self {
+      root,
+      fs,
+      counter: AtomicUsize::new(0),
+    }
+  }
+
+  fn get_temp_path(&self, key: &str) -> Utf8PathBuf {
+    let nanos = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map(|d| d.as_nanos())
+      .unwrap_or_default();
+    self.root.join(".temp").join(format!(
+      "{}.{}.{}.{}",
+      key.cow_replace('/', "_"),
+      std::process::id(),
+      nanos,
+      self.counter.fetch_add(1, Ordering::Relaxed)
+    ))
+  }
+}
+
+#[async_trait::async_trait]
+impl RemoteTransport for DirectoryTransport {
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+    let path = self.root.join(key);
+    if !self.fs.exists(&path).await? {
+      return Ok(None);
+    }
+    let mut reader = self.fs.read_file(&path).await?;
+    Ok(Some(reader.read_to_end().await?))
+  }
+
+  async fn put(&self, key: &str, value: Vec<u8>) -> Result<()> {
+    // the temp file is placed in the shared directory so that the rename is atomic
+    let temp_path = self.get_temp_path(key);
+    let mut writer = self.fs.write_file(&temp_path).await?;
+    writer.write_all(&value).await?;
+    writer.flush().await?;
+    self.fs.move_file(&temp_path, &self.root.join(key)).await?;
+    Ok(())
+  }
+
+  async fn exists(&self, key: &str) -> Result<bool> {
+    Ok(self.fs.exists(&self.root.join(key)).await?)
+  }
+}
+
+/// The response of a `RemoteHttpClient` request.
+#[derive(Debug)]
+pub struct RemoteHttpResponse {
+  pub status: u16,
+  pub body: Vec<u8>,
+}
+
+/// The http client used by `HttpTransport` to send the requests.
+///
+/// The requests are sent by the host, e.g. the js side uses `fetch`, so that tls,
+/// proxies and redirects are handled by a maintained http stack.
+#[async_trait::async_trait]
+pub trait RemoteHttpClient: std::fmt::Debug + Sync + Send {
+  async fn request(
+    &self,
+    method: &str,
+    url: &str,
+    headers: &[(String, String)],
+    body: Option<Vec<u8>>,
+  ) -> rspack_error::Result<RemoteHttpResponse>;
+}
+
+/// A transport on an http key/value server.
+///
+/// `GET {url}/{key}` reads a value, `HEAD {url}/{key}` checks existence and
+/// `PUT {url}/{key}` publishes a value. Missing keys are reported with status `404`.
+#[derive(Debug)]
+pub struct HttpTransport {
+  base_url: String,
+  headers: Vec<(String, String)>,
+  client: Arc<dyn RemoteHttpClient>,
+}
+
+impl HttpTransport {
+  pub fn new(
+    url: &str,
+    headers: Vec<(String, String)>,
+    client: Arc<dyn RemoteHttpClient>,
+  ) -> Result<Self> {
+    let parsed = url::Url::parse(url).map_err(|e| {
+      Error::from_reason(None, None, format!("invalid remote cache url `{url}`: {e}"))
+    })?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+      return Err(Error::from_reason(
+        None,
+        None,
+        format!("unsupported remote cache url `{url}`, only `http` and `https` are supported"),
+      ));
+    }
+    Ok(Self {
+      base_url: url.trim_end_matches('/').to_string(),
+      headers,
+      client,
+    })
+  }
+
+  async fn request(
+    &self,
+    method: &str,
+    key: &str,
+    body: Option<Vec<u8>>,
+  ) -> Result<(u16, Vec<u8>)> {
+    let url = format!("{}/{}", self.base_url, key);
+    // header values are not part of the error message since they may contain credentials
+    let response = self
+      .client
+      .request(method, &url, &self.headers, body)
+      .await
+      .map_err(|e| {
+        Error::from_reason(
+          None,
+          None,
+          format!("remote cache request `{method} {url}` failed: {e}"),
+        )
+      })?;
+    Ok((response.status, response.body))
+  }
+
+  fn status_error(&self, method: &str, key: &str, status: u16) -> Error {
+    Error::from_reason(
+      None,
+      None,
+      format!(
+        "remote cache request `{method} {}/{key}` failed with status {status}",
+        self.base_url
+      ),
+    )
+  }
+}
+
+#[async_trait::async_trait]
+impl RemoteTransport for HttpTransport {
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+    match self.request("GET", key, None).await? {
+      (200, body) => Ok(Some(body)),
+      (404, _) => Ok(None),
+      (status, _) => Err(self.status_error("GET", key, status)),
+    }
+  }
+
+  async fn put(&self, key: &str, value: Vec<u8>) -> Result<()> {
+    match self.request("PUT", key, Some(value)).await? {
+      (200..=299, _) => Ok(()),
+      (status, _) => Err(self.status_error("PUT", key, status)),
+    }
+  }
+
+  async fn exists(&self, key: &str) -> Result<bool> {
+    match self.request("HEAD", key, None).await? {
+      (200, _) => Ok(true),
+      (404, _) => Ok(false),
+      (status, _) => Err(self.status_error("HEAD", key, status)),
+    }
+  }
+}
@@ -37,6 +37,9 @@ mod test_storage_build {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      max_size: None,
+      max_generations: None,
+      compact_ratio: None,
     }
   }
 
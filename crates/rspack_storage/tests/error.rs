@@ -37,6 +37,9 @@ mod test_storage_error {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      max_size: None,
+      max_generations: None,
+      compact_ratio: None,
     }
   }
 
@@ -171,6 +174,73 @@ mod test_storage_error {
     Ok(())
   }
 
+  async fn test_quarantine_truncated_pack(
+    root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+    create_options: impl Fn() -> PackStorageOptions,
+  ) -> Result<()> {
+    let storage = PackStorage::new(create_options());
+    assert_eq!(storage.load("test_scope").await?.len(), 1000);
+    assert!(storage.load("other_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "other_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+
+    let meta_file = root.join("test_scope/scope_meta");
+    let first_pack = get_first_pack("test_scope", &meta_file, fs.as_ref()).await?;
+    let first_pack_file = root.join(&first_pack);
+    let first_pack_content = fs.read_file(&first_pack_file).await?.read_to_end().await?;
+
+    // mock a pack truncated by a killed process
+    let mut writer = fs.write_file(&first_pack_file).await?;
+    writer
+      .write_all(&first_pack_content[0..first_pack_content.len() / 2])
+      .await?;
+    writer.flush().await?;
+
+    // only the scope with corrupt packs is invalidated
+    let storage = PackStorage::new(create_options());
+    assert!(storage.load("test_scope").await.is_err_and(|e| {
+      e.to_string()
+        .contains("validate scope `test_scope` failed due to some packs are modified")
+    }));
+    assert_eq!(storage.load("other_scope").await?.len(), 100);
+
+    let corrupt_packs = storage.take_corrupt_packs();
+    assert_eq!(corrupt_packs.len(), 1);
+    assert_eq!(corrupt_packs[0].scope, "test_scope");
+    assert_eq!(corrupt_packs[0].packs, vec![first_pack_file.clone()]);
+    assert_eq!(corrupt_packs[0].quarantine, root.join(".quarantine"));
+    assert!(!fs.exists(&first_pack_file).await?);
+    assert!(
+      fs.exists(&root.join(".quarantine").join(&first_pack))
+        .await?
+    );
+    assert!(storage.take_corrupt_packs().is_empty());
+
+    // the scope can be saved again
+    for i in 0..1000 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    let storage = PackStorage::new(create_options());
+    assert_eq!(storage.load("test_scope").await?.len(), 1000);
+    assert!(storage.take_corrupt_packs().is_empty());
+
+    Ok(())
+  }
+
   #[tokio::test]
   #[cfg_attr(miri, ignore)]
   async fn test_error() -> Result<()> {
@@ -221,6 +291,11 @@ mod test_storage_error {
         create_pack_options(&root, &temp_root, &version, fs.clone()),
       )
       .await?;
+
+      test_quarantine_truncated_pack(&root.join(&version), fs.clone(), || {
+        create_pack_options(&root, &temp_root, &version, fs.clone())
+      })
+      .await?;
     }
     Ok(())
   }
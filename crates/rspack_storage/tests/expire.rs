@@ -37,6 +37,9 @@ mod test_storage_expire {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      max_size: None,
+      max_generations: None,
+      compact_ratio: None,
     });
     let data = storage.load("test_scope").await?;
     assert!(data.is_empty());
@@ -74,6 +77,9 @@ mod test_storage_expire {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      max_size: None,
+      max_generations: None,
+      compact_ratio: None,
     });
     assert!(storage.load("test_scope").await.is_err_and(|e| {
       e.to_string()
@@ -101,6 +107,9 @@ mod test_storage_expire {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      max_size: None,
+      max_generations: None,
+      compact_ratio: None,
     });
     let data = storage.load("test_scope").await?;
     assert!(data.is_empty());
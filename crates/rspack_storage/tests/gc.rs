@@ -0,0 +1,258 @@
+#[cfg(test)]
+mod test_storage_gc {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, EvictionResult, FileSystem, PackStorage, PackStorageOptions, Result, Storage,
+  };
+
+  pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_gc")
+      .join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_gc/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_pack_options(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    version: &str,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorageOptions {
+    PackStorageOptions {
+      version: version.to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs,
+      bucket_size: 2,
+      pack_size: 200,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+      max_size: None,
+      max_generations: None,
+      compact_ratio: None,
+    }
+  }
+
+  async fn build_version(options: PackStorageOptions) -> Result<EvictionResult> {
+    let storage = PackStorage::new(options);
+    let data = storage.load("test_scope").await?;
+    assert!(data.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    // make sure the versions have different access time
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    Ok(
+      storage
+        .take_eviction_result()
+        .expect("should have eviction result"),
+    )
+  }
+
+  async fn test_max_generations(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    for version in ["v1", "v2", "v3"] {
+      let res = build_version(create_pack_options(root, temp_root, version, fs.clone())).await?;
+      assert!(res.evicted_versions.is_empty());
+    }
+
+    let mut options = create_pack_options(root, temp_root, "v4", fs.clone());
+    options.max_generations = Some(3);
+    let res = build_version(options).await?;
+    assert_eq!(res.evicted_versions, vec!["v1".to_string()]);
+    assert!(!res.evicted_packs.is_empty());
+    assert!(
+      res
+        .evicted_packs
+        .iter()
+        .all(|pack| pack.starts_with(root.join("v1")))
+    );
+    assert!(res.reclaimed_size > 0);
+    assert!(!(fs.exists(&root.join("v1")).await?));
+    for version in ["v2", "v3", "v4"] {
+      assert!(
+        fs.exists(&root.join(version).join("test_scope/scope_meta"))
+          .await?
+      );
+    }
+    Ok(())
+  }
+
+  async fn resave_version(options: PackStorageOptions) -> Result<EvictionResult> {
+    let storage = PackStorage::new(options);
+    let data = storage.load("test_scope").await?;
+    assert_eq!(data.len(), 100);
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    Ok(
+      storage
+        .take_eviction_result()
+        .expect("should have eviction result"),
+    )
+  }
+
+  async fn dir_size(dir: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<u64> {
+    let mut size = 0;
+    let mut stack = vec![dir.clone()];
+    while let Some(path) = stack.pop() {
+      if fs.metadata(&path).await?.is_directory {
+        for name in fs.read_dir(&path).await? {
+          if !name.starts_with(".") {
+            stack.push(path.join(name));
+          }
+        }
+      } else {
+        size += fs.metadata(&path).await?.size;
+      }
+    }
+    Ok(size)
+  }
+
+  async fn test_max_size(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let res = build_version(create_pack_options(root, temp_root, "v5", fs.clone())).await?;
+    assert!(res.evicted_packs.is_empty());
+
+    // one byte over budget, the least recently accessed version is evicted as a whole
+    let mut total_size = 0;
+    for version in ["v2", "v3", "v4", "v5"] {
+      total_size += dir_size(&root.join(version), fs.clone()).await?;
+    }
+    let mut options = create_pack_options(root, temp_root, "v5", fs.clone());
+    options.max_size = Some(total_size - 1);
+    let res = resave_version(options).await?;
+    assert_eq!(res.evicted_versions, vec!["v2".to_string()]);
+    assert!(!res.evicted_packs.is_empty());
+    assert!(
+      res
+        .evicted_packs
+        .iter()
+        .all(|pack| pack.starts_with(root.join("v2")))
+    );
+    assert!(res.reclaimed_size > 0);
+    assert!(res.remain_size < total_size);
+    assert!(!(fs.exists(&root.join("v2")).await?));
+
+    // the version in use is never evicted
+    let mut options = create_pack_options(root, temp_root, "v5", fs.clone());
+    options.max_size = Some(1);
+    let res = resave_version(options).await?;
+    assert_eq!(
+      res.evicted_versions,
+      vec!["v3".to_string(), "v4".to_string()]
+    );
+    assert!(
+      res
+        .evicted_packs
+        .iter()
+        .all(|pack| !pack.starts_with(root.join("v5")))
+    );
+    assert!(res.remain_size > 0);
+
+    // the evicted versions start from an empty cache
+    for version in ["v2", "v3", "v4"] {
+      build_version(create_pack_options(root, temp_root, version, fs.clone())).await?;
+    }
+    resave_version(create_pack_options(root, temp_root, "v5", fs.clone())).await?;
+    Ok(())
+  }
+
+  async fn test_evict_scopes_atomically(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    // the module graph of the old version is only valid with the snapshot of the same version
+    let storage = PackStorage::new(create_pack_options(root, temp_root, "old", fs.clone()));
+    for scope in ["make", "snapshot"] {
+      assert!(storage.load(scope).await?.is_empty());
+      for i in 0..100 {
+        storage.set(
+          scope,
+          format!("key_{i:0>3}").as_bytes().to_vec(),
+          format!("val_{i:0>3}").as_bytes().to_vec(),
+        );
+      }
+    }
+    storage.trigger_save()?.await.expect("should save")?;
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    build_version(create_pack_options(root, temp_root, "new", fs.clone())).await?;
+
+    // one byte over budget, evicting a single pack of the make scope would be enough
+    let total_size = dir_size(&root.join("old"), fs.clone()).await?
+      + dir_size(&root.join("new"), fs.clone()).await?;
+    let mut options = create_pack_options(root, temp_root, "new", fs.clone());
+    options.max_size = Some(total_size - 1);
+    let res = resave_version(options).await?;
+    assert_eq!(res.evicted_versions, vec!["old".to_string()]);
+    assert!(!(fs.exists(&root.join("old")).await?));
+
+    // the old version recovers from an empty cache instead of an incomplete make scope
+    let storage = PackStorage::new(create_pack_options(root, temp_root, "old", fs.clone()));
+    for scope in ["make", "snapshot"] {
+      assert!(storage.load(scope).await?.is_empty());
+    }
+    assert!(storage.take_corrupt_packs().is_empty());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_gc() -> Result<()> {
+    let cases = [
+      (
+        get_native_path("test_gc_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_gc_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for ((root, temp_root), fs) in cases {
+      let root = root.assert_utf8();
+      let temp_root = temp_root.assert_utf8();
+      fs.remove_dir(&root).await.expect("should remove root");
+      fs.remove_dir(&temp_root)
+        .await
+        .expect("should remove temp root");
+
+      test_max_generations(&root, &temp_root, fs.clone()).await?;
+      test_max_size(&root, &temp_root, fs.clone()).await?;
+
+      let atomic_root = root.with_file_name("atomic_cache");
+      let atomic_temp_root = temp_root.with_file_name("atomic_temp");
+      fs.remove_dir(&atomic_root)
+        .await
+        .expect("should remove root");
+      fs.remove_dir(&atomic_temp_root)
+        .await
+        .expect("should remove temp root");
+      test_evict_scopes_atomically(&atomic_root, &atomic_temp_root, fs.clone()).await?;
+    }
+    Ok(())
+  }
+}
@@ -100,6 +100,9 @@ mod test_storage_lock {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      max_size: None,
+      max_generations: None,
+      compact_ratio: None,
     });
     let data = storage.load("test_scope").await?;
     assert!(data.is_empty());
@@ -137,6 +140,9 @@ mod test_storage_lock {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      max_size: None,
+      max_generations: None,
+      compact_ratio: None,
     });
     assert_eq!(storage.load("test_scope").await?.len(), 100);
     Ok(())
@@ -159,6 +165,9 @@ mod test_storage_lock {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      max_size: None,
+      max_generations: None,
+      compact_ratio: None,
     });
     assert!(storage.load("test_scope").await.is_err_and(|e| {
       e.to_string()
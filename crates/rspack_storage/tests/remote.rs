@@ -0,0 +1,323 @@
+#[cfg(test)]
+mod test_storage_remote {
+  use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+  };
+
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, DirectoryTransport, FileSystem, HttpTransport, PackStorageOptions,
+    RemoteHttpClient, RemoteHttpResponse, RemoteStorage, RemoteStorageOptions, RemoteTransport,
+    Result, Storage,
+  };
+
+  type ServerStore = Arc<Mutex<HashMap<String, Vec<u8>>>>;
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_remote/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_pack_options(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    version: &str,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorageOptions {
+    PackStorageOptions {
+      version: version.to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs,
+      bucket_size: 10,
+      pack_size: 200,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+      max_size: None,
+      max_generations: None,
+      compact_ratio: None,
+    }
+  }
+
+  /// A local stand-in for a remote cache server, which stores values by url path in memory.
+  #[derive(Debug, Default)]
+  struct MemoryHttpClient {
+    store: ServerStore,
+  }
+
+  #[async_trait::async_trait]
+  impl RemoteHttpClient for MemoryHttpClient {
+    async fn request(
+      &self,
+      method: &str,
+      url: &str,
+      _headers: &[(String, String)],
+      body: Option<Vec<u8>>,
+    ) -> rspack_error::Result<RemoteHttpResponse> {
+      let path = url
+        .strip_prefix("https://remote.cache")
+        .expect("should be remote cache url")
+        .to_string();
+      let mut store = self.store.lock().expect("should lock");
+      let (status, body) = match method {
+        "PUT" => {
+          store.insert(path, body.unwrap_or_default());
+          (201, vec![])
+        }
+        "GET" | "HEAD" => match store.get(&path) {
+          Some(value) if method == "GET" => (200, value.clone()),
+          Some(_) => (200, vec![]),
+          None => (404, vec![]),
+        },
+        _ => (405, vec![]),
+      };
+      Ok(RemoteHttpResponse { status, body })
+    }
+  }
+
+  fn create_http_transport(store: ServerStore) -> Result<HttpTransport> {
+    HttpTransport::new(
+      "https://remote.cache/cache/",
+      vec![],
+      Arc::new(MemoryHttpClient { store }),
+    )
+  }
+
+  fn create_storage(
+    case: &str,
+    fs: Arc<dyn FileSystem>,
+    transport: Arc<dyn RemoteTransport>,
+  ) -> RemoteStorage {
+    let (root, temp_root) = get_memory_path(case);
+    RemoteStorage::new(RemoteStorageOptions {
+      local: create_pack_options(&root.assert_utf8(), &temp_root.assert_utf8(), "xxx", fs),
+      transport,
+    })
+  }
+
+  async fn test_share_between_machines(
+    transport: Arc<dyn RemoteTransport>,
+    empty_transport: Arc<dyn RemoteTransport>,
+  ) -> Result<()> {
+    // first machine, write cache
+    let storage = create_storage(
+      "machine_a",
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      transport.clone(),
+    );
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    storage.trigger_save()?.await.expect("should save")?;
+
+    // second machine with an empty disk, read from remote
+    let fs_b: Arc<dyn FileSystem> =
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let storage = create_storage("machine_b", fs_b.clone(), transport.clone());
+    let data = storage.load("test_scope").await?;
+    assert_eq!(data.len(), 100);
+    storage.set(
+      "test_scope",
+      "key_000".as_bytes().to_vec(),
+      "new_000".as_bytes().to_vec(),
+    );
+    storage.remove("test_scope", "key_099".as_bytes());
+    storage.trigger_save()?.await.expect("should save")?;
+
+    // second machine restarts without remote, read from local packs
+    let storage = create_storage("machine_b", fs_b, empty_transport);
+    let data = storage
+      .load("test_scope")
+      .await?
+      .into_iter()
+      .map(|(k, v)| (k.as_ref().clone(), v.as_ref().clone()))
+      .collect::<HashMap<_, _>>();
+    assert_eq!(data.len(), 99);
+    assert_eq!(data.get("key_000".as_bytes()), Some(&b"new_000".to_vec()));
+
+    // third machine, read the changes of second machine from remote
+    let storage = create_storage(
+      "machine_c",
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      transport,
+    );
+    let data = storage
+      .load("test_scope")
+      .await?
+      .into_iter()
+      .map(|(k, v)| (k.as_ref().clone(), v.as_ref().clone()))
+      .collect::<HashMap<_, _>>();
+    assert_eq!(data.len(), 99);
+    assert_eq!(data.get("key_000".as_bytes()), Some(&b"new_000".to_vec()));
+    assert!(!data.contains_key("key_099".as_bytes()));
+
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_http_transport() -> Result<()> {
+    let store = ServerStore::default();
+
+    test_share_between_machines(
+      Arc::new(create_http_transport(store.clone())?),
+      Arc::new(create_http_transport(ServerStore::default())?),
+    )
+    .await?;
+
+    let store = store.lock().expect("should lock");
+    assert!(store.contains_key("/cache/xxx/test_scope"));
+    assert!(store.keys().any(|key| key.starts_with("/cache/blobs/")));
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_directory_transport() -> Result<()> {
+    let shared_fs: Arc<dyn FileSystem> =
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+
+    test_share_between_machines(
+      Arc::new(DirectoryTransport::new(
+        Utf8PathBuf::from("/shared"),
+        shared_fs.clone(),
+      )),
+      Arc::new(DirectoryTransport::new(
+        Utf8PathBuf::from("/empty"),
+        shared_fs.clone(),
+      )),
+    )
+    .await?;
+
+    assert!(
+      shared_fs
+        .exists(&Utf8PathBuf::from("/shared/xxx/test_scope"))
+        .await?
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_concurrent_publish() -> Result<()> {
+    let store = ServerStore::default();
+    let transport: Arc<dyn RemoteTransport> = Arc::new(create_http_transport(store.clone())?);
+
+    let storage = create_storage(
+      "concurrent_a",
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      transport.clone(),
+    );
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    storage.trigger_save()?.await.expect("should save")?;
+
+    // two machines load the same remote state
+    let storage_b = create_storage(
+      "concurrent_b",
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      transport.clone(),
+    );
+    let storage_c = create_storage(
+      "concurrent_c",
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      transport.clone(),
+    );
+    assert_eq!(storage_b.load("test_scope").await?.len(), 100);
+    assert_eq!(storage_c.load("test_scope").await?.len(), 100);
+
+    // `key_000` and `key_001` share a bucket,
+    // `key_010`, `key_030` and `key_100` share another bucket
+    storage_b.set("test_scope", b"key_000".to_vec(), b"new_000".to_vec());
+    storage_b.set("test_scope", b"key_030".to_vec(), b"new_030".to_vec());
+    storage_b.trigger_save()?.await.expect("should save")?;
+    storage_c.set("test_scope", b"key_001".to_vec(), b"new_001".to_vec());
+    storage_c.set("test_scope", b"key_100".to_vec(), b"val_100".to_vec());
+    storage_c.remove("test_scope", b"key_010");
+    storage_c.trigger_save()?.await.expect("should save")?;
+
+    // both changes are kept
+    let storage = create_storage(
+      "concurrent_d",
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      transport,
+    );
+    let data = storage
+      .load("test_scope")
+      .await?
+      .into_iter()
+      .map(|(k, v)| (k.as_ref().clone(), v.as_ref().clone()))
+      .collect::<HashMap<_, _>>();
+    assert_eq!(data.len(), 100);
+    assert_eq!(data.get("key_000".as_bytes()), Some(&b"new_000".to_vec()));
+    assert_eq!(data.get("key_001".as_bytes()), Some(&b"new_001".to_vec()));
+    assert_eq!(data.get("key_030".as_bytes()), Some(&b"new_030".to_vec()));
+    assert_eq!(data.get("key_100".as_bytes()), Some(&b"val_100".to_vec()));
+    assert!(!data.contains_key("key_010".as_bytes()));
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_unsupported_url() {
+    let error = HttpTransport::new(
+      "ftp://127.0.0.1/cache",
+      vec![],
+      Arc::new(MemoryHttpClient::default()),
+    )
+    .expect_err("should reject ftp url");
+    assert!(
+      error
+        .to_string()
+        .contains("only `http` and `https` are supported")
+    );
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_corrupted_remote() -> Result<()> {
+    let store = ServerStore::default();
+    let transport: Arc<dyn RemoteTransport> = Arc::new(create_http_transport(store.clone())?);
+
+    let storage = create_storage(
+      "corrupted_a",
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      transport.clone(),
+    );
+    storage.set("test_scope", b"key".to_vec(), b"value".to_vec());
+    storage.trigger_save()?.await.expect("should save")?;
+
+    for (key, value) in store.lock().expect("should lock").iter_mut() {
+      if key.starts_with("/cache/blobs/") {
+        value.push(0);
+      }
+    }
+
+    let storage = create_storage(
+      "corrupted_b",
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      transport,
+    );
+    // the corrupted remote is skipped with a warning and the build starts cold
+    assert!(storage.load("test_scope").await?.is_empty());
+    let warnings = storage.take_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("is corrupted"));
+    Ok(())
+  }
+}
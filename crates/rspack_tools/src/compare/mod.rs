@@ -58,10 +58,11 @@ pub fn load_storages_from_path(path: &Utf8PathBuf) -> HashMap<String, Arc<dyn St
       continue;
     }
 
-    // Create storage for this version
-    let storage = create_storage(
+    // Create storage for this version, the file system storage has no warnings
+    let (storage, _) = create_storage(
       StorageOptions::FileSystem {
         directory: path.clone().into(),
+        budget: Default::default(),
       },
       v.clone(),
       fs.clone(),
@@ -0,0 +1,538 @@
+mod summary;
+
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  fmt::Write,
+};
+
+use rspack_error::{Result, error};
+use rspack_paths::Utf8PathBuf;
+use rspack_util::size::format_size;
+use serde::Serialize;
+
+use self::summary::BuildSummary;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildDiff {
+  pub assets: AssetsDiff,
+  /// `None` if any of the builds is an output directory, which has no chunk information
+  pub chunks: Option<ChunksDiff>,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetsDiff {
+  pub added: Vec<AssetSize>,
+  pub removed: Vec<AssetSize>,
+  pub resized: Vec<ResizedAsset>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetSize {
+  pub name: String,
+  pub size: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResizedAsset {
+  pub name: String,
+  /// The name in the old build if it is different, such as the hash in the name is changed
+  pub old_name: Option<String>,
+  pub old_size: u64,
+  pub new_size: u64,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunksDiff {
+  pub added: Vec<String>,
+  pub removed: Vec<String>,
+  /// Chunks in both builds whose modules are changed
+  pub changed: Vec<ChangedChunk>,
+  /// Modules in both builds which belong to different chunks
+  pub moved_modules: Vec<MovedModule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedChunk {
+  pub name: String,
+  pub added_modules: Vec<String>,
+  pub removed_modules: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MovedModule {
+  pub name: String,
+  pub old_chunks: Vec<String>,
+  pub new_chunks: Vec<String>,
+}
+
+/// Compare two builds, each of them is either a stats JSON file or an output directory
+///
+/// Returns the human readable report, or the JSON report if `json` is true.
+pub fn diff_builds(path1: Utf8PathBuf, path2: Utf8PathBuf, json: bool) -> Result<String> {
+  let old = BuildSummary::load(&path1)?;
+  let new = BuildSummary::load(&path2)?;
+  let diff = BuildDiff {
+    assets: diff_assets(&old.assets, &new.assets),
+    chunks: match (&old.chunks, &new.chunks) {
+      (Some(old), Some(new)) => Some(diff_chunks(old, new)),
+      _ => None,
+    },
+  };
+  if json {
+    let mut output =
+      serde_json::to_string_pretty(&diff).map_err(|e| error!("serialize diff failed: {}", e))?;
+    output.push('\n');
+    Ok(output)
+  } else {
+    Ok(render_diff(&diff))
+  }
+}
+
+/// Assets are matched by their names, then the unmatched assets are matched by their names
+/// without hashes, if only one asset in each build has the same name without hashes.
+fn diff_assets(old: &BTreeMap<String, u64>, new: &BTreeMap<String, u64>) -> AssetsDiff {
+  let mut diff = AssetsDiff::default();
+  let mut unmatched_old: BTreeMap<String, Vec<&String>> = BTreeMap::new();
+  for (name, &old_size) in old {
+    match new.get(name) {
+      None => unmatched_old
+        .entry(strip_hash(name))
+        .or_default()
+        .push(name),
+      Some(&new_size) if new_size != old_size => diff.resized.push(ResizedAsset {
+        name: name.clone(),
+        old_name: None,
+        old_size,
+        new_size,
+      }),
+      Some(_) => {}
+    }
+  }
+  let mut unmatched_new: BTreeMap<String, Vec<&String>> = BTreeMap::new();
+  for name in new.keys() {
+    if !old.contains_key(name) {
+      unmatched_new
+        .entry(strip_hash(name))
+        .or_default()
+        .push(name);
+    }
+  }
+
+  for (key, old_names) in &unmatched_old {
+    if let [old_name] = old_names.as_slice()
+      && let Some([new_name]) = unmatched_new.get(key).map(Vec::as_slice)
+    {
+      let (old_size, new_size) = (old[*old_name], new[*new_name]);
+      if old_size != new_size {
+        diff.resized.push(ResizedAsset {
+          name: new_name.to_string(),
+          old_name: Some(old_name.to_string()),
+          old_size,
+          new_size,
+        });
+      }
+      continue;
+    }
+    diff.removed.extend(old_names.iter().map(|name| AssetSize {
+      name: name.to_string(),
+      size: old[*name],
+    }));
+  }
+  for (key, new_names) in &unmatched_new {
+    if let [_] = new_names.as_slice()
+      && let Some([_]) = unmatched_old.get(key).map(Vec::as_slice)
+    {
+      continue;
+    }
+    diff.added.extend(new_names.iter().map(|name| AssetSize {
+      name: name.to_string(),
+      size: new[*name],
+    }));
+  }
+  diff.added.sort_by(|a, b| a.name.cmp(&b.name));
+  diff.removed.sort_by(|a, b| a.name.cmp(&b.name));
+  diff.resized.sort_by(|a, b| a.name.cmp(&b.name));
+  diff
+}
+
+/// Replace the hashes in the asset name with `[hash]`, a hash is a hex segment of at least
+/// 8 characters with a digit, such as `3f2a1b4c` in `main.3f2a1b4c.js`.
+fn strip_hash(name: &str) -> String {
+  const SEPARATORS: [char; 5] = ['.', '-', '_', '/', '~'];
+  let mut result = String::with_capacity(name.len());
+  for part in name.split_inclusive(SEPARATORS) {
+    let segment = part.trim_end_matches(SEPARATORS);
+    if segment.len() >= 8
+      && segment.bytes().all(|b| b.is_ascii_hexdigit())
+      && segment.bytes().any(|b| b.is_ascii_digit())
+    {
+      result.push_str("[hash]");
+    } else {
+      result.push_str(segment);
+    }
+    result.push_str(&part[segment.len()..]);
+  }
+  result
+}
+
+fn diff_chunks(
+  old: &BTreeMap<String, BTreeSet<String>>,
+  new: &BTreeMap<String, BTreeSet<String>>,
+) -> ChunksDiff {
+  let mut diff = ChunksDiff {
+    added: new
+      .keys()
+      .filter(|name| !old.contains_key(*name))
+      .cloned()
+      .collect(),
+    removed: old
+      .keys()
+      .filter(|name| !new.contains_key(*name))
+      .cloned()
+      .collect(),
+    ..Default::default()
+  };
+  for (name, old_modules) in old {
+    let Some(new_modules) = new.get(name) else {
+      continue;
+    };
+    if old_modules != new_modules {
+      diff.changed.push(ChangedChunk {
+        name: name.clone(),
+        added_modules: new_modules.difference(old_modules).cloned().collect(),
+        removed_modules: old_modules.difference(new_modules).cloned().collect(),
+      });
+    }
+  }
+
+  let module_chunks = |chunks: &BTreeMap<String, BTreeSet<String>>| {
+    let mut result: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (chunk, modules) in chunks {
+      for module in modules {
+        result
+          .entry(module.clone())
+          .or_default()
+          .push(chunk.clone());
+      }
+    }
+    result
+  };
+  let old_module_chunks = module_chunks(old);
+  let new_module_chunks = module_chunks(new);
+  for (name, old_chunks) in old_module_chunks {
+    if let Some(new_chunks) = new_module_chunks.get(&name)
+      && &old_chunks != new_chunks
+    {
+      diff.moved_modules.push(MovedModule {
+        name,
+        old_chunks,
+        new_chunks: new_chunks.clone(),
+      });
+    }
+  }
+  diff
+}
+
+fn format_delta(old_size: u64, new_size: u64) -> String {
+  if new_size >= old_size {
+    format!("+{}", format_size((new_size - old_size) as f64))
+  } else {
+    format!("-{}", format_size((old_size - new_size) as f64))
+  }
+}
+
+fn render_diff(diff: &BuildDiff) -> String {
+  let mut output = String::new();
+  let assets = &diff.assets;
+  let old_total: u64 = assets.removed.iter().map(|asset| asset.size).sum::<u64>()
+    + assets
+      .resized
+      .iter()
+      .map(|asset| asset.old_size)
+      .sum::<u64>();
+  let new_total: u64 = assets.added.iter().map(|asset| asset.size).sum::<u64>()
+    + assets
+      .resized
+      .iter()
+      .map(|asset| asset.new_size)
+      .sum::<u64>();
+  writeln!(
+    output,
+    "Assets: {} added, {} removed, {} resized ({})",
+    assets.added.len(),
+    assets.removed.len(),
+    assets.resized.len(),
+    format_delta(old_total, new_total)
+  )
+  .expect("should write");
+  for asset in &assets.added {
+    writeln!(
+      output,
+      "  + {}  {}",
+      asset.name,
+      format_size(asset.size as f64)
+    )
+    .expect("should write");
+  }
+  for asset in &assets.removed {
+    writeln!(
+      output,
+      "  - {}  {}",
+      asset.name,
+      format_size(asset.size as f64)
+    )
+    .expect("should write");
+  }
+  for asset in &assets.resized {
+    let name = match &asset.old_name {
+      Some(old_name) => format!("{} -> {}", old_name, asset.name),
+      None => asset.name.clone(),
+    };
+    writeln!(
+      output,
+      "  ~ {}  {} -> {} ({})",
+      name,
+      format_size(asset.old_size as f64),
+      format_size(asset.new_size as f64),
+      format_delta(asset.old_size, asset.new_size)
+    )
+    .expect("should write");
+  }
+
+  let Some(chunks) = &diff.chunks else {
+    writeln!(
+      output,
+      "Chunks: skipped, chunks and modules are only compared between stats files"
+    )
+    .expect("should write");
+    return output;
+  };
+  writeln!(
+    output,
+    "Chunks: {} added, {} removed, {} changed",
+    chunks.added.len(),
+    chunks.removed.len(),
+    chunks.changed.len()
+  )
+  .expect("should write");
+  for name in &chunks.added {
+    writeln!(output, "  + {}", name).expect("should write");
+  }
+  for name in &chunks.removed {
+    writeln!(output, "  - {}", name).expect("should write");
+  }
+  for chunk in &chunks.changed {
+    writeln!(
+      output,
+      "  ~ {}  {} modules added, {} modules removed",
+      chunk.name,
+      chunk.added_modules.len(),
+      chunk.removed_modules.len()
+    )
+    .expect("should write");
+    for module in &chunk.added_modules {
+      writeln!(output, "      + {}", module).expect("should write");
+    }
+    for module in &chunk.removed_modules {
+      writeln!(output, "      - {}", module).expect("should write");
+    }
+  }
+
+  writeln!(output, "Moved modules: {}", chunks.moved_modules.len()).expect("should write");
+  for module in &chunks.moved_modules {
+    writeln!(
+      output,
+      "  {}  [{}] -> [{}]",
+      module.name,
+      module.old_chunks.join(", "),
+      module.new_chunks.join(", ")
+    )
+    .expect("should write");
+  }
+  output
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn chunks(entries: &[(&str, &[&str])]) -> BTreeMap<String, BTreeSet<String>> {
+    entries
+      .iter()
+      .map(|(name, modules)| {
+        (
+          name.to_string(),
+          modules.iter().map(ToString::to_string).collect(),
+        )
+      })
+      .collect()
+  }
+
+  #[test]
+  fn should_diff_assets() {
+    let old = BTreeMap::from([
+      ("a.js".to_string(), 100),
+      ("b.js".to_string(), 200),
+      ("c.js".to_string(), 300),
+    ]);
+    let new = BTreeMap::from([
+      ("a.js".to_string(), 100),
+      ("b.js".to_string(), 250),
+      ("d.js".to_string(), 400),
+    ]);
+    let diff = diff_assets(&old, &new);
+    assert_eq!(
+      diff
+        .added
+        .iter()
+        .map(|asset| (asset.name.as_str(), asset.size))
+        .collect::<Vec<_>>(),
+      vec![("d.js", 400)]
+    );
+    assert_eq!(
+      diff
+        .removed
+        .iter()
+        .map(|asset| (asset.name.as_str(), asset.size))
+        .collect::<Vec<_>>(),
+      vec![("c.js", 300)]
+    );
+    assert_eq!(
+      diff
+        .resized
+        .iter()
+        .map(|asset| (asset.name.as_str(), asset.old_size, asset.new_size))
+        .collect::<Vec<_>>(),
+      vec![("b.js", 200, 250)]
+    );
+  }
+
+  #[test]
+  fn should_match_hashed_assets() {
+    let old = BTreeMap::from([
+      ("main.3f2a1b4c.js".to_string(), 100),
+      ("static/css/main-0a1b2c3d.css".to_string(), 50),
+      ("vendor.1234abcd.js".to_string(), 300),
+      ("lazy.11111111.js".to_string(), 10),
+      ("lazy.22222222.js".to_string(), 20),
+    ]);
+    let new = BTreeMap::from([
+      ("main.9e8d7c6b.js".to_string(), 120),
+      ("static/css/main-4e5f6a7b.css".to_string(), 50),
+      ("vendor.1234abcd.js".to_string(), 300),
+      ("lazy.33333333.js".to_string(), 30),
+    ]);
+    let diff = diff_assets(&old, &new);
+    assert_eq!(
+      diff
+        .resized
+        .iter()
+        .map(|asset| (
+          asset.old_name.as_deref(),
+          asset.name.as_str(),
+          asset.old_size,
+          asset.new_size
+        ))
+        .collect::<Vec<_>>(),
+      vec![(Some("main.3f2a1b4c.js"), "main.9e8d7c6b.js", 100, 120)]
+    );
+    // the assets are ambiguous if several of them have the same name without hashes
+    assert_eq!(
+      diff
+        .removed
+        .iter()
+        .map(|asset| asset.name.as_str())
+        .collect::<Vec<_>>(),
+      vec!["lazy.11111111.js", "lazy.22222222.js"]
+    );
+    assert_eq!(
+      diff
+        .added
+        .iter()
+        .map(|asset| asset.name.as_str())
+        .collect::<Vec<_>>(),
+      vec!["lazy.33333333.js"]
+    );
+
+    assert_eq!(strip_hash("main.3f2a1b4c.js"), "main.[hash].js");
+    assert_eq!(
+      strip_hash("static/js/123.abcdef12.js"),
+      "static/js/123.[hash].js"
+    );
+    assert_eq!(strip_hash("deadbeefcafe.js"), "deadbeefcafe.js");
+    assert_eq!(strip_hash("main.js"), "main.js");
+  }
+
+  #[test]
+  fn should_diff_chunks_and_modules() {
+    let old = chunks(&[
+      ("main", &["./a.js", "./b.js"]),
+      ("lazy", &["./c.js"]),
+      ("removed", &["./d.js"]),
+    ]);
+    let new = chunks(&[
+      ("main", &["./a.js", "./c.js"]),
+      ("lazy", &["./c.js"]),
+      ("added", &["./e.js"]),
+    ]);
+    let diff = diff_chunks(&old, &new);
+    assert_eq!(diff.added, vec!["added"]);
+    assert_eq!(diff.removed, vec!["removed"]);
+    assert_eq!(diff.changed.len(), 1);
+    assert_eq!(diff.changed[0].name, "main");
+    assert_eq!(diff.changed[0].added_modules, vec!["./c.js"]);
+    assert_eq!(diff.changed[0].removed_modules, vec!["./b.js"]);
+    assert_eq!(diff.moved_modules.len(), 1);
+    assert_eq!(diff.moved_modules[0].name, "./c.js");
+    assert_eq!(diff.moved_modules[0].old_chunks, vec!["lazy"]);
+    assert_eq!(diff.moved_modules[0].new_chunks, vec!["lazy", "main"]);
+  }
+
+  #[test]
+  fn should_render_diff() {
+    let diff = BuildDiff {
+      assets: diff_assets(
+        &BTreeMap::from([("a.js".to_string(), 100), ("b.js".to_string(), 200)]),
+        &BTreeMap::from([("a.js".to_string(), 150), ("c.js".to_string(), 50)]),
+      ),
+      chunks: Some(diff_chunks(
+        &chunks(&[("main", &["./a.js", "./b.js"])]),
+        &chunks(&[("main", &["./a.js"]), ("lazy", &["./b.js"])]),
+      )),
+    };
+    assert_eq!(
+      render_diff(&diff),
+      "\
+Assets: 1 added, 1 removed, 1 resized (-100.000 bytes)
+  + c.js  50.000 bytes
+  - b.js  200.000 bytes
+  ~ a.js  100.000 bytes -> 150.000 bytes (+50.000 bytes)
+Chunks: 1 added, 0 removed, 1 changed
+  + lazy
+  ~ main  0 modules added, 1 modules removed
+      - ./b.js
+Moved modules: 1
+  ./b.js  [main] -> [lazy]
+"
+    );
+
+    let diff = BuildDiff {
+      assets: AssetsDiff::default(),
+      chunks: None,
+    };
+    assert_eq!(
+      render_diff(&diff),
+      "\
+Assets: 0 added, 0 removed, 0 resized (+0 bytes)
+Chunks: skipped, chunks and modules are only compared between stats files
+"
+    );
+  }
+}
@@ -0,0 +1,247 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use rspack_error::{Result, error};
+use rspack_fs::{NativeFileSystem, ReadableFileSystem};
+use rspack_paths::Utf8PathBuf;
+use serde_json::Value;
+
+/// The parts of a build that are compared, collected from a stats JSON file or an output directory
+pub struct BuildSummary {
+  /// Asset name to size in bytes
+  pub assets: BTreeMap<String, u64>,
+  /// Chunk key to the names of the modules in the chunk, `None` for output directories
+  pub chunks: Option<BTreeMap<String, BTreeSet<String>>>,
+}
+
+impl BuildSummary {
+  /// Load the summary from `path`, which is either a stats JSON file or an output directory
+  pub fn load(path: &Utf8PathBuf) -> Result<Self> {
+    let fs = NativeFileSystem::new(false);
+    let metadata = fs
+      .metadata_sync(path)
+      .map_err(|e| error!("read {} failed: {}", path, e))?;
+    if metadata.is_directory {
+      Ok(Self::from_output_dir(&fs, path))
+    } else {
+      let content = fs
+        .read_to_string_sync(path)
+        .map_err(|e| error!("read {} failed: {}", path, e))?;
+      let stats = serde_json::from_str::<Value>(&content)
+        .map_err(|e| error!("parse stats {} failed: {}", path, e))?;
+      Self::from_stats(&stats).map_err(|e| error!("invalid stats {}: {}", path, e))
+    }
+  }
+
+  /// Collect the files of an output directory as assets, the asset names are relative to `root`
+  fn from_output_dir(fs: &NativeFileSystem, root: &Utf8PathBuf) -> Self {
+    let mut assets = BTreeMap::new();
+    let mut stack = vec![root.clone()];
+    while let Some(dir) = stack.pop() {
+      let Ok(children) = fs.read_dir_sync(&dir) else {
+        continue;
+      };
+      for child in children {
+        let path = dir.join(child);
+        let Ok(metadata) = fs.metadata_sync(&path) else {
+          continue;
+        };
+        if metadata.is_directory {
+          stack.push(path);
+        } else if metadata.is_file {
+          let name = path
+            .strip_prefix(root)
+            .expect("should be a child of root")
+            .components()
+            .map(|component| component.as_str())
+            .collect::<Vec<_>>()
+            .join("/");
+          assets.insert(name, metadata.size);
+        }
+      }
+    }
+    Self {
+      assets,
+      chunks: None,
+    }
+  }
+
+  /// Collect the assets, chunks and modules of a stats JSON
+  ///
+  /// The modules of chunks are read from the top level `modules` with their `chunks`,
+  /// and fallback to `chunks[].modules` if the top level modules are not present.
+  fn from_stats(stats: &Value) -> Result<Self> {
+    // the stats of multi compiler only contains children
+    if stats.get("assets").is_none()
+      && let Some(children) = stats.get("children").and_then(Value::as_array)
+    {
+      if let [child] = children.as_slice() {
+        return Self::from_stats(child);
+      }
+      return Err(error!(
+        "stats of multiple compilers are not supported, compare the stats of each compiler instead"
+      ));
+    }
+
+    let mut assets = BTreeMap::new();
+    for asset in array(stats, "assets") {
+      let (Some(name), Some(size)) = (
+        asset.get("name").and_then(Value::as_str),
+        asset.get("size").and_then(Value::as_f64),
+      ) else {
+        return Err(error!("asset should have name and size"));
+      };
+      assets.insert(name.to_string(), size as u64);
+    }
+
+    let mut chunk_keys = BTreeMap::new();
+    let mut chunks = BTreeMap::new();
+    for chunk in array(stats, "chunks") {
+      let key = chunk_key(chunk);
+      if let Some(id) = chunk.get("id").and_then(id_to_string) {
+        chunk_keys.insert(id, key.clone());
+      }
+      let modules = array(chunk, "modules")
+        .filter_map(module_name)
+        .collect::<BTreeSet<_>>();
+      chunks.insert(key, modules);
+    }
+    if stats.get("chunks").is_none() {
+      return Ok(Self {
+        assets,
+        chunks: None,
+      });
+    }
+
+    if stats.get("modules").is_some() {
+      for modules in chunks.values_mut() {
+        modules.clear();
+      }
+      for module in array(stats, "modules") {
+        let Some(name) = module_name(module) else {
+          continue;
+        };
+        for chunk_id in array(module, "chunks").filter_map(id_to_string) {
+          if let Some(modules) = chunk_keys
+            .get(&chunk_id)
+            .and_then(|key| chunks.get_mut(key))
+          {
+            modules.insert(name.clone());
+          }
+        }
+      }
+    }
+
+    Ok(Self {
+      assets,
+      chunks: Some(chunks),
+    })
+  }
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> impl Iterator<Item = &'a Value> {
+  value
+    .get(key)
+    .and_then(Value::as_array)
+    .into_iter()
+    .flatten()
+}
+
+fn id_to_string(id: &Value) -> Option<String> {
+  match id {
+    Value::String(id) => Some(id.clone()),
+    Value::Number(id) => Some(id.to_string()),
+    _ => None,
+  }
+}
+
+/// The chunk ids are not stable between builds, so the chunks are matched by names first,
+/// which are usually the entry names or the chunk names of dynamic imports
+fn chunk_key(chunk: &Value) -> String {
+  let names = array(chunk, "names")
+    .filter_map(Value::as_str)
+    .collect::<Vec<_>>();
+  if !names.is_empty() {
+    return names.join(",");
+  }
+  chunk
+    .get("id")
+    .and_then(id_to_string)
+    .map_or_else(|| "<unknown>".to_string(), |id| format!("#{id}"))
+}
+
+/// The readable name is relative to the context, so it's stable across different checkouts
+fn module_name(module: &Value) -> Option<String> {
+  module
+    .get("name")
+    .or_else(|| module.get("identifier"))
+    .and_then(Value::as_str)
+    .map(ToString::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  fn modules(summary: &BuildSummary, chunk: &str) -> Vec<String> {
+    summary
+      .chunks
+      .as_ref()
+      .and_then(|chunks| chunks.get(chunk))
+      .expect("should have chunk")
+      .iter()
+      .cloned()
+      .collect()
+  }
+
+  #[test]
+  fn should_collect_modules_from_top_level_modules() {
+    let stats = json!({
+      "assets": [{ "name": "main.js", "size": 100 }],
+      "chunks": [
+        { "id": "909", "names": ["main"], "modules": [] },
+        { "id": 1, "names": [] }
+      ],
+      "modules": [
+        { "name": "./a.js", "chunks": ["909"] },
+        { "identifier": "/abs/b.js", "chunks": ["909", 1] }
+      ]
+    });
+    let summary = BuildSummary::from_stats(&stats).expect("should be valid stats");
+    assert_eq!(
+      summary.assets,
+      BTreeMap::from([("main.js".to_string(), 100)])
+    );
+    assert_eq!(modules(&summary, "main"), vec!["./a.js", "/abs/b.js"]);
+    assert_eq!(modules(&summary, "#1"), vec!["/abs/b.js"]);
+  }
+
+  #[test]
+  fn should_fallback_to_modules_of_chunks() {
+    let stats = json!({
+      "children": [{
+        "assets": [],
+        "chunks": [{ "id": 0, "names": ["a", "b"], "modules": [{ "name": "./a.js" }] }]
+      }]
+    });
+    let summary = BuildSummary::from_stats(&stats).expect("should be valid stats");
+    assert_eq!(modules(&summary, "a,b"), vec!["./a.js"]);
+  }
+
+  #[test]
+  fn should_skip_chunks_if_stats_has_no_chunks() {
+    let stats = json!({ "assets": [{ "name": "main.js", "size": 100 }] });
+    let summary = BuildSummary::from_stats(&stats).expect("should be valid stats");
+    assert!(summary.chunks.is_none());
+  }
+
+  #[test]
+  fn should_reject_invalid_stats() {
+    let stats = json!({ "children": [{ "assets": [] }, { "assets": [] }] });
+    assert!(BuildSummary::from_stats(&stats).is_err());
+
+    let stats = json!({ "assets": [{ "name": "main.js" }] });
+    assert!(BuildSummary::from_stats(&stats).is_err());
+  }
+}
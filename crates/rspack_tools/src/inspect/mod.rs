@@ -0,0 +1,235 @@
+mod module;
+mod scopes;
+mod snapshot;
+
+use std::sync::Arc;
+
+use rspack_core::cache::persistent::storage::Storage;
+use rspack_error::{Result, error};
+use rspack_paths::Utf8PathBuf;
+
+pub use self::{
+  module::dump_module,
+  scopes::list_scopes,
+  snapshot::{explain_invalidation, show_snapshot},
+};
+use crate::compare::load_storages_from_path;
+
+/// Load the storages of a cache directory sorted by version
+///
+/// Only the storage of `version` is returned if it is specified.
+fn load_storages(
+  path: &Utf8PathBuf,
+  version: Option<&str>,
+) -> Result<Vec<(String, Arc<dyn Storage>)>> {
+  let mut storages = load_storages_from_path(path)
+    .into_iter()
+    .filter(|(v, _)| version.is_none_or(|target| target == v))
+    .collect::<Vec<_>>();
+  if storages.is_empty() {
+    return Err(match version {
+      Some(version) => error!("Cache version {} not found in {}", version, path),
+      None => error!("No cache version found in {}", path),
+    });
+  }
+  storages.sort_by(|a, b| a.0.cmp(&b.0));
+  Ok(storages)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_core::{
+    BuildModuleGraphArtifact, ExportsInfoData, ModuleExt, ModuleGraph, ModuleGraphModule,
+    ModuleIdentifier, RawModule, RuntimeGlobals,
+    cache::persistent::{
+      codec::CacheCodec,
+      occasion::make::MakeOccasion,
+      snapshot::{SnapshotScope, Strategy},
+      storage::{StorageOptions, create_storage},
+    },
+  };
+  use rspack_fs::NativeFileSystem;
+  use rspack_paths::{ArcPath, Utf8PathBuf};
+
+  use super::{dump_module, explain_invalidation, list_scopes, show_snapshot};
+
+  /// Create a cache directory with version `v1`, which contains a module graph with
+  /// `src/index.js` and the snapshot of it
+  async fn create_cache(name: &str) -> (Utf8PathBuf, Utf8PathBuf) {
+    let root = Utf8PathBuf::from_path_buf(std::env::temp_dir().join("rspack_tools_inspect"))
+      .expect("should be utf8 path")
+      .join(name);
+    let _ = std::fs::remove_dir_all(&root);
+    let context = root.join("project");
+    let cache_path = root.join("cache");
+    std::fs::create_dir_all(context.join("src")).expect("should create dir");
+    std::fs::write(context.join("src/index.js"), "export default 1;").expect("should write file");
+
+    let (storage, _) = create_storage(
+      StorageOptions::FileSystem {
+        directory: cache_path.clone().into(),
+        budget: Default::default(),
+      },
+      "v1".to_string(),
+      Arc::new(NativeFileSystem::new(false)),
+    );
+    let codec = Arc::new(CacheCodec::new(Some(context.clone())));
+
+    let module_id = ModuleIdentifier::from(context.join("src/index.js").as_str());
+    let mut module_graph = ModuleGraph::default();
+    let exports_info = ExportsInfoData::default();
+    module_graph.add_module_graph_module(ModuleGraphModule::new(module_id, exports_info.id()));
+    module_graph.set_exports_info(exports_info.id(), exports_info);
+    module_graph.add_module(
+      RawModule::new(
+        "export default 1;".to_string(),
+        module_id,
+        "./src/index.js".to_string(),
+        RuntimeGlobals::empty(),
+      )
+      .boxed(),
+    );
+    let artifact = BuildModuleGraphArtifact {
+      module_graph,
+      issuer_update_modules: [module_id].into_iter().collect(),
+      ..Default::default()
+    };
+    MakeOccasion::new(storage.clone(), codec.clone()).save(&artifact);
+
+    // the recorded content hash doesn't match the file, and the missing path exists now
+    for (scope, path, strategy) in [
+      (
+        SnapshotScope::FILE,
+        context.join("src/index.js"),
+        Strategy::ContentHash { hash: 0 },
+      ),
+      (
+        SnapshotScope::MISSING,
+        context.join("src"),
+        Strategy::Missing,
+      ),
+    ] {
+      storage.set(
+        scope.name(),
+        codec
+          .encode(&ArcPath::from(path.as_std_path()))
+          .expect("should encode path"),
+        codec.encode(&strategy).expect("should encode strategy"),
+      );
+    }
+    storage
+      .trigger_save()
+      .expect("should trigger save")
+      .await
+      .expect("should receive save result")
+      .expect("should save");
+    (cache_path, context)
+  }
+
+  #[tokio::test]
+  async fn should_list_scopes() {
+    let (cache_path, _) = create_cache("scopes").await;
+    let output = list_scopes(cache_path.clone(), None)
+      .await
+      .expect("should list scopes");
+    assert!(output.starts_with("Version v1\n"), "{output}");
+    for scope in [
+      "occasion_make_module_graph",
+      "snapshot_file",
+      "snapshot_missing",
+    ] {
+      assert!(
+        output.contains(&format!("  {scope}: 1 packs, ")),
+        "{output}"
+      );
+    }
+    assert!(!output.contains("missing ("), "{output}");
+
+    let err = list_scopes(cache_path, Some("v2".to_string()))
+      .await
+      .expect_err("should not find the version");
+    assert!(err.to_string().contains("Cache version v2 not found"));
+  }
+
+  #[tokio::test]
+  async fn should_dump_module() {
+    let (cache_path, context) = create_cache("module").await;
+    let output = dump_module(
+      cache_path.clone(),
+      None,
+      context.clone(),
+      "index.js".to_string(),
+    )
+    .await
+    .expect("should dump module");
+    assert!(
+      output.starts_with(&format!(
+        "Version v1\nModule {}\n  type: javascript/auto\n  cacheable: true\n",
+        context.join("src/index.js")
+      )),
+      "{output}"
+    );
+
+    let err = dump_module(cache_path, None, context, "other.js".to_string())
+      .await
+      .expect_err("should not find the module");
+    assert!(err.to_string().contains("Module other.js not found"));
+  }
+
+  #[tokio::test]
+  async fn should_show_snapshot() {
+    let (cache_path, context) = create_cache("snapshot").await;
+    let output = show_snapshot(cache_path.clone(), None, context.clone(), None)
+      .await
+      .expect("should show snapshot");
+    assert_eq!(
+      output,
+      format!(
+        "Version v1\n  snapshot_file: 1 entries\n    {}  content hash 0000000000000000\n  snapshot_context: 0 entries\n  snapshot_missing: 1 entries\n    {}  missing\n  snapshot_build: 0 entries\n",
+        context.join("src/index.js"),
+        context.join("src")
+      )
+    );
+
+    let output = show_snapshot(cache_path, None, context, Some("other".to_string()))
+      .await
+      .expect("should show snapshot");
+    assert!(output.contains("  snapshot_file: 0 entries\n"), "{output}");
+  }
+
+  #[tokio::test]
+  async fn should_explain_invalidation() {
+    let (cache_path, context) = create_cache("explain").await;
+    let output = explain_invalidation(
+      cache_path.clone(),
+      None,
+      context.clone(),
+      "src/index.js".into(),
+    )
+    .await
+    .expect("should explain invalidation");
+    assert!(
+      output.contains(
+        "  snapshot_file: content hash 0000000000000000\n    modified: content hash changed from 0000000000000000 to "
+      ),
+      "{output}"
+    );
+
+    let output = explain_invalidation(cache_path.clone(), None, context.clone(), "src".into())
+      .await
+      .expect("should explain invalidation");
+    assert!(
+      output.contains(
+        "  snapshot_missing: missing\n    modified: the path was missing but exists now\n"
+      ),
+      "{output}"
+    );
+
+    let output = explain_invalidation(cache_path, None, context, "src/other.js".into())
+      .await
+      .expect("should explain invalidation");
+    assert!(output.contains("is not in snapshot"), "{output}");
+  }
+}
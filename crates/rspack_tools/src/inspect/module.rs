@@ -0,0 +1,103 @@
+use std::{fmt::Write, sync::Arc};
+
+use itertools::Itertools;
+use rspack_core::{
+  ModuleGraph, ModuleIdentifier,
+  cache::persistent::{codec::CacheCodec, occasion::make::MakeOccasion},
+};
+use rspack_error::{Result, error};
+use rspack_paths::Utf8PathBuf;
+use rspack_tasks::{CompilerContext, within_compiler_context};
+
+use super::load_storages;
+
+/// Dump the decoded module graph entries whose identifier contains `identifier`
+///
+/// `context` should be the project context of the cache, it's used to restore the
+/// portable paths in cache.
+pub async fn dump_module(
+  path: Utf8PathBuf,
+  version: Option<String>,
+  context: Utf8PathBuf,
+  identifier: String,
+) -> Result<String> {
+  let codec = Arc::new(CacheCodec::new(Some(context)));
+  let mut output = String::new();
+  let mut found = false;
+  for (version, storage) in load_storages(&path, version.as_deref())? {
+    let occasion = MakeOccasion::new(storage, codec.clone());
+    // the recovered dependencies take their ids from the compiler context
+    let artifact =
+      within_compiler_context(Arc::new(CompilerContext::new()), occasion.recovery()).await?;
+    let mg = &artifact.module_graph;
+    let module_ids = mg
+      .modules()
+      .into_keys()
+      .filter(|id| id.as_str().contains(identifier.as_str()))
+      .sorted_by(|a, b| a.as_str().cmp(b.as_str()))
+      .collect::<Vec<_>>();
+    for module_id in module_ids {
+      found = true;
+      writeln!(output, "Version {}", version).expect("should write");
+      dump_module_entry(&mut output, mg, &module_id);
+    }
+  }
+  if !found {
+    return Err(error!("Module {} not found in {}", identifier, path));
+  }
+  Ok(output)
+}
+
+fn dump_module_entry(output: &mut String, mg: &ModuleGraph, module_id: &ModuleIdentifier) {
+  let module = mg
+    .module_by_identifier(module_id)
+    .expect("should have module");
+  let build_info = module.build_info();
+
+  writeln!(output, "Module {}", module_id).expect("should write");
+  writeln!(output, "  type: {}", module.module_type()).expect("should write");
+  writeln!(output, "  cacheable: {}", build_info.cacheable).expect("should write");
+  if let Some(hash) = &build_info.hash {
+    writeln!(output, "  hash: {}", hash.encoded()).expect("should write");
+  }
+  for (name, deps) in [
+    ("file dependencies", &build_info.file_dependencies),
+    ("context dependencies", &build_info.context_dependencies),
+    ("missing dependencies", &build_info.missing_dependencies),
+    ("build dependencies", &build_info.build_dependencies),
+  ] {
+    if deps.is_empty() {
+      continue;
+    }
+    writeln!(output, "  {}:", name).expect("should write");
+    for dep in deps.iter().map(|dep| dep.to_string_lossy()).sorted() {
+      writeln!(output, "    {}", dep).expect("should write");
+    }
+  }
+
+  writeln!(output, "  dependencies:").expect("should write");
+  for dep_id in module.get_dependencies() {
+    let dep = mg.dependency_by_id(dep_id);
+    let request = dep
+      .as_module_dependency()
+      .map(|dep| format!(" {:?}", dep.request()))
+      .unwrap_or_default();
+    let target = mg
+      .module_identifier_by_dependency_id(dep_id)
+      .map(|id| format!(" -> {}", id))
+      .unwrap_or_default();
+    writeln!(output, "    {}{}{}", dep.dependency_type(), request, target).expect("should write");
+  }
+
+  writeln!(output, "  incoming:").expect("should write");
+  for origin in mg
+    .get_incoming_connections(module_id)
+    .filter_map(|con| con.original_module_identifier)
+    .unique()
+    .sorted_by(|a, b| a.as_str().cmp(b.as_str()))
+  {
+    writeln!(output, "    {}", origin).expect("should write");
+  }
+
+  writeln!(output, "  module: {:#?}", module).expect("should write");
+}
@@ -0,0 +1,93 @@
+use std::fmt::Write;
+
+use itertools::Itertools;
+use rspack_error::{Result, error};
+use rspack_fs::{NativeFileSystem, ReadableFileSystem};
+use rspack_paths::Utf8PathBuf;
+use rspack_util::size::format_size;
+
+use super::load_storages;
+
+/// Pack file recorded in `scope_meta`
+struct PackSummary {
+  bucket: usize,
+  name: String,
+  /// the size recorded in scope meta
+  size: u64,
+  generation: usize,
+  /// the size of pack file on disk, `None` if the file is missing
+  file_size: Option<u64>,
+}
+
+/// Read the pack list of a scope directory
+///
+/// The `scope_meta` file starts with a `{bucket_size} {pack_size} {generation}` line,
+/// then each bucket has a line of `{name},{hash},{size},{generation}` separated by space.
+fn read_scope_packs(fs: &NativeFileSystem, scope_path: &Utf8PathBuf) -> Result<Vec<PackSummary>> {
+  let meta_path = scope_path.join("scope_meta");
+  let content = fs
+    .read_to_string_sync(&meta_path)
+    .map_err(|e| error!("read {} failed: {}", meta_path, e))?;
+  let invalid = || error!("invalid scope meta {}", meta_path);
+
+  let mut packs = vec![];
+  for (bucket, line) in content.lines().skip(1).enumerate() {
+    for item in line.split(' ').filter(|item| !item.is_empty()) {
+      let parts = item.split(',').collect::<Vec<_>>();
+      if parts.len() < 4 {
+        return Err(invalid());
+      }
+      let name = parts[0].to_string();
+      let file_size = fs
+        .metadata_sync(&scope_path.join(bucket.to_string()).join(&name))
+        .ok()
+        .map(|meta| meta.size);
+      packs.push(PackSummary {
+        bucket,
+        name,
+        size: parts[2].parse().map_err(|_| invalid())?,
+        generation: parts[3].parse().map_err(|_| invalid())?,
+        file_size,
+      });
+    }
+  }
+  Ok(packs)
+}
+
+/// List the scopes and packs of each version in cache directory
+pub async fn list_scopes(path: Utf8PathBuf, version: Option<String>) -> Result<String> {
+  let fs = NativeFileSystem::new(false);
+  let mut output = String::new();
+  for (version, storage) in load_storages(&path, version.as_deref())? {
+    writeln!(output, "Version {}", version).expect("should write");
+    for scope in storage.scopes().await?.into_iter().sorted() {
+      let packs = read_scope_packs(&fs, &path.join(&version).join(&scope))?;
+      let total_size: u64 = packs.iter().filter_map(|pack| pack.file_size).sum();
+      writeln!(
+        output,
+        "  {}: {} packs, {}",
+        scope,
+        packs.len(),
+        format_size(total_size as f64)
+      )
+      .expect("should write");
+      for pack in packs {
+        let file_size = match pack.file_size {
+          Some(size) => format_size(size as f64),
+          None => "missing".to_string(),
+        };
+        writeln!(
+          output,
+          "    {}/{}  {} (content {})  generation {}",
+          pack.bucket,
+          pack.name,
+          file_size,
+          format_size(pack.size as f64),
+          pack.generation
+        )
+        .expect("should write");
+      }
+    }
+  }
+  Ok(output)
+}
@@ -0,0 +1,191 @@
+use std::{fmt::Write, sync::Arc};
+
+use itertools::Itertools;
+use rspack_core::cache::persistent::{
+  codec::CacheCodec,
+  snapshot::{SnapshotScope, Strategy, StrategyHelper, ValidateResult},
+  storage::Storage,
+};
+use rspack_error::Result;
+use rspack_fs::NativeFileSystem;
+use rspack_paths::{ArcPath, Utf8PathBuf};
+
+use super::load_storages;
+
+const SCOPES: [SnapshotScope; 4] = [
+  SnapshotScope::FILE,
+  SnapshotScope::CONTEXT,
+  SnapshotScope::MISSING,
+  SnapshotScope::BUILD,
+];
+
+/// Load the decoded snapshot entries of a scope sorted by path
+async fn load_entries(
+  storage: &Arc<dyn Storage>,
+  codec: &CacheCodec,
+  scope: SnapshotScope,
+) -> Result<Vec<(ArcPath, Strategy)>> {
+  let mut entries = storage
+    .load(scope.name())
+    .await?
+    .into_iter()
+    .map(|(key, value)| {
+      Ok((
+        codec.decode::<ArcPath>(&key)?,
+        codec.decode::<Strategy>(&value)?,
+      ))
+    })
+    .collect::<Result<Vec<_>>>()?;
+  entries.sort_by(|a, b| (**a.0).cmp(&**b.0));
+  Ok(entries)
+}
+
+fn describe_strategy(strategy: &Strategy) -> String {
+  match strategy {
+    Strategy::PackageVersion(version) => format!("lib version {}", version),
+    Strategy::FileHash { mtime, hash } => {
+      format!("file hash {:016x}, compile time {}", hash, mtime)
+    }
+    Strategy::ContentHash { hash } => format!("content hash {:016x}", hash),
+    Strategy::DirHash { hash } => format!("dir hash {:016x}", hash),
+    Strategy::Missing => "missing".to_string(),
+    Strategy::Failed => "failed".to_string(),
+  }
+}
+
+/// Show the snapshot entries and their strategies
+///
+/// Only the entries whose path contains `filter` are shown if it is specified.
+pub async fn show_snapshot(
+  path: Utf8PathBuf,
+  version: Option<String>,
+  context: Utf8PathBuf,
+  filter: Option<String>,
+) -> Result<String> {
+  let codec = CacheCodec::new(Some(context));
+  let mut output = String::new();
+  for (version, storage) in load_storages(&path, version.as_deref())? {
+    writeln!(output, "Version {}", version).expect("should write");
+    for scope in SCOPES {
+      let entries = load_entries(&storage, &codec, scope)
+        .await?
+        .into_iter()
+        .filter(|(path, _)| {
+          filter
+            .as_deref()
+            .is_none_or(|filter| path.to_string_lossy().contains(filter))
+        })
+        .collect_vec();
+      writeln!(output, "  {}: {} entries", scope.name(), entries.len()).expect("should write");
+      for (path, strategy) in entries {
+        writeln!(
+          output,
+          "    {}  {}",
+          path.display(),
+          describe_strategy(&strategy)
+        )
+        .expect("should write");
+      }
+    }
+  }
+  Ok(output)
+}
+
+/// Explain the validate result of a strategy by comparing it with the current state of file
+async fn explain_strategy(helper: &StrategyHelper, path: &ArcPath, strategy: &Strategy) -> String {
+  let result = helper.validate(path, strategy).await;
+  let reason = match (strategy, &result) {
+    (_, ValidateResult::Deleted) => "the path can not be read anymore".to_string(),
+    (Strategy::PackageVersion(version), ValidateResult::Modified) => {
+      match helper.package_version(path).await {
+        Some(Strategy::PackageVersion(current)) => {
+          format!("lib version changed from {} to {}", version, current)
+        }
+        _ => "lib version can not be resolved".to_string(),
+      }
+    }
+    (Strategy::FileHash { mtime, hash }, ValidateResult::Modified) => {
+      match helper.file_hash(path).await {
+        Strategy::FileHash {
+          mtime: current_mtime,
+          hash: current_hash,
+        } => format!(
+          "compile time changed from {} to {} and file hash changed from {:016x} to {:016x}",
+          mtime, current_mtime, hash, current_hash
+        ),
+        _ => "file hash can not be calculated".to_string(),
+      }
+    }
+    (Strategy::ContentHash { hash }, ValidateResult::Modified) => {
+      match helper.content_hash(path).await {
+        Strategy::ContentHash { hash: current_hash } => format!(
+          "content hash changed from {:016x} to {:016x}",
+          hash, current_hash
+        ),
+        _ => "content hash can not be calculated".to_string(),
+      }
+    }
+    (Strategy::DirHash { hash }, ValidateResult::Modified) => match helper.dir_hash(path).await {
+      Strategy::DirHash { hash: current_hash } => format!(
+        "dir hash changed from {:016x} to {:016x}",
+        hash, current_hash
+      ),
+      _ => "dir hash can not be calculated".to_string(),
+    },
+    (Strategy::Missing, ValidateResult::Modified) => {
+      "the path was missing but exists now".to_string()
+    }
+    (Strategy::Failed, _) => "the snapshot failed to be created last time".to_string(),
+    (_, ValidateResult::NoChanged) => "the path is not changed".to_string(),
+  };
+  let status = match result {
+    ValidateResult::Deleted => "deleted",
+    ValidateResult::Modified => "modified",
+    ValidateResult::NoChanged => "valid",
+  };
+  format!("{}: {}", status, reason)
+}
+
+/// Explain why the `file` would be invalidated by persistent cache snapshot
+pub async fn explain_invalidation(
+  path: Utf8PathBuf,
+  version: Option<String>,
+  context: Utf8PathBuf,
+  file: Utf8PathBuf,
+) -> Result<String> {
+  let file = ArcPath::from(context.join(file).as_std_path());
+  let codec = CacheCodec::new(Some(context));
+  let helper = StrategyHelper::new(Arc::new(NativeFileSystem::new(false)));
+  let mut output = String::new();
+  for (version, storage) in load_storages(&path, version.as_deref())? {
+    writeln!(output, "Version {}", version).expect("should write");
+    let mut found = false;
+    for scope in SCOPES {
+      let Some((_, strategy)) = load_entries(&storage, &codec, scope)
+        .await?
+        .into_iter()
+        .find(|(path, _)| path == &file)
+      else {
+        continue;
+      };
+      found = true;
+      writeln!(
+        output,
+        "  {}: {}\n    {}",
+        scope.name(),
+        describe_strategy(&strategy),
+        explain_strategy(&helper, &file, &strategy).await
+      )
+      .expect("should write");
+    }
+    if !found {
+      writeln!(
+        output,
+        "  {} is not in snapshot, it is either not a dependency or matched by immutable paths",
+        file.display()
+      )
+      .expect("should write");
+    }
+  }
+  Ok(output)
+}
@@ -1,5 +1,9 @@
 mod compare;
 mod debug_info;
+mod diff;
+mod inspect;
 mod utils;
 
 pub use compare::compare_cache_dir;
+pub use diff::diff_builds;
+pub use inspect::{dump_module, explain_invalidation, list_scopes, show_snapshot};
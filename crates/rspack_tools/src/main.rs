@@ -1,7 +1,9 @@
 use clap::{Parser, Subcommand};
 use rspack_error::Diagnostic;
 use rspack_paths::Utf8PathBuf;
-use rspack_tools::compare_cache_dir;
+use rspack_tools::{
+  compare_cache_dir, diff_builds, dump_module, explain_invalidation, list_scopes, show_snapshot,
+};
 
 /// Toolkit for debugging and testing rspack internals
 #[derive(Parser, Debug)]
@@ -25,13 +27,108 @@ enum Commands {
     #[arg(value_name = "CACHE2")]
     cache2: String,
   },
+  /// List the scopes and packs in a cache directory with their sizes
+  Scopes {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+
+    /// Only inspect the given cache version
+    #[arg(long)]
+    version: Option<String>,
+  },
+  /// Dump the decoded module graph entries of modules
+  Module {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+
+    /// Module identifier, all of the modules containing it will be dumped
+    #[arg(value_name = "IDENTIFIER")]
+    identifier: String,
+
+    /// Only inspect the given cache version
+    #[arg(long)]
+    version: Option<String>,
+
+    /// Project context of the cache, defaults to current directory
+    #[arg(long)]
+    context: Option<String>,
+  },
+  /// Show the snapshot entries with their strategies
+  Snapshot {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+
+    /// Only show the entries whose path contains the filter
+    #[arg(long)]
+    filter: Option<String>,
+
+    /// Only inspect the given cache version
+    #[arg(long)]
+    version: Option<String>,
+
+    /// Project context of the cache, defaults to current directory
+    #[arg(long)]
+    context: Option<String>,
+  },
+  /// Explain why a file would be invalidated by the snapshot
+  Explain {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+
+    /// Path to the file, relative paths are resolved from context
+    #[arg(value_name = "FILE")]
+    file: String,
+
+    /// Only inspect the given cache version
+    #[arg(long)]
+    version: Option<String>,
+
+    /// Project context of the cache, defaults to current directory
+    #[arg(long)]
+    context: Option<String>,
+  },
+  /// Compare the assets, chunks and modules of two builds
+  Diff {
+    /// Path to the stats JSON file or output directory of the old build
+    #[arg(value_name = "OLD")]
+    old: String,
+
+    /// Path to the stats JSON file or output directory of the new build
+    #[arg(value_name = "NEW")]
+    new: String,
+
+    /// Print the diff as JSON
+    #[arg(long)]
+    json: bool,
+  },
+}
+
+fn print_error(err: rspack_error::Error) {
+  eprintln!(
+    "{}",
+    Diagnostic::from(err)
+      .render_report(true)
+      .expect("render error failed")
+  );
+}
+
+fn resolve_context(context: Option<String>) -> Utf8PathBuf {
+  match context {
+    Some(context) => Utf8PathBuf::from(context),
+    None => Utf8PathBuf::from_path_buf(std::env::current_dir().expect("should get current dir"))
+      .expect("current dir should be utf8"),
+  }
 }
 
 #[tokio::main]
 async fn main() {
   let cli = Cli::parse();
 
-  match cli.command {
+  let report = match cli.command {
     Commands::Compare { cache1, cache2 } => {
       println!("Comparing cache directories:");
       println!("  Path 1: {}", cache1);
@@ -42,16 +139,66 @@ async fn main() {
       let path2 = Utf8PathBuf::from(&cache2);
 
       if let Err(err) = compare_cache_dir(path1, path2).await {
-        eprintln!(
-          "{}",
-          Diagnostic::from(err)
-            .render_report(true)
-            .expect("render error failed")
-        );
+        print_error(err);
         std::process::exit(1);
       }
 
       println!("✓ Cache directories are identical");
+      return;
+    }
+    Commands::Scopes { cache, version } => list_scopes(Utf8PathBuf::from(cache), version).await,
+    Commands::Module {
+      cache,
+      identifier,
+      version,
+      context,
+    } => {
+      dump_module(
+        Utf8PathBuf::from(cache),
+        version,
+        resolve_context(context),
+        identifier,
+      )
+      .await
+    }
+    Commands::Snapshot {
+      cache,
+      filter,
+      version,
+      context,
+    } => {
+      show_snapshot(
+        Utf8PathBuf::from(cache),
+        version,
+        resolve_context(context),
+        filter,
+      )
+      .await
+    }
+    Commands::Explain {
+      cache,
+      file,
+      version,
+      context,
+    } => {
+      explain_invalidation(
+        Utf8PathBuf::from(cache),
+        version,
+        resolve_context(context),
+        Utf8PathBuf::from(file),
+      )
+      .await
+    }
+    Commands::Diff { old, new, json } => {
+      diff_builds(Utf8PathBuf::from(old), Utf8PathBuf::from(new), json)
+    }
+  };
+
+  match report {
+    Ok(report) => print!("{}", report),
+    Err(err) => {
+      print_error(err);
+      std::process::exit(1);
     }
   }
 }
@@ -1,7 +1,7 @@
 use crate::{WatchPattern, paths::PathAccessor};
 
 mod directories;
-mod root;
+pub(crate) mod root;
 
 /// The `Analyzer` trait defines an interface for analyzing a [`PathAccessor`]
 /// and producing a set of [`WatchPattern`]s to be watched by the file system watcher.
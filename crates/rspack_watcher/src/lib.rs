@@ -3,20 +3,25 @@ mod disk_watcher;
 mod executor;
 mod ignored;
 mod paths;
+mod query_watcher;
 mod scanner;
+mod state;
 mod trigger;
 
-use std::{sync::Arc, time::SystemTime};
+use std::{path::PathBuf, sync::Arc, time::SystemTime};
 
 use analyzer::{Analyzer, RecommendedAnalyzer};
 use disk_watcher::DiskWatcher;
 use executor::Executor;
 pub use ignored::FsWatcherIgnored;
 use paths::PathManager;
+use query_watcher::QueryWatcher;
 use rspack_error::Result;
 use rspack_paths::ArcPath;
 use rspack_util::fx_hash::FxHashSet as HashSet;
 use scanner::Scanner;
+pub use state::ResumedChanges;
+use state::WatcherState;
 use tokio::sync::mpsc;
 use trigger::Trigger;
 
@@ -82,15 +87,34 @@ pub struct FsWatcherOptions {
 
   /// The timeout in milliseconds to aggregate events.
   pub aggregate_timeout: Option<u32>,
+
+  /// The socket of a watchman-like daemon.
+  ///
+  /// If it is set, the changes since the last clock are queried from the daemon every `poll_interval`,
+  /// instead of watching the disk and scanning the paths on startup.
+  pub query_socket: Option<PathBuf>,
+
+  /// The file to persist the last seen state of the watcher, such as the place next to the persistent cache.
+  ///
+  /// The state is saved on each watch, and the changes made while the process is not running
+  /// can be resumed by [`FsWatcher::resume`] on the next startup.
+  pub state_file: Option<PathBuf>,
+}
+
+/// The backend which detects the changes of disk.
+enum WatcherBackend {
+  Disk(DiskWatcher),
+  Query(QueryWatcher),
 }
 
 pub struct FsWatcher {
   path_manager: Arc<PathManager>,
-  disk_watcher: DiskWatcher,
+  backend: WatcherBackend,
   executor: Executor,
   scanner: Scanner,
   analyzer: RecommendedAnalyzer,
   trigger: Option<Arc<Trigger>>,
+  state_file: Option<PathBuf>,
 }
 
 impl FsWatcher {
@@ -100,21 +124,29 @@ impl FsWatcher {
 
     let path_manager = Arc::new(PathManager::new(ignored));
     let trigger = Arc::new(Trigger::new(Arc::clone(&path_manager), tx.clone()));
-    let disk_watcher = DiskWatcher::new(
-      options.follow_symlinks,
-      options.poll_interval,
-      trigger.clone(),
-    );
+    let backend = match options.query_socket {
+      Some(socket) => WatcherBackend::Query(QueryWatcher::new(
+        socket,
+        options.poll_interval,
+        trigger.clone(),
+      )),
+      None => WatcherBackend::Disk(DiskWatcher::new(
+        options.follow_symlinks,
+        options.poll_interval,
+        trigger.clone(),
+      )),
+    };
     let executor = Executor::new(rx, options.aggregate_timeout);
     let scanner = Scanner::new(tx, Arc::clone(&path_manager));
 
     Self {
-      disk_watcher,
+      backend,
       executor,
       path_manager,
       scanner,
       analyzer: RecommendedAnalyzer::default(),
       trigger: Some(trigger),
+      state_file: options.state_file,
     }
   }
 
@@ -137,7 +169,10 @@ impl FsWatcher {
   ) {
     self.path_manager.reset();
 
-    if let Err(e) = self.wait_for_event(files, directories, missing, start_time) {
+    if let Err(e) = self
+      .wait_for_event(files, directories, missing, start_time)
+      .await
+    {
       event_aggregate_handler.on_error(e);
       return;
     };
@@ -148,9 +183,45 @@ impl FsWatcher {
       .await;
   }
 
+  /// Resumes the changes since the last seen state which is saved by the previous process.
+  ///
+  /// The returned changes are exact, so they can be passed to the compiler directly
+  /// instead of checking the snapshot of every file.
+  /// Returns `None` if there is no saved state or the changes can not be told exactly.
+  pub async fn resume(&self) -> Result<Option<ResumedChanges>> {
+    let Some(state_file) = &self.state_file else {
+      return Ok(None);
+    };
+    let Some(state) = WatcherState::load(state_file).await? else {
+      return Ok(None);
+    };
+    let events = match &self.backend {
+      WatcherBackend::Disk(_) => state.scan(),
+      WatcherBackend::Query(query_watcher) => {
+        let path_manager = state.path_manager()?;
+        let Some(events) = query_watcher
+          .query_since(&path_manager, state.start_time())
+          .await?
+        else {
+          return Ok(None);
+        };
+        events
+      }
+    };
+    let mut changes = ResumedChanges {
+      since: state.since(),
+      ..Default::default()
+    };
+    changes.extend(events);
+    Ok(Some(changes))
+  }
+
   /// Closes the file system watcher, stopping all background tasks and releasing resources.
   pub async fn close(&mut self) -> Result<()> {
-    self.disk_watcher.close();
+    match &mut self.backend {
+      WatcherBackend::Disk(disk_watcher) => disk_watcher.close(),
+      WatcherBackend::Query(query_watcher) => query_watcher.close(),
+    }
     self.scanner.close();
     self.executor.close().await;
     self.trigger.take();
@@ -171,7 +242,7 @@ impl FsWatcher {
     Ok(())
   }
 
-  fn wait_for_event(
+  async fn wait_for_event(
     &mut self,
     files: (impl Iterator<Item = ArcPath>, impl Iterator<Item = ArcPath>),
     directories: (impl Iterator<Item = ArcPath>, impl Iterator<Item = ArcPath>),
@@ -179,10 +250,33 @@ impl FsWatcher {
     start_time: SystemTime,
   ) -> Result<()> {
     self.path_manager.update(files, directories, missing)?;
-    self.scanner.scan(start_time);
 
-    let watch_patterns = self.analyzer.analyze(self.path_manager.access());
-    self.disk_watcher.watch(watch_patterns.into_iter())?;
+    if let Some(state_file) = &self.state_file {
+      // the outdated state is removed, otherwise the changes of new paths will be missed on resume
+      if let Err(e) = WatcherState::new(self.path_manager.access(), start_time)
+        .save(state_file)
+        .await
+      {
+        tracing::warn!("save watcher state failed: {e}");
+        WatcherState::remove(state_file).await;
+      }
+    }
+
+    match &mut self.backend {
+      WatcherBackend::Disk(disk_watcher) => {
+        self.scanner.scan(start_time);
+
+        let watch_patterns = self.analyzer.analyze(self.path_manager.access());
+        disk_watcher.watch(watch_patterns.into_iter())?;
+      }
+      WatcherBackend::Query(query_watcher) => {
+        // the daemon reports the changes since `start_time`, so the paths are not scanned
+        let watch_patterns = query_watcher.analyze(self.path_manager.access());
+        query_watcher
+          .watch(watch_patterns.into_iter(), start_time)
+          .await?;
+      }
+    }
 
     Ok(())
   }
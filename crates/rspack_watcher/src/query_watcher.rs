@@ -0,0 +1,453 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use rspack_error::{Result, error};
+use rspack_paths::ArcPath;
+use rspack_util::fx_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tokio::{sync::Mutex, task::JoinHandle};
+
+use crate::{
+  FsEvent, FsEventKind, WatchPattern,
+  analyzer::{Analyzer, root::WatcherRootAnalyzer},
+  paths::{PathAccessor, PathManager},
+  trigger::{DependencyFinder, Trigger},
+};
+
+const DEFAULT_QUERY_INTERVAL: u64 = 100; // Default interval in milliseconds
+
+/// The clock of a watched path, the changes are queried since it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryClock {
+  /// Unix timestamp in seconds, used before the first query of the path.
+  Timestamp(u64),
+  /// Opaque clock returned by the daemon.
+  Clock(String),
+}
+
+impl QueryClock {
+  fn to_json(&self) -> Value {
+    match self {
+      QueryClock::Timestamp(seconds) => json!(seconds),
+      QueryClock::Clock(clock) => json!(clock),
+    }
+  }
+}
+
+/// A watched path and the watch root of daemon which contains it.
+#[derive(Debug)]
+struct QueryRoot {
+  watch: String,
+  relative_path: Option<String>,
+  clock: QueryClock,
+}
+
+#[derive(Debug, Deserialize)]
+struct WatchProjectResponse {
+  watch: String,
+  relative_path: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct QueryResponse {
+  clock: String,
+  /// The daemon does not know the changes since the clock, e.g. it is restarted.
+  #[serde(default)]
+  is_fresh_instance: bool,
+  #[serde(default)]
+  files: Vec<QueryFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct QueryFile {
+  name: String,
+  exists: bool,
+  #[serde(default)]
+  new: bool,
+}
+
+impl QueryFile {
+  fn kind(&self) -> FsEventKind {
+    match (self.exists, self.new) {
+      (false, _) => FsEventKind::Remove,
+      (true, true) => FsEventKind::Create,
+      (true, false) => FsEventKind::Change,
+    }
+  }
+}
+
+fn to_timestamp(time: SystemTime) -> QueryClock {
+  QueryClock::Timestamp(
+    time
+      .duration_since(UNIX_EPOCH)
+      .map(|duration| duration.as_secs())
+      .unwrap_or_default(),
+  )
+}
+
+/// `QueryClient` sends the commands to a watchman-like daemon.
+///
+/// Each command and response is a json value terminated by a newline,
+/// the unilateral responses such as logs are skipped.
+struct QueryClient {
+  #[cfg(unix)]
+  reader: tokio::io::BufReader<tokio::net::unix::OwnedReadHalf>,
+  #[cfg(unix)]
+  writer: tokio::net::unix::OwnedWriteHalf,
+}
+
+impl QueryClient {
+  #[cfg(unix)]
+  async fn connect(socket: &Path) -> Result<Self> {
+    let stream = tokio::net::UnixStream::connect(socket)
+      .await
+      .map_err(|e| error!("connect to watch daemon {} failed: {e}", socket.display()))?;
+    let (reader, writer) = stream.into_split();
+    Ok(Self {
+      reader: tokio::io::BufReader::new(reader),
+      writer,
+    })
+  }
+
+  #[cfg(not(unix))]
+  async fn connect(_socket: &Path) -> Result<Self> {
+    Err(error!("query watcher is only supported on unix"))
+  }
+
+  #[cfg(unix)]
+  async fn request(&mut self, command: Value) -> Result<Value> {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+    let mut line = command.to_string();
+    line.push('\n');
+    self
+      .writer
+      .write_all(line.as_bytes())
+      .await
+      .map_err(|e| error!("send command to watch daemon failed: {e}"))?;
+
+    loop {
+      let mut line = String::new();
+      let size = self
+        .reader
+        .read_line(&mut line)
+        .await
+        .map_err(|e| error!("read response from watch daemon failed: {e}"))?;
+      if size == 0 {
+        return Err(error!("watch daemon closed the connection"));
+      }
+      let response: Value = serde_json::from_str(&line)
+        .map_err(|e| error!("parse response from watch daemon failed: {e}"))?;
+      if response.get("unilateral").is_some() {
+        continue;
+      }
+      if let Some(err) = response.get("error") {
+        return Err(error!("watch daemon responded error: {err}"));
+      }
+      return Ok(response);
+    }
+  }
+
+  #[cfg(not(unix))]
+  async fn request(&mut self, _command: Value) -> Result<Value> {
+    Err(error!("query watcher is only supported on unix"))
+  }
+
+  /// Resolve the watch root of daemon which contains the path.
+  async fn watch_project(&mut self, path: &Path, clock: QueryClock) -> Result<QueryRoot> {
+    let response = self
+      .request(json!(["watch-project", path.to_string_lossy()]))
+      .await?;
+    let response: WatchProjectResponse = serde_json::from_value(response)
+      .map_err(|e| error!("invalid watch-project response: {e}"))?;
+    Ok(QueryRoot {
+      watch: response.watch,
+      relative_path: response.relative_path,
+      clock,
+    })
+  }
+
+  /// Query the changed files of root since its clock.
+  async fn query(&mut self, root: &QueryRoot) -> Result<QueryResponse> {
+    let mut query = json!({
+      "since": root.clock.to_json(),
+      "fields": ["name", "exists", "new"],
+    });
+    if let Some(relative_path) = &root.relative_path {
+      query["relative_root"] = json!(relative_path);
+    }
+    let response = self.request(json!(["query", root.watch, query])).await?;
+    serde_json::from_value(response).map_err(|e| error!("invalid query response: {e}"))
+  }
+}
+
+/// `QueryWatcher` asks a watchman-like daemon for the changed files since the last clock,
+/// instead of watching the disk and scanning the paths on startup by itself.
+///
+/// The changes are passed to the trigger, which works the same as the events from [`DiskWatcher`](crate::disk_watcher::DiskWatcher).
+pub struct QueryWatcher {
+  socket: PathBuf,
+  interval: Duration,
+  trigger: Arc<Trigger>,
+  /// The common root of watched paths, so that only one root is queried in most cases.
+  analyzer: WatcherRootAnalyzer,
+  roots: Arc<Mutex<HashMap<ArcPath, QueryRoot>>>,
+  handle: Option<JoinHandle<()>>,
+}
+
+impl QueryWatcher {
+  /// Creates a new `QueryWatcher` which connects to the daemon by `socket`.
+  pub fn new(socket: PathBuf, poll_interval: Option<u32>, trigger: Arc<Trigger>) -> Self {
+    Self {
+      socket,
+      // `poll: false` of watch options is passed as 0
+      interval: Duration::from_millis(
+        poll_interval
+          .filter(|interval| *interval > 0)
+          .map_or(DEFAULT_QUERY_INTERVAL, u64::from),
+      ),
+      trigger,
+      analyzer: WatcherRootAnalyzer::default(),
+      roots: Default::default(),
+      handle: None,
+    }
+  }
+
+  pub fn analyze(&self, path_accessor: PathAccessor<'_>) -> Vec<WatchPattern> {
+    self.analyzer.analyze(path_accessor)
+  }
+
+  /// Resolves the watch roots of patterns, the changes of new patterns will be queried since `start_time`.
+  ///
+  /// The clocks of the patterns which are already watched are kept, so no change is missed between two watches.
+  pub async fn watch(
+    &mut self,
+    patterns: impl Iterator<Item = WatchPattern>,
+    start_time: SystemTime,
+  ) -> Result<()> {
+    let paths = patterns.map(|p| p.path).collect::<HashSet<_>>();
+
+    {
+      let mut roots = self.roots.lock().await;
+      roots.retain(|path, _| paths.contains(path));
+      let new_paths = paths
+        .into_iter()
+        .filter(|path| !roots.contains_key(path))
+        .collect::<Vec<_>>();
+      if !new_paths.is_empty() {
+        let mut client = QueryClient::connect(&self.socket).await?;
+        for path in new_paths {
+          let root = client
+            .watch_project(&path, to_timestamp(start_time))
+            .await?;
+          roots.insert(path, root);
+        }
+      }
+    }
+
+    if self.handle.is_none() {
+      self.handle = Some(tokio::spawn(query_loop(
+        self.socket.clone(),
+        self.interval,
+        self.trigger.clone(),
+        self.roots.clone(),
+      )));
+    }
+    Ok(())
+  }
+
+  /// Queries the changes of the paths in `path_manager` since `start_time`, without watching them.
+  ///
+  /// Returns `None` if the daemon can not tell the exact changes, e.g. it is restarted after `start_time`.
+  pub async fn query_since(
+    &self,
+    path_manager: &PathManager,
+    start_time: SystemTime,
+  ) -> Result<Option<Vec<FsEvent>>> {
+    let accessor = path_manager.access();
+    let finder = DependencyFinder {
+      files: accessor.files().0,
+      directories: accessor.directories().0,
+      missing: accessor.missing().0,
+    };
+    let patterns = WatcherRootAnalyzer::default().analyze(path_manager.access());
+    if patterns.is_empty() {
+      return Ok(Some(vec![]));
+    }
+
+    let mut client = QueryClient::connect(&self.socket).await?;
+    let mut events = vec![];
+    for pattern in patterns {
+      let root = client
+        .watch_project(&pattern.path, to_timestamp(start_time))
+        .await?;
+      let response = client.query(&root).await?;
+      if response.is_fresh_instance {
+        return Ok(None);
+      }
+      for file in response.files {
+        let path = ArcPath::from(pattern.path.join(&file.name));
+        events.extend(
+          finder
+            .find_associated_event(&path, file.kind())
+            .into_iter()
+            .map(|(path, kind)| FsEvent { path, kind }),
+        );
+      }
+    }
+    Ok(Some(events))
+  }
+
+  pub fn close(&mut self) {
+    if let Some(handle) = self.handle.take() {
+      handle.abort();
+    }
+  }
+}
+
+async fn query_loop(
+  socket: PathBuf,
+  interval: Duration,
+  trigger: Arc<Trigger>,
+  roots: Arc<Mutex<HashMap<ArcPath, QueryRoot>>>,
+) {
+  let mut client: Option<QueryClient> = None;
+  // only report the first error of reconnection to avoid flooding the output
+  let mut reported = false;
+  loop {
+    tokio::time::sleep(interval).await;
+
+    if client.is_none() {
+      match QueryClient::connect(&socket).await {
+        Ok(connected) => {
+          client = Some(connected);
+          reported = false;
+        }
+        Err(e) => {
+          if !reported {
+            eprintln!("Error in query watcher: {e}");
+            reported = true;
+          }
+          continue;
+        }
+      }
+    }
+    let Some(current) = &mut client else {
+      continue;
+    };
+    if let Err(e) = query_changes(current, &trigger, &roots).await {
+      eprintln!("Error in query watcher: {e}");
+      // reconnect on next query
+      client = None;
+    }
+  }
+}
+
+async fn query_changes(
+  client: &mut QueryClient,
+  trigger: &Trigger,
+  roots: &Mutex<HashMap<ArcPath, QueryRoot>>,
+) -> Result<()> {
+  let mut roots = roots.lock().await;
+  for (path, root) in roots.iter_mut() {
+    let response = client.query(root).await?;
+    // the clock is updated even if the daemon is restarted, all of the files are reported as changed in that case
+    root.clock = QueryClock::Clock(response.clock);
+    for file in response.files {
+      trigger.on_event(&ArcPath::from(path.join(&file.name)), file.kind());
+    }
+  }
+  Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+  use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::UnixListener,
+  };
+
+  use super::*;
+
+  /// Starts a daemon which answers each command with the given responses in order.
+  fn mock_daemon(socket: &Path, responses: Vec<Vec<Value>>) -> JoinHandle<Vec<Value>> {
+    let listener = UnixListener::bind(socket).expect("should bind socket");
+    tokio::spawn(async move {
+      let (stream, _) = listener.accept().await.expect("should accept");
+      let (reader, mut writer) = stream.into_split();
+      let mut lines = BufReader::new(reader).lines();
+      let mut commands = vec![];
+      for response in responses {
+        let line = lines
+          .next_line()
+          .await
+          .expect("should read")
+          .expect("should have command");
+        commands.push(serde_json::from_str(&line).expect("should be json"));
+        for value in response {
+          writer
+            .write_all(format!("{value}\n").as_bytes())
+            .await
+            .expect("should write");
+        }
+      }
+      commands
+    })
+  }
+
+  #[tokio::test]
+  async fn test_query_client() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let socket = dir.path().join("daemon.sock");
+    let daemon = mock_daemon(
+      &socket,
+      vec![
+        vec![json!({ "watch": "/project", "relative_path": "src" })],
+        vec![
+          json!({ "unilateral": true, "log": "recrawl" }),
+          json!({
+            "clock": "c:1:2",
+            "files": [
+              { "name": "a.js", "exists": true, "new": true },
+              { "name": "b.js", "exists": false, "new": false },
+            ],
+          }),
+        ],
+        vec![json!({ "error": "unable to resolve root" })],
+      ],
+    );
+
+    let mut client = QueryClient::connect(&socket).await.expect("should connect");
+    let mut root = client
+      .watch_project(Path::new("/project/src"), QueryClock::Timestamp(10))
+      .await
+      .expect("should watch project");
+    assert_eq!(root.watch, "/project");
+    assert_eq!(root.relative_path.as_deref(), Some("src"));
+
+    let response = client.query(&root).await.expect("should query");
+    assert_eq!(response.clock, "c:1:2");
+    assert_eq!(response.files.len(), 2);
+    assert!(response.files[0].exists && response.files[0].new);
+    assert!(!response.files[1].exists);
+
+    root.clock = QueryClock::Clock(response.clock);
+    assert!(client.query(&root).await.is_err());
+
+    let commands = daemon.await.expect("should finish");
+    assert_eq!(commands[0], json!(["watch-project", "/project/src"]));
+    assert_eq!(
+      commands[1],
+      json!([
+        "query",
+        "/project",
+        { "since": 10, "fields": ["name", "exists", "new"], "relative_root": "src" }
+      ])
+    );
+    assert_eq!(commands[2][2]["since"], json!("c:1:2"));
+  }
+}
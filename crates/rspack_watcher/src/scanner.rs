@@ -102,7 +102,7 @@ fn scan_path_changed(
   tx.send(changed_event).is_ok()
 }
 
-fn check_path_metadata(filepath: &ArcPath, start_time: &SystemTime) -> bool {
+pub(crate) fn check_path_metadata(filepath: &ArcPath, start_time: &SystemTime) -> bool {
   if let Ok(m_time) = filepath
     .metadata()
     .and_then(|metadata| metadata.modified().or(metadata.created()))
@@ -0,0 +1,241 @@
+use std::{
+  path::Path,
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use rspack_error::{Result, error};
+use rspack_paths::ArcPath;
+use rspack_util::fx_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  FsEvent, FsEventKind, FsWatcherIgnored,
+  paths::{PathAccessor, PathManager},
+  scanner::check_path_metadata,
+};
+
+/// The changes since the last seen state of the watcher, see [`FsWatcher::resume`](crate::FsWatcher::resume).
+#[derive(Debug, Default)]
+pub struct ResumedChanges {
+  /// Unix timestamp in milliseconds of the saved state, the changes are made after it.
+  pub since: u64,
+  pub changed_files: HashSet<String>,
+  pub deleted_files: HashSet<String>,
+}
+
+impl ResumedChanges {
+  pub(crate) fn extend(&mut self, events: impl IntoIterator<Item = FsEvent>) {
+    for event in events {
+      let path = event.path.to_string_lossy().to_string();
+      match event.kind {
+        FsEventKind::Change | FsEventKind::Create => {
+          self.changed_files.insert(path);
+        }
+        FsEventKind::Remove => {
+          self.deleted_files.insert(path);
+        }
+      }
+    }
+  }
+}
+
+/// `WatcherState` is the last seen state of the watcher which is persisted to disk,
+/// so that the changes made while the process is not running can be resumed on the next startup.
+///
+/// It records the watched paths and the start time of the last watch,
+/// any change after the start time is reported the same as [`Scanner`](crate::scanner::Scanner).
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct WatcherState {
+  /// Unix timestamp in milliseconds.
+  since: u64,
+  files: Vec<String>,
+  directories: Vec<String>,
+  missing: Vec<String>,
+}
+
+impl WatcherState {
+  pub fn new(path_accessor: PathAccessor<'_>, start_time: SystemTime) -> Self {
+    let collect = |paths: &rspack_paths::ArcPathDashSet| {
+      paths
+        .iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+    };
+    Self {
+      since: start_time
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default(),
+      files: collect(path_accessor.files().0),
+      directories: collect(path_accessor.directories().0),
+      missing: collect(path_accessor.missing().0),
+    }
+  }
+
+  pub fn start_time(&self) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(self.since)
+  }
+
+  /// Unix timestamp in milliseconds of the start time.
+  pub fn since(&self) -> u64 {
+    self.since
+  }
+
+  /// Loads the state from `path`, returns `None` if the state does not exist.
+  pub async fn load(path: &Path) -> Result<Option<Self>> {
+    let content = match tokio::fs::read(path).await {
+      Ok(content) => content,
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+      Err(e) => return Err(error!("read watcher state {} failed: {e}", path.display())),
+    };
+    serde_json::from_slice(&content)
+      .map(Some)
+      .map_err(|e| error!("parse watcher state {} failed: {e}", path.display()))
+  }
+
+  /// Saves the state to `path`.
+  ///
+  /// The state is written to a temporary file first, so that a half written state is never loaded.
+  pub async fn save(&self, path: &Path) -> Result<()> {
+    let content =
+      serde_json::to_vec(self).map_err(|e| error!("serialize watcher state failed: {e}"))?;
+    if let Some(parent) = path.parent() {
+      tokio::fs::create_dir_all(parent)
+        .await
+        .map_err(|e| error!("create directory {} failed: {e}", parent.display()))?;
+    }
+    let temp_path = path.with_extension("tmp");
+    tokio::fs::write(&temp_path, content)
+      .await
+      .map_err(|e| error!("write watcher state {} failed: {e}", temp_path.display()))?;
+    tokio::fs::rename(&temp_path, path)
+      .await
+      .map_err(|e| error!("write watcher state {} failed: {e}", path.display()))
+  }
+
+  /// Removes the state of `path`, so that an outdated state is not resumed.
+  pub async fn remove(path: &Path) {
+    let _ = tokio::fs::remove_file(path).await;
+  }
+
+  /// Creates a `PathManager` which contains the recorded paths.
+  pub fn path_manager(&self) -> Result<PathManager> {
+    let path_manager = PathManager::new(FsWatcherIgnored::None);
+    let to_paths = |paths: &Vec<String>| {
+      (
+        paths
+          .iter()
+          .map(|path| ArcPath::from(Path::new(path)))
+          .collect::<Vec<_>>()
+          .into_iter(),
+        std::iter::empty(),
+      )
+    };
+    path_manager.update(
+      to_paths(&self.files),
+      to_paths(&self.directories),
+      to_paths(&self.missing),
+    )?;
+    Ok(path_manager)
+  }
+
+  /// Checks the metadata of recorded paths to find the changes since the start time.
+  pub fn scan(&self) -> Vec<FsEvent> {
+    let start_time = self.start_time();
+    let mut events = vec![];
+    for path in self.files.iter().chain(self.directories.iter()) {
+      let path = ArcPath::from(Path::new(path));
+      if !path.exists() {
+        events.push(FsEvent {
+          path,
+          kind: FsEventKind::Remove,
+        });
+      } else if check_path_metadata(&path, &start_time) {
+        events.push(FsEvent {
+          path,
+          kind: FsEventKind::Change,
+        });
+      }
+    }
+    for path in &self.missing {
+      let path = ArcPath::from(Path::new(path));
+      if path.exists() {
+        events.push(FsEvent {
+          path,
+          kind: FsEventKind::Create,
+        });
+      }
+    }
+    events
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_save_and_scan() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let root = dir.path().canonicalize().expect("should canonicalize");
+    let unchanged = root.join("unchanged.js");
+    let changed = root.join("changed.js");
+    let deleted = root.join("deleted.js");
+    let created = root.join("created.js");
+    for file in [&unchanged, &changed, &deleted] {
+      std::fs::write(file, "").expect("should write");
+    }
+
+    let path_manager = PathManager::default();
+    let to_paths = |paths: Vec<&Path>| {
+      (
+        paths
+          .into_iter()
+          .map(ArcPath::from)
+          .collect::<Vec<_>>()
+          .into_iter(),
+        std::iter::empty(),
+      )
+    };
+    path_manager
+      .update(
+        to_paths(vec![&unchanged, &changed, &deleted]),
+        to_paths(vec![]),
+        to_paths(vec![&created]),
+      )
+      .expect("should update");
+
+    let state_path = root.join("state/watcher.json");
+    assert!(
+      WatcherState::load(&state_path)
+        .await
+        .expect("should load")
+        .is_none()
+    );
+    std::thread::sleep(Duration::from_millis(10));
+    WatcherState::new(path_manager.access(), SystemTime::now())
+      .save(&state_path)
+      .await
+      .expect("should save");
+    std::thread::sleep(Duration::from_millis(10));
+
+    std::fs::write(&changed, "changed").expect("should write");
+    std::fs::remove_file(&deleted).expect("should remove");
+    std::fs::write(&created, "").expect("should write");
+
+    let state = WatcherState::load(&state_path)
+      .await
+      .expect("should load")
+      .expect("should have state");
+    let mut changes = ResumedChanges::default();
+    changes.extend(state.scan());
+    let to_set = |paths: Vec<&Path>| {
+      paths
+        .into_iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect::<HashSet<_>>()
+    };
+    assert_eq!(changes.changed_files, to_set(vec![&changed, &created]));
+    assert_eq!(changes.deleted_files, to_set(vec![&deleted]));
+  }
+}
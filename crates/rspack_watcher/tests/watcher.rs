@@ -95,3 +95,82 @@ fn should_watch_a_single_file() {
     },
   );
 }
+
+#[cfg(unix)]
+#[test]
+fn should_rebuild_on_daemon_events() {
+  use std::{
+    io::{BufRead, BufReader, Write},
+    os::unix::net::UnixListener,
+    sync::{
+      Arc,
+      atomic::{AtomicBool, Ordering},
+    },
+  };
+
+  use serde_json::{Value, json};
+
+  let socket_dir = tempfile::tempdir().unwrap();
+  let socket = socket_dir.path().join("daemon.sock");
+  let mut helper = h!(FsWatcherOptions {
+    aggregate_timeout: Some(100),
+    poll_interval: Some(50),
+    query_socket: Some(socket.clone()),
+    ..Default::default()
+  });
+  helper.file("a");
+
+  // a daemon which reports `a` as changed in the first query
+  let target = helper.join("a");
+  let listener = UnixListener::bind(&socket).unwrap();
+  let reported = Arc::new(AtomicBool::new(false));
+  std::thread::spawn(move || {
+    for stream in listener.incoming() {
+      let stream = stream.unwrap();
+      let target = target.clone();
+      let reported = reported.clone();
+      std::thread::spawn(move || {
+        let mut writer = stream.try_clone().unwrap();
+        for line in BufReader::new(stream).lines() {
+          let command: Value = serde_json::from_str(&line.unwrap()).unwrap();
+          let response = match command[0].as_str() {
+            Some("watch-project") => json!({ "watch": command[1] }),
+            Some("query") => {
+              let root = command[1].as_str().unwrap();
+              let files = if reported.swap(true, Ordering::SeqCst) {
+                json!([])
+              } else {
+                let name = target.strip_prefix(root).unwrap().as_str();
+                json!([{ "name": name, "exists": true, "new": false }])
+              };
+              json!({ "clock": "c:1", "files": files })
+            }
+            _ => json!({ "error": "unknown command" }),
+          };
+          writer
+            .write_all(format!("{response}\n").as_bytes())
+            .unwrap();
+        }
+      });
+    }
+  });
+
+  let rx = watch!(helper, "a");
+
+  let change_events = c!();
+  let aggregated_events = c!();
+  helper.collect_events(
+    rx,
+    |file, _| {
+      file.assert_path(helper.join("a"));
+      add!(change_events);
+    },
+    |changes, abort| {
+      changes.assert_changed(helper.join("a"));
+      assert!(load!(change_events) > 0);
+      add!(aggregated_events);
+      *abort = true;
+    },
+  );
+  assert_eq!(load!(aggregated_events), 1);
+}
@@ -0,0 +1,943 @@
+use rustc_hash::FxHashSet;
+use swc_core::{
+  atoms::Atom,
+  common::{DUMMY_SP, Span, Spanned, sync::Lrc},
+  ecma::{
+    ast::{
+      Accessibility, BindingIdent, Class, ClassDecl, ClassExpr, ClassMember, ClassMethod,
+      ClassProp, Constructor, Decl, DefaultDecl, ExportDecl, ExportDefaultDecl, ExportDefaultExpr,
+      ExportSpecifier, Expr, FnDecl, FnExpr, Function, Ident, ImportSpecifier, Key, Lit,
+      MethodKind, Module, ModuleDecl, ModuleItem, Param, ParamOrTsParamProp, Pat, PrivateName,
+      PrivateProp, Prop, PropName, PropOrSpread, Stmt, TsFnOrConstructorType, TsFnParam, TsFnType,
+      TsKeywordType, TsKeywordTypeKind, TsModuleBlock, TsNamespaceBody, TsParamProp,
+      TsParamPropParam, TsParenthesizedType, TsPropertySignature, TsType, TsTypeAnn, TsTypeElement,
+      TsTypeLit, TsUnionOrIntersectionType, TsUnionType, UnaryOp, VarDecl, VarDeclKind,
+      VarDeclarator,
+    },
+    codegen::{Config, Emitter, Node, text_writer::JsWriter},
+    visit::{Visit, VisitWith},
+  },
+};
+
+const DEFAULT_EXPORT_NAME: &str = "_default";
+
+/// A declaration that can't be emitted without type inference, which is reported by tsc as
+/// an error with `--isolatedDeclarations`
+#[derive(Debug, Clone)]
+pub struct DtsViolation {
+  pub span: Span,
+  pub message: &'static str,
+}
+
+/// Emit the declarations of a TypeScript module without type checking, the declarations
+/// follow the restrictions of `--isolatedDeclarations`.
+///
+/// The types are taken from the annotations, or inferred from the literals, the declarations
+/// that can't be emitted in this way are reported as [DtsViolation]s and typed as `unknown`.
+pub fn isolated_declarations(module: &Module) -> (Module, Vec<DtsViolation>) {
+  let mut emitter = IsolatedDtsEmitter::default();
+  let mut body = emitter.transform_items(&module.body);
+  remove_unused_imports(&mut body);
+  (
+    Module {
+      span: module.span,
+      body,
+      shebang: None,
+    },
+    emitter.violations,
+  )
+}
+
+/// Print the declarations emitted by [isolated_declarations]
+pub fn print_declarations(module: &Module, cm: Lrc<swc_core::common::SourceMap>) -> String {
+  let mut buf = vec![];
+  {
+    let mut emitter = Emitter {
+      cfg: Config::default(),
+      cm: cm.clone(),
+      comments: None,
+      wr: Box::new(JsWriter::new(cm, "\n", &mut buf, None)),
+    };
+    module
+      .emit_with(&mut emitter)
+      .expect("should emit declarations to the buffer");
+  }
+  // SAFETY: SWC will emit valid utf8 for sure
+  unsafe { String::from_utf8_unchecked(buf) }
+}
+
+#[derive(Debug, Default)]
+struct IsolatedDtsEmitter {
+  violations: Vec<DtsViolation>,
+  /// Whether the declarations are in a `declare namespace`, where the `declare` modifier
+  /// is not allowed
+  in_ambient: bool,
+  /// Whether the current declaration is exported, the violations are only reported for the
+  /// exported declarations
+  exported: bool,
+}
+
+impl IsolatedDtsEmitter {
+  fn report(&mut self, span: Span, message: &'static str) {
+    if self.exported {
+      self.violations.push(DtsViolation { span, message });
+    }
+  }
+
+  fn transform_items(&mut self, items: &[ModuleItem]) -> Vec<ModuleItem> {
+    // the locals exported by `export { a }` and `export default a`
+    let mut exported_locals: FxHashSet<Atom> = Default::default();
+    for item in items {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export))
+          if named_export.src.is_none() =>
+        {
+          for specifier in &named_export.specifiers {
+            if let ExportSpecifier::Named(specifier) = specifier {
+              exported_locals.insert(specifier.orig.atom().into_owned());
+            }
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_default)) => {
+          if let Expr::Ident(ident) = &*export_default.expr {
+            exported_locals.insert(ident.sym.clone());
+          }
+        }
+        _ => {}
+      }
+    }
+
+    // the functions that have overload signatures, their implementations are not emitted
+    let mut overloaded: FxHashSet<Atom> = Default::default();
+    let mut result = vec![];
+    for item in items {
+      match item {
+        ModuleItem::ModuleDecl(module_decl) => match module_decl {
+          ModuleDecl::Import(import) => {
+            // side effect imports are not needed by the declarations
+            if !import.specifiers.is_empty() {
+              result.push(item.clone());
+            }
+          }
+          ModuleDecl::ExportDecl(export_decl) => {
+            if is_overload_implementation(&export_decl.decl, &mut overloaded) {
+              continue;
+            }
+            self.exported = true;
+            let decl = self.transform_decl(&export_decl.decl);
+            self.exported = false;
+            if let Some(decl) = decl {
+              result.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span: export_decl.span,
+                decl,
+              })));
+            }
+          }
+          ModuleDecl::ExportDefaultDecl(export_default) => {
+            self.exported = true;
+            let decl = match &export_default.decl {
+              DefaultDecl::Class(class_expr) => DefaultDecl::Class(ClassExpr {
+                ident: class_expr.ident.clone(),
+                class: Box::new(self.transform_class(&class_expr.class)),
+              }),
+              DefaultDecl::Fn(fn_expr) => {
+                if fn_expr.function.body.is_some()
+                  && overloaded.contains(&Atom::from(DEFAULT_EXPORT_NAME))
+                {
+                  self.exported = false;
+                  continue;
+                }
+                if fn_expr.function.body.is_none() {
+                  overloaded.insert(DEFAULT_EXPORT_NAME.into());
+                }
+                DefaultDecl::Fn(FnExpr {
+                  ident: fn_expr.ident.clone(),
+                  function: Box::new(self.transform_function(&fn_expr.function, true)),
+                })
+              }
+              DefaultDecl::TsInterfaceDecl(_) => export_default.decl.clone(),
+            };
+            self.exported = false;
+            result.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+              ExportDefaultDecl {
+                span: export_default.span,
+                decl,
+              },
+            )));
+          }
+          ModuleDecl::ExportDefaultExpr(export_default) => {
+            if export_default.expr.is_ident() {
+              result.push(item.clone());
+              continue;
+            }
+            // export default <expr> => declare const _default: <type>; export default _default;
+            self.exported = true;
+            let type_ann = self
+              .infer_expr_type(&export_default.expr)
+              .unwrap_or_else(|| {
+                self.report(
+                  export_default.expr.span(),
+                  "Default exports can't be inferred with --isolatedDeclarations.",
+                );
+                keyword_type(TsKeywordTypeKind::TsUnknownKeyword)
+              });
+            self.exported = false;
+            let ident = Ident::new_no_ctxt(DEFAULT_EXPORT_NAME.into(), DUMMY_SP);
+            result.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+              span: export_default.span,
+              ctxt: Default::default(),
+              kind: VarDeclKind::Const,
+              declare: !self.in_ambient,
+              decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(BindingIdent {
+                  id: ident.clone(),
+                  type_ann: Some(type_annotation(type_ann)),
+                }),
+                init: None,
+                definite: false,
+              }],
+            })))));
+            result.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(
+              ExportDefaultExpr {
+                span: export_default.span,
+                expr: Box::new(Expr::Ident(ident)),
+              },
+            )));
+          }
+          ModuleDecl::ExportNamed(_)
+          | ModuleDecl::ExportAll(_)
+          | ModuleDecl::TsImportEquals(_)
+          | ModuleDecl::TsExportAssignment(_)
+          | ModuleDecl::TsNamespaceExport(_) => result.push(item.clone()),
+        },
+        ModuleItem::Stmt(Stmt::Decl(decl)) => {
+          if is_overload_implementation(decl, &mut overloaded) {
+            continue;
+          }
+          self.exported = decl_ident(decl).is_some_and(|ident| exported_locals.contains(ident));
+          let decl = self.transform_decl(decl);
+          self.exported = false;
+          if let Some(decl) = decl {
+            result.push(ModuleItem::Stmt(Stmt::Decl(decl)));
+          }
+        }
+        // the statements other than declarations are not emitted
+        ModuleItem::Stmt(_) => {}
+      }
+    }
+    result
+  }
+
+  fn transform_decl(&mut self, decl: &Decl) -> Option<Decl> {
+    let declare = !self.in_ambient;
+    match decl {
+      Decl::Class(class_decl) => Some(Decl::Class(ClassDecl {
+        ident: class_decl.ident.clone(),
+        declare,
+        class: Box::new(self.transform_class(&class_decl.class)),
+      })),
+      Decl::Fn(fn_decl) => Some(Decl::Fn(FnDecl {
+        ident: fn_decl.ident.clone(),
+        declare,
+        function: Box::new(self.transform_function(&fn_decl.function, true)),
+      })),
+      Decl::Var(var_decl) => self.transform_var(var_decl).map(Decl::Var),
+      Decl::Using(_) => None,
+      Decl::TsInterface(_) | Decl::TsTypeAlias(_) => Some(decl.clone()),
+      Decl::TsEnum(enum_decl) => {
+        let mut enum_decl = enum_decl.clone();
+        enum_decl.declare = declare;
+        Some(Decl::TsEnum(enum_decl))
+      }
+      Decl::TsModule(module_decl) => {
+        let mut module_decl = module_decl.clone();
+        module_decl.declare = declare;
+        let in_ambient = std::mem::replace(&mut self.in_ambient, true);
+        let exported = std::mem::take(&mut self.exported);
+        if let Some(body) = &mut module_decl.body {
+          let block = namespace_block(body);
+          block.body = self.transform_items(&block.body);
+        }
+        self.in_ambient = in_ambient;
+        self.exported = exported;
+        Some(Decl::TsModule(module_decl))
+      }
+    }
+  }
+
+  fn transform_var(&mut self, var_decl: &VarDecl) -> Option<Box<VarDecl>> {
+    let mut decls = vec![];
+    for declarator in &var_decl.decls {
+      let Pat::Ident(binding) = &declarator.name else {
+        self.report(
+          declarator.name.span(),
+          "Binding elements can't be exported directly with --isolatedDeclarations.",
+        );
+        continue;
+      };
+      let mut binding = binding.clone();
+      let mut init = None;
+      if binding.type_ann.is_none() {
+        match declarator.init.as_deref() {
+          // declare const a = 1;
+          Some(expr) if var_decl.kind == VarDeclKind::Const && is_literal(expr) => {
+            init = Some(Box::new(expr.clone()));
+          }
+          expr => {
+            let type_ann = expr
+              .and_then(|expr| self.infer_expr_type(expr))
+              .unwrap_or_else(|| {
+                self.report(
+                  binding.id.span,
+                  "Variable must have an explicit type annotation with --isolatedDeclarations.",
+                );
+                keyword_type(TsKeywordTypeKind::TsUnknownKeyword)
+              });
+            binding.type_ann = Some(type_annotation(type_ann));
+          }
+        }
+      }
+      decls.push(VarDeclarator {
+        span: declarator.span,
+        name: Pat::Ident(binding),
+        init,
+        definite: false,
+      });
+    }
+    if decls.is_empty() {
+      return None;
+    }
+    Some(Box::new(VarDecl {
+      span: var_decl.span,
+      ctxt: var_decl.ctxt,
+      kind: var_decl.kind,
+      declare: !self.in_ambient,
+      decls,
+    }))
+  }
+
+  fn transform_function(&mut self, function: &Function, require_return_type: bool) -> Function {
+    let optional_from = optional_params_from(function.params.iter().map(|param| &param.pat));
+    let params = function
+      .params
+      .iter()
+      .enumerate()
+      .map(|(index, param)| Param {
+        span: param.span,
+        decorators: vec![],
+        pat: self.transform_param(&param.pat, index >= optional_from),
+      })
+      .collect();
+    let return_type = match &function.return_type {
+      Some(return_type) => Some(return_type.clone()),
+      None if require_return_type => {
+        if function.body.is_some() {
+          self.report(
+            function.span,
+            "Function must have an explicit return type annotation with --isolatedDeclarations.",
+          );
+        }
+        Some(type_annotation(keyword_type(
+          TsKeywordTypeKind::TsUnknownKeyword,
+        )))
+      }
+      None => None,
+    };
+    Function {
+      params,
+      decorators: vec![],
+      span: function.span,
+      ctxt: function.ctxt,
+      body: None,
+      is_generator: false,
+      is_async: false,
+      type_params: function.type_params.clone(),
+      return_type,
+    }
+  }
+
+  /// Remove the default values of the parameters, `a = 1` is emitted as `a?: number` when
+  /// the following parameters are all optional, otherwise as `a: number | undefined`
+  fn transform_param(&mut self, pat: &Pat, optional: bool) -> Pat {
+    match pat {
+      Pat::Ident(binding) => {
+        let mut binding = binding.clone();
+        if binding.type_ann.is_none() {
+          self.report(
+            binding.id.span,
+            "Parameter must have an explicit type annotation with --isolatedDeclarations.",
+          );
+          binding.type_ann = Some(type_annotation(keyword_type(
+            TsKeywordTypeKind::TsUnknownKeyword,
+          )));
+        }
+        Pat::Ident(binding)
+      }
+      Pat::Assign(assign) => match &*assign.left {
+        Pat::Ident(binding) => {
+          let mut binding = binding.clone();
+          let type_ann = match binding.type_ann.take() {
+            Some(type_ann) => type_ann.type_ann,
+            None => widened_literal_type(&assign.right).unwrap_or_else(|| {
+              self.report(
+                binding.id.span,
+                "Parameter must have an explicit type annotation with --isolatedDeclarations.",
+              );
+              keyword_type(TsKeywordTypeKind::TsUnknownKeyword)
+            }),
+          };
+          binding.id.optional = optional;
+          binding.type_ann = Some(type_annotation(if optional {
+            type_ann
+          } else {
+            or_undefined(type_ann)
+          }));
+          Pat::Ident(binding)
+        }
+        left => self.transform_param(left, optional),
+      },
+      Pat::Rest(rest) => {
+        if rest.type_ann.is_none() {
+          self.report(
+            rest.span,
+            "Parameter must have an explicit type annotation with --isolatedDeclarations.",
+          );
+        }
+        pat.clone()
+      }
+      Pat::Array(array) => {
+        if array.type_ann.is_none() {
+          self.report(
+            array.span,
+            "Parameter must have an explicit type annotation with --isolatedDeclarations.",
+          );
+        }
+        pat.clone()
+      }
+      Pat::Object(object) => {
+        if object.type_ann.is_none() {
+          self.report(
+            object.span,
+            "Parameter must have an explicit type annotation with --isolatedDeclarations.",
+          );
+        }
+        pat.clone()
+      }
+      Pat::Expr(_) | Pat::Invalid(_) => pat.clone(),
+    }
+  }
+
+  fn transform_class(&mut self, class: &Class) -> Class {
+    if let Some(super_class) = &class.super_class
+      && !matches!(&**super_class, Expr::Ident(_) | Expr::Member(_))
+    {
+      self.report(
+        super_class.span(),
+        "Extends clause can't contain an expression with --isolatedDeclarations.",
+      );
+    }
+
+    let mut overloaded: FxHashSet<Atom> = Default::default();
+    let mut param_props = vec![];
+    let mut body = vec![];
+    for member in &class.body {
+      match member {
+        ClassMember::Constructor(constructor) => {
+          // the parameter properties are declared by the implementation
+          if constructor.body.is_some() {
+            param_props.extend(constructor.params.iter().filter_map(|param| match param {
+              ParamOrTsParamProp::TsParamProp(prop) => Some(param_prop(prop)),
+              ParamOrTsParamProp::Param(_) => None,
+            }));
+          }
+          let name = Atom::from("constructor");
+          if constructor.body.is_some() && overloaded.contains(&name) {
+            continue;
+          }
+          if constructor.body.is_none() {
+            overloaded.insert(name);
+          }
+          let params = self.transform_constructor_params(&constructor.params);
+          body.push(ClassMember::Constructor(Constructor {
+            span: constructor.span,
+            ctxt: constructor.ctxt,
+            key: constructor.key.clone(),
+            params,
+            body: None,
+            accessibility: constructor.accessibility,
+            is_optional: constructor.is_optional,
+          }));
+        }
+        ClassMember::Method(method) => {
+          if matches!(method.accessibility, Some(Accessibility::Private)) {
+            body.push(private_prop(&method.key, method.is_static));
+            continue;
+          }
+          let name = prop_name_atom(&method.key);
+          if let Some(name) = &name {
+            if method.function.body.is_some() && overloaded.contains(name) {
+              continue;
+            }
+            if method.function.body.is_none() {
+              overloaded.insert(name.clone());
+            }
+          }
+          let function =
+            self.transform_function(&method.function, !matches!(method.kind, MethodKind::Setter));
+          body.push(ClassMember::Method(ClassMethod {
+            span: method.span,
+            key: method.key.clone(),
+            function: Box::new(function),
+            kind: method.kind,
+            is_static: method.is_static,
+            accessibility: method.accessibility,
+            is_abstract: method.is_abstract,
+            is_optional: method.is_optional,
+            is_override: method.is_override,
+          }));
+        }
+        ClassMember::ClassProp(prop) => {
+          if matches!(prop.accessibility, Some(Accessibility::Private)) {
+            body.push(private_prop(&prop.key, prop.is_static));
+            continue;
+          }
+          let mut type_ann = prop.type_ann.clone();
+          let mut value = None;
+          if type_ann.is_none() {
+            match prop.value.as_deref() {
+              // readonly a = 1;
+              Some(expr) if prop.readonly && is_literal(expr) => {
+                value = Some(Box::new(expr.clone()));
+              }
+              expr => {
+                let inferred = expr
+                  .and_then(|expr| self.infer_expr_type(expr))
+                  .unwrap_or_else(|| {
+                    self.report(
+                      prop.span,
+                      "Property must have an explicit type annotation with --isolatedDeclarations.",
+                    );
+                    keyword_type(TsKeywordTypeKind::TsUnknownKeyword)
+                  });
+                type_ann = Some(type_annotation(inferred));
+              }
+            }
+          }
+          body.push(ClassMember::ClassProp(ClassProp {
+            span: prop.span,
+            key: prop.key.clone(),
+            value,
+            type_ann,
+            is_static: prop.is_static,
+            decorators: vec![],
+            accessibility: prop.accessibility,
+            is_abstract: prop.is_abstract,
+            is_optional: prop.is_optional,
+            is_override: prop.is_override,
+            readonly: prop.readonly,
+            declare: false,
+            definite: false,
+          }));
+        }
+        ClassMember::TsIndexSignature(_) => body.push(member.clone()),
+        // the private names (#a) are replaced by a single `#private;`, the static blocks are
+        // not a part of the declarations
+        ClassMember::PrivateMethod(_)
+        | ClassMember::PrivateProp(_)
+        | ClassMember::StaticBlock(_)
+        | ClassMember::AutoAccessor(_)
+        | ClassMember::Empty(_) => {}
+      }
+    }
+
+    // `#private;` keeps the class from being structurally compatible with the other classes
+    let has_private_names = class.body.iter().any(|member| match member {
+      ClassMember::PrivateMethod(_) | ClassMember::PrivateProp(_) => true,
+      ClassMember::AutoAccessor(accessor) => matches!(accessor.key, Key::Private(_)),
+      _ => false,
+    });
+    let private_names = has_private_names.then(|| {
+      ClassMember::PrivateProp(PrivateProp {
+        span: DUMMY_SP,
+        ctxt: Default::default(),
+        key: PrivateName {
+          span: DUMMY_SP,
+          name: "private".into(),
+        },
+        value: None,
+        type_ann: None,
+        is_static: false,
+        decorators: vec![],
+        accessibility: None,
+        is_optional: false,
+        is_override: false,
+        readonly: false,
+        definite: false,
+      })
+    });
+
+    Class {
+      span: class.span,
+      ctxt: class.ctxt,
+      decorators: vec![],
+      body: private_names
+        .into_iter()
+        .chain(param_props)
+        .chain(body)
+        .collect(),
+      super_class: class.super_class.clone(),
+      is_abstract: class.is_abstract,
+      type_params: class.type_params.clone(),
+      super_type_params: class.super_type_params.clone(),
+      implements: class.implements.clone(),
+    }
+  }
+
+  /// The parameter properties are emitted as the plain parameters, the properties are
+  /// declared by [param_prop]
+  fn transform_constructor_params(
+    &mut self,
+    params: &[ParamOrTsParamProp],
+  ) -> Vec<ParamOrTsParamProp> {
+    let pats = params
+      .iter()
+      .map(|param| match param {
+        ParamOrTsParamProp::Param(param) => (param.span, param.pat.clone()),
+        ParamOrTsParamProp::TsParamProp(prop) => (
+          prop.span,
+          match &prop.param {
+            TsParamPropParam::Ident(binding) => Pat::Ident(binding.clone()),
+            TsParamPropParam::Assign(assign) => Pat::Assign(assign.clone()),
+          },
+        ),
+      })
+      .collect::<Vec<_>>();
+    let optional_from = optional_params_from(pats.iter().map(|(_, pat)| pat));
+    pats
+      .iter()
+      .enumerate()
+      .map(|(index, (span, pat))| {
+        ParamOrTsParamProp::Param(Param {
+          span: *span,
+          decorators: vec![],
+          pat: self.transform_param(pat, index >= optional_from),
+        })
+      })
+      .collect()
+  }
+
+  /// Infer the type of an expression without type checking, the literals are widened
+  fn infer_expr_type(&mut self, expr: &Expr) -> Option<Box<TsType>> {
+    if let Some(ty) = widened_literal_type(expr) {
+      return Some(ty);
+    }
+    match expr {
+      Expr::Paren(paren) => self.infer_expr_type(&paren.expr),
+      Expr::TsAs(as_expr) => Some(as_expr.type_ann.clone()),
+      Expr::TsTypeAssertion(assertion) => Some(assertion.type_ann.clone()),
+      Expr::TsSatisfies(satisfies) => self.infer_expr_type(&satisfies.expr),
+      Expr::Arrow(arrow) => {
+        let return_type = arrow.return_type.clone()?;
+        let optional_from = optional_params_from(arrow.params.iter());
+        let params = arrow
+          .params
+          .iter()
+          .enumerate()
+          .map(|(index, pat)| self.fn_type_param(pat, index >= optional_from))
+          .collect::<Option<Vec<_>>>()?;
+        Some(Box::new(TsType::TsFnOrConstructorType(
+          TsFnOrConstructorType::TsFnType(TsFnType {
+            span: arrow.span,
+            params,
+            type_params: arrow.type_params.clone(),
+            type_ann: return_type,
+          }),
+        )))
+      }
+      Expr::Fn(fn_expr) => {
+        let return_type = fn_expr.function.return_type.clone()?;
+        let optional_from =
+          optional_params_from(fn_expr.function.params.iter().map(|param| &param.pat));
+        let params = fn_expr
+          .function
+          .params
+          .iter()
+          .enumerate()
+          .map(|(index, param)| self.fn_type_param(&param.pat, index >= optional_from))
+          .collect::<Option<Vec<_>>>()?;
+        Some(Box::new(TsType::TsFnOrConstructorType(
+          TsFnOrConstructorType::TsFnType(TsFnType {
+            span: fn_expr.function.span,
+            params,
+            type_params: fn_expr.function.type_params.clone(),
+            type_ann: return_type,
+          }),
+        )))
+      }
+      Expr::Object(object) => {
+        let mut members = vec![];
+        for prop in &object.props {
+          let PropOrSpread::Prop(prop) = prop else {
+            return None;
+          };
+          let Prop::KeyValue(key_value) = &**prop else {
+            return None;
+          };
+          let key = match &key_value.key {
+            PropName::Ident(ident) => {
+              Expr::Ident(Ident::new_no_ctxt(ident.sym.clone(), ident.span))
+            }
+            PropName::Str(str) => Expr::Lit(Lit::Str(str.clone())),
+            PropName::Num(num) => Expr::Lit(Lit::Num(num.clone())),
+            PropName::Computed(_) | PropName::BigInt(_) => return None,
+          };
+          let type_ann = self.infer_expr_type(&key_value.value)?;
+          members.push(TsTypeElement::TsPropertySignature(TsPropertySignature {
+            span: DUMMY_SP,
+            readonly: false,
+            key: Box::new(key),
+            computed: false,
+            optional: false,
+            type_ann: Some(type_annotation(type_ann)),
+          }));
+        }
+        Some(Box::new(TsType::TsTypeLit(TsTypeLit {
+          span: object.span,
+          members,
+        })))
+      }
+      _ => None,
+    }
+  }
+
+  fn fn_type_param(&mut self, pat: &Pat, optional: bool) -> Option<TsFnParam> {
+    match pat {
+      Pat::Ident(binding) if binding.type_ann.is_some() => Some(TsFnParam::Ident(binding.clone())),
+      Pat::Assign(assign) if matches!(&*assign.left, Pat::Ident(_)) => {
+        let Pat::Ident(binding) = self.transform_param(pat, optional) else {
+          return None;
+        };
+        Some(TsFnParam::Ident(binding))
+      }
+      Pat::Rest(rest) if rest.type_ann.is_some() => Some(TsFnParam::Rest(rest.clone())),
+      Pat::Array(array) if array.type_ann.is_some() => Some(TsFnParam::Array(array.clone())),
+      Pat::Object(object) if object.type_ann.is_some() => Some(TsFnParam::Object(object.clone())),
+      _ => None,
+    }
+  }
+}
+
+/// Remove the imports that are not referenced by the declarations, the references are
+/// matched by names, so the shadowed ones are kept conservatively
+fn remove_unused_imports(items: &mut Vec<ModuleItem>) {
+  let mut collector = IdentCollector::default();
+  for item in items.iter() {
+    if !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))) {
+      item.visit_with(&mut collector);
+    }
+  }
+  items.retain_mut(|item| {
+    let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+      return true;
+    };
+    import.specifiers.retain(|specifier| {
+      let local = match specifier {
+        ImportSpecifier::Named(specifier) => &specifier.local,
+        ImportSpecifier::Default(specifier) => &specifier.local,
+        ImportSpecifier::Namespace(specifier) => &specifier.local,
+      };
+      collector.names.contains(&local.sym)
+    });
+    !import.specifiers.is_empty()
+  });
+}
+
+#[derive(Debug, Default)]
+struct IdentCollector {
+  names: FxHashSet<Atom>,
+}
+
+impl Visit for IdentCollector {
+  fn visit_ident(&mut self, ident: &Ident) {
+    self.names.insert(ident.sym.clone());
+  }
+}
+
+/// Whether the declaration is the implementation of a function with overload signatures
+fn is_overload_implementation(decl: &Decl, overloaded: &mut FxHashSet<Atom>) -> bool {
+  let Decl::Fn(fn_decl) = decl else {
+    return false;
+  };
+  if fn_decl.function.body.is_none() {
+    overloaded.insert(fn_decl.ident.sym.clone());
+    return false;
+  }
+  overloaded.contains(&fn_decl.ident.sym)
+}
+
+/// `namespace A.B.C {}` is parsed as the nested namespace declarations
+fn namespace_block(body: &mut TsNamespaceBody) -> &mut TsModuleBlock {
+  match body {
+    TsNamespaceBody::TsNamespaceDecl(namespace_decl) => namespace_block(&mut namespace_decl.body),
+    TsNamespaceBody::TsModuleBlock(block) => block,
+  }
+}
+
+fn decl_ident(decl: &Decl) -> Option<&Atom> {
+  match decl {
+    Decl::Class(class_decl) => Some(&class_decl.ident.sym),
+    Decl::Fn(fn_decl) => Some(&fn_decl.ident.sym),
+    Decl::Var(var_decl) => var_decl
+      .decls
+      .first()
+      .and_then(|declarator| declarator.name.as_ident())
+      .map(|binding| &binding.id.sym),
+    Decl::TsEnum(enum_decl) => Some(&enum_decl.id.sym),
+    _ => None,
+  }
+}
+
+fn prop_name_atom(prop_name: &PropName) -> Option<Atom> {
+  match prop_name {
+    PropName::Ident(ident) => Some(ident.sym.clone()),
+    PropName::Str(str) => Some(str.value.to_atom_lossy().into_owned()),
+    _ => None,
+  }
+}
+
+/// `private a;`, the type of a private member is not a part of the declarations
+fn private_prop(key: &PropName, is_static: bool) -> ClassMember {
+  ClassMember::ClassProp(ClassProp {
+    span: DUMMY_SP,
+    key: key.clone(),
+    value: None,
+    type_ann: None,
+    is_static,
+    decorators: vec![],
+    accessibility: Some(Accessibility::Private),
+    is_abstract: false,
+    is_optional: false,
+    is_override: false,
+    readonly: false,
+    declare: false,
+    definite: false,
+  })
+}
+
+/// `constructor(public a: number)` declares the property `a: number;`
+fn param_prop(prop: &TsParamProp) -> ClassMember {
+  let (binding, default) = match &prop.param {
+    TsParamPropParam::Ident(binding) => (binding, None),
+    TsParamPropParam::Assign(assign) => match &*assign.left {
+      Pat::Ident(binding) => (binding, Some(&*assign.right)),
+      _ => unreachable!("should be a binding identifier"),
+    },
+  };
+  let private = matches!(prop.accessibility, Some(Accessibility::Private));
+  // the missing type annotation is reported by the parameter of the constructor
+  let type_ann = (!private).then(|| {
+    binding.type_ann.clone().unwrap_or_else(|| {
+      type_annotation(
+        default
+          .and_then(widened_literal_type)
+          .unwrap_or_else(|| keyword_type(TsKeywordTypeKind::TsUnknownKeyword)),
+      )
+    })
+  });
+  ClassMember::ClassProp(ClassProp {
+    span: prop.span,
+    key: PropName::Ident(binding.id.clone().into()),
+    value: None,
+    type_ann,
+    is_static: false,
+    decorators: vec![],
+    accessibility: prop
+      .accessibility
+      .filter(|accessibility| !matches!(accessibility, Accessibility::Public)),
+    is_abstract: false,
+    is_optional: binding.id.optional,
+    is_override: prop.is_override,
+    readonly: prop.readonly,
+    declare: false,
+    definite: false,
+  })
+}
+
+/// The index from which all the parameters are optional, a parameter with the default value
+/// before a required parameter still has to be passed
+fn optional_params_from<'a>(
+  pats: impl DoubleEndedIterator<Item = &'a Pat> + ExactSizeIterator,
+) -> usize {
+  let len = pats.len();
+  len
+    - pats
+      .rev()
+      .take_while(|pat| match pat {
+        Pat::Ident(binding) => binding.id.optional,
+        Pat::Assign(_) | Pat::Rest(_) => true,
+        _ => false,
+      })
+      .count()
+}
+
+fn is_literal(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Num(_) | Lit::Str(_) | Lit::Bool(_) | Lit::BigInt(_)) => true,
+    Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+    Expr::Unary(unary) => {
+      matches!(unary.op, UnaryOp::Minus) && matches!(&*unary.arg, Expr::Lit(Lit::Num(_)))
+    }
+    _ => false,
+  }
+}
+
+fn widened_literal_type(expr: &Expr) -> Option<Box<TsType>> {
+  let kind = match expr {
+    Expr::Lit(Lit::Num(_)) => TsKeywordTypeKind::TsNumberKeyword,
+    Expr::Lit(Lit::Str(_)) => TsKeywordTypeKind::TsStringKeyword,
+    Expr::Lit(Lit::Bool(_)) => TsKeywordTypeKind::TsBooleanKeyword,
+    Expr::Lit(Lit::BigInt(_)) => TsKeywordTypeKind::TsBigIntKeyword,
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => TsKeywordTypeKind::TsStringKeyword,
+    Expr::Unary(unary)
+      if matches!(unary.op, UnaryOp::Minus) && matches!(&*unary.arg, Expr::Lit(Lit::Num(_))) =>
+    {
+      TsKeywordTypeKind::TsNumberKeyword
+    }
+    _ => return None,
+  };
+  Some(keyword_type(kind))
+}
+
+/// `T | undefined`, the function types are wrapped in the parentheses
+fn or_undefined(ty: Box<TsType>) -> Box<TsType> {
+  let ty = match *ty {
+    TsType::TsFnOrConstructorType(_) | TsType::TsConditionalType(_) => {
+      Box::new(TsType::TsParenthesizedType(TsParenthesizedType {
+        span: DUMMY_SP,
+        type_ann: ty,
+      }))
+    }
+    _ => ty,
+  };
+  Box::new(TsType::TsUnionOrIntersectionType(
+    TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+      span: DUMMY_SP,
+      types: vec![ty, keyword_type(TsKeywordTypeKind::TsUndefinedKeyword)],
+    }),
+  ))
+}
+
+fn keyword_type(kind: TsKeywordTypeKind) -> Box<TsType> {
+  Box::new(TsType::TsKeywordType(TsKeywordType {
+    span: DUMMY_SP,
+    kind,
+  }))
+}
+
+fn type_annotation(type_ann: Box<TsType>) -> Box<TsTypeAnn> {
+  Box::new(TsTypeAnn {
+    span: DUMMY_SP,
+    type_ann,
+  })
+}
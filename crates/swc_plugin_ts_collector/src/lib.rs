@@ -1,5 +1,7 @@
 mod enums;
+mod isolated_dts;
 mod type_exports;
 
 pub use enums::{EnumMemberValue, ExportedEnumCollector};
+pub use isolated_dts::{DtsViolation, isolated_declarations, print_declarations};
 pub use type_exports::TypeExportsCollector;
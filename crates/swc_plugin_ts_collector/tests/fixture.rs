@@ -1,15 +1,17 @@
-use std::{fs, path::PathBuf};
+use std::{fs, path::PathBuf, sync::Arc};
 
 use glob::glob;
 use rspack_javascript_compiler::{JavaScriptCompiler, transform::SwcOptions};
-use rspack_swc_plugin_ts_collector::TypeExportsCollector;
+use rspack_swc_plugin_ts_collector::{
+  TypeExportsCollector, isolated_declarations, print_declarations,
+};
 use rustc_hash::FxHashSet;
 use swc_core::{
   atoms::Atom,
-  common::FileName,
+  common::{FileName, SourceMap},
   ecma::{
-    ast::noop_pass,
-    parser::{Syntax, TsSyntax},
+    ast::{EsVersion, noop_pass},
+    parser::{Syntax, TsSyntax, parse_file_as_module},
     visit::VisitWith,
   },
 };
@@ -59,3 +61,47 @@ fn type_exports() {
     }
   }
 }
+
+#[test]
+fn isolated_dts() {
+  let tests_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR").to_string()).join("tests");
+  let cases = glob(&format!("{}/isolated-dts/**/input.ts", tests_dir.display()))
+    .expect("glob failed")
+    .collect::<Result<Vec<_>, _>>()
+    .expect("glob error");
+  assert!(!cases.is_empty(), "no test cases found");
+  for input in cases {
+    let dir = input.parent().expect("test should under tests dir");
+    let expected = fs::read_to_string(dir.join("output.d.ts")).expect("failed to read output.d.ts");
+    let violations =
+      fs::read_to_string(dir.join("violations.json")).expect("failed to read violations.json");
+    let violations: Vec<String> =
+      serde_json::from_str(&violations).expect("violations.json is not a valid json");
+    let source = fs::read_to_string(&input).expect("failed to read input.ts");
+    let cm: Arc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(Arc::new(FileName::Real(input.clone())), source);
+    let module = parse_file_as_module(
+      &fm,
+      Syntax::Typescript(TsSyntax::default()),
+      EsVersion::EsNext,
+      None,
+      &mut vec![],
+    )
+    .expect("input.ts should be valid typescript");
+    let (dts, dts_violations) = isolated_declarations(&module);
+    let actual = print_declarations(&dts, cm);
+    // the formatting of the printer is not a concern of the declarations
+    let normalize = |code: &str| code.split_whitespace().collect::<Vec<_>>().join(" ");
+    assert_eq!(
+      normalize(&expected),
+      normalize(&actual),
+      "{}\nactual:\n{actual}",
+      input.display()
+    );
+    let dts_violations = dts_violations
+      .iter()
+      .map(|violation| violation.message.to_string())
+      .collect::<Vec<_>>();
+    assert_eq!(violations, dts_violations, "{}", input.display());
+  }
+}
@@ -0,0 +1,37 @@
+import "./side-effect";
+import type { Id } from "./types";
+
+export const VERSION = "1.0.0";
+export let count = 0;
+export const negative = -1;
+export const label = "label" as string;
+
+export function add(a: number, b = 1): number {
+  return a + b;
+}
+
+export function pad(value: string, width = 2, fill: string): string {
+  return value.padStart(width, fill);
+}
+
+export function format(value: string): string;
+export function format(value: number): string;
+export function format(value: string | number): string {
+  return String(value);
+}
+
+export class Counter {
+  private count = 0;
+  readonly step = 1;
+  #secret = 1;
+  constructor(public initial: number, private readonly offset = 0) {}
+  increment(): number {
+    return ++this.count;
+  }
+}
+
+console.log(Counter);
+
+export type Value = Id | number;
+
+export default 42;
@@ -0,0 +1,21 @@
+import type { Id } from "./types";
+export declare const VERSION = "1.0.0";
+export declare let count: number;
+export declare const negative = -1;
+export declare const label: string;
+export declare function add(a: number, b?: number): number;
+export declare function pad(value: string, width: number | undefined, fill: string): string;
+export declare function format(value: string): string;
+export declare function format(value: number): string;
+export declare class Counter {
+    #private;
+    initial: number;
+    private readonly offset;
+    private count;
+    readonly step = 1;
+    constructor(initial: number, offset?: number);
+    increment(): number;
+}
+export type Value = Id | number;
+declare const _default: number;
+export default _default;
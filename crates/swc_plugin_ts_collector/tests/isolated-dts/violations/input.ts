@@ -0,0 +1,15 @@
+import { compute, mixin } from "./utils";
+
+export function noReturn(a) {
+  return a;
+}
+
+export const computed = compute();
+
+const local = compute();
+
+export class Base extends mixin() {
+  value = compute();
+}
+
+export default compute();
@@ -0,0 +1,9 @@
+import { mixin } from "./utils";
+export declare function noReturn(a: unknown): unknown;
+export declare const computed: unknown;
+declare const local: unknown;
+export declare class Base extends mixin() {
+    value: unknown;
+}
+declare const _default: unknown;
+export default _default;
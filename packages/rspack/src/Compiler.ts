@@ -45,6 +45,7 @@ import type { rspack } from './index';
 import Cache from './lib/Cache';
 import CacheFacade from './lib/CacheFacade';
 import { Logger } from './logging/Logger';
+import NativeWatchFileSystem from './NativeWatchFileSystem';
 import { NormalModuleFactory } from './NormalModuleFactory';
 import { ResolverFactory } from './ResolverFactory';
 import { RuleSetCompiler } from './RuleSetCompiler';
@@ -789,6 +790,10 @@ class Compiler {
       if (error) {
         return callback(error);
       }
+      // persistent cache saves the start time of watcher to match the watcher state resumed by the next process
+      instance!.setWatcherStartTime(
+        this.watching ? BigInt(this.watching.lastWatcherStartTime) : undefined,
+      );
       if (!this.#initial) {
         instance!.rebuild(
           Array.from(this.modifiedFiles || []),
@@ -798,6 +803,35 @@ class Compiler {
         return;
       }
       this.#initial = false;
+      if (
+        this.watching &&
+        this.watchFileSystem instanceof NativeWatchFileSystem
+      ) {
+        // resume the changes made while the process is not running from the saved state of watcher
+        this.watchFileSystem.resume(this.watching.watchOptions).then(
+          (result) => {
+            if (!result) {
+              instance!.build(callback);
+              return;
+            }
+            this.modifiedFiles = new Set(result.changedFiles);
+            this.removedFiles = new Set(result.removedFiles);
+            instance!.resume(
+              result.changedFiles,
+              result.removedFiles,
+              result.since,
+              callback,
+            );
+          },
+          (err: unknown) => {
+            this.getInfrastructureLogger('rspack.NativeWatchFileSystem').warn(
+              `Failed to resume the watcher state, fallback to build: ${err}`,
+            );
+            instance!.build(callback);
+          },
+        );
+        return;
+      }
       instance!.build(callback);
     });
   }
@@ -167,7 +167,37 @@ export default class NativeWatchFileSystem implements WatchFileSystem {
     };
   }
 
-  getNativeWatcher(options: Watchpack.WatchOptions): binding.NativeWatcher {
+  /**
+   * Resume the changes since the last seen state which is saved in `stateFile` by the previous process.
+   */
+  async resume(
+    options: Watchpack.WatchOptions & {
+      querySocket?: string;
+      stateFile?: string;
+    },
+  ): Promise<binding.NativeResumeResult | null> {
+    if (!options.stateFile) {
+      return null;
+    }
+    const result = await this.getNativeWatcher(options).resume();
+    if (result && this.#inputFileSystem?.purge) {
+      const fs = this.#inputFileSystem;
+      for (const item of result.changedFiles) {
+        fs.purge?.(item);
+      }
+      for (const item of result.removedFiles) {
+        fs.purge?.(item);
+      }
+    }
+    return result;
+  }
+
+  getNativeWatcher(
+    options: Watchpack.WatchOptions & {
+      querySocket?: string;
+      stateFile?: string;
+    },
+  ): binding.NativeWatcher {
     if (this.#inner) {
       return this.#inner;
     }
@@ -177,6 +207,8 @@ export default class NativeWatchFileSystem implements WatchFileSystem {
       aggregateTimeout: options.aggregateTimeout,
       pollInterval: typeof options.poll === 'boolean' ? 0 : options.poll,
       ignored: toJsWatcherIgnored(options.ignored),
+      querySocket: options.querySocket,
+      stateFile: options.stateFile,
     };
     const nativeWatcher = new binding.NativeWatcher(nativeWatcherOptions);
     this.#inner = nativeWatcher;
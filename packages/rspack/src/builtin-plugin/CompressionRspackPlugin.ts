@@ -0,0 +1,48 @@
+import {
+  BuiltinPluginName,
+  type RawCompressionRspackPluginOptions,
+} from '@rspack/binding';
+
+import type { AssetConditions } from '../util/assetCondition';
+import { create } from './base';
+
+export type CompressionAlgorithm = 'gzip' | 'brotli' | 'zstd';
+
+export type CompressionRspackPluginOptions = {
+  test?: AssetConditions;
+  include?: AssetConditions;
+  exclude?: AssetConditions;
+  /**
+   * The algorithms to compress the assets with. Each algorithm emits a sibling asset
+   * with the `.gz`, `.br` or `.zst` extension.
+   * @default ['gzip']
+   */
+  algorithms?: CompressionAlgorithm[];
+  /**
+   * Only the assets whose size in bytes is not less than this value are compressed.
+   * @default 0
+   */
+  threshold?: number;
+  /**
+   * Only the compressed assets whose ratio (compressed size / original size) is less than
+   * this value are emitted.
+   * @default 0.8
+   */
+  minRatio?: number;
+};
+
+export const CompressionRspackPlugin = create(
+  BuiltinPluginName.CompressionRspackPlugin,
+  (
+    options: CompressionRspackPluginOptions = {},
+  ): RawCompressionRspackPluginOptions => {
+    return {
+      test: options.test,
+      include: options.include,
+      exclude: options.exclude,
+      algorithms: options.algorithms ?? ['gzip'],
+      threshold: options.threshold ?? 0,
+      minRatio: options.minRatio ?? 0.8,
+    };
+  },
+);
@@ -27,14 +27,26 @@ export type HttpUriPluginOptions = {
    * Detect changes to remote resources and upgrade them automatically
    */
   upgrade?: boolean;
-  // /**
-  //  * Specify the proxy server to use for fetching remote resources
-  //  */
-  // proxy?: string;
-  // /**
-  //  * Freeze the remote resources and lockfile. Any modification to the lockfile or resource contents will result in an error
-  //  */
-  // frozen?: boolean;
+  /**
+   * Specify the proxy server to use for fetching remote resources, the credentials in it are sent as `Proxy-Authorization`
+   * @default process.env.https_proxy for https urls, falling back to process.env.http_proxy
+   */
+  proxy?: string;
+  /**
+   * The hosts which are fetched without the proxy, such as `example.com`, `.example.com`, `localhost:8080` or `*`
+   * @default process.env.no_proxy
+   */
+  noProxy?: string | string[];
+  /**
+   * The request headers keyed by origin, they are only sent to the requests of the origin.
+   * The header values are redacted from error messages.
+   * @example { 'https://cdn.example.com': { authorization: 'Bearer token' } }
+   */
+  headers?: Record<string, Record<string, string>>;
+  /**
+   * Freeze the remote resources and lockfile. Any modification to the lockfile or resource contents will result in an error
+   */
+  frozen?: boolean;
   /**
    * Custom http client
    */
@@ -44,11 +56,49 @@ export type HttpUriPluginOptions = {
 const getHttp = memoize(() => require('node:http'));
 const getHttps = memoize(() => require('node:https'));
 
-function compatibleFetch(
+/**
+ * Create a tunnel to the target host through the proxy
+ * align with https://github.com/webpack/webpack/blob/dec18718be5dfba28f067fb3827dd620a1f33667/lib/schemes/HttpUriPlugin.js#L70
+ */
+function connectProxy(url: URL, proxy: string) {
+  const { hostname, port, username, password } = new URL(proxy);
+  const headers: Record<string, string> = {};
+  if (username || password) {
+    const credentials = `${decodeURIComponent(username)}:${decodeURIComponent(password)}`;
+    headers['Proxy-Authorization'] =
+      `Basic ${Buffer.from(credentials).toString('base64')}`;
+  }
+  return new Promise<import('node:net').Socket>((resolve, reject) => {
+    getHttp()
+      .request({
+        host: hostname,
+        port,
+        method: 'CONNECT',
+        path: `${url.hostname}:${url.port || (url.protocol === 'https:' ? 443 : 80)}`,
+        headers,
+      })
+      .on('connect', (res, socket) => {
+        if (res.statusCode === 200) {
+          resolve(socket);
+        } else {
+          socket.destroy();
+          reject(
+            new Error(`Failed to connect to proxy server: ${res.statusCode}`),
+          );
+        }
+      })
+      .on('error', reject)
+      .end();
+  });
+}
+
+async function compatibleFetch(
   url: string,
   options: { headers: Record<string, string> },
+  proxy?: string,
 ) {
   const parsedURL = new URL(url);
+  const socket = proxy ? await connectProxy(parsedURL, proxy) : undefined;
   const send: typeof import('node:http') =
     parsedURL.protocol === 'https:' ? getHttps() : getHttp();
   const {
@@ -60,34 +110,51 @@ function compatibleFetch(
   return new Promise<{ res: IncomingMessage; body: Buffer }>(
     (resolve, reject) => {
       send
-        .get(url, options, (res) => {
-          // align with https://github.com/webpack/webpack/blob/dec18718be5dfba28f067fb3827dd620a1f33667/lib/schemes/HttpUriPlugin.js#L807
-          const contentEncoding = res.headers['content-encoding'];
-          /** @type {Readable} */
-          let stream = res;
-          if (contentEncoding === 'gzip') {
-            stream = stream.pipe(createGunzip()) as IncomingMessage;
-          } else if (contentEncoding === 'br') {
-            stream = stream.pipe(createBrotliDecompress()) as IncomingMessage;
-          } else if (contentEncoding === 'deflate') {
-            stream = stream.pipe(createInflate()) as IncomingMessage;
-          }
-          const chunks: Buffer[] = [];
-          stream.on('data', (chunk) => {
-            chunks.push(chunk);
-          });
-          stream.on('end', () => {
-            const bodyBuffer = Buffer.concat(chunks);
-            if (!res.complete) {
-              reject(new Error(`${url} request was terminated early`));
-              return;
+        .get(
+          url,
+          socket
+            ? {
+                ...options,
+                createConnection: () =>
+                  parsedURL.protocol === 'https:'
+                    ? require('node:tls').connect({
+                        socket,
+                        servername: parsedURL.hostname,
+                      })
+                    : socket,
+              }
+            : options,
+          (res) => {
+            // align with https://github.com/webpack/webpack/blob/dec18718be5dfba28f067fb3827dd620a1f33667/lib/schemes/HttpUriPlugin.js#L807
+            const contentEncoding = res.headers['content-encoding'];
+            /** @type {Readable} */
+            let stream = res;
+            if (contentEncoding === 'gzip') {
+              stream = stream.pipe(createGunzip()) as IncomingMessage;
+            } else if (contentEncoding === 'br') {
+              stream = stream.pipe(
+                createBrotliDecompress(),
+              ) as IncomingMessage;
+            } else if (contentEncoding === 'deflate') {
+              stream = stream.pipe(createInflate()) as IncomingMessage;
             }
-            resolve({
-              res,
-              body: bodyBuffer,
+            const chunks: Buffer[] = [];
+            stream.on('data', (chunk) => {
+              chunks.push(chunk);
             });
-          });
-        })
+            stream.on('end', () => {
+              const bodyBuffer = Buffer.concat(chunks);
+              if (!res.complete) {
+                reject(new Error(`${url} request was terminated early`));
+                return;
+              }
+              resolve({
+                res,
+                body: bodyBuffer,
+              });
+            });
+          },
+        )
         .on('error', reject);
     },
   );
@@ -96,11 +163,12 @@ function compatibleFetch(
 const defaultHttpClientForNode = async (
   url: string,
   headers: Record<string, string>,
+  proxy?: string,
 ) => {
   // Return a promise that resolves to the response
   // setting redirect: "manual" to prevent automatic redirection which will break the redirect logic in rust plugin
   // webpack use require('http').get while rspack use fetch which treats redirect differently
-  const { res, body } = await compatibleFetch(url, { headers });
+  const { res, body } = await compatibleFetch(url, { headers }, proxy);
   const responseHeaders: Record<string, string> = {};
   for (const [key, value] of Object.entries(res.headers)) {
     if (Array.isArray(value)) {
@@ -143,6 +211,33 @@ const defaultHttpClientForBrowser = async (
   };
 };
 
+function getEnv(name: string): string | undefined {
+  if (IS_BROWSER) {
+    return undefined;
+  }
+  return process.env[name] || process.env[name.toUpperCase()] || undefined;
+}
+
+/**
+ * Get the proxy of `url` from the environment, `https_proxy` is preferred for https urls
+ */
+function getEnvProxy(url: string): string | undefined {
+  const protocol = new URL(url).protocol;
+  return (
+    (protocol === 'https:' ? getEnv('https_proxy') : undefined) ??
+    getEnv('http_proxy')
+  );
+}
+
+function toNoProxy(noProxy?: string | string[]): string[] | undefined {
+  if (noProxy === undefined) {
+    return undefined;
+  }
+  return (Array.isArray(noProxy) ? noProxy : [noProxy]).flatMap((item) =>
+    item.split(','),
+  );
+}
+
 /**
  * Plugin that allows loading modules from HTTP URLs
  */
@@ -170,15 +265,23 @@ export class HttpUriPlugin extends RspackBuiltinPlugin {
     const defaultHttpClient = IS_BROWSER
       ? defaultHttpClientForBrowser
       : defaultHttpClientForNode;
+    const httpClient = options.httpClient ?? defaultHttpClient;
 
     const raw: RawHttpUriPluginOptions = {
       allowedUris: options.allowedUris,
       lockfileLocation,
       cacheLocation,
       upgrade: options.upgrade ?? false,
-      // frozen: options.frozen,
-      // proxy: options.proxy,
-      httpClient: options.httpClient ?? defaultHttpClient,
+      proxy: options.proxy ?? getEnv('https_proxy') ?? getEnv('http_proxy'),
+      noProxy: toNoProxy(options.noProxy ?? getEnv('no_proxy')),
+      headers: options.headers,
+      frozen: options.frozen ?? false,
+      httpClient: options.proxy
+        ? httpClient
+        : // the proxy is only passed if the url is not excluded by `noProxy`,
+          // then the proxy of the environment is picked by the protocol of the url
+          (url, headers, proxy) =>
+            httpClient(url, headers, proxy && getEnvProxy(url)),
     };
     return createBuiltinPlugin(this.name, raw);
   }
@@ -0,0 +1,25 @@
+import {
+  BuiltinPluginName,
+  type RawImportCostRspackPluginOptions,
+} from '@rspack/binding';
+
+import { create } from './base';
+
+export type ImportCostRspackPluginOptions = {
+  /**
+   * The filename of the emitted report, relative to `output.path`.
+   * @default 'import-cost.json'
+   */
+  filename?: string;
+};
+
+export const ImportCostRspackPlugin = create(
+  BuiltinPluginName.ImportCostRspackPlugin,
+  (
+    options: ImportCostRspackPluginOptions = {},
+  ): RawImportCostRspackPluginOptions => {
+    return {
+      filename: options.filename ?? 'import-cost.json',
+    };
+  },
+);
@@ -0,0 +1,30 @@
+import path from 'node:path';
+import {
+  BuiltinPluginName,
+  type RawIsolatedDtsPluginOptions,
+} from '@rspack/binding';
+
+import { create } from './base';
+
+export type IsolatedDtsPluginOptions = {
+  /**
+   * Emit a declaration file for each module under this directory instead of
+   * bundling the declarations per entry, which should be the same as the
+   * `preserveModules` of `EsmLibraryPlugin`.
+   */
+  preserveModules?: string;
+};
+
+export const IsolatedDtsPlugin = create(
+  BuiltinPluginName.IsolatedDtsPlugin,
+  function (
+    options: IsolatedDtsPluginOptions = {},
+  ): RawIsolatedDtsPluginOptions {
+    return {
+      preserveModules:
+        options.preserveModules === undefined
+          ? undefined
+          : path.resolve(this.context, options.preserveModules),
+    };
+  },
+);
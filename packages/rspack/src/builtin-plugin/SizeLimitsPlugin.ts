@@ -10,7 +10,12 @@ export const SizeLimitsPlugin = create(
   BuiltinPluginName.SizeLimitsPlugin,
   (options: Exclude<Performance, false>): RawSizeLimitsPluginOptions => {
     const hints = options.hints === false ? undefined : options.hints;
+    const baseline = options.baseline && {
+      path: options.baseline.path,
+      threshold: options.baseline.threshold ?? 0,
+      update: options.baseline.update ?? false,
+    };
 
-    return { ...options, hints };
+    return { ...options, hints, baseline };
   },
 );
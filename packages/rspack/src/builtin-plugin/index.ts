@@ -9,6 +9,7 @@ export * from './CaseSensitivePlugin';
 export * from './ChunkPrefetchPreloadPlugin';
 export * from './CircularDependencyRspackPlugin';
 export * from './CommonJsChunkFormatPlugin';
+export * from './CompressionRspackPlugin';
 export * from './ContextReplacementPlugin';
 export * from './CopyRspackPlugin';
 export * from './CssChunkingPlugin';
@@ -40,6 +41,8 @@ export * from './HttpExternalsRspackPlugin';
 export * from './HttpUriPlugin';
 export * from './html-plugin/index';
 export * from './IgnorePlugin';
+export * from './ImportCostRspackPlugin';
+export * from './IsolatedDtsPlugin';
 export * from './InferAsyncModulesPlugin';
 export * from './InlineExportsPlugin';
 export * from './JavascriptModulesPlugin';
@@ -80,5 +83,6 @@ export * from './SplitChunksPlugin';
 export * from './SubresourceIntegrityPlugin';
 export * from './SwcJsMinimizerPlugin';
 export * from './URLPlugin';
+export * from './WebAssemblyModulesPlugin';
 export * from './WebWorkerTemplatePlugin';
 export * from './WorkerPlugin';
@@ -545,7 +545,7 @@ function getRawParserOptions(
     };
   }
 
-  if (type === 'json') {
+  if (type === 'json' || type === 'yaml' || type === 'toml') {
     return {
       type: 'json',
       json: getRawJsonParserOptions(parser),
@@ -598,6 +598,7 @@ function getRawJavascriptParserOptions(
     typeReexportsPresence: parser.typeReexportsPresence,
     jsx: parser.jsx,
     deferImport: parser.deferImport,
+    exportedEnum: parser.exportedEnum,
   };
 }
 
@@ -645,6 +646,7 @@ function getRawJsonParserOptions(
       typeof parser.parse === 'function'
         ? (str) => JSON.stringify(parser.parse!(str))
         : undefined,
+    syntax: parser.syntax,
   };
 }
 
@@ -692,7 +694,7 @@ function getRawGeneratorOptions(
       cssModule: getRawCssAutoOrModuleGeneratorOptions(generator),
     };
   }
-  if (type === 'json') {
+  if (type === 'json' || type === 'yaml' || type === 'toml') {
     return {
       type: 'json',
       json: getRawJsonGeneratorOptions(generator),
@@ -783,6 +785,7 @@ function getRawCssAutoOrModuleGeneratorOptions(
     exportsConvention: options.exportsConvention,
     exportsOnly: options.exportsOnly,
     esModule: options.esModule,
+    exportsDeclaration: options.exportsDeclaration,
   };
 }
 
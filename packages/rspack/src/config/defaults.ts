@@ -114,6 +114,7 @@ export const applyRspackOptionsDefaults = (
   applyModuleDefaults(options.module, {
     cache: !!options.cache,
     asyncWebAssembly: options.experiments.asyncWebAssembly!,
+    syncWebAssembly: options.experiments.syncWebAssembly!,
     css: options.experiments.css,
     targetProperties,
     mode: options.mode,
@@ -234,6 +235,7 @@ const applyExperimentsDefaults = (
   // IGNORE(experiments.lazyCompilation): In webpack, lazyCompilation is undefined by default
   D(experiments, 'lazyCompilation', false);
   D(experiments, 'asyncWebAssembly', experiments.futureDefaults);
+  D(experiments, 'syncWebAssembly', false);
   D(experiments, 'css', experiments.futureDefaults ? true : undefined);
   D(experiments, 'topLevelAwait', true);
   D(experiments, 'deferImport', false);
@@ -241,13 +243,14 @@ const applyExperimentsDefaults = (
   D(experiments, 'buildHttp', undefined);
   if (experiments.buildHttp && typeof experiments.buildHttp === 'object') {
     D(experiments.buildHttp, 'upgrade', false);
-    // D(experiments.buildHttp, "frozen", false);
+    D(experiments.buildHttp, 'frozen', false);
   }
 
   // IGNORE(experiments.incremental): Rspack specific configuration for incremental
   D(experiments, 'incremental', {});
   if (typeof experiments.incremental === 'object') {
     D(experiments.incremental, 'silent', true);
+    D(experiments.incremental, 'explain', false);
     D(experiments.incremental, 'make', true);
     D(experiments.incremental, 'inferAsyncModules', true);
     D(experiments.incremental, 'providedExports', true);
@@ -331,6 +334,7 @@ const applyJavascriptParserOptionsDefaults = (
   D(parserOptions, 'importMeta', true);
   D(parserOptions, 'typeReexportsPresence', 'no-tolerant');
   D(parserOptions, 'jsx', false);
+  D(parserOptions, 'exportedEnum', false);
   D(parserOptions, 'deferImport', deferImport);
 };
 
@@ -357,6 +361,7 @@ const applyModuleDefaults = (
   {
     cache,
     asyncWebAssembly,
+    syncWebAssembly,
     css,
     targetProperties,
     mode,
@@ -365,6 +370,7 @@ const applyModuleDefaults = (
   }: {
     cache: boolean;
     asyncWebAssembly: boolean;
+    syncWebAssembly: boolean;
     css?: boolean;
     targetProperties: any;
     mode?: Mode;
@@ -444,6 +450,7 @@ const applyModuleDefaults = (
           : '[id]-[local]'
         : '[fullhash]';
     D(module.generator['css/auto'], 'localIdentName', localIdentName);
+    D(module.generator['css/auto'], 'exportsDeclaration', false);
 
     F(module.generator, 'css/module', () => ({}));
     assertNotNill(module.generator['css/module']);
@@ -452,6 +459,7 @@ const applyModuleDefaults = (
     });
     D(module.generator['css/module'], 'exportsConvention', 'as-is');
     D(module.generator['css/module'], 'localIdentName', localIdentName);
+    D(module.generator['css/module'], 'exportsDeclaration', false);
   }
 
   // IGNORE(module.defaultRules): Rspack does not support `rule.assert`
@@ -535,6 +543,28 @@ const applyModuleDefaults = (
         mimetype: 'application/wasm',
         ...wasm,
       });
+    } else if (syncWebAssembly) {
+      const wasm = {
+        type: 'webassembly/sync',
+        rules: [
+          {
+            descriptionData: {
+              type: 'module',
+            },
+            resolve: {
+              fullySpecified: true,
+            },
+          },
+        ],
+      };
+      rules.push({
+        test: /\.wasm$/i,
+        ...wasm,
+      });
+      rules.push({
+        mimetype: 'application/wasm',
+        ...wasm,
+      });
     }
 
     if (css) {
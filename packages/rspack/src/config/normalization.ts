@@ -37,6 +37,8 @@ import type {
   EntryDescription,
   EntryStatic,
   Environment,
+  ExperimentCacheRemoteHttpClient,
+  ExperimentCacheRemoteStorage,
   Externals,
   ExternalsPresets,
   ExternalsType,
@@ -403,6 +405,7 @@ export const getNormalizedRspackOptions = (
               managedPaths: optionalNestedArray(snapshot.managedPaths, (p) => [
                 ...p,
               ]) || [/[\\/]node_modules[\\/][^.]/],
+              contentHash: snapshot.contentHash ?? false,
             },
             storage: {
               type: 'filesystem',
@@ -410,6 +413,25 @@ export const getNormalizedRspackOptions = (
                 config.context || process.cwd(),
                 cache.storage?.directory || 'node_modules/.cache/rspack',
               ),
+              remote: optionalNestedConfig(
+                cache.storage?.remote,
+                (remote): ExperimentCacheRemoteStorage =>
+                  remote.type === 'directory'
+                    ? {
+                        type: 'directory',
+                        directory: path.resolve(
+                          config.context || process.cwd(),
+                          remote.directory,
+                        ),
+                      }
+                    : {
+                        ...remote,
+                        httpClient:
+                          remote.httpClient ?? defaultRemoteCacheHttpClient,
+                      },
+              ),
+              maxSize: cache.storage?.maxSize,
+              maxGenerations: cache.storage?.maxGenerations,
             },
           };
         }),
@@ -538,6 +560,23 @@ const getNormalizedIncrementalOptions = (
   return incremental;
 };
 
+const defaultRemoteCacheHttpClient: ExperimentCacheRemoteHttpClient = async (
+  method,
+  url,
+  headers,
+  body,
+) => {
+  const res = await fetch(url, {
+    method,
+    headers,
+    body: body && new Uint8Array(body),
+  });
+  return {
+    status: res.status,
+    body: Buffer.from(await res.arrayBuffer()),
+  };
+};
+
 const nestedConfig = <T, R>(value: T | undefined, fn: (value: T) => R) =>
   value === undefined ? fn({} as T) : fn(value);
 
@@ -676,10 +715,14 @@ export type ExperimentCacheNormalized =
         immutablePaths: (string | RegExp)[];
         unmanagedPaths: (string | RegExp)[];
         managedPaths: (string | RegExp)[];
+        contentHash: boolean;
       };
       storage: {
         type: 'filesystem';
         directory: string;
+        remote?: ExperimentCacheRemoteStorage;
+        maxSize?: number;
+        maxGenerations?: number;
       };
     };
 
@@ -692,6 +735,7 @@ export interface ExperimentsNormalized {
    */
   lazyCompilation?: false | LazyCompilationOptions;
   asyncWebAssembly?: boolean;
+  syncWebAssembly?: boolean;
   outputModule?: boolean;
   topLevelAwait?: boolean;
   css?: boolean;
@@ -1202,6 +1202,12 @@ export type JavascriptParserOptions = {
 
   /** Whether to enable defer import */
   deferImport?: boolean;
+
+  /**
+   * Whether to collect the exported enums of the modules transpiled from TypeScript,
+   * so the enums can be inlined across modules no matter which loader is used
+   */
+  exportedEnum?: boolean;
 };
 
 export type JsonParserOptions = {
@@ -1213,6 +1219,13 @@ export type JsonParserOptions = {
    * If Rule.type is set to 'json' then Rules.parser.parse option may be a function that implements custom logic to parse module's source and convert it to a json-compatible data.
    */
   parse?: (source: string) => any;
+  /**
+   * The syntax of json modules, `jsonc` allows comments and trailing commas,
+   * `json5` additionally allows unquoted property names, single quoted strings, extended string escapes
+   * and the numbers such as `0xFF`, `+1` and `.5`.
+   * @default 'json'
+   */
+  syntax?: 'json' | 'jsonc' | 'json5';
 };
 
 /** Configure all parsers' options in one place with module.parser. */
@@ -1341,6 +1354,16 @@ export type CssGeneratorLocalIdentName = string;
 
 export type CssGeneratorEsModule = boolean;
 
+export type CssGeneratorExportsDeclaration =
+  | boolean
+  | {
+      /**
+       * The directory to write the declarations into, relative to the context.
+       * The paths of the CSS modules relative to the context are kept.
+       */
+      outputDir?: string;
+    };
+
 /** Generator options for css modules. */
 export type CssGeneratorOptions = {
   /**
@@ -1372,6 +1395,12 @@ export type CssAutoGeneratorOptions = {
 
   /** This configuration is available for improved ESM-CJS interoperability purposes. */
   esModule?: CssGeneratorEsModule;
+
+  /**
+   * Write a `.d.ts` declaration file for the exports of each CSS module, next to the source or into `outputDir`.
+   * @default false
+   */
+  exportsDeclaration?: CssGeneratorExportsDeclaration;
 };
 
 /** Generator options for css/module modules. */
@@ -1934,6 +1963,12 @@ export type StatsOptions = {
    * @default true
    */
   outputPath?: boolean;
+  /**
+   * Enables or disables the display of why the modules, chunks and passes are recomputed
+   * in a rebuild, which requires `experiments.incremental.explain` to be enabled.
+   * @default false
+   */
+  incremental?: boolean;
   /**
    * Enables or disables the display of chunk module stats.
    * @default true
@@ -2599,13 +2634,62 @@ export type ExperimentCacheOptions =
         immutablePaths?: (string | RegExp)[];
         unmanagedPaths?: (string | RegExp)[];
         managedPaths?: (string | RegExp)[];
+        /**
+         * Validate unmanaged files by content hash instead of modified time.
+         */
+        contentHash?: boolean;
       };
       storage?: {
         type: 'filesystem';
         directory?: string;
+        /**
+         * Share the cache through a remote cache, the local directory is used as a read-through cache.
+         */
+        remote?: ExperimentCacheRemoteStorage;
+        /**
+         * The max bytes of the cache directory, the least recently used cache versions are removed when exceeded.
+         */
+        maxSize?: number;
+        /**
+         * The max count of cache versions kept in the cache directory.
+         */
+        maxGenerations?: number;
       };
     };
 
+/**
+ * The location of a remote persistent cache.
+ * - `http`: a key/value server which supports `GET`, `HEAD` and `PUT` requests on `${url}/${key}`.
+ * - `directory`: a directory shared between machines, e.g. a network mount.
+ */
+export type ExperimentCacheRemoteStorage =
+  | {
+      type: 'http';
+      url: string;
+      /**
+       * Headers sent with every request, e.g. authorization.
+       */
+      headers?: Record<string, string>;
+      /**
+       * Custom http client to send the requests, defaults to `fetch`.
+       */
+      httpClient?: ExperimentCacheRemoteHttpClient;
+    }
+  | {
+      type: 'directory';
+      directory: string;
+    };
+
+/**
+ * Send a request to the remote cache, the body of `HEAD` responses is ignored.
+ */
+export type ExperimentCacheRemoteHttpClient = (
+  method: 'GET' | 'HEAD' | 'PUT',
+  url: string,
+  headers: Record<string, string>,
+  body?: Buffer,
+) => Promise<{ status: number; body: Buffer }>;
+
 /**
  * Options for future Rspack features.
  */
@@ -2675,6 +2759,12 @@ export type Incremental = {
    * Warning if there are cases that not friendly for incremental
    */
   silent?: boolean;
+  /**
+   * Record why the modules, chunks and passes are recomputed in each rebuild,
+   * which is exposed as `incremental` of the stats.
+   * @default false
+   */
+  explain?: boolean;
   /**
    * Enable incremental make.
    */
@@ -2791,6 +2881,12 @@ export type Experiments = {
    * @default false
    */
   asyncWebAssembly?: boolean;
+  /**
+   * Enable sync WebAssembly.
+   * Support the old WebAssembly like in webpack 4, the WebAssembly module is instantiated while loading the chunk, so it must be placed in an async chunk.
+   * @default false
+   */
+  syncWebAssembly?: boolean;
   /**
    * Enable output as ES module.
    * @default false
@@ -2918,6 +3014,18 @@ export type WatchOptions = {
    */
   poll?: number | boolean;
 
+  /**
+   * The unix socket of a watchman-like daemon, the changes are queried from it instead of watching the disk.
+   * Only works with `experiments.nativeWatcher`.
+   */
+  querySocket?: string;
+
+  /**
+   * The file to persist the last seen state of the watcher, the changes made while the process is not running are resumed from it on the next startup.
+   * Only works with `experiments.nativeWatcher`.
+   */
+  stateFile?: string;
+
   /**
    * Stop watching when stdin stream has ended.
    */
@@ -3003,7 +3111,46 @@ export type Performance =
        * @default 250000
        */
       maxEntrypointSize?: number;
+      /**
+       * Size budgets of entrypoints, named chunks and assets, which are checked besides `maxAssetSize` and `maxEntrypointSize`.
+       */
+      budgets?: PerformanceBudget[];
+      /**
+       * Compare the budgets with the sizes recorded in a baseline file besides their max sizes.
+       */
+      baseline?: PerformanceBaseline;
     };
+
+/** A size budget, exactly one of `entrypoint`, `chunk` and `asset` should be specified. */
+export type PerformanceBudget = {
+  /** The name of the entrypoint, the total size of its assets is checked. */
+  entrypoint?: string;
+  /** The name of the chunk, the total size of its files is checked. */
+  chunk?: string;
+  /** The glob of asset names, the size of each matched asset is checked. */
+  asset?: string;
+  /** Max size in bytes. */
+  maxSize?: number;
+  /** Max size in bytes after gzip compression. */
+  maxGzipSize?: number;
+  /** Max size in bytes after brotli compression. */
+  maxBrotliSize?: number;
+};
+
+export type PerformanceBaseline = {
+  /** Path of the baseline file, relative paths are resolved from the context. */
+  path: string;
+  /**
+   * The allowed regression in percent compared to the baseline.
+   * @default 0
+   */
+  threshold?: number;
+  /**
+   * Overwrite the baseline file with the sizes of the current build, the file is always written if it does not exist.
+   * @default false
+   */
+  update?: boolean;
+};
 //#endregion
 
 export type RspackOptions = {
@@ -139,6 +139,7 @@ export type { OutputFileSystem, WatchFileSystem } from './util/fs';
 import {
   EsmLibraryPlugin,
   FetchCompileAsyncWasmPlugin,
+  IsolatedDtsPlugin,
   lazyCompilationMiddleware,
   SubresourceIntegrityPlugin,
 } from './builtin-plugin';
@@ -324,11 +325,14 @@ export type {
 ///// Rspack Postfixed Internal Plugins /////
 export type {
   CircularDependencyRspackPluginOptions,
+  CompressionAlgorithm,
+  CompressionRspackPluginOptions,
   CopyRspackPluginOptions,
   CssExtractRspackLoaderOptions,
   CssExtractRspackPluginOptions,
   EvalDevToolModulePluginOptions,
   HtmlRspackPluginOptions,
+  ImportCostRspackPluginOptions,
   LightningCssMinimizerRspackPluginOptions,
   RsdoctorPluginData,
   RsdoctorPluginHooks,
@@ -338,12 +342,14 @@ export type {
 } from './builtin-plugin';
 export {
   CircularDependencyRspackPlugin,
+  CompressionRspackPlugin,
   ContextReplacementPlugin,
   CopyRspackPlugin,
   CssExtractRspackPlugin,
   EvalDevToolModulePlugin,
   EvalSourceMapDevToolPlugin,
   HtmlRspackPlugin,
+  ImportCostRspackPlugin,
   LightningCssMinimizerRspackPlugin,
   NormalModuleReplacementPlugin,
   SourceMapDevToolPlugin,
@@ -382,6 +388,7 @@ interface Experiments {
    */
   SubresourceIntegrityPlugin: typeof SubresourceIntegrityPlugin;
   EsmLibraryPlugin: typeof EsmLibraryPlugin;
+  IsolatedDtsPlugin: typeof IsolatedDtsPlugin;
   RsdoctorPlugin: typeof RsdoctorPlugin;
   RstestPlugin: typeof RstestPlugin;
   RslibPlugin: typeof RslibPlugin;
@@ -423,6 +430,7 @@ export const experiments: Experiments = {
   RemoveDuplicateModulesPlugin,
   SubresourceIntegrityPlugin,
   EsmLibraryPlugin,
+  IsolatedDtsPlugin,
   /**
    * Note: This plugin is unstable yet
    *
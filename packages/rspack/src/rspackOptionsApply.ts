@@ -66,6 +66,7 @@ import {
   SourceMapDevToolPlugin,
   SplitChunksPlugin,
   URLPlugin,
+  WebAssemblyModulesPlugin,
   WorkerPlugin,
 } from './builtin-plugin';
 import MemoryCachePlugin from './lib/cache/MemoryCachePlugin';
@@ -232,6 +233,9 @@ export class RspackOptionsApply {
     if (options.experiments.asyncWebAssembly) {
       new AsyncWebAssemblyModulesPlugin().apply(compiler);
     }
+    if (options.experiments.syncWebAssembly) {
+      new WebAssemblyModulesPlugin().apply(compiler);
+    }
     if (options.experiments.css) {
       new CssModulesPlugin().apply(compiler);
     }
@@ -891,6 +891,12 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
     outputPath: (object, compilation) => {
       object.outputPath = compilation.outputOptions.path;
     },
+    incremental: (object, compilation, context: KnownStatsFactoryContext) => {
+      const incremental = context.getInner(compilation).getIncremental();
+      if (incremental) {
+        object.incremental = incremental;
+      }
+    },
     assets: (
       object,
       compilation,
@@ -1152,6 +1158,11 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
           asset.info.related.map((i) => [i.name, i.value]),
         ),
       };
+      if (asset.compressedSizes.length) {
+        object.compressedSizes = Object.fromEntries(
+          asset.compressedSizes.map((i) => [i.algorithm, i.size]),
+        );
+      }
       // - comparedForEmit
       const cached = !object.emitted;
       object.cached = cached;
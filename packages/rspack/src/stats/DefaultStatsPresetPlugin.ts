@@ -45,6 +45,7 @@ const NAMED_PRESETS: Record<string, StatsOptions> = {
     errorStack: true,
     publicPath: true,
     logging: 'verbose',
+    incremental: true,
     orphanModules: true,
     runtimeModules: true,
     // exclude: false,
@@ -249,6 +250,7 @@ const DEFAULTS: StatsDefault = {
   chunksSort: () => false,
   assetsSort: () => '!size',
   outputPath: OFF_FOR_TO_STRING,
+  incremental: OFF_FOR_TO_STRING,
   colors: () => false,
 };
 
@@ -14,7 +14,11 @@ import { compareIds } from '../util/comparators';
 import { formatSize } from '../util/SizeFormatHelpers';
 import { SHARED_ITEM_NAMES } from './DefaultStatsFactoryPlugin';
 import type { StatsPrinter, StatsPrinterContext } from './StatsPrinter';
-import type { StatsChunkGroup, StatsCompilation } from './statsFactoryUtils';
+import type {
+  StatsChunkGroup,
+  StatsCompilation,
+  StatsIncremental,
+} from './statsFactoryUtils';
 
 const DATA_URI_CONTENT_LENGTH = 16;
 
@@ -227,6 +231,47 @@ const SIMPLE_PRINTERS: Record<
           'assets',
         )}`
       : undefined,
+  'compilation.incremental': (
+    incremental: StatsIncremental,
+    { bold, yellow },
+  ) => {
+    const lines = [
+      `Incremental rebuild: ${bold(
+        `${incremental.changedFiles.length} ${plural(
+          incremental.changedFiles.length,
+          'file',
+          'files',
+        )} changed`,
+      )}, ${incremental.removedFiles.length} removed`,
+    ];
+    for (const file of incremental.changedFiles) {
+      lines.push(`  changed ${file}`);
+    }
+    for (const file of incremental.removedFiles) {
+      lines.push(`  removed ${file}`);
+    }
+    for (const { identifier, kind, files } of incremental.invalidatedModules) {
+      const cause = files.length ? ` <- ${files.join(', ')}` : '';
+      lines.push(`  ${kind} module ${identifier}${cause}`);
+    }
+    for (const { identifier, affectedBy } of incremental.affectedModules) {
+      const cause = affectedBy ? ` <- ${affectedBy}` : '';
+      lines.push(`  affected module ${identifier}${cause}`);
+    }
+    if (incremental.affectedChunks.length) {
+      lines.push(`  affected chunks ${incremental.affectedChunks.join(', ')}`);
+    }
+    for (const pass of incremental.passes) {
+      if (pass.incremental) {
+        const affected =
+          pass.affected !== undefined ? ` (${pass.affected}/${pass.total})` : '';
+        lines.push(`  incremental ${pass.name}${affected}`);
+      } else {
+        lines.push(yellow(`  full ${pass.name}: ${pass.reason}`));
+      }
+    }
+    return lines.join('\n');
+  },
   'compilation.logging': (logging, context, printer) =>
     Array.isArray(logging)
       ? undefined
@@ -291,6 +336,12 @@ const SIMPLE_PRINTERS: Record<
     formatFilename(name, isOverSizeLimit),
   'asset.size': (size, { asset: { isOverSizeLimit }, yellow, formatSize }) =>
     isOverSizeLimit ? yellow(formatSize(size)) : formatSize(size),
+  'asset.compressedSizes': (compressedSizes, { formatSize }) =>
+    compressedSizes
+      ? `(${Object.entries(compressedSizes)
+          .map(([algorithm, size]) => `${algorithm}: ${formatSize(size)}`)
+          .join(', ')})`
+      : undefined,
   'asset.emitted': (emitted, { green, formatFlag }) =>
     emitted ? green(formatFlag('emitted')) : undefined,
   'asset.comparedForEmit': (comparedForEmit, { yellow, formatFlag }) =>
@@ -736,6 +787,7 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
     'chunks',
     'modules',
     'filteredModules',
+    'incremental',
     'children',
     'logging',
     'warnings',
@@ -751,6 +803,7 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
     'type',
     'name',
     'size',
+    'compressedSizes',
     'chunks',
     'auxiliaryChunks',
     'emitted',
@@ -64,6 +64,7 @@ export type KnownAssetInfo = {
   development?: boolean;
   hotModuleReplacement?: boolean;
   javascriptModule?: boolean;
+  compressed?: boolean;
   related?: Record<string, string | string[]>;
 };
 
@@ -88,6 +89,7 @@ export type KnownStatsAsset = {
   auxiliaryChunkIdHints?: (string | number)[];
   filteredRelated?: number;
   isOverSizeLimit?: boolean;
+  compressedSizes?: Record<string, number>;
 };
 
 export type StatsAsset = KnownStatsAsset & Record<string, any>;
@@ -287,6 +289,7 @@ export type KnownStatsCompilation = {
   filteredModules?: number;
   children?: StatsCompilation[];
   logging?: Record<string, StatsLogging>;
+  incremental?: StatsIncremental;
 
   // TODO: not aligned with webpack
   // env?: any;
@@ -296,6 +299,33 @@ export type KnownStatsCompilation = {
 
 export type StatsCompilation = KnownStatsCompilation & Record<string, any>;
 
+export type StatsIncremental = {
+  changedFiles: string[];
+  removedFiles: string[];
+  /** The modules which are added, rebuilt or removed */
+  invalidatedModules: {
+    identifier: string;
+    kind: 'add' | 'update' | 'remove';
+    /** The changed or removed files that the module depends on */
+    files: string[];
+  }[];
+  /** The modules which are not rebuilt, but affected by the invalidated modules */
+  affectedModules: {
+    identifier: string;
+    /** The invalidated or affected module that the module depends on */
+    affectedBy?: string;
+  }[];
+  affectedChunks: string[];
+  passes: {
+    name: string;
+    incremental: boolean;
+    affected?: number;
+    total?: number;
+    /** Why the pass is fully recomputed */
+    reason?: string;
+  }[];
+};
+
 export type StatsLogging = KnownStatsLogging & Record<string, any>;
 
 export type KnownStatsLogging = {
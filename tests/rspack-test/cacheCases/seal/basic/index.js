@@ -0,0 +1,23 @@
+import value from "./file";
+import { value as unchangedValue } from "./unchanged";
+import dataUrl from "./data.txt";
+
+it("should restore code generation results", async () => {
+	expect(unchangedValue).toBe("unchanged");
+	expect(dataUrl.endsWith("data.txt")).toBeTruthy();
+	if (COMPILER_INDEX == 0) {
+		expect(value).toBe(1);
+		await NEXT_HMR();
+		expect(value).toBe(2);
+		await NEXT_START();
+	}
+	if (COMPILER_INDEX == 1) {
+		expect(value).toBe(2);
+		await NEXT_START();
+	}
+	if (COMPILER_INDEX == 2) {
+		expect(value).toBe(3);
+	}
+});
+
+module.hot.accept("./file");
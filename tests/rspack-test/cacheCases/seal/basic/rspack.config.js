@@ -0,0 +1,32 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /\.txt$/,
+				type: "asset/resource",
+				generator: {
+					filename: "[name][ext]"
+				}
+			}
+		]
+	},
+	experiments: {
+		cache: {
+			type: "persistent"
+		}
+	},
+	plugins: [
+		{
+			apply(compiler) {
+				compiler.hooks.done.tap("Test", function (stats) {
+					const s = stats.toJson({
+						assets: true
+					});
+					expect(s.assets.some(item => item.name === "data.txt")).toBeTruthy();
+				});
+			}
+		}
+	]
+};
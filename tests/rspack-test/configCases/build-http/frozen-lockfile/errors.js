@@ -0,0 +1,5 @@
+module.exports = [
+	[/http\/\/test\.rspack\.rs has no lockfile entry and lockfile is frozen/],
+	[/http\/\/test\.rspack\.rs has no cached content and lockfile is frozen/],
+	[/http\/\/test\.rspack\.rs has an integrity mismatch and lockfile is frozen/]
+];
@@ -0,0 +1,17 @@
+import allowedModule from "http://test.rspack.rs/allowed-module.js";
+
+it("should serve the module from the cache without requesting it", () => {
+	expect(allowedModule).toBe("This module is from an allowed URI");
+});
+
+it("should fail to build the modules which are not locked and cached", () => {
+	expect(() => {
+		require("http://test.rspack.rs/unlocked-module.js");
+	}).toThrowError();
+	expect(() => {
+		require("http://test.rspack.rs/uncached-module.js");
+	}).toThrowError();
+	expect(() => {
+		require("http://test.rspack.rs/regex-module.js");
+	}).toThrowError();
+});
@@ -0,0 +1 @@
+module.exports = "This module is from an allowed URI";
\ No newline at end of file
@@ -0,0 +1 @@
+module.exports = "This module is from a regex-matched URI";
\ No newline at end of file
@@ -0,0 +1,19 @@
+const path = require("node:path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	experiments: {
+		buildHttp: {
+			allowedUris: ["http://test.rspack.rs/"],
+			cacheLocation: path.join(__dirname, "rspack-http-cache"),
+			lockfileLocation: path.join(__dirname, "rspack-http-lockfile.json"),
+			frozen: true,
+			// The network is never touched in frozen mode
+			httpClient: async url => {
+				throw new Error(`Unexpected request to ${url}`);
+			}
+		},
+		css: false
+	}
+};
@@ -0,0 +1,17 @@
+import fs from "fs";
+import path from "path";
+import zlib from "zlib";
+import text from "./text";
+
+it("should emit the precompressed assets", () => {
+	expect(text.startsWith("rspack compression")).toBe(true);
+
+	const bundle = fs.readFileSync(__filename);
+	const gzip = fs.readFileSync(__filename + ".gz");
+	const brotli = fs.readFileSync(__filename + ".br");
+	expect(zlib.gunzipSync(gzip).equals(bundle)).toBe(true);
+	expect(zlib.brotliDecompressSync(brotli).equals(bundle)).toBe(true);
+	expect(fs.existsSync(__filename + ".zst")).toBe(true);
+	expect(gzip.length).toBeLessThan(bundle.length);
+	expect(fs.existsSync(path.resolve(path.dirname(__filename), "bundle0.js.gz.gz"))).toBe(false);
+});
@@ -0,0 +1,39 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	plugins: [
+		new rspack.CompressionRspackPlugin({
+			test: /\.js$/,
+			algorithms: ["gzip", "brotli", "zstd"]
+		}),
+		compiler => {
+			compiler.hooks.done.tap("Test", stats => {
+				const { info } = stats.compilation.getAsset("bundle0.js");
+				expect(info.related.compressed).toEqual({
+					brotli: "bundle0.js.br",
+					gzip: "bundle0.js.gz",
+					zstd: "bundle0.js.zst"
+				});
+				expect(stats.compilation.getAsset("bundle0.js.gz").info.compressed).toBe(
+					true
+				);
+
+				const { assets } = stats.toJson({ all: false, assets: true });
+				const asset = assets.find(asset => asset.name === "bundle0.js");
+				expect(Object.keys(asset.compressedSizes).sort()).toEqual([
+					"brotli",
+					"gzip",
+					"zstd"
+				]);
+				expect(asset.compressedSizes.gzip).toBeLessThan(asset.size);
+				expect(asset.related.map(related => related.name).sort()).toEqual([
+					"bundle0.js.br",
+					"bundle0.js.gz",
+					"bundle0.js.zst"
+				]);
+			});
+		}
+	]
+};
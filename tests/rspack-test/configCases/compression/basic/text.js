@@ -0,0 +1 @@
+export default "rspack compression ".repeat(200);
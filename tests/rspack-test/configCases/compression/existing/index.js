@@ -0,0 +1,8 @@
+import fs from "fs";
+import path from "path";
+
+it("should not overwrite the existing assets", () => {
+	expect(
+		fs.readFileSync(path.resolve(path.dirname(__filename), "data.txt.gz"), "utf-8")
+	).toBe("precompressed");
+});
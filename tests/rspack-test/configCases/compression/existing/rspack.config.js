@@ -0,0 +1,20 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	plugins: [
+		new rspack.CompressionRspackPlugin({
+			test: /\.txt$/
+		}),
+		compiler => {
+			compiler.hooks.thisCompilation.tap("Test", compilation => {
+				compilation.hooks.processAssets.tap("Test", () => {
+					const { RawSource } = compiler.webpack.sources;
+					compilation.emitAsset("data.txt", new RawSource("a".repeat(10000)));
+					compilation.emitAsset("data.txt.gz", new RawSource("precompressed"));
+				});
+			});
+		}
+	]
+};
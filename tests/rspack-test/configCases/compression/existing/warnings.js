@@ -0,0 +1,3 @@
+module.exports = [
+	/Skip emitting data\.txt\.gz, as an asset with the same name already exists/
+];
@@ -0,0 +1,7 @@
+import fs from "fs";
+
+it("should skip the assets below the threshold or above the min ratio", () => {
+	expect(fs.existsSync(__filename + ".gz")).toBe(false);
+	expect(fs.existsSync(__filename.replace(/bundle0\.js$/, "large.txt"))).toBe(true);
+	expect(fs.existsSync(__filename.replace(/bundle0\.js$/, "large.txt.gz"))).toBe(false);
+});
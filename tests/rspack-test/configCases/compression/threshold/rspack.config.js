@@ -0,0 +1,25 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	plugins: [
+		new rspack.CompressionRspackPlugin({
+			threshold: 100000000
+		}),
+		new rspack.CompressionRspackPlugin({
+			test: /\.txt$/,
+			minRatio: 0
+		}),
+		compiler => {
+			compiler.hooks.thisCompilation.tap("Test", compilation => {
+				compilation.hooks.processAssets.tap("Test", () => {
+					compilation.emitAsset(
+						"large.txt",
+						new compiler.webpack.sources.RawSource("a".repeat(10000))
+					);
+				});
+			});
+		}
+	]
+};
@@ -0,0 +1,38 @@
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	plugins: [
+		new rspack.experiments.CssChunkingPlugin({
+			strict: false
+		}),
+		{
+			/**
+			 * @param {import("@rspack/core").Compiler} compiler
+			 */
+			apply(compiler) {
+				compiler.hooks.done.tap("PLUGIN", stats => {
+					// Test scenario:
+					// - `page1` imports a.css before b.css, `page2` imports them in the reverse order
+					// - In loose mode, CssChunkingPlugin merges a.css and b.css into one chunk, so one
+					//   of the pages loads them in the wrong order and the conflict is reported
+					const json = stats.toJson({ all: false, assets: true, warnings: true });
+					const cssAssets = json.assets.filter(asset =>
+						asset.name?.endsWith(".css")
+					);
+					expect(cssAssets.length).toBe(1);
+					const conflict =
+						/chunk group page[12]\nConflicting order between .*[ab]\.css and .*[ab]\.css/;
+					const conflicts = json.warnings.filter(warning =>
+						conflict.test(warning.message)
+					);
+					expect(conflicts.length).toBe(1);
+				});
+			}
+		}
+	],
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,4 @@
+module.exports = [
+	[/Conflicting order between .*[ab]\.css and .*[ab]\.css/],
+	[/Conflicting order between .*[ab]\.css and .*[ab]\.css/]
+];
@@ -0,0 +1,2 @@
+import(/* webpackChunkName: "page1" */ "./page1");
+import(/* webpackChunkName: "page2" */ "./page2");
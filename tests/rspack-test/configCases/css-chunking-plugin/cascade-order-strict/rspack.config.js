@@ -0,0 +1,29 @@
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	plugins: [
+		new rspack.experiments.CssChunkingPlugin({
+			strict: true
+		}),
+		{
+			/**
+			 * @param {import("@rspack/core").Compiler} compiler
+			 */
+			apply(compiler) {
+				compiler.hooks.done.tap("PLUGIN", stats => {
+					// Test scenario:
+					// - `page1` imports a.css before b.css, `page2` imports them in the reverse order
+					// - In strict mode, CssChunkingPlugin never merges modules that change the order
+					//   of any chunk group, so the cascade order is kept without warnings
+					const json = stats.toJson({ all: false, warnings: true });
+					expect(json.warnings).toEqual([]);
+				});
+			}
+		}
+	],
+	experiments: {
+		css: true
+	}
+};
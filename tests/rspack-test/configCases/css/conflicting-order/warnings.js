@@ -1 +1,4 @@
-module.exports = [[/Conflicting order between \.\/b\.css and \.\/c\.css/]];
+module.exports = [
+	[/Conflicting order between \.\/b\.css and \.\/c\.css/],
+	[/Conflicting order between \.\/b\.css and \.\/c\.css/]
+];
@@ -0,0 +1,54 @@
+import "./global.css";
+import * as named from "./named.module.css";
+import defaultStyles from "./default.module.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+const readDeclaration = name =>
+	fs.readFileSync(path.resolve(__dirname, "types", name), "utf-8");
+
+it("should write the declaration with named exports", () => {
+	expect(named.foo).toBeDefined();
+	expect(named["foo-bar"]).toBeDefined();
+	expect(readDeclaration("named.module.css.d.ts")).toBe(
+		[
+			"// This file is generated by Rspack, do not edit it manually.",
+			"export declare const foo: string;",
+			"declare const __css_export_1__: string;",
+			'export { __css_export_1__ as "foo-bar" };',
+			"declare const __css_export_2__: string;",
+			'export { __css_export_2__ as "class" };',
+			"export declare const primary: string;",
+			""
+		].join("\n")
+	);
+});
+
+it("should write the declaration with the default export and the exports convention", () => {
+	expect(defaultStyles.fooBar).toBe(defaultStyles["foo-bar"]);
+	expect(readDeclaration("default.module.css.d.ts")).toBe(
+		[
+			"// This file is generated by Rspack, do not edit it manually.",
+			"declare const styles: {",
+			'  readonly "foo-bar": string;',
+			"  readonly fooBar: string;",
+			"};",
+			"export default styles;",
+			""
+		].join("\n")
+	);
+});
+
+it("should not rewrite the declaration if the content is not changed", () => {
+	const { mtimeMs } = fs.statSync(
+		path.resolve(__dirname, "types", "default.module.css.d.ts")
+	);
+	expect(mtimeMs).toBe(new Date("2000-01-01T00:00:00Z").getTime());
+});
+
+it("should not write the declaration for global css", () => {
+	expect(fs.existsSync(path.resolve(__dirname, "types", "global.css.d.ts"))).toBe(
+		false
+	);
+});
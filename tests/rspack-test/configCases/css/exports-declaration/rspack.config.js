@@ -0,0 +1,59 @@
+const fs = require("fs");
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = (_, { testPath }) => {
+	const outputDir = path.resolve(testPath, "types");
+	// the declaration is up to date, which should not be rewritten
+	const declarationPath = path.resolve(outputDir, "default.module.css.d.ts");
+	fs.mkdirSync(outputDir, { recursive: true });
+	fs.writeFileSync(
+		declarationPath,
+		[
+			"// This file is generated by Rspack, do not edit it manually.",
+			"declare const styles: {",
+			'  readonly "foo-bar": string;',
+			"  readonly fooBar: string;",
+			"};",
+			"export default styles;",
+			""
+		].join("\n")
+	);
+	const time = new Date("2000-01-01T00:00:00Z");
+	fs.utimesSync(declarationPath, time, time);
+
+	return {
+		target: "web",
+		mode: "development",
+		module: {
+			generator: {
+				"css/auto": {
+					exportsDeclaration: { outputDir }
+				}
+			},
+			rules: [
+				{
+					test: /named\.module\.css$/,
+					type: "css/module",
+					generator: {
+						exportsDeclaration: { outputDir }
+					}
+				},
+				{
+					test: /default\.module\.css$/,
+					type: "css/module",
+					parser: {
+						namedExports: false
+					},
+					generator: {
+						exportsConvention: "camel-case",
+						exportsDeclaration: { outputDir }
+					}
+				}
+			]
+		},
+		experiments: {
+			css: true
+		}
+	};
+};
@@ -0,0 +1,47 @@
+import "./style.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should wrap the rules of nested @import with the conditions of every @import", async () => {
+	const css = (
+		await fs.promises.readFile(path.resolve(__dirname, "bundle0.css"), "utf-8")
+	).replace(/\s/g, "");
+
+	expect(css).toContain(
+		"@mediascreenand(min-width:400px){@media(orientation:portrait){.media-nested{color:red;}}}"
+	);
+	expect(css).toContain("@mediascreenand(min-width:400px){.media{color:red;}}");
+	expect(css).toContain(
+		"@supports(display:grid){@supports(display:flex){.supports-nested{color:red;}}}"
+	);
+	expect(css).toContain("@supports(display:grid){.supports{color:red;}}");
+	expect(css).toContain("@layerbase{@layernested{.layer-nested{color:red;}}}");
+	expect(css).toContain("@layerbase{.layer{color:red;}}");
+	expect(css).toContain(
+		"@mediaprint{@supports(display:flex){@layermixed{@layer{.mixed-nested{color:red;}}}}}"
+	);
+	expect(css).toContain(
+		"@mediaprint{@supports(display:flex){@layermixed{.mixed{color:red;}}}}"
+	);
+});
+
+it("should keep the imported rules before the rules of the importer", async () => {
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "bundle0.css"),
+		"utf-8"
+	);
+	const order = [...css.matchAll(/\.([a-z-]+) \{/g)].map(match => match[1]);
+
+	expect(order).toEqual([
+		"media-nested",
+		"media",
+		"supports-nested",
+		"supports",
+		"layer-nested",
+		"layer",
+		"mixed-nested",
+		"mixed",
+		"style"
+	]);
+});
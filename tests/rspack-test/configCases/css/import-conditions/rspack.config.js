@@ -0,0 +1,11 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	mode: "development",
+	node: {
+		__dirname: false
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,5 @@
+"use strict";
+
+module.exports = [
+	[/The value "missing" is not exported by "\.\/colors\.module\.css"/]
+];
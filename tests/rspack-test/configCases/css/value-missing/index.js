@@ -0,0 +1,11 @@
+import * as styles from "./style.module.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should keep the references of the missing values", () => {
+	expect(styles.primary).toBe("#0c77f8");
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css).toContain("color: #0c77f8;");
+	expect(css).toContain("border-color: missing;");
+});
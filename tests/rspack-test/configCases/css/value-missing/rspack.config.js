@@ -0,0 +1,12 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	mode: "development",
+	node: {
+		__dirname: false,
+		__filename: false
+	},
+	experiments: {
+		css: true
+	}
+};
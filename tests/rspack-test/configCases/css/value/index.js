@@ -0,0 +1,24 @@
+import * as styles from "./style.module.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should export the values to js", () => {
+	expect(styles.gap).toBe("8px");
+	expect(styles.padding).toBe("8px 8px");
+	expect(styles.secondary).toBe("red");
+	expect(styles.small).toBe("(max-width: 599px)");
+	expect(styles.brand).toBe("#0c77f8");
+});
+
+it("should substitute the values in css", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css).not.toContain("@value");
+	expect(css).toContain("color: red;");
+	expect(css).toContain("border-color: #0c77f8;");
+	expect(css).toContain("padding: 8px 8px;");
+	expect(css).toContain("box-shadow: 0 0 8px #secondary;");
+	expect(css).toContain("@media (max-width: 599px)");
+	expect(css).toContain("margin: 8px;");
+	expect(css).toContain("h1:hover");
+});
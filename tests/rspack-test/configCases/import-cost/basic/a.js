@@ -0,0 +1,4 @@
+import aOnly from "./a-only";
+import shared from "./shared";
+
+export default `${aOnly} ${shared} `;
@@ -0,0 +1,31 @@
+import fs from "fs";
+import path from "path";
+import a from "./a";
+import b from "./b";
+
+it("should report the retained size of modules", () => {
+	expect(a + b).toBe("a-only shared b shared");
+
+	const report = JSON.parse(
+		fs.readFileSync(path.resolve(path.dirname(__filename), "import-cost.json"), "utf-8")
+	);
+	const chunk = report.chunks.find(chunk => chunk.names.includes("main"));
+	const find = name => chunk.modules.find(module => module.name === name);
+	const [index, moduleA, aOnly, moduleB, shared] = [
+		"./index.js",
+		"./a.js",
+		"./a-only.js",
+		"./b.js",
+		"./shared.js"
+	].map(find);
+
+	// `a-only.js` is only imported by `a.js`, so it is retained by `a.js`
+	expect(aOnly.dominator).toBe("./a.js");
+	expect(moduleA.retainedSize).toBe(moduleA.size + aOnly.size);
+	// `shared.js` is imported by both `a.js` and `b.js`, so it is retained by `index.js`
+	expect(shared.dominator).toBe("./index.js");
+	expect(moduleB.retainedSize).toBe(moduleB.size);
+	expect(index.dominator).toBe(null);
+	expect(index.retainedSize).toBe(chunk.size);
+	expect(chunk.modules[0]).toBe(index);
+});
@@ -0,0 +1,10 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	optimization: {
+		concatenateModules: false
+	},
+	plugins: [new rspack.ImportCostRspackPlugin()]
+};
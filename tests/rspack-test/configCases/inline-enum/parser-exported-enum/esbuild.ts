@@ -0,0 +1,7 @@
+// the output of esbuild for `export enum F { A, B = -1, C = "c" }`
+export var F = /* @__PURE__ */ ((F2) => {
+  F2[F2["A"] = 0] = "A";
+  F2[F2["B"] = -1] = "B";
+  F2["C"] = "c";
+  return F2;
+})(F || {});
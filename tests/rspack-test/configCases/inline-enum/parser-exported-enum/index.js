@@ -0,0 +1,33 @@
+import { E } from "./swc";
+import { F } from "./esbuild";
+import { G } from "./tsc";
+
+const generated = /** @type {string} */ (__non_webpack_require__("fs").readFileSync(__filename, "utf-8"));
+
+it("should inline the enum collected by the parser", () => {
+  // START:A
+  expect(E.A).toBe(0);
+  expect(E.B).toBe(5);
+  expect(E.C).toBe("c");
+  // END:A
+  const block = generated.match(/\/\/ START:A([\s\S]*)\/\/ END:A/)[1];
+  expect(block.includes(`((/* inlined export .E.A */0)).toBe(0)`)).toBe(true);
+  expect(block.includes(`((/* inlined export .E.B */5)).toBe(5)`)).toBe(true);
+  expect(block.includes(`((/* inlined export .E.C */"c")).toBe("c")`)).toBe(true);
+})
+
+it("should inline the enum transpiled by other loaders", () => {
+  // START:B
+  expect(F.A).toBe(0);
+  expect(F.B).toBe(-1);
+  expect(F.C).toBe("c");
+  expect(G.A).toBe(0);
+  expect(G.B).toBe(1);
+  // END:B
+  const block = generated.match(/\/\/ START:B([\s\S]*)\/\/ END:B/)[1];
+  expect(block.includes(`((/* inlined export .F.A */0)).toBe(0)`)).toBe(true);
+  expect(block.includes(`((/* inlined export .F.B */-1)).toBe(-1)`)).toBe(true);
+  expect(block.includes(`((/* inlined export .F.C */"c")).toBe("c")`)).toBe(true);
+  expect(block.includes(`((/* inlined export .G.A */0)).toBe(0)`)).toBe(true);
+  expect(block.includes(`((/* inlined export .G.B */1)).toBe(1)`)).toBe(true);
+})
@@ -0,0 +1,35 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	resolve: {
+		extensions: [".ts", "..."]
+	},
+	module: {
+		parser: {
+			javascript: {
+				exportedEnum: true
+			}
+		},
+		rules: [
+			{
+				test: /swc\.ts$/,
+				use: [
+					{
+						loader: "builtin:swc-loader",
+						options: {
+							jsc: {
+								parser: {
+									syntax: "typescript"
+								},
+								target: "esnext"
+							}
+						}
+					}
+				]
+			}
+		]
+	},
+	optimization: {
+		moduleIds: "named",
+		concatenateModules: false
+	}
+};
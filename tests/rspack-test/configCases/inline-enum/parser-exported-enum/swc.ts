@@ -0,0 +1,5 @@
+export enum E {
+  A,
+  B = 5,
+  C = "c",
+}
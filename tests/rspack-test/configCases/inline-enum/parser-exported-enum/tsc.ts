@@ -0,0 +1,7 @@
+// the output of tsc for `enum G { A, B }` and `export { G }`
+var G;
+(function (G) {
+  G[G["A"] = 0] = "A";
+  G[G["B"] = 1] = "B";
+})(G || (G = {}));
+export { G };
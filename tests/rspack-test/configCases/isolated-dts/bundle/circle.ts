@@ -0,0 +1,11 @@
+import type { Shape } from "./shape";
+
+export default class Circle implements Shape {
+	width: number;
+	height: number;
+
+	constructor(public radius: number) {
+		this.width = radius * 2;
+		this.height = radius * 2;
+	}
+}
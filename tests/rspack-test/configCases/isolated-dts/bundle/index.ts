@@ -0,0 +1,32 @@
+import fs from "fs";
+import path from "path";
+import { add } from "./math";
+import type { Shape } from "./shape";
+
+export { add };
+export type { Shape };
+export * as utils from "./utils";
+export { default as Circle } from "./circle";
+
+export function area(shape: Shape): number {
+	return shape.width * shape.height;
+}
+
+it("should bundle the declarations of the entry", () => {
+	const dts = fs.readFileSync(path.resolve(__dirname, "bundle0.d.ts"), "utf-8");
+	expect(dts).toContain("declare function add(a: number, b: number): number;");
+	expect(dts).toContain("declare function area(shape: Shape): number;");
+	// the type only imports are not in the module graph
+	expect(dts).toContain("interface Shape {");
+	expect(dts).toContain("declare class Circle implements Shape {");
+	// the conflicted names are renamed
+	expect(dts).toContain("declare function format(value: number): string;");
+	expect(dts).toContain("declare function format$1(value: number): string;");
+	expect(dts).toMatch(
+		/declare namespace utils \{\s*export \{\s*VERSION,\s*clamp,\s*format\$1 as format\s*\};?\s*\}/
+	);
+	expect(dts).toMatch(/export \{\s*add,\s*Shape,\s*utils,\s*Circle,\s*area\s*\};/);
+	// the imports only used by the statements are removed
+	expect(dts).not.toContain("import");
+	expect(dts).not.toContain("should bundle");
+});
@@ -0,0 +1,7 @@
+function format(value: number): string {
+	return value.toFixed(2);
+}
+
+export function add(a: number, b: number): number {
+	return Number(format(a + b));
+}
@@ -0,0 +1,9 @@
+export const VERSION = "1.0.0";
+
+export function clamp(value: number, min = 0, max = 1): number {
+	return Math.min(Math.max(value, min), max);
+}
+
+export function format(value: number): string {
+	return `${value}`;
+}
@@ -0,0 +1,19 @@
+import fs from "fs";
+import path from "path";
+
+export { add } from "./lib/math.ts";
+export type { Options } from "./lib/options";
+
+it("should emit the declarations of each module", () => {
+	const read = (file: string) =>
+		fs.readFileSync(path.resolve(__dirname, file), "utf-8");
+	const index = read("index.d.ts");
+	expect(index).toContain('export { add } from "./lib/math.js";');
+	expect(index).toContain('export type { Options } from "./lib/options";');
+	expect(read("lib/math.d.ts")).toContain(
+		"export declare function add(a: number, b: number): number;"
+	);
+	// the type only dependencies are emitted as well
+	expect(read("lib/options.d.ts")).toContain("export interface Options {");
+	expect(fs.existsSync(path.resolve(__dirname, "bundle0.d.ts"))).toBe(false);
+});
@@ -0,0 +1,4 @@
+module.exports = [
+	/Parameter must have an explicit type annotation with --isolatedDeclarations\./,
+	/Function must have an explicit return type annotation with --isolatedDeclarations\./
+];
@@ -0,0 +1,11 @@
+import fs from "fs";
+import path from "path";
+
+export function double(value) {
+	return value * 2;
+}
+
+it("should emit the declarations with the violations typed as unknown", () => {
+	const dts = fs.readFileSync(path.resolve(__dirname, "bundle0.d.ts"), "utf-8");
+	expect(dts).toContain("declare function double(value: unknown): unknown;");
+});
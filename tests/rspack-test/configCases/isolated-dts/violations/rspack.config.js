@@ -0,0 +1,26 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.ts",
+	resolve: {
+		extensions: [".ts", "..."]
+	},
+	module: {
+		rules: [
+			{
+				test: /\.ts$/,
+				loader: "builtin:swc-loader",
+				options: {
+					jsc: {
+						parser: {
+							syntax: "typescript"
+						},
+						target: "esnext"
+					}
+				}
+			}
+		]
+	},
+	plugins: [new rspack.experiments.IsolatedDtsPlugin()]
+};
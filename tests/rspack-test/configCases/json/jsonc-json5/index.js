@@ -0,0 +1,19 @@
+import fs from "fs";
+import { compilerOptions } from "./tsconfig.jsonc";
+import { name, version, list } from "./data.json5";
+
+it("should parse jsonc", () => {
+	expect(compilerOptions).toEqual({ target: "es2022", strict: true });
+});
+
+it("should parse json5", () => {
+	expect(name).toBe("rspack");
+	expect(version).toBe(1.5);
+	expect(list).toEqual([1, 2, 3]);
+});
+
+it("should tree shake unused exports", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).not.toContain("jsonc-unused" + "-value");
+	expect(content).not.toContain("json5-unused" + "-value");
+});
@@ -0,0 +1,25 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	optimization: {
+		minimize: false
+	},
+	module: {
+		rules: [
+			{
+				test: /\.jsonc$/,
+				type: "json",
+				parser: {
+					syntax: "jsonc"
+				}
+			},
+			{
+				test: /\.json5$/,
+				type: "json",
+				parser: {
+					syntax: "json5"
+				}
+			}
+		]
+	}
+};
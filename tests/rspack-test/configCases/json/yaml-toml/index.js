@@ -0,0 +1,21 @@
+import fs from "fs";
+import yaml, { name, features, server } from "./data.yaml";
+import { title, released, server as tomlServer } from "./data.toml";
+
+it("should import yaml as json data", () => {
+	expect(name).toBe("rspack");
+	expect(features).toEqual(["yaml", "toml"]);
+	expect(server.port).toBe(8080);
+	expect(yaml.server.host).toBe("localhost");
+});
+
+it("should import toml as json data", () => {
+	expect(title).toBe("rspack");
+	expect(released).toBe("2024-01-01");
+	expect(tomlServer.port).toBe(8080);
+});
+
+it("should tree shake unused exports", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).not.toContain("toml-unused" + "-value");
+});
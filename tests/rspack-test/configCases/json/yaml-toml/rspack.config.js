@@ -0,0 +1,19 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	optimization: {
+		minimize: false
+	},
+	module: {
+		rules: [
+			{
+				test: /\.ya?ml$/,
+				type: "yaml"
+			},
+			{
+				test: /\.toml$/,
+				type: "toml"
+			}
+		]
+	}
+};
@@ -0,0 +1 @@
+export default "amet aliqua dolor sed sit et labore et incididunt adipiscing sit et lorem incididunt ut lorem labore sed elit aliqua sit eiusmod lorem lorem lorem magna lorem incididunt adipiscing ut lorem dolore elit labore et magna elit tempor elit elit labore do lorem ut magna sit consectetur do sit eiusmod dolore ut dolore adipiscing do do aliqua et dolore incididunt aliqua ipsum et elit incididunt ut consectetur tempor magna tempor dolor labore dolore sit consectetur dolore incididunt tempor et lorem et ipsum do aliqua aliqua incididunt consectetur consectetur dolore elit lorem adipiscing magna magna elit incididunt dolore tempor aliqua tempor labore sed magna lorem incididunt dolore amet dolore magna adipiscing ut ipsum et tempor aliqua magna adipiscing dolore ut et tempor ut tempor lorem magna magna eiusmod labore lorem elit consectetur magna aliqua consectetur dolor magna sed ipsum dolor dolor lorem labore lorem sed elit sed sit consectetur tempor do dolor consectetur consectetur sed dolore consectetur sed do labore eiusmod et et sit lorem do incididunt eiusmod ut adipiscing sed sit sed dolore adipiscing ut lorem elit lorem incididunt amet ipsum consectetur labore dolore ut magna elit dolore labore elit dolore lorem incididunt aliqua eiusmod ut ipsum do amet adipiscing ipsum do dolor dolor do do consectetur ut aliqua sed amet lorem magna ipsum aliqua adipiscing aliqua labore consectetur dolore ipsum incididunt adipiscing tempor sit adipiscing aliqua ut aliqua adipiscing et sit incididunt do dolore et lorem eiusmod incididunt do lorem consectetur adipiscing eiusmod aliqua amet eiusmod ut adipiscing sed sit incididunt magna tempor magna et magna elit dolor ipsum dolor amet consectetur consectetur magna adipiscing sed eiusmod dolore sed tempor eiusmod eiusmod sit do elit et amet aliqua magna sit eiusmod ipsum ut dolor incididunt amet amet eiusmod sit aliqua incididunt dolor aliqua magna elit aliqua dolor sed tempor do aliqua magna sit labore sed sit ipsum do lorem lorem dolor ut sit ipsum adipiscing elit aliqua ut consectetur sit labore consectetur elit consectetur sit ut incididunt magna do magna sed et eiusmod sit adipiscing eiusmod ipsum lorem lorem do eiusmod labore incididunt eiusmod incididunt dolor dolor eiusmod labore sit sed adipiscing magna et tempor sed consectetur magna adipiscing do adipiscing elit tempor dolor sed dolor labore dolor aliqua eiusmod elit incididunt do ipsum eiusmod consectetur eiusmod aliqua do elit eiusmod sit magna aliqua dolor elit elit lorem elit incididunt dolor sed magna dolor dolor lorem lorem do tempor";
@@ -0,0 +1,45 @@
+const fs = require("fs");
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = (_, { testPath }) => {
+	const baselinePath = path.resolve(testPath, "size-baseline.json");
+	fs.mkdirSync(testPath, { recursive: true });
+	fs.writeFileSync(
+		baselinePath,
+		JSON.stringify({
+			"entrypoint:main": { size: 100 },
+			"chunk:main": { size: 100000000 }
+		})
+	);
+	return {
+		optimization: {
+			concatenateModules: false
+		},
+		performance: {
+			hints: "warning",
+			budgets: [
+				// regressed compared to the baseline
+				{ entrypoint: "main", maxSize: 100000000 },
+				// the max size is still enforced for the targets in the baseline
+				{ chunk: "main", maxSize: 100 },
+				// not recorded in the baseline, fallback to the max size
+				{ asset: "*.js", maxSize: 100000000 }
+			],
+			baseline: {
+				path: baselinePath,
+				threshold: 10
+			}
+		},
+		plugins: [
+			compiler => {
+				compiler.hooks.done.tap("Test", () => {
+					const baseline = JSON.parse(fs.readFileSync(baselinePath, "utf-8"));
+					// the baseline is not updated
+					expect(baseline["entrypoint:main"].size).toBe(100);
+					expect(baseline["asset:bundle0.js"]).toBeUndefined();
+				});
+			}
+		]
+	};
+};
@@ -0,0 +1,5 @@
+module.exports = [
+	/size budget: entrypoint "main" size regressed by [\d.]+% compared to the baseline \(100\.000 bytes -> [^)]+\), which exceeds the allowed 10%/,
+	/size budget: chunk "main" exceeds the size budget \([^)]+ > 100\.000 bytes\)/,
+	/You can limit the size of your bundles by using import\(\)/
+];
@@ -0,0 +1,5 @@
+import big from "./big";
+
+it("should bundle the big module", () => {
+	expect(big.length).toBeGreaterThan(1000);
+});
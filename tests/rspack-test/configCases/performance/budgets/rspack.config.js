@@ -0,0 +1,43 @@
+const fs = require("fs");
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = (_, { testPath }) => {
+	const baselinePath = path.resolve(testPath, "size-baseline.json");
+	fs.rmSync(baselinePath, { force: true });
+	return {
+		optimization: {
+			concatenateModules: false
+		},
+		performance: {
+			hints: "warning",
+			budgets: [
+				{ entrypoint: "main", maxSize: 100 },
+				{ asset: "*.js", maxGzipSize: 100 },
+				{ chunk: "main", maxBrotliSize: 100000000 }
+			],
+			baseline: {
+				path: baselinePath
+			}
+		},
+		plugins: [
+			compiler => {
+				compiler.hooks.done.tap("Test", () => {
+					const baseline = JSON.parse(fs.readFileSync(baselinePath, "utf-8"));
+					expect(Object.keys(baseline)).toEqual([
+						"asset:bundle0.js",
+						"chunk:main",
+						"entrypoint:main"
+					]);
+					expect(baseline["asset:bundle0.js"].gzipSize).toBeGreaterThan(100);
+					expect(baseline["chunk:main"].brotliSize).toBeLessThan(
+						baseline["chunk:main"].size
+					);
+					expect(baseline["entrypoint:main"].size).toBe(
+						baseline["chunk:main"].size
+					);
+				});
+			}
+		]
+	};
+};
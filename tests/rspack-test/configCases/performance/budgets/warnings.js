@@ -0,0 +1,5 @@
+module.exports = [
+	/size budget: entrypoint "main" exceeds the size budget[\s\S]*Largest modules:\n  \.\/big\.js/,
+	/size budget: asset "bundle0\.js" exceeds the gzip size budget/,
+	/You can limit the size of your bundles by using import\(\)/
+];
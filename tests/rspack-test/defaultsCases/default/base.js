@@ -34,6 +34,7 @@ module.exports = {
 			      chunksRuntimeRequirements: true,
 			      dependenciesDiagnostics: true,
 			      emitAssets: true,
+			      explain: false,
 			      inferAsyncModules: true,
 			      make: true,
 			      moduleIds: true,
@@ -211,6 +212,7 @@ module.exports = {
 			        dynamicImportMode: lazy,
 			        dynamicImportPrefetch: false,
 			        dynamicImportPreload: false,
+			        exportedEnum: false,
 			        exprContextCritical: true,
 			        importDynamic: true,
 			        importMeta: true,
@@ -82,12 +82,14 @@ module.exports = {
 			+       "css/auto": Object {
 			+         "esModule": true,
 			+         "exportsConvention": "as-is",
+			+         "exportsDeclaration": false,
 			+         "exportsOnly": false,
 			+         "localIdentName": "[fullhash]",
 			+       },
 			+       "css/module": Object {
 			+         "esModule": true,
 			+         "exportsConvention": "as-is",
+			+         "exportsDeclaration": false,
 			+         "exportsOnly": false,
 			+         "localIdentName": "[fullhash]",
 			+       },
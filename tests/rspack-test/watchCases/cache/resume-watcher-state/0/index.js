@@ -0,0 +1,5 @@
+import value from "./file";
+
+it("should build with the resumed changes", () => {
+	expect(value).toBe(WATCH_STEP === "0" ? 1 : 2);
+});
@@ -0,0 +1,52 @@
+const path = require("node:path");
+const fs = require("node:fs");
+
+class ResumeWatcherStatePlugin {
+	constructor() {
+		this.compileCount = 0;
+	}
+	apply(compiler) {
+		const stateFile = path.join(compiler.context, ".watcher/state.json");
+		const changedFile = path.join(compiler.context, "file.js");
+		const removedFile = path.join(compiler.context, "removed.js");
+
+		compiler.hooks.watchRun.tap(ResumeWatcherStatePlugin.name, () => {
+			if (this.compileCount > 0) {
+				return;
+			}
+			// simulate the state saved by the watcher of previous process
+			compiler.watching.watchOptions.stateFile = stateFile;
+			fs.mkdirSync(path.dirname(stateFile), { recursive: true });
+			fs.writeFileSync(
+				stateFile,
+				JSON.stringify({
+					since: Date.now() - 60 * 1000,
+					files: [changedFile, removedFile],
+					directories: [],
+					missing: []
+				})
+			);
+		});
+
+		compiler.hooks.done.tap(ResumeWatcherStatePlugin.name, () => {
+			if (this.compileCount === 0) {
+				expect(Array.from(compiler.modifiedFiles)).toEqual([changedFile]);
+				expect(Array.from(compiler.removedFiles)).toEqual([removedFile]);
+			} else {
+				// the state is saved again when the watcher starts
+				const state = JSON.parse(fs.readFileSync(stateFile, "utf-8"));
+				expect(state.files).toContain(changedFile);
+				expect(state.files).not.toContain(removedFile);
+			}
+			this.compileCount++;
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [new ResumeWatcherStatePlugin()],
+	experiments: {
+		nativeWatcher: true
+	}
+};
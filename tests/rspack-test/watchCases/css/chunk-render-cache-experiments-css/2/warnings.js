@@ -1,3 +1,4 @@
 module.exports = [
   /Conflicting order between/,
+  /Conflicting order between/,
 ]
@@ -0,0 +1,16 @@
+import * as a from "./a.module.css";
+import * as b from "./b.module.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+const declarationPath = name => path.resolve(__dirname, "types", name);
+
+it("should write the declarations of the css modules", () => {
+	expect(a.a).toBeDefined();
+	expect(b.b).toBeDefined();
+	expect(fs.readFileSync(declarationPath("a.module.css.d.ts"), "utf-8")).toContain(
+		"export declare const a: string;"
+	);
+	expect(fs.existsSync(declarationPath("b.module.css.d.ts"))).toBe(true);
+});
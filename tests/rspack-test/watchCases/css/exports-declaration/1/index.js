@@ -0,0 +1,14 @@
+import * as a from "./a.module.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+const declarationPath = name => path.resolve(__dirname, "types", name);
+
+it("should update the changed declarations and remove the stale ones", () => {
+	expect(a.c).toBeDefined();
+	expect(fs.readFileSync(declarationPath("a.module.css.d.ts"), "utf-8")).toContain(
+		"export declare const c: string;"
+	);
+	expect(fs.existsSync(declarationPath("b.module.css.d.ts"))).toBe(false);
+});
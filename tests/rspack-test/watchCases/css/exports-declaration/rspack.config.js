@@ -0,0 +1,17 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = (_, { testPath }) => ({
+	target: "web",
+	mode: "development",
+	module: {
+		generator: {
+			"css/auto": {
+				exportsDeclaration: { outputDir: path.resolve(testPath, "types") }
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+});
@@ -0,0 +1,45 @@
+import a from "./a";
+import c from "./c";
+
+it("should explain why the modules are rebuilt", () => {
+	const { incremental } = __STATS__;
+	switch (WATCH_STEP) {
+		case "0":
+			expect(a).toBe("b0");
+			// the explanation is only recorded for rebuilds
+			expect(incremental).toBeUndefined();
+			break;
+		case "1": {
+			expect(a).toBe("b1");
+			expect(c).toBe("c");
+			expect(incremental.changedFiles).toEqual([
+				expect.stringMatching(/b\.js$/)
+			]);
+			const invalidated = incremental.invalidatedModules.map(
+				module => module.identifier
+			);
+			expect(invalidated).toContain("./b.js");
+			expect(invalidated).not.toContain("./c.js");
+			const b = incremental.invalidatedModules.find(
+				module => module.identifier === "./b.js"
+			);
+			expect(b.kind).toBe("update");
+			expect(b.files).toEqual(incremental.changedFiles);
+			for (const module of incremental.affectedModules) {
+				expect(module.identifier).not.toBe("./c.js");
+			}
+			expect(incremental.affectedChunks).toContain("main");
+
+			const passes = Object.fromEntries(
+				incremental.passes.map(pass => [pass.name, pass])
+			);
+			expect(passes.make.incremental).toBe(true);
+			expect(passes.make.affected).toBe(invalidated.length);
+			expect(passes.buildChunkGraph.incremental).toBe(false);
+			expect(passes.buildChunkGraph.reason).toBe(
+				"incremental.buildChunkGraph is disabled by options"
+			);
+			break;
+		}
+	}
+});
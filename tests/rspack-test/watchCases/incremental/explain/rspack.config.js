@@ -0,0 +1,9 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	experiments: {
+		incremental: {
+			explain: true,
+			buildChunkGraph: false
+		}
+	}
+};
@@ -0,0 +1,25 @@
+import fs from "fs";
+import path from "path";
+import { area } from "./math";
+import type { Shape } from "./shape";
+
+export { area };
+
+export function describe(shape: Shape): string {
+	return `area: ${area(shape.width, shape.width)}`;
+}
+
+it("should update the declarations of the changed type only modules", () => {
+	const dts = fs.readFileSync(path.resolve(__dirname, "bundle.d.ts"), "utf-8");
+	expect(dts).toContain("declare function area(width: number, height: number): number;");
+	expect(dts).toContain("declare function describe(shape: Shape): string;");
+	expect(dts).toContain("width: number;");
+	switch (WATCH_STEP) {
+		case "0":
+			expect(dts).not.toContain("height: number;");
+			break;
+		case "1":
+			expect(dts).toContain("height: number;");
+			break;
+	}
+});